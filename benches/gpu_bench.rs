@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use psrx::core::GPU;
+use std::hint::black_box;
+
+/// Build a frame's worth of GP0 words: shaded and textured triangles
+/// scattered over a 320x240 framebuffer
+fn build_frame(triangles: u32) -> Vec<u32> {
+    let mut words = Vec::new();
+
+    // 64x64 15-bit texture at (512, 0)
+    words.extend([0xA0000000, 0x00000200, 0x00400040]);
+    words.extend((0..64 * 64 / 2).map(|i| 0x7FFF_0000 | (i & 0x7FFF)));
+
+    words.extend([0xE3000000, 0xE4000000 | (239 << 10) | 319, 0xE5000000]);
+    words.push(0xE1000000 | (1 << 9) | 8 | (2 << 7));
+
+    for i in 0..triangles {
+        let x = (i * 37) % 260;
+        let y = (i * 23) % 180;
+        let vertex = |dx: u32, dy: u32| ((y + dy) << 16) | (x + dx);
+
+        if i % 2 == 0 {
+            // Gouraud-shaded triangle
+            words.extend([
+                0x300000FF,
+                vertex(0, 0),
+                0x0000FF00,
+                vertex(60, 0),
+                0x00FF0000,
+                vertex(30, 60),
+            ]);
+        } else {
+            // Textured triangle
+            let texpage = 8 | (2 << 7);
+            words.extend([
+                0x24808080,
+                vertex(0, 0),
+                0x00000000,
+                vertex(60, 0),
+                (texpage << 16) | 0x003F,
+                vertex(30, 60),
+                0x00003F20,
+            ]);
+        }
+    }
+
+    words
+}
+
+fn gpu_rendering_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gpu_rendering");
+    let frame = build_frame(500);
+
+    for threaded in [false, true] {
+        let mode = if threaded { "threaded" } else { "synchronous" };
+        group.bench_with_input(BenchmarkId::new("frame", mode), &frame, |b, frame| {
+            let mut gpu = GPU::new();
+            gpu.set_threaded_rendering(threaded);
+
+            b.iter(|| {
+                for &word in frame {
+                    gpu.write_gp0(black_box(word));
+                }
+                // Wait for the frame to finish, as at VBlank
+                gpu.sync_renderer();
+                black_box(gpu.read_vram(0, 0));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, gpu_rendering_benchmark);
+criterion_main!(benches);
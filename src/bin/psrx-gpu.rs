@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GPU debugging tools
+//!
+//! Works on GPU capture files recorded with `GPU::start_capture`, so
+//! rendering problems can be inspected without the disc or BIOS. Image
+//! exports show VRAM after replaying the capture, or at its start with
+//! `--start`.
+
+use clap::{Args as ClapArgs, Parser, Subcommand};
+use log::info;
+use psrx::core::gpu::{
+    GpuCapture, GpuCommandDisassembler, TextureDepth, TextureInfo, VramImage, GPU,
+};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+/// PlayStation GPU capture tools
+#[derive(Parser)]
+#[command(name = "psrx-gpu")]
+#[command(about = "Inspect PlayStation GPU captures", long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Disassemble the GP0/GP1 command stream of a capture
+    Disasm {
+        /// Path to a GPU capture file
+        capture: String,
+
+        /// Write the listing to a file instead of stdout
+        #[arg(short = 'o', long)]
+        output: Option<String>,
+    },
+
+    /// Export the full 1024x512 VRAM as a PNG or PPM image
+    Vram {
+        #[command(flatten)]
+        source: Source,
+
+        /// Output image (.png or .ppm)
+        output: String,
+    },
+
+    /// Decode a texture page through a CLUT into a 256x256 image
+    Texpage {
+        #[command(flatten)]
+        source: Source,
+
+        /// Output image (.png or .ppm)
+        output: String,
+
+        /// Texture page X in VRAM pixels (multiple of 64)
+        #[arg(long, default_value_t = 0)]
+        page_x: u16,
+
+        /// Texture page Y in VRAM pixels (0 or 256)
+        #[arg(long, default_value_t = 0)]
+        page_y: u16,
+
+        /// Texel depth in bits (4, 8 or 15)
+        #[arg(long, default_value = "4", value_parser = parse_depth)]
+        depth: TextureDepth,
+
+        /// CLUT X in VRAM pixels (multiple of 16)
+        #[arg(long, default_value_t = 0)]
+        clut_x: u16,
+
+        /// CLUT Y in VRAM pixels
+        #[arg(long, default_value_t = 0)]
+        clut_y: u16,
+    },
+
+    /// Export all CLUTs in VRAM as palettes, one per image line
+    Palettes {
+        #[command(flatten)]
+        source: Source,
+
+        /// Output image (.png or .ppm)
+        output: String,
+
+        /// Palette size as texel depth in bits (4 or 8)
+        #[arg(long, default_value = "4", value_parser = parse_depth)]
+        depth: TextureDepth,
+
+        /// Size of each color swatch in pixels
+        #[arg(long, default_value_t = 8)]
+        swatch: u32,
+    },
+}
+
+/// Capture to take VRAM from
+#[derive(ClapArgs)]
+struct Source {
+    /// Path to a GPU capture file
+    capture: String,
+
+    /// Use VRAM at the start of the capture instead of after replaying it
+    #[arg(long)]
+    start: bool,
+}
+
+impl Source {
+    /// Load the capture and rebuild the GPU state to export from
+    fn load(&self) -> Result<GPU, Box<dyn std::error::Error>> {
+        let mut capture = GpuCapture::load_from_file(&self.capture)?;
+        if self.start {
+            capture.events.clear();
+        }
+        Ok(capture.replay())
+    }
+}
+
+/// Parse a texel depth given in bits
+fn parse_depth(s: &str) -> Result<TextureDepth, String> {
+    match s {
+        "4" => Ok(TextureDepth::T4Bit),
+        "8" => Ok(TextureDepth::T8Bit),
+        "15" | "16" => Ok(TextureDepth::T15Bit),
+        _ => Err(format!("invalid depth '{}' (expected 4, 8 or 15)", s)),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Keep the tool's own messages; GPU command logging is too chatty here.
+    // RUST_LOG still overrides both.
+    env_logger::Builder::new()
+        .filter_level(log::LevelFilter::Info)
+        .filter_module("psrx::core", log::LevelFilter::Warn)
+        .parse_default_env()
+        .init();
+
+    let args = Args::parse();
+
+    match args.command {
+        Command::Disasm { capture, output } => {
+            let capture = GpuCapture::load_from_file(&capture)?;
+            info!(
+                "Loaded capture: {} events, {} frames",
+                capture.events.len(),
+                capture.frames()
+            );
+
+            let mut out: Box<dyn Write> = match output {
+                Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+                None => Box::new(BufWriter::new(io::stdout().lock())),
+            };
+            for line in GpuCommandDisassembler::disassemble_capture(&capture) {
+                writeln!(out, "{}", line)?;
+            }
+            out.flush()?;
+        }
+        Command::Vram { source, output } => {
+            source.load()?.export_vram().save(&output)?;
+            info!("VRAM written to {}", output);
+        }
+        Command::Texpage {
+            source,
+            output,
+            page_x,
+            page_y,
+            depth,
+            clut_x,
+            clut_y,
+        } => {
+            let texture = TextureInfo {
+                page_x,
+                page_y,
+                clut_x,
+                clut_y,
+                depth,
+            };
+            source.load()?.export_texture_page(&texture).save(&output)?;
+            info!(
+                "Texture page ({}, {}) written to {}",
+                page_x, page_y, output
+            );
+        }
+        Command::Palettes {
+            source,
+            output,
+            depth,
+            swatch,
+        } => {
+            let palettes = source.load()?.clut_palettes(depth);
+            if palettes.is_empty() {
+                return Err("no palettes found in VRAM".into());
+            }
+            VramImage::from_palettes(&palettes, swatch).save(&output)?;
+            for (line, palette) in palettes.iter().enumerate() {
+                println!("{:4}: clut=({},{})", line, palette.x, palette.y);
+            }
+            info!("{} palettes written to {}", palettes.len(), output);
+        }
+    }
+
+    Ok(())
+}
@@ -19,10 +19,12 @@
 //! It displays the GPU framebuffer in real-time and provides an FPS counter.
 
 use clap::Parser;
-use log::{error, info};
+use log::{error, info, warn};
+use psrx::core::gpu::{CropMode, Deinterlace, RendererKind, TextureReplacementConfig};
 use psrx::core::system::System;
 use psrx::frontend::Frontend;
 use std::env;
+use std::path::PathBuf;
 
 /// PlayStation (PSX) emulator with UI
 #[derive(Parser)]
@@ -35,6 +37,51 @@ struct Args {
     /// Path to CD-ROM disc image (.cue file)
     #[arg(short = 'c', long)]
     cdrom: Option<String>,
+
+    /// Enable precision geometry (sub-pixel vertices, perspective-correct textures)
+    #[arg(long)]
+    pgxp: bool,
+
+    /// Enable the widescreen hack (16:9 projection and widened drawing
+    /// areas, toggle at runtime with F9)
+    #[arg(long)]
+    widescreen: bool,
+
+    /// Disable ordered dithering (cleaner true-color shading)
+    #[arg(long)]
+    no_dither: bool,
+
+    /// Deinterlacing for 480-line interlaced output (weave, bob or blend)
+    #[arg(long, default_value = "weave")]
+    deinterlace: Deinterlace,
+
+    /// Show the full overscan area with borders instead of cropping to the picture
+    #[arg(long)]
+    overscan: bool,
+
+    /// Emulate the GPU texture cache (needed by a few games, slightly slower)
+    #[arg(long)]
+    texture_cache: bool,
+
+    /// Rasterize on a separate GPU thread
+    #[arg(long)]
+    threaded_gpu: bool,
+
+    /// Internal resolution scale factor (1 = native, up to 8)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..=8))]
+    scale: u16,
+
+    /// GPU rendering backend (software, null or recording)
+    #[arg(long, default_value = "software")]
+    renderer: RendererKind,
+
+    /// Dump every texture drawn to this directory as PNG files
+    #[arg(long, value_name = "DIR")]
+    dump_textures: Option<PathBuf>,
+
+    /// Load replacement textures from this directory (used with --scale above 1)
+    #[arg(long, value_name = "DIR")]
+    texture_dir: Option<PathBuf>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -60,7 +107,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Loading BIOS from: {}", args.bios_file);
 
     // Create and initialize system
-    let mut system = System::new();
+    let mut system = System::with_renderer(args.renderer.create());
 
     // Load BIOS
     if let Err(e) = system.load_bios(&args.bios_file) {
@@ -70,6 +117,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("BIOS loaded successfully");
 
+    if args.pgxp {
+        system.set_precision_geometry(true);
+    }
+    if args.widescreen {
+        system.set_widescreen(true);
+    }
+    if args.no_dither {
+        system.set_dithering(false);
+    }
+    system.set_deinterlace_mode(args.deinterlace);
+    if args.overscan {
+        system.set_crop_mode(CropMode::Overscan);
+    }
+    if args.texture_cache {
+        system.set_texture_cache(true);
+    }
+    if args.threaded_gpu {
+        system.set_threaded_rendering(true);
+    }
+    if args.scale > 1 {
+        if let Err(e) = system.set_resolution_scale(args.scale) {
+            warn!("Resolution scaling unavailable: {}", e);
+        }
+    }
+    if args.texture_dir.is_some() && args.scale == 1 {
+        warn!("Texture replacements are only drawn with --scale above 1");
+    }
+    let textures = TextureReplacementConfig {
+        dump_dir: args.dump_textures,
+        replacement_dir: args.texture_dir,
+    };
+    if textures.is_enabled() {
+        if let Err(e) = system.set_texture_replacement(textures) {
+            warn!("Texture replacement unavailable: {}", e);
+        }
+    }
+
     // Load CD-ROM disc if specified
     if let Some(cdrom_path) = &args.cdrom {
         info!("Loading CD-ROM disc from: {}", cdrom_path);
@@ -30,9 +30,17 @@ struct Args {
     #[arg(short = 'c', long)]
     cdrom: Option<String>,
 
+    /// Path to a PSX-EXE file to sideload once the BIOS reaches the shell
+    #[arg(short = 'e', long)]
+    exe: Option<String>,
+
     /// Number of instructions to execute
     #[arg(short = 'n', long, default_value = "100000")]
     instructions: usize,
+
+    /// Arguments passed to the sideloaded executable (argc/argv), after `--`
+    #[arg(last = true, requires = "exe")]
+    exe_args: Vec<String>,
 }
 
 fn main() -> Result<()> {
@@ -79,6 +87,15 @@ fn main() -> Result<()> {
     info!("Starting emulation...");
     system.reset();
 
+    // Sideload executable if provided (runs the BIOS up to the shell first)
+    if let Some(exe_path) = &args.exe {
+        info!("Loading executable from: {}", exe_path);
+        if let Err(e) = system.load_exe_with_args(exe_path, &args.exe_args) {
+            error!("Failed to load executable: {}", e);
+            return Err(e);
+        }
+    }
+
     // Run for specified number of instructions
     let total_instructions = args.instructions;
     let log_interval = (total_instructions / 10).max(1); // Log ~10 times during execution
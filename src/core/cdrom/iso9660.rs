@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ISO9660 filesystem support
+//!
+//! PlayStation discs use a standard ISO9660 filesystem stored in Mode 2
+//! Form 1 sectors. This module contains the on-disc structure parsing used
+//! by [`CDROM::read_file`](super::CDROM::read_file) to locate files such as
+//! `SYSTEM.CNF` and the boot executable.
+//!
+//! # Disc Layout
+//!
+//! - Sectors 0-15: System area (license data, unused by the filesystem)
+//! - Sector 16: Primary Volume Descriptor (PVD)
+//! - Sector 17+: Volume descriptor set terminator, path tables, directories
+//!
+//! Each raw 2352-byte Mode 2 sector stores 2048 bytes of user data at
+//! offset 24 (12 sync + 4 header + 8 subheader bytes).
+//!
+//! # References
+//!
+//! - [PSX-SPX: CDROM ISO Volume Descriptors](https://psx-spx.consoledev.net/cdromdrive/#cdrom-iso-volume-descriptors)
+//! - [PSX-SPX: CDROM ISO File and Directory Descriptors](https://psx-spx.consoledev.net/cdromdrive/#cdrom-iso-file-and-directory-descriptors)
+
+use crate::core::error::CdRomError;
+
+/// LBA of the Primary Volume Descriptor
+pub(super) const PVD_LBA: u32 = 16;
+
+/// Offset of the user data area within a raw Mode 2 sector
+pub(super) const USER_DATA_OFFSET: usize = 24;
+
+/// Size of the user data area of a Mode 2 Form 1 sector
+pub(super) const USER_DATA_SIZE: usize = 2048;
+
+/// Offset of the root directory record within the PVD
+const ROOT_RECORD_OFFSET: usize = 156;
+
+/// Directory record flag: entry is a directory
+const FLAG_DIRECTORY: u8 = 0x02;
+
+/// A single ISO9660 directory record
+///
+/// Only the fields needed to locate and read files are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DirectoryRecord {
+    /// First sector (LBA) of the file or directory extent
+    pub lba: u32,
+
+    /// Size of the extent in bytes
+    pub size: u32,
+
+    /// True if this record describes a directory
+    pub is_dir: bool,
+
+    /// Identifier with any `;1` version suffix removed
+    pub name: String,
+}
+
+impl DirectoryRecord {
+    /// Parse a directory record from the start of `data`
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Bytes starting at the record's length byte
+    ///
+    /// # Returns
+    ///
+    /// - `Some(DirectoryRecord)` if the record is well-formed
+    /// - `None` if the record is truncated or has a zero length
+    pub(super) fn parse(data: &[u8]) -> Option<Self> {
+        let length = *data.first()? as usize;
+        if length < 34 || data.len() < length {
+            return None;
+        }
+
+        let lba = u32::from_le_bytes(data[2..6].try_into().ok()?);
+        let size = u32::from_le_bytes(data[10..14].try_into().ok()?);
+        let is_dir = data[25] & FLAG_DIRECTORY != 0;
+        let name_len = data[32] as usize;
+        if 33 + name_len > length {
+            return None;
+        }
+
+        let raw_name = &data[33..33 + name_len];
+        let name = match raw_name {
+            // Special identifiers for "." and ".."
+            [0x00] => ".".to_string(),
+            [0x01] => "..".to_string(),
+            _ => strip_version(&String::from_utf8_lossy(raw_name)).to_string(),
+        };
+
+        Some(Self {
+            lba,
+            size,
+            is_dir,
+            name,
+        })
+    }
+}
+
+/// Parse the root directory record from a Primary Volume Descriptor
+///
+/// # Arguments
+///
+/// * `pvd` - 2048 bytes of user data from sector 16
+///
+/// # Returns
+///
+/// - `Ok(DirectoryRecord)` describing the root directory
+/// - `Err(CdRomError)` if the sector is not a valid PVD
+pub(super) fn parse_root_record(pvd: &[u8]) -> Result<DirectoryRecord, CdRomError> {
+    if pvd.len() < ROOT_RECORD_OFFSET + 34 || pvd[0] != 0x01 || &pvd[1..6] != b"CD001" {
+        return Err(CdRomError::ReadError {
+            sector: PVD_LBA,
+            reason: "Invalid ISO9660 primary volume descriptor".to_string(),
+        });
+    }
+
+    DirectoryRecord::parse(&pvd[ROOT_RECORD_OFFSET..]).ok_or(CdRomError::ReadError {
+        sector: PVD_LBA,
+        reason: "Invalid root directory record".to_string(),
+    })
+}
+
+/// Parse all records of a directory extent
+///
+/// Directory records never cross sector boundaries; the remainder of a
+/// sector after the last record is zero-padded. A zero length byte
+/// therefore means "skip to the next sector".
+///
+/// # Arguments
+///
+/// * `data` - Concatenated user data of all sectors of the directory
+///
+/// # Returns
+///
+/// All records in the directory, excluding `.` and `..`
+pub(super) fn parse_directory(data: &[u8]) -> Vec<DirectoryRecord> {
+    let mut records = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        let length = data[offset] as usize;
+
+        if length == 0 {
+            // Padding: continue at the next sector boundary
+            offset = (offset / USER_DATA_SIZE + 1) * USER_DATA_SIZE;
+            continue;
+        }
+
+        match DirectoryRecord::parse(&data[offset..]) {
+            Some(record) => {
+                if record.name != "." && record.name != ".." {
+                    records.push(record);
+                }
+            }
+            None => break,
+        }
+
+        offset += length;
+    }
+
+    records
+}
+
+/// Split a PlayStation file path into normalized path components
+///
+/// Accepts the forms found in `SYSTEM.CNF` (`cdrom:\DIR\FILE.EXE;1`,
+/// `cdrom:FILE.EXE;1`) as well as plain names (`SYSTEM.CNF;1`). Any text
+/// after the first whitespace (e.g. boot arguments) is ignored.
+///
+/// # Arguments
+///
+/// * `path` - File path to normalize
+///
+/// # Returns
+///
+/// Upper-case path components with version suffixes removed
+///
+/// # Example
+///
+/// ```text
+/// "cdrom:\\DATA\\MAIN.EXE;1" -> ["DATA", "MAIN.EXE"]
+/// ```
+pub(super) fn split_path(path: &str) -> Vec<String> {
+    let path = path.split_whitespace().next().unwrap_or("");
+    let path = match path.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("cdrom:") => &path[6..],
+        _ => path,
+    };
+
+    path.split(['\\', '/'])
+        .filter(|component| !component.is_empty())
+        .map(|component| strip_version(component).to_ascii_uppercase())
+        .collect()
+}
+
+/// Remove the `;N` version suffix from an ISO9660 identifier
+///
+/// A trailing `.` left over from extension-less names (`FILE.;1`) is
+/// removed as well.
+fn strip_version(name: &str) -> &str {
+    let name = name.split(';').next().unwrap_or(name);
+    name.strip_suffix('.').unwrap_or(name)
+}
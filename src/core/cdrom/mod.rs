@@ -80,8 +80,9 @@ use super::timing::{EventHandle, TickCount};
 pub mod cd_audio;
 mod commands;
 mod disc;
+mod iso9660;
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 pub use cd_audio::CDAudio;
 pub use disc::{DiscImage, Track, TrackType};
@@ -764,29 +765,21 @@ impl CDROM {
         self.position = position;
     }
 
-    /// Read a file from the disc (simplified ISO9660 implementation)
+    /// Read a file from the disc's ISO9660 filesystem
     ///
-    /// This is a simplified implementation that can read specific files
-    /// from the PlayStation disc. It handles the special case of SYSTEM.CNF
-    /// which is typically located at a known sector.
+    /// Locates the file by reading the Primary Volume Descriptor at sector 16
+    /// and walking the directory tree from the root directory. Directory
+    /// extents spanning multiple sectors are supported, and names are matched
+    /// case-insensitively with the `;1` version suffix ignored.
     ///
     /// # Arguments
     ///
-    /// * `filename` - File name to read (e.g., "SYSTEM.CNF;1")
+    /// * `filename` - File path to read (e.g., "SYSTEM.CNF;1" or "cdrom:\DIR\GAME.EXE;1")
     ///
     /// # Returns
     ///
-    /// - `Ok(Vec<u8>)` - File data
-    /// - `Err(CdRomError)` - If file not found or disc not loaded
-    ///
-    /// # Implementation Note
-    ///
-    /// This is a simplified implementation. A full ISO9660 parser would:
-    /// 1. Read the Primary Volume Descriptor at sector 16
-    /// 2. Parse the root directory
-    /// 3. Search for the file in the directory tree
-    ///
-    /// For now, we use known sector locations for common files.
+    /// - `Ok(Vec<u8>)` - File data (exactly the size recorded in the directory)
+    /// - `Err(CdRomError)` - If file not found, disc not loaded, or the image is truncated
     ///
     /// # Example
     ///
@@ -794,45 +787,82 @@ impl CDROM {
     /// use psrx::core::cdrom::CDROM;
     ///
     /// let mut cdrom = CDROM::new();
-    /// // cdrom.load_disc("game.cue").unwrap();
-    /// // let system_cnf = cdrom.read_file("SYSTEM.CNF;1").unwrap();
+    /// cdrom.load_disc("game.cue").unwrap();
+    /// let system_cnf = cdrom.read_file("SYSTEM.CNF;1").unwrap();
     /// ```
     pub fn read_file(&mut self, filename: &str) -> Result<Vec<u8>, super::error::CdRomError> {
         if !self.has_disc() {
             return Err(super::error::CdRomError::NoDisc);
         }
 
-        // Special case: SYSTEM.CNF is typically at sector 00:00:22 (LBA 22)
-        if filename.to_uppercase().starts_with("SYSTEM.CNF") {
-            let old_position = self.position;
+        let not_found =
+            || super::error::CdRomError::DiscLoadError(format!("File not found: {}", filename));
 
-            // Seek to sector 00:00:22
-            self.position = CDPosition::from_lba(22);
+        let components = iso9660::split_path(filename);
+        if components.is_empty() {
+            return Err(not_found());
+        }
 
-            if let Some(sector_data) = self.read_current_sector() {
-                // Restore original position
-                self.position = old_position;
+        let pvd = self.read_user_data(iso9660::PVD_LBA, iso9660::USER_DATA_SIZE as u32)?;
+        let mut current = iso9660::parse_root_record(&pvd)?;
 
-                // Extract text data (skip 24-byte header for Mode 2 sectors)
-                let text_start = 24;
-                let text_data = &sector_data[text_start..];
+        for (i, component) in components.iter().enumerate() {
+            if !current.is_dir {
+                return Err(not_found());
+            }
 
-                // Find null terminator or end of sector
-                let text_end = text_data
-                    .iter()
-                    .position(|&b| b == 0)
-                    .unwrap_or(text_data.len());
+            let directory = self.read_user_data(current.lba, current.size)?;
+            let is_last = i == components.len() - 1;
 
-                return Ok(text_data[..text_end].to_vec());
-            }
+            current = iso9660::parse_directory(&directory)
+                .into_iter()
+                .find(|record| {
+                    record.name.eq_ignore_ascii_case(component) && record.is_dir != is_last
+                })
+                .ok_or_else(not_found)?;
+        }
+
+        log::debug!(
+            "ISO9660: {} found at LBA {} ({} bytes)",
+            filename,
+            current.lba,
+            current.size
+        );
+
+        self.read_user_data(current.lba, current.size)
+    }
+
+    /// Read user data from consecutive Mode 2 Form 1 sectors
+    ///
+    /// # Arguments
+    ///
+    /// * `lba` - First sector to read
+    /// * `size` - Number of bytes to read
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)` - `size` bytes of user data
+    /// - `Err(CdRomError)` - If no disc is loaded or a sector is out of range
+    fn read_user_data(&self, lba: u32, size: u32) -> Result<Vec<u8>, super::error::CdRomError> {
+        let disc = self.disc.as_ref().ok_or(super::error::CdRomError::NoDisc)?;
+
+        let size = size as usize;
+        let mut data = Vec::with_capacity(size);
+        let mut sector = lba;
+
+        while data.len() < size {
+            let raw = disc
+                .read_sector(&CDPosition::from_lba(sector as i32))
+                .ok_or(super::error::CdRomError::InvalidSector { sector })?;
+
+            let user_data = &raw
+                [iso9660::USER_DATA_OFFSET..iso9660::USER_DATA_OFFSET + iso9660::USER_DATA_SIZE];
+            let remaining = size - data.len();
+            data.extend_from_slice(&user_data[..remaining.min(iso9660::USER_DATA_SIZE)]);
+            sector += 1;
         }
 
-        // For executables and other files, we would need full ISO9660 parsing
-        // For now, return an error
-        Err(super::error::CdRomError::DiscLoadError(format!(
-            "File not found: {}",
-            filename
-        )))
+        Ok(data)
     }
 
     /// Advance execution by the specified number of CPU cycles
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ISO9660 filesystem tests
+
+use super::super::iso9660::{self, DirectoryRecord};
+use super::super::*;
+use crate::core::error::CdRomError;
+use tempfile::{Builder, NamedTempFile};
+
+const SECTOR_SIZE: usize = 2352;
+
+/// Build a directory record for a synthetic image
+fn dir_record(name: &[u8], lba: u32, size: u32, is_dir: bool) -> Vec<u8> {
+    let mut length = 33 + name.len();
+    if !length.is_multiple_of(2) {
+        length += 1;
+    }
+
+    let mut record = vec![0u8; length];
+    record[0] = length as u8;
+    record[2..6].copy_from_slice(&lba.to_le_bytes());
+    record[6..10].copy_from_slice(&lba.to_be_bytes());
+    record[10..14].copy_from_slice(&size.to_le_bytes());
+    record[14..18].copy_from_slice(&size.to_be_bytes());
+    record[25] = if is_dir { 0x02 } else { 0x00 };
+    record[32] = name.len() as u8;
+    record[33..33 + name.len()].copy_from_slice(name);
+    record
+}
+
+/// Write user data into a raw Mode 2 sector of the image
+fn write_user_data(image: &mut [u8], lba: usize, data: &[u8]) {
+    for (i, chunk) in data.chunks(2048).enumerate() {
+        let offset = (lba + i) * SECTOR_SIZE + 24;
+        image[offset..offset + chunk.len()].copy_from_slice(chunk);
+    }
+}
+
+/// Build a small ISO9660 image
+///
+/// Layout:
+/// - LBA 16: PVD
+/// - LBA 18: Root directory (SYSTEM.CNF;1, DATA/)
+/// - LBA 19-20: DATA directory (two sectors, MAIN.EXE;1 in the second)
+/// - LBA 21: SYSTEM.CNF
+/// - LBA 22-23: MAIN.EXE (3000 bytes)
+pub(crate) fn build_image(system_cnf: &[u8], main_exe: &[u8]) -> Vec<u8> {
+    let mut image = vec![0u8; 24 * SECTOR_SIZE];
+
+    // Primary Volume Descriptor
+    let mut pvd = vec![0u8; 2048];
+    pvd[0] = 0x01;
+    pvd[1..6].copy_from_slice(b"CD001");
+    pvd[6] = 0x01;
+    let root = dir_record(&[0x00], 18, 2048, true);
+    pvd[156..156 + root.len()].copy_from_slice(&root);
+    write_user_data(&mut image, 16, &pvd);
+
+    // Root directory
+    let mut root_dir = Vec::new();
+    root_dir.extend(dir_record(&[0x00], 18, 2048, true));
+    root_dir.extend(dir_record(&[0x01], 18, 2048, true));
+    root_dir.extend(dir_record(b"DATA", 19, 4096, true));
+    root_dir.extend(dir_record(
+        b"SYSTEM.CNF;1",
+        21,
+        system_cnf.len() as u32,
+        false,
+    ));
+    write_user_data(&mut image, 18, &root_dir);
+
+    // DATA directory: fill the first sector with filler entries so that
+    // MAIN.EXE ends up in the second sector
+    let mut data_dir = Vec::new();
+    data_dir.extend(dir_record(&[0x00], 19, 4096, true));
+    data_dir.extend(dir_record(&[0x01], 18, 2048, true));
+    let mut index = 0;
+    loop {
+        let record = dir_record(format!("FILLER{:02}.BIN;1", index).as_bytes(), 0, 0, false);
+        if data_dir.len() + record.len() > 2048 {
+            break;
+        }
+        data_dir.extend(record);
+        index += 1;
+    }
+    data_dir.resize(2048, 0);
+    data_dir.extend(dir_record(b"MAIN.EXE;1", 22, main_exe.len() as u32, false));
+    write_user_data(&mut image, 19, &data_dir);
+
+    write_user_data(&mut image, 21, system_cnf);
+    write_user_data(&mut image, 22, main_exe);
+
+    image
+}
+
+/// Write an image to temporary .bin/.cue files
+///
+/// The returned files must be kept alive while the disc is in use.
+pub(crate) fn write_disc(image: &[u8]) -> (NamedTempFile, NamedTempFile) {
+    let bin_file = Builder::new()
+        .prefix("test_iso_")
+        .suffix(".bin")
+        .tempfile()
+        .unwrap();
+    let bin_name = bin_file.path().file_name().unwrap().to_str().unwrap();
+
+    let cue_file = Builder::new()
+        .prefix("test_iso_")
+        .suffix(".cue")
+        .tempfile()
+        .unwrap();
+
+    let cue_content = format!(
+        r#"FILE "{}" BINARY
+  TRACK 01 MODE2/2352
+    INDEX 01 00:02:00
+"#,
+        bin_name
+    );
+    std::fs::write(cue_file.path(), cue_content).unwrap();
+    std::fs::write(bin_file.path(), image).unwrap();
+
+    (bin_file, cue_file)
+}
+
+fn exe_payload() -> Vec<u8> {
+    (0..3000).map(|i| (i % 251) as u8).collect()
+}
+
+fn load_test_disc() -> (CDROM, NamedTempFile, NamedTempFile) {
+    let image = build_image(b"BOOT = cdrom:\\DATA\\MAIN.EXE;1\r\n", &exe_payload());
+    let (bin, cue) = write_disc(&image);
+
+    let mut cdrom = CDROM::new();
+    cdrom.load_disc(cue.path().to_str().unwrap()).unwrap();
+    (cdrom, bin, cue)
+}
+
+#[test]
+fn test_split_path() {
+    assert_eq!(iso9660::split_path("SYSTEM.CNF;1"), vec!["SYSTEM.CNF"]);
+    assert_eq!(
+        iso9660::split_path("cdrom:\\DATA\\MAIN.EXE;1"),
+        vec!["DATA", "MAIN.EXE"]
+    );
+    assert_eq!(iso9660::split_path("cdrom:main.exe;1"), vec!["MAIN.EXE"]);
+    assert_eq!(
+        iso9660::split_path("CDROM:\\SLUS_000.01;1 arg1"),
+        vec!["SLUS_000.01"]
+    );
+    assert!(iso9660::split_path("cdrom:\\").is_empty());
+}
+
+#[test]
+fn test_directory_record_parse() {
+    let record = DirectoryRecord::parse(&dir_record(b"FILE.;1", 100, 1234, false)).unwrap();
+    assert_eq!(record.lba, 100);
+    assert_eq!(record.size, 1234);
+    assert!(!record.is_dir);
+    assert_eq!(record.name, "FILE");
+
+    let record = DirectoryRecord::parse(&dir_record(&[0x01], 18, 2048, true)).unwrap();
+    assert!(record.is_dir);
+    assert_eq!(record.name, "..");
+
+    assert!(DirectoryRecord::parse(&[0u8; 40]).is_none());
+    assert!(DirectoryRecord::parse(&[]).is_none());
+}
+
+#[test]
+fn test_parse_directory_skips_sector_padding() {
+    let mut data = Vec::new();
+    data.extend(dir_record(&[0x00], 18, 4096, true));
+    data.extend(dir_record(b"A.BIN;1", 30, 10, false));
+    data.resize(2048, 0);
+    data.extend(dir_record(b"B.BIN;1", 31, 20, false));
+    data.resize(4096, 0);
+
+    let records = iso9660::parse_directory(&data);
+    let names: Vec<&str> = records.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["A.BIN", "B.BIN"]);
+}
+
+#[test]
+fn test_parse_root_record_rejects_invalid_pvd() {
+    let result = iso9660::parse_root_record(&[0u8; 2048]);
+    assert!(matches!(
+        result,
+        Err(CdRomError::ReadError { sector: 16, .. })
+    ));
+}
+
+#[test]
+fn test_read_file_no_disc() {
+    let mut cdrom = CDROM::new();
+    assert!(matches!(
+        cdrom.read_file("SYSTEM.CNF;1"),
+        Err(CdRomError::NoDisc)
+    ));
+}
+
+#[test]
+fn test_read_file_root() {
+    let (mut cdrom, _bin, _cue) = load_test_disc();
+
+    let data = cdrom.read_file("SYSTEM.CNF;1").unwrap();
+    assert_eq!(data, b"BOOT = cdrom:\\DATA\\MAIN.EXE;1\r\n");
+
+    // Version suffix and case are ignored
+    let data = cdrom.read_file("system.cnf").unwrap();
+    assert_eq!(data, b"BOOT = cdrom:\\DATA\\MAIN.EXE;1\r\n");
+}
+
+#[test]
+fn test_read_file_multi_sector_directory_and_file() {
+    let (mut cdrom, _bin, _cue) = load_test_disc();
+
+    let data = cdrom.read_file("cdrom:\\DATA\\MAIN.EXE;1").unwrap();
+    assert_eq!(data.len(), 3000);
+    assert_eq!(data, exe_payload());
+}
+
+#[test]
+fn test_read_file_preserves_position() {
+    let (mut cdrom, _bin, _cue) = load_test_disc();
+    cdrom.set_position(CDPosition::new(0, 2, 5));
+
+    cdrom.read_file("SYSTEM.CNF;1").unwrap();
+
+    assert_eq!(*cdrom.position(), CDPosition::new(0, 2, 5));
+}
+
+#[test]
+fn test_read_file_not_found() {
+    let (mut cdrom, _bin, _cue) = load_test_disc();
+
+    assert!(matches!(
+        cdrom.read_file("MISSING.EXE;1"),
+        Err(CdRomError::DiscLoadError(_))
+    ));
+    // A directory is not a file
+    assert!(matches!(
+        cdrom.read_file("DATA"),
+        Err(CdRomError::DiscLoadError(_))
+    ));
+    // A file is not a directory
+    assert!(matches!(
+        cdrom.read_file("SYSTEM.CNF\\MAIN.EXE"),
+        Err(CdRomError::DiscLoadError(_))
+    ));
+}
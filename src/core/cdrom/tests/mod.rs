@@ -19,4 +19,5 @@ mod basic;
 mod cd_audio;
 mod commands;
 mod disc;
+pub(crate) mod iso9660;
 mod timing;
@@ -166,6 +166,14 @@ impl Disassembler {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
                 format!("swr r{}, {}(r{})", rt, (imm as i16), rs)
             }
+            0x32 => {
+                let (_, rs, rt, imm) = decode_i_type(instruction);
+                format!("lwc2 cop2r{}, {}(r{})", rt, (imm as i16), rs)
+            }
+            0x3A => {
+                let (_, rs, rt, imm) = decode_i_type(instruction);
+                format!("swc2 cop2r{}, {}(r{})", rt, (imm as i16), rs)
+            }
             _ => format!("??? 0x{:08X}", instruction),
         }
     }
@@ -288,6 +296,18 @@ mod tests {
         assert_eq!(result, "lw r2, 4(r1)");
     }
 
+    #[test]
+    fn test_disasm_lwc2() {
+        let result = Disassembler::disassemble(0xC8800008, 0); // LWC2 cop2r0, 8(r4)
+        assert_eq!(result, "lwc2 cop2r0, 8(r4)");
+    }
+
+    #[test]
+    fn test_disasm_swc2() {
+        let result = Disassembler::disassemble(0xE88EFFFC, 0); // SWC2 cop2r14, -4(r4)
+        assert_eq!(result, "swc2 cop2r14, -4(r4)");
+    }
+
     #[test]
     fn test_disasm_j() {
         let result = Disassembler::disassemble(0x0BF00000, 0xBFC00000); // J 0xBFC00000
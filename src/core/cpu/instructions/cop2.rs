@@ -18,8 +18,10 @@
 //! This module implements CPU instructions that interact with Coprocessor 2
 //! (the Geometry Transformation Engine).
 
-use super::CPU;
+use super::super::decode::decode_i_type;
+use super::super::{ExceptionCause, CPU};
 use crate::core::error::Result;
+use crate::core::memory::Bus;
 
 impl CPU {
     /// MFC2: Move From Coprocessor 2 (data register)
@@ -138,6 +140,110 @@ impl CPU {
         Ok(())
     }
 
+    /// LWC2: Load Word to Coprocessor 2
+    ///
+    /// Loads a 32-bit word from memory directly into a GTE data register.
+    /// The address must be 4-byte aligned.
+    ///
+    /// Format: lwc2 rt, offset(rs)
+    /// - rs: CPU base register (bits [25:21])
+    /// - rt: GTE data register (bits [20:16])
+    ///
+    /// The write goes through [`GTE::write_data`](crate::core::gte::GTE::write_data),
+    /// so register side effects (SXYP FIFO push, IRGB expansion, LZCS count)
+    /// apply just as they do for MTC2.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The full 32-bit instruction
+    /// * `bus` - Memory bus for reading
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, triggers AddressErrorLoad exception on misalignment
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// LWC2 [0], 0(r4)  // GTE.data[0] = memory[r4] (load VXY0)
+    /// ```
+    pub(crate) fn op_lwc2(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        // Check alignment
+        if addr & 0x3 != 0 {
+            self.exception(ExceptionCause::AddressErrorLoad);
+            return Ok(());
+        }
+
+        let value = bus.read32(addr)?;
+        self.gte.write_data(rt as usize, value as i32);
+
+        log::trace!(
+            "LWC2: GTE.data[{}] = [0x{:08X}] (0x{:08X})",
+            rt,
+            addr,
+            value
+        );
+
+        Ok(())
+    }
+
+    /// SWC2: Store Word from Coprocessor 2
+    ///
+    /// Stores a GTE data register to memory.
+    /// The address must be 4-byte aligned.
+    ///
+    /// Format: swc2 rt, offset(rs)
+    /// - rs: CPU base register (bits [25:21])
+    /// - rt: GTE data register (bits [20:16])
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The full 32-bit instruction
+    /// * `bus` - Memory bus for writing
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, triggers AddressErrorStore exception on misalignment
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// SWC2 [14], 0(r4)  // memory[r4] = GTE.data[14] (store SXY2)
+    /// ```
+    pub(crate) fn op_swc2(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        // Check alignment
+        if addr & 0x3 != 0 {
+            self.exception(ExceptionCause::AddressErrorStore);
+            return Ok(());
+        }
+
+        let value = self.gte.read_data(rt as usize) as u32;
+        bus.write32(addr, value)?;
+
+        // Precision geometry: remember where the projected vertex went so
+        // the GPU can find its sub-pixel position again
+        if let Some(vertex) = self.gte.precise_sxy(rt as usize) {
+            bus.record_precision_vertex(addr, value, vertex);
+        }
+
+        log::trace!(
+            "SWC2: [0x{:08X}] = GTE.data[{}] (0x{:08X})",
+            addr,
+            rt,
+            value
+        );
+
+        Ok(())
+    }
+
     /// Execute GTE command
     ///
     /// Executes a GTE transformation/calculation command.
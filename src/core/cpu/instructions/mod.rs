@@ -87,6 +87,8 @@ impl CPU {
             0x2B => self.op_sw(instruction, bus),   // SW
             0x2E => self.op_swr(instruction, bus),  // SWR
             0x2F => self.op_cache(instruction),     // CACHE (treated as NOP)
+            0x32 => self.op_lwc2(instruction, bus), // LWC2
+            0x3A => self.op_swc2(instruction, bus), // SWC2
             0x3F => {
                 // Invalid opcode 0x3F (all 1s in opcode field)
                 // This typically appears when reading from unpopulated memory (0xFFFFFFFF)
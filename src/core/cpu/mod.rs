@@ -16,7 +16,7 @@
 use crate::core::error::Result;
 use crate::core::gte::GTE;
 use crate::core::memory::Bus;
-use crate::core::timing::TimingEventManager;
+use crate::core::timing::{EventHandle, TimingEventManager};
 
 /// CPU (MIPS R3000A) emulation implementation
 ///
@@ -447,11 +447,39 @@ impl CPU {
     /// cpu.execute(&mut bus, &mut timing).unwrap();
     /// ```
     pub fn execute(&mut self, bus: &mut Bus, timing: &mut TimingEventManager) -> Result<()> {
+        self.execute_with_events(bus, timing, |_, _| {})
+    }
+
+    /// Execute instructions, passing fired timing events to a handler
+    ///
+    /// Same as [`execute`](Self::execute), but every batch of events fired
+    /// by the timing system is handed to `on_events` before execution
+    /// continues, so devices driven by timing events keep running.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus for reading instructions and data
+    /// * `timing` - Timing event manager
+    /// * `on_events` - Called with the timing manager and the fired events
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) when execution completes normally
+    pub fn execute_with_events<F>(
+        &mut self,
+        bus: &mut Bus,
+        timing: &mut TimingEventManager,
+        mut on_events: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&mut TimingEventManager, &[EventHandle]),
+    {
         loop {
             // Check if timing events need to run
             if timing.pending_ticks >= timing.downcount {
                 // Run all pending timing events
-                timing.run_events();
+                let triggered = timing.run_events();
+                on_events(timing, &triggered);
 
                 // Check if we should exit (e.g., frame complete)
                 if timing.should_exit_loop() {
@@ -569,6 +597,16 @@ impl CPU {
         self.in_branch_delay
     }
 
+    /// Get a reference to the GTE (COP2)
+    pub fn gte(&self) -> &GTE {
+        &self.gte
+    }
+
+    /// Get a mutable reference to the GTE (COP2)
+    pub fn gte_mut(&mut self) -> &mut GTE {
+        &mut self.gte
+    }
+
     /// Get current PC value
     ///
     /// # Returns
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! COP2 (GTE) transfer instruction tests
+
+use super::super::*;
+use crate::core::gpu::GPU;
+use crate::core::memory::Bus;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Encode LWC2/SWC2: opcode | rs | rt (GTE register) | offset
+fn encode(opcode: u32, rs: u32, rt: u32, offset: i16) -> u32 {
+    (opcode << 26) | (rs << 21) | (rt << 16) | (offset as u16 as u32)
+}
+
+fn exception_code(cpu: &CPU) -> u32 {
+    (cpu.cop0.regs[COP0::CAUSE] >> 2) & 0x1F
+}
+
+#[test]
+fn test_lwc2_loads_gte_register() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.set_reg(4, 0x80000100);
+    bus.write32(0x80000108, 0x0020_0010).unwrap();
+
+    // LWC2 VXY0, 8(r4)
+    cpu.op_lwc2(encode(0x32, 4, 0, 8), &mut bus).unwrap();
+
+    assert_eq!(cpu.gte.read_data(0), 0x0020_0010);
+}
+
+#[test]
+fn test_lwc2_negative_offset() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.set_reg(4, 0x80000100);
+    bus.write32(0x800000FC, 0x1234).unwrap();
+
+    // LWC2 VZ0, -4(r4)
+    cpu.op_lwc2(encode(0x32, 4, 1, -4), &mut bus).unwrap();
+
+    assert_eq!(cpu.gte.read_data(1), 0x1234);
+}
+
+#[test]
+fn test_lwc2_sxyp_pushes_fifo() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.set_reg(4, 0x80000000);
+    bus.write32(0x80000000, 0x0001_0001).unwrap();
+    bus.write32(0x80000004, 0x0002_0002).unwrap();
+    bus.write32(0x80000008, 0x0003_0003).unwrap();
+
+    // LWC2 SXYP three times
+    for offset in [0, 4, 8] {
+        cpu.op_lwc2(encode(0x32, 4, 15, offset), &mut bus).unwrap();
+    }
+
+    assert_eq!(cpu.gte.read_data(12), 0x0001_0001); // SXY0
+    assert_eq!(cpu.gte.read_data(13), 0x0002_0002); // SXY1
+    assert_eq!(cpu.gte.read_data(14), 0x0003_0003); // SXY2
+}
+
+#[test]
+fn test_lwc2_irgb_expands_to_ir() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.set_reg(4, 0x80000000);
+    // R=0x1F, G=0x10, B=0x01
+    bus.write32(0x80000000, (0x01 << 10) | (0x10 << 5) | 0x1F)
+        .unwrap();
+
+    // LWC2 IRGB, 0(r4)
+    cpu.op_lwc2(encode(0x32, 4, 28, 0), &mut bus).unwrap();
+
+    assert_eq!(cpu.gte.read_data(9), 0xF80); // IR1
+    assert_eq!(cpu.gte.read_data(10), 0x800); // IR2
+    assert_eq!(cpu.gte.read_data(11), 0x080); // IR3
+}
+
+#[test]
+fn test_lwc2_unaligned() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.set_reg(4, 0x80000002);
+    cpu.op_lwc2(encode(0x32, 4, 0, 0), &mut bus).unwrap();
+
+    assert_eq!(
+        exception_code(&cpu),
+        ExceptionCause::AddressErrorLoad as u32
+    );
+    assert_eq!(cpu.gte.read_data(0), 0);
+}
+
+#[test]
+fn test_swc2_stores_gte_register() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.gte.write_data(25, -2); // MAC1
+    cpu.set_reg(4, 0x80000100);
+
+    // SWC2 MAC1, 16(r4)
+    cpu.op_swc2(encode(0x3A, 4, 25, 16), &mut bus).unwrap();
+
+    assert_eq!(bus.read32(0x80000110).unwrap(), 0xFFFF_FFFE);
+}
+
+/// GTE with an identity rotation, H=200 and precision geometry enabled
+fn setup_precise_projection(cpu: &mut CPU) {
+    cpu.gte.write_control(0, 0x1000); // RT11
+    cpu.gte.write_control(2, 0x1000); // RT22
+    cpu.gte.write_control(4, 0x1000); // RT33
+    cpu.gte.write_control(26, 200); // H
+    cpu.gte.write_data(0, 7); // VX0 = 7, VY0 = 0
+    cpu.gte.write_data(1, 300); // VZ0
+    cpu.gte.set_precision_enabled(true);
+    cpu.gte.rtps(true, false);
+}
+
+#[test]
+fn test_swc2_records_precision_vertex() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+    let gpu = Rc::new(RefCell::new(GPU::new()));
+    gpu.borrow_mut().set_precision_enabled(true);
+    bus.set_gpu(gpu.clone());
+
+    setup_precise_projection(&mut cpu);
+    cpu.set_reg(4, 0x80000100);
+
+    // SWC2 SXY2, 8(r4)
+    cpu.op_swc2(encode(0x3A, 4, 14, 8), &mut bus).unwrap();
+
+    let value = bus.read32(0x80000108).unwrap();
+    let vertex = gpu.borrow().precision_cache.lookup(0x108, value).unwrap();
+    assert!((vertex.x - 14.0 / 3.0).abs() < 1e-3);
+    assert_eq!(vertex.w, 300.0);
+}
+
+#[test]
+fn test_swc2_precision_only_for_sxy_in_ram() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+    let gpu = Rc::new(RefCell::new(GPU::new()));
+    gpu.borrow_mut().set_precision_enabled(true);
+    bus.set_gpu(gpu.clone());
+
+    setup_precise_projection(&mut cpu);
+
+    // SWC2 SZ3 to RAM, SWC2 SXY2 to the scratchpad
+    cpu.set_reg(4, 0x80000100);
+    cpu.op_swc2(encode(0x3A, 4, 19, 0), &mut bus).unwrap();
+    cpu.set_reg(4, 0x1F800000);
+    cpu.op_swc2(encode(0x3A, 4, 14, 0), &mut bus).unwrap();
+
+    assert!(gpu.borrow().precision_cache.is_empty());
+}
+
+#[test]
+fn test_swc2_unaligned() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.gte.write_data(25, 0x1234);
+    cpu.set_reg(4, 0x80000001);
+    cpu.op_swc2(encode(0x3A, 4, 25, 0), &mut bus).unwrap();
+
+    assert_eq!(
+        exception_code(&cpu),
+        ExceptionCause::AddressErrorStore as u32
+    );
+    assert_eq!(bus.read32(0x80000000).unwrap(), 0);
+}
+
+#[test]
+fn test_lwc2_swc2_dispatch() {
+    let mut cpu = CPU::new();
+    let mut bus = Bus::new();
+
+    cpu.pc = 0x80000000;
+    cpu.next_pc = 0x80000004;
+    cpu.set_reg(4, 0x80001000);
+    bus.write32(0x80001000, 0xCAFE_0042).unwrap();
+
+    // LWC2 RGB, 0(r4); SWC2 RGB, 4(r4)
+    bus.write32(0x80000000, encode(0x32, 4, 6, 0)).unwrap();
+    bus.write32(0x80000004, encode(0x3A, 4, 6, 4)).unwrap();
+
+    cpu.step(&mut bus).unwrap();
+    cpu.step(&mut bus).unwrap();
+
+    assert_eq!(bus.read32(0x80001004).unwrap(), 0xCAFE_0042);
+}
@@ -20,6 +20,7 @@
 //! - `load_delay`: Load delay slot behavior
 //! - `exceptions`: Exception handling, syscall, break, interrupts
 //! - `cop0`: COP0 coprocessor operations (MFC0, MTC0, RFE)
+//! - `cop2`: COP2 memory transfers (LWC2, SWC2)
 //! - `decode`: Instruction decoding
 //! - `instructions`: All instruction execution tests
 //! - `timing`: Timing event system integration tests
@@ -36,6 +37,9 @@ mod exceptions;
 #[cfg(test)]
 mod cop0;
 
+#[cfg(test)]
+mod cop2;
+
 #[cfg(test)]
 mod decode;
 
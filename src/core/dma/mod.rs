@@ -359,8 +359,9 @@ impl DMA {
 
                     // Send all words in this node to GPU
                     for i in 0..count {
-                        let word = self.read_ram_u32(ram, addr + 4 + (i * 4) as u32);
-                        gpu.write_gp0(word);
+                        let word_addr = addr + 4 + (i * 4) as u32;
+                        let word = self.read_ram_u32(ram, word_addr);
+                        gpu.write_gp0_from_address(word, word_addr);
                     }
 
                     // Check for end of list marker (bit 23)
@@ -392,7 +393,7 @@ impl DMA {
                     // RAM → GPU
                     for _ in 0..total_words {
                         let word = self.read_ram_u32(ram, addr);
-                        gpu.write_gp0(word);
+                        gpu.write_gp0_from_address(word, addr);
                         addr = (addr + 4) & 0x001F_FFFC;
                     }
                 } else if direction == DMAChannel::TRANSFER_TO_RAM {
@@ -130,6 +130,35 @@ fn test_gpu_dma_linked_list_chain() {
     assert!(!dma.channels[DMA::CH_GPU].is_active());
 }
 
+#[test]
+fn test_gpu_dma_linked_list_does_not_overrun_fifo() {
+    let mut dma = DMA::new();
+    let mut ram = vec![0u8; 2 * 1024 * 1024];
+    let mut gpu = GPU::new();
+
+    // 8 nodes with one large rectangle each, far more words than the FIFO holds
+    let node_count = 8;
+    for i in 0..node_count {
+        let addr = 0x1000 + i * 0x10;
+        let header = if i == node_count - 1 {
+            0x0380_0000
+        } else {
+            0x0300_0000 | (addr + 0x10)
+        };
+        dma.write_ram_u32(&mut ram, addr, header);
+        dma.write_ram_u32(&mut ram, addr + 4, 0x6000_00FF); // Monochrome rectangle
+        dma.write_ram_u32(&mut ram, addr + 8, 0x0000_0000); // Position
+        dma.write_ram_u32(&mut ram, addr + 12, 0x0100_0100); // 256x256
+    }
+
+    dma.write_madr(DMA::CH_GPU, 0x1000);
+    dma.write_chcr(DMA::CH_GPU, 0x1100_0401); // Linked-list mode
+
+    assert!(dma.transfer_gpu(&mut ram, &mut gpu));
+    assert!(gpu.is_busy());
+    assert_eq!(gpu.fifo_overruns(), 0);
+}
+
 #[test]
 fn test_cdrom_dma_transfer() {
     let mut dma = DMA::new();
@@ -76,6 +76,12 @@ pub enum GpuError {
 
     #[error("Rendering backend error: {0}")]
     BackendError(String),
+
+    #[error("Image export error: {0}")]
+    ImageExport(String),
+
+    #[error("Texture replacement error: {0}")]
+    TextureReplacement(String),
 }
 
 /// CD-ROM-specific error types
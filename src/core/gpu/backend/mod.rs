@@ -0,0 +1,472 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Rendering backends
+//!
+//! The GPU front end decodes GP0/GP1 commands and keeps the hardware-visible
+//! state (registers, native VRAM, GPUSTAT, timing). Everything that turns
+//! that state into pixels goes through the [`Renderer`] trait:
+//! - Polygons, lines and rectangles as decoded [`RenderCommand`]s
+//! - Draw state changes as [`RasterizerSetting`]s
+//! - VRAM fills, CPU→VRAM stores and VRAM→VRAM copies
+//! - Display output (the pixels scanned out for each frame)
+//!
+//! Native VRAM is owned by the GPU and passed to every call, so CPU
+//! readbacks always see the same memory regardless of backend. Backends may
+//! keep additional state of their own, such as the shadow VRAM used for
+//! upscaling.
+//!
+//! Available backends:
+//! - [`SoftwareRenderer`]: the reference software rasterizer
+//! - [`NullRenderer`]: discards all drawing, for fast headless runs
+//! - [`RecordingRenderer`]: records and logs every call, then forwards it
+//!   to another backend
+
+mod null;
+mod recording;
+mod software;
+
+pub use null::NullRenderer;
+pub use recording::{RecordingRenderer, RenderCall, RenderLog};
+pub use software::SoftwareRenderer;
+
+use super::render::{
+    RasterizerSetting, RenderCommand, TextureReplacementConfig, WidescreenSurface,
+};
+use super::GPU;
+use crate::core::error::GpuError;
+
+/// Rendering backend driven by the GPU front end
+///
+/// Only [`name`](Self::name), [`configure`](Self::configure) and
+/// [`draw`](Self::draw) are required. The remaining methods default to
+/// operating on native VRAM directly, which is what a backend without
+/// extra state wants.
+///
+/// Backends must be [`Send`] so they can run on the GPU render thread.
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{NullRenderer, Renderer, GPU};
+///
+/// let mut gpu = GPU::with_renderer(Box::new(NullRenderer::new()));
+/// assert_eq!(gpu.renderer_name(), "null");
+///
+/// // Red triangle is discarded
+/// gpu.write_gp0(0x200000FF);
+/// gpu.write_gp0(0x00000000);
+/// gpu.write_gp0(0x00000040);
+/// gpu.write_gp0(0x00400000);
+/// assert_eq!(gpu.read_vram(1, 1), 0x0000);
+/// ```
+pub trait Renderer: Send {
+    /// Short backend name used in logs and on the command line
+    fn name(&self) -> &'static str;
+
+    /// Apply a draw state change
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - New clip rectangle, dithering, mask or cache setting
+    fn configure(&mut self, setting: RasterizerSetting);
+
+    /// Draw a polygon, line or rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM (1024×512)
+    /// * `command` - Decoded primitive
+    fn draw(&mut self, vram: &mut [u16], command: RenderCommand);
+
+    /// Fill a rectangle with a solid color (GP0(02h))
+    ///
+    /// Coordinates wrap around VRAM. Fills ignore the drawing area and
+    /// mask settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM
+    /// * `x` - Left edge
+    /// * `y` - Top edge
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    /// * `color` - 15-bit color with mask bit
+    fn fill(&mut self, vram: &mut [u16], x: u16, y: u16, width: u16, height: u16, color: u16) {
+        for dy in 0..height {
+            for dx in 0..width {
+                self.write_vram(vram, (x + dx) & 0x3FF, (y + dy) & 0x1FF, color);
+            }
+        }
+    }
+
+    /// Store one pixel of a CPU→VRAM transfer or direct VRAM write
+    ///
+    /// Mask bit handling has already been applied by the GPU.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM
+    /// * `x` - X coordinate (wrapped to 0-1023)
+    /// * `y` - Y coordinate (wrapped to 0-511)
+    /// * `value` - Pixel value
+    fn write_vram(&mut self, vram: &mut [u16], x: u16, y: u16, value: u16) {
+        vram[vram_index(x, y)] = value;
+    }
+
+    /// Copy a rectangle within VRAM (GP0(80h))
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM
+    /// * `src` - Source position (x, y)
+    /// * `dst` - Destination position (x, y)
+    /// * `size` - Size (width, height)
+    /// * `mask_set` - Force bit 15 on in written pixels
+    /// * `mask_check` - Skip destination pixels with bit 15 set
+    fn copy_vram(
+        &mut self,
+        vram: &mut [u16],
+        src: (u16, u16),
+        dst: (u16, u16),
+        size: (u16, u16),
+        mask_set: bool,
+        mask_check: bool,
+    ) {
+        copy_native(vram, src, dst, size, mask_set, mask_check);
+    }
+
+    /// Clear VRAM to black on GPU reset
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM
+    fn clear(&mut self, vram: &mut [u16]) {
+        vram.fill(0);
+    }
+
+    /// Internal resolution scale factor of the display output
+    fn resolution_scale(&self) -> u16 {
+        1
+    }
+
+    /// Change the internal resolution scale factor
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM, used to seed any high-resolution copy
+    /// * `scale` - Scale factor (1 = native)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the scale was applied
+    /// - `Err(GpuError::BackendError)` if the backend does not support it
+    fn set_resolution_scale(&mut self, vram: &[u16], scale: u16) -> Result<(), GpuError> {
+        let _ = vram;
+        if scale == 1 {
+            Ok(())
+        } else {
+            Err(GpuError::BackendError(format!(
+                "{} renderer does not support resolution scaling",
+                self.name()
+            )))
+        }
+    }
+
+    /// Change the texture dumping and replacement directories
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Dump and replacement directories
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the backend applied the directories
+    /// - `Err(GpuError::BackendError)` if the backend does not support it
+    fn set_texture_replacement(
+        &mut self,
+        config: TextureReplacementConfig,
+    ) -> Result<(), GpuError> {
+        if config.is_enabled() {
+            Err(GpuError::BackendError(format!(
+                "{} renderer does not support texture replacement",
+                self.name()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read a 15-bit pixel for display output
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM
+    /// * `x` - Native VRAM X coordinate (0-1023)
+    /// * `y` - Native VRAM Y coordinate (0-511)
+    /// * `sub` - Position within the upscaled pixel (x, y), below
+    ///   [`resolution_scale`](Self::resolution_scale)
+    fn display_pixel(&self, vram: &[u16], x: usize, y: usize, sub: (usize, usize)) -> u16 {
+        let _ = sub;
+        vram[y * 1024 + x]
+    }
+
+    /// Enable or disable widescreen rendering
+    ///
+    /// Backends that support it redraw every primitive into a surface 4/3
+    /// as wide as its drawing area, which
+    /// [`widescreen_surface`](Self::widescreen_surface) hands out for
+    /// display. The default does nothing, so frames are stretched instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM, used to seed the surfaces
+    /// * `enabled` - Whether widescreen surfaces should be drawn
+    fn set_widescreen(&mut self, vram: &[u16], enabled: bool) {
+        let _ = (vram, enabled);
+    }
+
+    /// Find the widescreen surface drawn for a display area
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Display area X coordinate in VRAM
+    /// * `y` - Display area Y coordinate in VRAM
+    /// * `width` - Display area width
+    ///
+    /// # Returns
+    ///
+    /// The surface of the drawing area at (x, y) with the same width, or
+    /// None if there is none
+    fn widescreen_surface(&self, x: u16, y: u16, width: u16) -> Option<WidescreenSurface> {
+        let _ = (x, y, width);
+        None
+    }
+
+    /// Read a 15-bit pixel of a widescreen surface for display output
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - Surface returned by [`widescreen_surface`](Self::widescreen_surface)
+    /// * `column` - Column within the surface
+    /// * `y` - VRAM row (0-511)
+    fn widescreen_pixel(&self, surface: &WidescreenSurface, column: usize, y: usize) -> u16 {
+        let _ = (surface, column, y);
+        0
+    }
+}
+
+/// Backend selection for front ends
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::RendererKind;
+///
+/// let kind: RendererKind = "null".parse().unwrap();
+/// assert_eq!(kind.create().name(), "null");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RendererKind {
+    /// Reference software rasterizer
+    #[default]
+    Software,
+
+    /// Discard all drawing
+    Null,
+
+    /// Software rasterizer with every call logged at trace level
+    Recording,
+}
+
+impl RendererKind {
+    /// Number of calls kept by the recording backend created by
+    /// [`create`](Self::create)
+    pub const RECORDING_LIMIT: usize = 65536;
+
+    /// Create a backend of this kind
+    pub fn create(self) -> Box<dyn Renderer> {
+        match self {
+            Self::Software => Box::new(SoftwareRenderer::new()),
+            Self::Null => Box::new(NullRenderer::new()),
+            Self::Recording => Box::new(RecordingRenderer::new(
+                Box::new(SoftwareRenderer::new()),
+                Self::RECORDING_LIMIT,
+            )),
+        }
+    }
+}
+
+impl std::str::FromStr for RendererKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "software" => Ok(Self::Software),
+            "null" => Ok(Self::Null),
+            "recording" => Ok(Self::Recording),
+            _ => Err(format!(
+                "unknown renderer '{}' (expected software, null or recording)",
+                s
+            )),
+        }
+    }
+}
+
+impl GPU {
+    /// Highest supported internal resolution scale factor
+    pub const MAX_RESOLUTION_SCALE: u16 = 8;
+
+    /// Set the internal resolution scale factor
+    ///
+    /// With a factor above 1, the software backend also draws polygons,
+    /// lines and rectangles into a shadow VRAM `scale` times larger in each
+    /// direction, and [`get_framebuffer`](Self::get_framebuffer) returns
+    /// the high-resolution image. Native VRAM, CPU readbacks and texture
+    /// sampling are unaffected. Values are clamped to
+    /// 1..=[`MAX_RESOLUTION_SCALE`](Self::MAX_RESOLUTION_SCALE).
+    ///
+    /// The shadow VRAM starts as an upscaled copy of the current VRAM.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Scale factor (1 = native resolution)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the backend applied the scale
+    /// - `Err(GpuError::BackendError)` if the backend cannot upscale; the
+    ///   previous scale is kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{RendererKind, GPU};
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.set_resolution_scale(2).unwrap();
+    ///
+    /// let framebuffer = gpu.get_framebuffer();
+    /// assert_eq!((framebuffer.width, framebuffer.height), (640, 480));
+    ///
+    /// let mut gpu = GPU::with_renderer(RendererKind::Null.create());
+    /// assert!(gpu.set_resolution_scale(2).is_err());
+    /// assert_eq!(gpu.resolution_scale(), 1);
+    /// ```
+    pub fn set_resolution_scale(&mut self, scale: u16) -> Result<(), GpuError> {
+        let scale = scale.clamp(1, Self::MAX_RESOLUTION_SCALE);
+        if scale == self.resolution_scale {
+            return Ok(());
+        }
+
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.set_resolution_scale(vram, scale)?;
+        self.resolution_scale = scale;
+        Ok(())
+    }
+
+    /// Get the internal resolution scale factor
+    pub fn resolution_scale(&self) -> u16 {
+        self.resolution_scale
+    }
+
+    /// Set the texture dumping and replacement directories
+    ///
+    /// With a dump directory, every texture drawn is written there as a PNG
+    /// the first time it is seen. With a replacement directory, textures
+    /// with a PNG of the same name are drawn from it instead, which only
+    /// takes effect while [upscaling](Self::set_resolution_scale); at
+    /// native resolution the original textures are drawn. The replacement
+    /// directory is listed here, so files added later need another call.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Dump and replacement directories
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the backend applied the directories
+    /// - `Err(GpuError::BackendError)` if the backend does not support it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{RendererKind, TextureReplacementConfig, GPU};
+    ///
+    /// let config = TextureReplacementConfig {
+    ///     replacement_dir: Some("textures".into()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.set_texture_replacement(config.clone()).unwrap();
+    ///
+    /// let mut gpu = GPU::with_renderer(RendererKind::Null.create());
+    /// assert!(gpu.set_texture_replacement(config).is_err());
+    /// ```
+    pub fn set_texture_replacement(
+        &mut self,
+        config: TextureReplacementConfig,
+    ) -> Result<(), GpuError> {
+        let (renderer, _) = self.renderer_and_vram();
+        renderer.set_texture_replacement(config)
+    }
+}
+
+/// Index of a native VRAM pixel, wrapping coordinates
+#[inline(always)]
+fn vram_index(x: u16, y: u16) -> usize {
+    ((y & 0x1FF) as usize) * 1024 + (x & 0x3FF) as usize
+}
+
+/// Copy a rectangle within native VRAM
+///
+/// Reads the whole source before writing so overlapping copies behave like
+/// the hardware. Coordinates wrap around VRAM.
+///
+/// # Arguments
+///
+/// * `vram` - Native VRAM
+/// * `src` - Source position (x, y)
+/// * `dst` - Destination position (x, y)
+/// * `size` - Size (width, height)
+/// * `mask_set` - Force bit 15 on in written pixels
+/// * `mask_check` - Skip destination pixels with bit 15 set
+pub(crate) fn copy_native(
+    vram: &mut [u16],
+    src: (u16, u16),
+    dst: (u16, u16),
+    size: (u16, u16),
+    mask_set: bool,
+    mask_check: bool,
+) {
+    let (width, height) = size;
+    let mut buffer = Vec::with_capacity(width as usize * height as usize);
+    for y in 0..height {
+        for x in 0..width {
+            buffer.push(vram[vram_index(src.0 + x, src.1 + y)]);
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let index = vram_index(dst.0 + x, dst.1 + y);
+            if mask_check && vram[index] & 0x8000 != 0 {
+                continue;
+            }
+            let pixel = buffer[y as usize * width as usize + x as usize];
+            vram[index] = if mask_set { pixel | 0x8000 } else { pixel };
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Null backend
+//!
+//! Discards polygons, lines, rectangles and fills. CPU→VRAM and VRAM→VRAM
+//! transfers still reach native VRAM, so uploads and readbacks round-trip
+//! as on hardware. Useful for headless runs where the picture does not
+//! matter, such as test ROMs reporting over the TTY.
+
+use super::super::render::{RasterizerSetting, RenderCommand};
+use super::Renderer;
+
+/// Rendering backend that draws nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullRenderer;
+
+impl NullRenderer {
+    /// Create a null backend
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Renderer for NullRenderer {
+    fn name(&self) -> &'static str {
+        "null"
+    }
+
+    fn configure(&mut self, _setting: RasterizerSetting) {}
+
+    fn draw(&mut self, _vram: &mut [u16], _command: RenderCommand) {}
+
+    fn fill(
+        &mut self,
+        _vram: &mut [u16],
+        _x: u16,
+        _y: u16,
+        _width: u16,
+        _height: u16,
+        _color: u16,
+    ) {
+    }
+}
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Recording backend
+//!
+//! Wraps another backend, records every call in a bounded log and writes
+//! it to the `log` crate at trace level. The log can be inspected from
+//! another thread while the GPU keeps running, which makes it useful for
+//! debugging command decoding and for tests.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use super::super::render::{
+    RasterizerSetting, RenderCommand, TextureReplacementConfig, WidescreenSurface,
+};
+use super::Renderer;
+use crate::core::error::GpuError;
+
+/// A recorded backend call
+#[derive(Debug, Clone)]
+pub enum RenderCall {
+    /// [`Renderer::configure`]
+    Configure(RasterizerSetting),
+
+    /// [`Renderer::draw`]
+    Draw(RenderCommand),
+
+    /// [`Renderer::fill`]
+    Fill {
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        color: u16,
+    },
+
+    /// [`Renderer::write_vram`]
+    WriteVram { x: u16, y: u16, value: u16 },
+
+    /// [`Renderer::copy_vram`]
+    CopyVram {
+        src: (u16, u16),
+        dst: (u16, u16),
+        size: (u16, u16),
+    },
+
+    /// [`Renderer::clear`]
+    Clear,
+
+    /// [`Renderer::set_resolution_scale`]
+    SetResolutionScale(u16),
+
+    /// [`Renderer::set_texture_replacement`]
+    SetTextureReplacement(TextureReplacementConfig),
+
+    /// [`Renderer::set_widescreen`]
+    SetWidescreen(bool),
+}
+
+/// Shared handle to the calls recorded by a [`RecordingRenderer`]
+#[derive(Debug, Clone, Default)]
+pub struct RenderLog {
+    calls: Arc<Mutex<VecDeque<RenderCall>>>,
+}
+
+impl RenderLog {
+    /// Get a copy of the recorded calls, oldest first
+    pub fn calls(&self) -> Vec<RenderCall> {
+        self.calls.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Number of recorded calls
+    pub fn len(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+
+    /// Check whether no calls are recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard all recorded calls
+    pub fn clear(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+}
+
+/// Rendering backend that records calls before forwarding them
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{RecordingRenderer, RenderCall, SoftwareRenderer, GPU};
+///
+/// let recorder = RecordingRenderer::new(Box::new(SoftwareRenderer::new()), 1024);
+/// let log = recorder.log();
+/// let mut gpu = GPU::with_renderer(Box::new(recorder));
+/// log.clear();
+///
+/// // Fill a 16x16 rectangle
+/// gpu.write_gp0(0x02FFFFFF);
+/// gpu.write_gp0(0x00000000);
+/// gpu.write_gp0(0x00100010);
+///
+/// assert!(matches!(log.calls()[..], [RenderCall::Fill { width: 16, .. }]));
+/// assert_eq!(gpu.read_vram(0, 0), 0x7FFF);
+/// ```
+pub struct RecordingRenderer {
+    /// Backend doing the actual work
+    inner: Box<dyn Renderer>,
+
+    /// Recorded calls
+    log: RenderLog,
+
+    /// Maximum number of calls kept (oldest are dropped first)
+    limit: usize,
+}
+
+impl RecordingRenderer {
+    /// Wrap a backend
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Backend that receives every call after it is recorded
+    /// * `limit` - Maximum number of calls kept in the log
+    pub fn new(inner: Box<dyn Renderer>, limit: usize) -> Self {
+        Self {
+            inner,
+            log: RenderLog::default(),
+            limit,
+        }
+    }
+
+    /// Get a handle to the recorded calls
+    pub fn log(&self) -> RenderLog {
+        self.log.clone()
+    }
+
+    fn record(&self, call: RenderCall) {
+        log::trace!("renderer: {:?}", call);
+        let mut calls = self.log.calls.lock().unwrap();
+        if calls.len() >= self.limit {
+            calls.pop_front();
+        }
+        if self.limit > 0 {
+            calls.push_back(call);
+        }
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    fn configure(&mut self, setting: RasterizerSetting) {
+        self.record(RenderCall::Configure(setting));
+        self.inner.configure(setting);
+    }
+
+    fn draw(&mut self, vram: &mut [u16], command: RenderCommand) {
+        self.record(RenderCall::Draw(command.clone()));
+        self.inner.draw(vram, command);
+    }
+
+    fn fill(&mut self, vram: &mut [u16], x: u16, y: u16, width: u16, height: u16, color: u16) {
+        self.record(RenderCall::Fill {
+            x,
+            y,
+            width,
+            height,
+            color,
+        });
+        self.inner.fill(vram, x, y, width, height, color);
+    }
+
+    fn write_vram(&mut self, vram: &mut [u16], x: u16, y: u16, value: u16) {
+        self.record(RenderCall::WriteVram { x, y, value });
+        self.inner.write_vram(vram, x, y, value);
+    }
+
+    fn copy_vram(
+        &mut self,
+        vram: &mut [u16],
+        src: (u16, u16),
+        dst: (u16, u16),
+        size: (u16, u16),
+        mask_set: bool,
+        mask_check: bool,
+    ) {
+        self.record(RenderCall::CopyVram { src, dst, size });
+        self.inner
+            .copy_vram(vram, src, dst, size, mask_set, mask_check);
+    }
+
+    fn clear(&mut self, vram: &mut [u16]) {
+        self.record(RenderCall::Clear);
+        self.inner.clear(vram);
+    }
+
+    fn resolution_scale(&self) -> u16 {
+        self.inner.resolution_scale()
+    }
+
+    fn set_resolution_scale(&mut self, vram: &[u16], scale: u16) -> Result<(), GpuError> {
+        self.record(RenderCall::SetResolutionScale(scale));
+        self.inner.set_resolution_scale(vram, scale)
+    }
+
+    fn set_texture_replacement(
+        &mut self,
+        config: TextureReplacementConfig,
+    ) -> Result<(), GpuError> {
+        self.record(RenderCall::SetTextureReplacement(config.clone()));
+        self.inner.set_texture_replacement(config)
+    }
+
+    fn display_pixel(&self, vram: &[u16], x: usize, y: usize, sub: (usize, usize)) -> u16 {
+        self.inner.display_pixel(vram, x, y, sub)
+    }
+
+    fn set_widescreen(&mut self, vram: &[u16], enabled: bool) {
+        self.record(RenderCall::SetWidescreen(enabled));
+        self.inner.set_widescreen(vram, enabled);
+    }
+
+    fn widescreen_surface(&self, x: u16, y: u16, width: u16) -> Option<WidescreenSurface> {
+        self.inner.widescreen_surface(x, y, width)
+    }
+
+    fn widescreen_pixel(&self, surface: &WidescreenSurface, column: usize, y: usize) -> u16 {
+        self.inner.widescreen_pixel(surface, column, y)
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Reference software backend
+//!
+//! Draws with the CPU [`Rasterizer`] into native VRAM and, when upscaling,
+//! into the high-resolution shadow VRAM of an [`Upscaler`]. In widescreen
+//! mode primitives are also drawn into the widened drawing areas of a
+//! [`Widescreen`] target.
+
+use super::super::render::{
+    Rasterizer, RasterizerSetting, RenderCommand, TextureReplacementConfig, Upscaler, Widescreen,
+    WidescreenSurface,
+};
+use super::{copy_native, Renderer};
+use crate::core::error::GpuError;
+
+/// Software rendering backend
+///
+/// This is the default backend and the reference for the others.
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{Renderer, SoftwareRenderer, GPU};
+///
+/// let mut gpu = GPU::with_renderer(Box::new(SoftwareRenderer::new()));
+///
+/// // Red triangle
+/// gpu.write_gp0(0x200000FF);
+/// gpu.write_gp0(0x00000000);
+/// gpu.write_gp0(0x00000040);
+/// gpu.write_gp0(0x00400000);
+/// assert_eq!(gpu.read_vram(1, 1), 0x001F);
+/// ```
+#[derive(Clone, Default)]
+pub struct SoftwareRenderer {
+    /// Rasterizer drawing into native VRAM
+    rasterizer: Rasterizer,
+
+    /// Shadow VRAM for internal resolution upscaling (None = native only)
+    upscaler: Option<Box<Upscaler>>,
+
+    /// Widened drawing areas for widescreen output (None = disabled)
+    widescreen: Option<Box<Widescreen>>,
+}
+
+impl SoftwareRenderer {
+    /// Create a software backend at native resolution
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the native rasterizer
+    pub fn rasterizer(&self) -> &Rasterizer {
+        &self.rasterizer
+    }
+}
+
+impl Renderer for SoftwareRenderer {
+    fn name(&self) -> &'static str {
+        "software"
+    }
+
+    fn configure(&mut self, setting: RasterizerSetting) {
+        setting.apply(&mut self.rasterizer);
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.configure(setting);
+        }
+        if let Some(widescreen) = &mut self.widescreen {
+            widescreen.configure(setting);
+        }
+    }
+
+    fn draw(&mut self, vram: &mut [u16], command: RenderCommand) {
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.execute(command.clone());
+        }
+        if let Some(widescreen) = &mut self.widescreen {
+            widescreen.execute(command.clone());
+        }
+        command.execute(&mut self.rasterizer, vram);
+    }
+
+    fn write_vram(&mut self, vram: &mut [u16], x: u16, y: u16, value: u16) {
+        vram[((y & 0x1FF) as usize) * 1024 + (x & 0x3FF) as usize] = value;
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.write_native(x, y, value);
+        }
+        if let Some(widescreen) = &mut self.widescreen {
+            widescreen.write_native(x, y, value);
+        }
+    }
+
+    fn copy_vram(
+        &mut self,
+        vram: &mut [u16],
+        src: (u16, u16),
+        dst: (u16, u16),
+        size: (u16, u16),
+        mask_set: bool,
+        mask_check: bool,
+    ) {
+        // Copy the shadow VRAM at full resolution so rendered detail survives
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.copy_rect(src, dst, size, mask_set, mask_check);
+        }
+        copy_native(vram, src, dst, size, mask_set, mask_check);
+
+        // Widescreen surfaces get the copied native pixels stretched
+        if let Some(widescreen) = &mut self.widescreen {
+            for y in dst.1..dst.1 + size.1 {
+                for x in dst.0..dst.0 + size.0 {
+                    let value = vram[((y & 0x1FF) as usize) * 1024 + (x & 0x3FF) as usize];
+                    widescreen.write_native(x, y, value);
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self, vram: &mut [u16]) {
+        vram.fill(0);
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.clear();
+        }
+        if let Some(widescreen) = &mut self.widescreen {
+            widescreen.clear();
+        }
+    }
+
+    fn resolution_scale(&self) -> u16 {
+        self.upscaler
+            .as_ref()
+            .map_or(1, |upscaler| upscaler.scale())
+    }
+
+    fn set_resolution_scale(&mut self, vram: &[u16], scale: u16) -> Result<(), GpuError> {
+        self.upscaler = (scale > 1).then(|| Box::new(Upscaler::new(scale, vram, &self.rasterizer)));
+        Ok(())
+    }
+
+    fn set_texture_replacement(
+        &mut self,
+        config: TextureReplacementConfig,
+    ) -> Result<(), GpuError> {
+        if let Some(upscaler) = &mut self.upscaler {
+            upscaler.set_texture_replacement(config.clone());
+        }
+        self.rasterizer.set_texture_replacement(config);
+        Ok(())
+    }
+
+    fn display_pixel(
+        &self,
+        vram: &[u16],
+        x: usize,
+        y: usize,
+        (sub_x, sub_y): (usize, usize),
+    ) -> u16 {
+        match &self.upscaler {
+            Some(upscaler) => {
+                let scale = upscaler.scale() as usize;
+                upscaler.pixel(x * scale + sub_x, y * scale + sub_y)
+            }
+            None => vram[y * 1024 + x],
+        }
+    }
+
+    fn set_widescreen(&mut self, vram: &[u16], enabled: bool) {
+        self.widescreen = enabled.then(|| Box::new(Widescreen::new(vram, &self.rasterizer)));
+    }
+
+    fn widescreen_surface(&self, x: u16, y: u16, width: u16) -> Option<WidescreenSurface> {
+        self.widescreen.as_ref()?.surface(x, y, width)
+    }
+
+    fn widescreen_pixel(&self, surface: &WidescreenSurface, column: usize, y: usize) -> u16 {
+        self.widescreen
+            .as_ref()
+            .map_or(0, |widescreen| widescreen.pixel(surface, column, y))
+    }
+}
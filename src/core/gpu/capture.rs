@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU frame capture and replay
+//!
+//! A capture records everything the CPU side feeds the GPU over one or more
+//! frames: every GP0 and GP1 word (which includes CPU→VRAM uploads), direct
+//! VRAM writes and VBlank boundaries, together with the VRAM contents and
+//! register state at the start. Replaying a capture into a fresh [`GPU`]
+//! reproduces the same VRAM, so a capture file is a self-contained
+//! rendering bug report or regression fixture that needs no disc or BIOS.
+//!
+//! Captures are serialized with bincode, like save states, and carry a
+//! format version that is checked on load.
+//!
+//! Precise (sub-pixel) vertices from precision geometry are not recorded;
+//! replays always draw with integer vertices.
+
+use bincode::{config, Decode, Encode};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use super::registers::{ColorDepth, VRAMTransfer, VRAMTransferDirection};
+use super::render::RasterizerSetting;
+use super::GPU;
+
+/// Capture format version
+///
+/// Incremented whenever the capture format changes incompatibly.
+pub const GPU_CAPTURE_VERSION: u32 = 1;
+
+/// An input recorded during a capture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub enum CaptureEvent {
+    /// Word written to GP0 (commands and CPU→VRAM transfer data)
+    Gp0(u32),
+
+    /// Word written to GP1
+    Gp1(u32),
+
+    /// Direct VRAM write through [`GPU::write_vram`]
+    VramWrite { x: u16, y: u16, value: u16 },
+
+    /// Start of VBlank (end of a frame)
+    VBlank,
+}
+
+/// GPU register state at the start of a capture
+///
+/// Registers are stored as the GP0/GP1 command words that set them, so
+/// restoring them is just a matter of writing the words back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub struct CaptureRegisters {
+    /// GP0(E1h)-GP0(E6h) draw state commands
+    pub gp0: Vec<u32>,
+
+    /// GP1(03h)-GP1(08h) display control commands
+    pub gp1: Vec<u32>,
+
+    /// Interlace field being displayed (GPUSTAT bit 13)
+    pub interlace_field: bool,
+
+    /// GP0 words of a partially received command
+    pub pending_gp0: Vec<u32>,
+
+    /// CPU→VRAM transfer in progress as
+    /// (x, y, width, height, current x, current y)
+    pub pending_upload: Option<(u16, u16, u16, u16, u16, u16)>,
+
+    /// User dithering option ([`GPU::set_dithering_enabled`])
+    pub dithering_enabled: bool,
+
+    /// Texture cache emulation ([`GPU::set_texture_cache_enabled`])
+    pub texture_cache_enabled: bool,
+}
+
+/// A recorded GPU input stream
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::GPU;
+///
+/// let mut gpu = GPU::new();
+/// gpu.start_capture(1);
+///
+/// // Red triangle, then end the frame
+/// gpu.write_gp0(0x200000FF);
+/// gpu.write_gp0(0x00000000);
+/// gpu.write_gp0(0x00000040);
+/// gpu.write_gp0(0x00400000);
+/// gpu.tick(GPU::VBLANK_START as u32 * GPU::DOTS_PER_SCANLINE as u32);
+///
+/// let capture = gpu.finish_capture().unwrap();
+/// assert_eq!(capture.frames(), 1);
+///
+/// let mut replayed = capture.replay();
+/// assert_eq!(replayed.read_vram(1, 1), gpu.read_vram(1, 1));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct GpuCapture {
+    /// Format version ([`GPU_CAPTURE_VERSION`])
+    pub version: u32,
+
+    /// VRAM at the start of the capture (1024×512)
+    pub vram: Vec<u16>,
+
+    /// Register state at the start of the capture
+    pub registers: CaptureRegisters,
+
+    /// Recorded inputs in order
+    pub events: Vec<CaptureEvent>,
+}
+
+impl GpuCapture {
+    /// Number of complete frames in the capture
+    pub fn frames(&self) -> u32 {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, CaptureEvent::VBlank))
+            .count() as u32
+    }
+
+    /// Replay the capture into a new GPU
+    ///
+    /// # Returns
+    ///
+    /// A software-rendered GPU in the state the captured GPU was in at the
+    /// end of the capture
+    pub fn replay(&self) -> GPU {
+        let mut gpu = GPU::new();
+        self.replay_into(&mut gpu);
+        gpu
+    }
+
+    /// Replay the capture into an existing GPU
+    ///
+    /// The GPU is reset first, so only its rendering backend and options
+    /// such as threaded rendering or resolution scale carry over.
+    ///
+    /// # Arguments
+    ///
+    /// * `gpu` - GPU to replay into
+    pub fn replay_into(&self, gpu: &mut GPU) {
+        gpu.restore_capture_start(self);
+        for event in &self.events {
+            match *event {
+                CaptureEvent::Gp0(value) => gpu.write_gp0(value),
+                CaptureEvent::Gp1(value) => gpu.write_gp1(value),
+                CaptureEvent::VramWrite { x, y, value } => gpu.write_vram(x, y, value),
+                CaptureEvent::VBlank => gpu.begin_vblank(),
+            }
+        }
+        gpu.sync_renderer();
+    }
+
+    /// Save the capture to a file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the capture file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be created or written
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let encoded = bincode::encode_to_vec(self, config::standard())?;
+        let mut file = File::create(path)?;
+        file.write_all(&encoded)?;
+        Ok(())
+    }
+
+    /// Load a capture from a file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the capture file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - File cannot be read or decoded
+    /// - Version is incompatible
+    /// - VRAM size is wrong
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut file = File::open(path)?;
+        let mut buffer = Vec::new();
+        file.read_to_end(&mut buffer)?;
+
+        // Captures of long sequences can be large, but cap allocations for
+        // corrupted files
+        let config = config::standard().with_limit::<{ 256 * 1024 * 1024 }>();
+        let (capture, _): (GpuCapture, usize) = bincode::decode_from_slice(&buffer, config)?;
+
+        if capture.version != GPU_CAPTURE_VERSION {
+            return Err(format!(
+                "Incompatible GPU capture version: expected {}, got {}",
+                GPU_CAPTURE_VERSION, capture.version
+            )
+            .into());
+        }
+        if capture.vram.len() != GPU::VRAM_SIZE {
+            return Err(format!(
+                "Invalid GPU capture: VRAM has {} pixels, expected {}",
+                capture.vram.len(),
+                GPU::VRAM_SIZE
+            )
+            .into());
+        }
+
+        Ok(capture)
+    }
+}
+
+/// Capture being recorded by a GPU
+pub(crate) struct CaptureRecorder {
+    /// Capture recorded so far
+    capture: GpuCapture,
+
+    /// Frames left to record (None = until finished)
+    frames_left: Option<u32>,
+}
+
+impl CaptureRecorder {
+    /// Record an event if the requested frames are not complete yet
+    pub(crate) fn record(&mut self, event: CaptureEvent) {
+        if self.frames_left == Some(0) {
+            return;
+        }
+
+        self.capture.events.push(event);
+        if event == CaptureEvent::VBlank {
+            if let Some(frames) = &mut self.frames_left {
+                *frames -= 1;
+            }
+        }
+    }
+}
+
+impl GPU {
+    /// Start capturing GPU input
+    ///
+    /// Records the current VRAM and register state, then every GP0/GP1
+    /// word and direct VRAM write until `frames` VBlanks have passed, or
+    /// until [`finish_capture`](Self::finish_capture) if `frames` is 0.
+    /// A capture already in progress is discarded.
+    ///
+    /// The texture cache is flushed so the replay starts from the same
+    /// cache state.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Number of frames to record (0 = unlimited)
+    pub fn start_capture(&mut self, frames: u32) {
+        self.sync_renderer();
+        self.configure_renderer(RasterizerSetting::InvalidateTextureCache);
+
+        let capture = GpuCapture {
+            version: GPU_CAPTURE_VERSION,
+            vram: self.vram.clone(),
+            registers: self.capture_registers(),
+            events: Vec::new(),
+        };
+        self.capture = Some(Box::new(CaptureRecorder {
+            capture,
+            frames_left: (frames > 0).then_some(frames),
+        }));
+
+        log::info!("GPU capture started ({} frames)", frames);
+    }
+
+    /// Check whether input is still being captured
+    ///
+    /// # Returns
+    ///
+    /// true while a capture is running and has not reached its frame count
+    pub fn capture_in_progress(&self) -> bool {
+        self.capture
+            .as_ref()
+            .is_some_and(|recorder| recorder.frames_left != Some(0))
+    }
+
+    /// Stop capturing and return the capture
+    ///
+    /// # Returns
+    ///
+    /// The recorded capture, or None if no capture was started
+    pub fn finish_capture(&mut self) -> Option<GpuCapture> {
+        let capture = self.capture.take()?.capture;
+        log::info!(
+            "GPU capture finished ({} frames, {} events)",
+            capture.frames(),
+            capture.events.len()
+        );
+        Some(capture)
+    }
+
+    /// Record an input event if capturing
+    #[inline(always)]
+    pub(crate) fn record_capture_event(&mut self, event: CaptureEvent) {
+        if let Some(recorder) = &mut self.capture {
+            recorder.record(event);
+        }
+    }
+
+    /// Encode the current register state as command words
+    fn capture_registers(&self) -> CaptureRegisters {
+        let mode = &self.draw_mode;
+        let e1 = 0xE100_0000
+            | (mode.texture_page_x_base as u32 / 64)
+            | (mode.texture_page_y_base as u32 / 256) << 4
+            | (mode.semi_transparency as u32) << 5
+            | (mode.texture_depth as u32) << 7
+            | (mode.dithering as u32) << 9
+            | (mode.draw_to_display as u32) << 10
+            | (mode.texture_disable as u32) << 11
+            | (mode.texture_x_flip as u32) << 12
+            | (mode.texture_y_flip as u32) << 13;
+
+        let window = &self.texture_window;
+        let e2 = 0xE200_0000
+            | window.mask_x as u32
+            | (window.mask_y as u32) << 5
+            | (window.offset_x as u32) << 10
+            | (window.offset_y as u32) << 15;
+
+        let area = &self.draw_area;
+        let e3 = 0xE300_0000 | area.left as u32 | (area.top as u32) << 10;
+        let e4 = 0xE400_0000 | area.right as u32 | (area.bottom as u32) << 10;
+
+        let (offset_x, offset_y) = self.draw_offset;
+        let e5 = 0xE500_0000 | (offset_x as u32 & 0x7FF) | (offset_y as u32 & 0x7FF) << 11;
+
+        let e6 =
+            0xE600_0000 | self.status.set_mask_bit as u32 | (self.status.draw_pixels as u32) << 1;
+
+        let status = &self.status;
+        let display_mode = 0x0800_0000
+            | status.horizontal_res_1 as u32
+            | (status.vertical_res as u32) << 2
+            | (status.video_mode as u32) << 3
+            | ((self.display_mode.display_area_color_depth == ColorDepth::C24Bit) as u32) << 4
+            | (self.display_mode.interlaced as u32) << 5
+            | (status.horizontal_res_2 as u32) << 6
+            | (status.reverse_flag as u32) << 7;
+
+        let range = &self.display_range;
+        let gp1 = vec![
+            0x0300_0000 | self.display_mode.display_disabled as u32,
+            0x0400_0000 | status.dma_direction as u32,
+            0x0500_0000 | self.display_area.x as u32 | (self.display_area.y as u32) << 10,
+            0x0600_0000 | range.x1 as u32 | (range.x2 as u32) << 12,
+            0x0700_0000 | range.y1 as u32 | (range.y2 as u32) << 10,
+            display_mode,
+        ];
+
+        let pending_upload = self
+            .vram_transfer
+            .as_ref()
+            .filter(|transfer| transfer.direction == VRAMTransferDirection::CpuToVram)
+            .map(|transfer| {
+                (
+                    transfer.x,
+                    transfer.y,
+                    transfer.width,
+                    transfer.height,
+                    transfer.current_x,
+                    transfer.current_y,
+                )
+            });
+
+        CaptureRegisters {
+            gp0: vec![e1, e2, e3, e4, e5, e6],
+            gp1,
+            interlace_field: self.status.interlace_field,
+            pending_gp0: self.command_fifo.iter().copied().collect(),
+            pending_upload,
+            dithering_enabled: self.dithering_enabled,
+            texture_cache_enabled: self.texture_cache_enabled,
+        }
+    }
+
+    /// Reset the GPU to the state at the start of a capture
+    ///
+    /// # Arguments
+    ///
+    /// * `capture` - Capture to restore
+    fn restore_capture_start(&mut self, capture: &GpuCapture) {
+        let registers = &capture.registers;
+
+        self.reset();
+        self.set_dithering_enabled(registers.dithering_enabled);
+        self.set_texture_cache_enabled(registers.texture_cache_enabled);
+
+        for (index, &value) in capture.vram.iter().enumerate() {
+            self.write_vram((index % 1024) as u16, (index / 1024) as u16, value);
+        }
+        for &word in &registers.gp0 {
+            self.write_gp0(word);
+        }
+        for &word in &registers.gp1 {
+            self.write_gp1(word);
+        }
+
+        self.status.interlace_field = registers.interlace_field && self.display_mode.interlaced;
+        self.update_rasterizer_interlace();
+
+        for &word in &registers.pending_gp0 {
+            self.write_gp0(word);
+        }
+        if let Some((x, y, width, height, current_x, current_y)) = registers.pending_upload {
+            self.vram_transfer = Some(VRAMTransfer {
+                x,
+                y,
+                width,
+                height,
+                current_x,
+                current_y,
+                direction: VRAMTransferDirection::CpuToVram,
+            });
+        }
+    }
+}
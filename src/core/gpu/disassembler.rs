@@ -0,0 +1,624 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GPU command disassembler for debugging
+//!
+//! Converts GP0 packets and GP1 words to human-readable text, in the spirit
+//! of the MIPS [`Disassembler`](crate::core::cpu::Disassembler). GP0 packets
+//! are decoded with [`GPUCommand::decode`] and then formatted. Packet
+//! boundaries match what the GPU consumes, so commands it does not execute
+//! are shown as `Nop` words, while fields are shown as the hardware defines
+//! them.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::capture::{CaptureEvent, GpuCapture};
+use super::primitives::{
+    BlendMode, Color, GPUCommand, TexCoord, TextureDepth, TextureInfo, Vertex,
+};
+
+/// GPU command disassembler
+///
+/// The associated functions disassemble complete packets. An instance
+/// disassembles a GP0 word stream as it is written, buffering partial
+/// packets and skipping CPU→VRAM pixel data, which makes it usable for
+/// tracing live GPU traffic.
+///
+/// # Example
+/// ```
+/// use psrx::core::gpu::GpuCommandDisassembler;
+///
+/// let text = GpuCommandDisassembler::disassemble_gp1(0x05000000 | (240 << 10));
+/// assert_eq!(text, "DisplayStart (0,240)");
+///
+/// let mut disasm = GpuCommandDisassembler::new();
+/// assert_eq!(disasm.feed_gp0(0xE5000000 | (4 << 11) | 8), Some("DrawOffset (8,4)".to_string()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GpuCommandDisassembler {
+    /// Words of the packet being received
+    pending: VecDeque<u32>,
+
+    /// CPU→VRAM data words still to be skipped
+    data_words_left: usize,
+}
+
+impl GpuCommandDisassembler {
+    /// Create a disassembler with no pending packet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disassemble one GP0 packet
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - GP0 words, starting with a command word
+    ///
+    /// # Returns
+    ///
+    /// - `Some((text, words used))` if a complete packet was decoded
+    /// - `None` if `words` ends before the packet is complete
+    ///
+    /// # Example
+    /// ```
+    /// use psrx::core::gpu::GpuCommandDisassembler;
+    ///
+    /// // Textured triangle, semi-transparent
+    /// let words = [
+    ///     0x26808080, 0x0014000A, 0x78000000, 0x00140020,
+    ///     0x00050000, 0x0030000A, 0x00002000,
+    /// ];
+    /// let (text, used) = GpuCommandDisassembler::disassemble_gp0(&words).unwrap();
+    /// assert_eq!(used, 7);
+    /// assert_eq!(
+    ///     text,
+    ///     "Poly3 flat textured semi=1 v0=(10,20) uv=(0,0) rgb=(128,128,128) \
+    ///      v1=(32,20) uv=(0,0) v2=(10,48) uv=(0,32) \
+    ///      clut=(0,480) tpage=(320,0) 4bpp blend=average"
+    /// );
+    /// ```
+    pub fn disassemble_gp0(words: &[u32]) -> Option<(String, usize)> {
+        GPUCommand::decode(words).map(|(command, len)| (Self::format_command(&command), len))
+    }
+
+    /// Disassemble a GP1 (display control) word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Value written to GP1
+    ///
+    /// # Returns
+    ///
+    /// String describing the command
+    ///
+    /// # Example
+    /// ```
+    /// use psrx::core::gpu::GpuCommandDisassembler;
+    ///
+    /// let text = GpuCommandDisassembler::disassemble_gp1(0x08000001);
+    /// assert_eq!(text, "DisplayMode 320x240 ntsc 15bpp interlace=0 reverse=0");
+    /// ```
+    pub fn disassemble_gp1(word: u32) -> String {
+        let command = word >> 24;
+        match command {
+            0x00 => "ResetGpu".to_string(),
+            0x01 => "ResetCommandBuffer".to_string(),
+            0x02 => "AckIrq".to_string(),
+            0x03 => {
+                let state = if word & 1 == 0 { "on" } else { "off" };
+                format!("DisplayEnable {}", state)
+            }
+            0x04 => {
+                let direction = match word & 3 {
+                    0 => "off",
+                    1 => "fifo",
+                    2 => "cpu_to_gp0",
+                    _ => "gpuread_to_cpu",
+                };
+                format!("DmaDirection {}", direction)
+            }
+            0x05 => format!("DisplayStart ({},{})", word & 0x3FF, (word >> 10) & 0x1FF),
+            0x06 => format!("HorizontalRange {}..{}", word & 0xFFF, (word >> 12) & 0xFFF),
+            0x07 => format!("VerticalRange {}..{}", word & 0x3FF, (word >> 10) & 0x3FF),
+            0x08 => {
+                let width = if word & 0x40 != 0 {
+                    368
+                } else {
+                    [256, 320, 512, 640][(word & 3) as usize]
+                };
+                let interlaced = (word >> 5) & 1;
+                let height = if word & 0x04 != 0 && interlaced != 0 {
+                    480
+                } else {
+                    240
+                };
+                let video = if word & 0x08 != 0 { "pal" } else { "ntsc" };
+                let depth = if word & 0x10 != 0 { 24 } else { 15 };
+                format!(
+                    "DisplayMode {}x{} {} {}bpp interlace={} reverse={}",
+                    width,
+                    height,
+                    video,
+                    depth,
+                    interlaced,
+                    (word >> 7) & 1
+                )
+            }
+            0x10..=0x1F => format!("GetGpuInfo 0x{:X}", word & 0xF),
+            _ => format!("Unknown 0x{:08X}", word),
+        }
+    }
+
+    /// Feed one GP0 word from a command stream
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Value written to GP0
+    ///
+    /// # Returns
+    ///
+    /// The disassembled packet when `word` completes one, `None` while a
+    /// packet is incomplete or for CPU→VRAM pixel data
+    pub fn feed_gp0(&mut self, word: u32) -> Option<String> {
+        if self.data_words_left > 0 {
+            self.data_words_left -= 1;
+            return None;
+        }
+
+        self.pending.push_back(word);
+        let words = self.pending.make_contiguous();
+        let (command, _) = GPUCommand::decode(words)?;
+        self.pending.clear();
+        self.data_words_left = command.data_words();
+        Some(Self::format_command(&command))
+    }
+
+    /// Drop any partially received packet or pending transfer data
+    ///
+    /// Mirrors GP1(01h), which clears the GPU command buffer.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.data_words_left = 0;
+    }
+
+    /// Disassemble a captured command stream
+    ///
+    /// Produces one line per GP0 packet, GP1 command and direct VRAM write,
+    /// prefixed with the port, plus a marker line at the end of each frame.
+    /// The capture's starting register state is listed first.
+    ///
+    /// # Arguments
+    ///
+    /// * `capture` - Capture to disassemble
+    ///
+    /// # Returns
+    ///
+    /// Disassembly lines in stream order
+    ///
+    /// # Example
+    /// ```
+    /// use psrx::core::GPU;
+    /// use psrx::core::gpu::GpuCommandDisassembler;
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.start_capture(0);
+    /// gpu.write_gp0(0x02FF0000);
+    /// gpu.write_gp0(0x00000000);
+    /// gpu.write_gp0(0x00100010);
+    /// let capture = gpu.finish_capture().unwrap();
+    ///
+    /// let lines = GpuCommandDisassembler::disassemble_capture(&capture);
+    /// assert_eq!(
+    ///     lines.last().unwrap(),
+    ///     "GP0 Fill pos=(0,0) size=16x16 rgb=(0,0,255)"
+    /// );
+    /// ```
+    pub fn disassemble_capture(capture: &GpuCapture) -> Vec<String> {
+        let registers = &capture.registers;
+        let mut lines = Vec::new();
+        let mut disasm = Self::new();
+
+        for &word in &registers.gp0 {
+            if let Some(text) = disasm.feed_gp0(word) {
+                lines.push(format!("start GP0 {}", text));
+            }
+        }
+        for &word in &registers.gp1 {
+            lines.push(format!("start GP1 {}", Self::disassemble_gp1(word)));
+        }
+        if let Some((x, y, width, height, current_x, current_y)) = registers.pending_upload {
+            let done = current_y as usize * width as usize + current_x as usize;
+            let total = width as usize * height as usize;
+            disasm.data_words_left = (total - done).div_ceil(2);
+            lines.push(format!(
+                "start CpuToVram pos=({},{}) size={}x{} words_left={}",
+                x, y, width, height, disasm.data_words_left
+            ));
+        }
+        for &word in &registers.pending_gp0 {
+            disasm.pending.push_back(word);
+        }
+
+        let mut frame = 0;
+        for event in &capture.events {
+            match *event {
+                CaptureEvent::Gp0(word) => {
+                    if let Some(text) = disasm.feed_gp0(word) {
+                        lines.push(format!("GP0 {}", text));
+                    }
+                }
+                CaptureEvent::Gp1(word) => {
+                    if matches!(word >> 24, 0x00 | 0x01) {
+                        disasm.reset();
+                    }
+                    lines.push(format!("GP1 {}", Self::disassemble_gp1(word)));
+                }
+                CaptureEvent::VramWrite { x, y, value } => {
+                    lines.push(format!("VRAM ({},{}) = 0x{:04X}", x, y, value));
+                }
+                CaptureEvent::VBlank => {
+                    lines.push(format!("-- end of frame {} --", frame));
+                    frame += 1;
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Format a decoded GP0 packet
+    fn format_command(command: &GPUCommand) -> String {
+        let mut text = String::new();
+        match command {
+            GPUCommand::MonochromeTriangle {
+                vertices,
+                color,
+                semi_transparent,
+            } => {
+                Self::header(&mut text, "Poly3", false, false, false, *semi_transparent);
+                Self::color(&mut text, color);
+                for (i, vertex) in vertices.iter().enumerate() {
+                    Self::vertex(&mut text, i, vertex);
+                }
+            }
+            GPUCommand::MonochromeQuad {
+                vertices,
+                color,
+                semi_transparent,
+            } => {
+                Self::header(&mut text, "Poly4", false, false, false, *semi_transparent);
+                Self::color(&mut text, color);
+                for (i, vertex) in vertices.iter().enumerate() {
+                    Self::vertex(&mut text, i, vertex);
+                }
+            }
+            GPUCommand::ShadedTriangle {
+                vertices,
+                semi_transparent,
+            } => {
+                Self::header(&mut text, "Poly3", true, false, false, *semi_transparent);
+                Self::shaded_vertices(&mut text, vertices);
+            }
+            GPUCommand::ShadedQuad {
+                vertices,
+                semi_transparent,
+            } => {
+                Self::header(&mut text, "Poly4", true, false, false, *semi_transparent);
+                Self::shaded_vertices(&mut text, vertices);
+            }
+            GPUCommand::TexturedTriangle {
+                vertices,
+                texture,
+                blend,
+                shaded,
+                raw_texture,
+                semi_transparent,
+            } => {
+                Self::header(
+                    &mut text,
+                    "Poly3",
+                    *shaded,
+                    true,
+                    *raw_texture,
+                    *semi_transparent,
+                );
+                Self::textured_vertices(&mut text, vertices, *shaded);
+                Self::texture(&mut text, texture, blend);
+            }
+            GPUCommand::TexturedQuad {
+                vertices,
+                texture,
+                blend,
+                shaded,
+                raw_texture,
+                semi_transparent,
+            } => {
+                Self::header(
+                    &mut text,
+                    "Poly4",
+                    *shaded,
+                    true,
+                    *raw_texture,
+                    *semi_transparent,
+                );
+                Self::textured_vertices(&mut text, vertices, *shaded);
+                Self::texture(&mut text, texture, blend);
+            }
+            GPUCommand::Line {
+                vertices,
+                shaded,
+                semi_transparent,
+            } => {
+                Self::header(&mut text, "Line", *shaded, false, false, *semi_transparent);
+                Self::line_vertices(&mut text, vertices, *shaded);
+            }
+            GPUCommand::Polyline {
+                vertices,
+                terminator,
+                shaded,
+                semi_transparent,
+            } => {
+                Self::header(
+                    &mut text,
+                    "Polyline",
+                    *shaded,
+                    false,
+                    false,
+                    *semi_transparent,
+                );
+                Self::line_vertices(&mut text, vertices, *shaded);
+                let _ = write!(text, " end=0x{:08X}", terminator);
+            }
+            GPUCommand::Rectangle {
+                position,
+                width,
+                height,
+                color,
+                texture,
+                raw_texture,
+                semi_transparent,
+            } => {
+                text.push_str("Rect");
+                if texture.is_some() {
+                    text.push_str(" textured");
+                }
+                if *raw_texture {
+                    text.push_str(" raw");
+                }
+                let _ = write!(
+                    text,
+                    " semi={} pos=({},{}) size={}x{}",
+                    *semi_transparent as u8, position.x, position.y, width, height
+                );
+                if let Some((texcoord, clut_x, clut_y)) = texture {
+                    Self::texcoord(&mut text, texcoord);
+                    let _ = write!(text, " clut=({},{})", clut_x, clut_y);
+                }
+                Self::color(&mut text, color);
+            }
+            GPUCommand::FillRectangle {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => {
+                let _ = write!(text, "Fill pos=({},{}) size={}x{}", x, y, width, height);
+                Self::color(&mut text, color);
+            }
+            GPUCommand::CopyRectangle {
+                src,
+                dst,
+                width,
+                height,
+            } => {
+                let _ = write!(
+                    text,
+                    "CopyVram src=({},{}) dst=({},{}) size={}x{}",
+                    src.0, src.1, dst.0, dst.1, width, height
+                );
+            }
+            GPUCommand::CpuToVram {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let _ = write!(
+                    text,
+                    "CpuToVram pos=({},{}) size={}x{} words={}",
+                    x,
+                    y,
+                    width,
+                    height,
+                    command.data_words()
+                );
+            }
+            GPUCommand::VramToCpu {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let _ = write!(
+                    text,
+                    "VramToCpu pos=({},{}) size={}x{}",
+                    x, y, width, height
+                );
+            }
+            GPUCommand::DrawMode {
+                page_x,
+                page_y,
+                blend,
+                depth,
+                dithering,
+                draw_to_display,
+                texture_disable,
+            } => {
+                let _ = write!(
+                    text,
+                    "DrawMode tpage=({},{}) {} blend={} dither={} draw_to_display={} texture_disable={}",
+                    page_x,
+                    page_y,
+                    Self::depth_name(*depth),
+                    Self::blend_name(blend),
+                    *dithering as u8,
+                    *draw_to_display as u8,
+                    *texture_disable as u8
+                );
+            }
+            GPUCommand::TextureWindow {
+                mask_x,
+                mask_y,
+                offset_x,
+                offset_y,
+            } => {
+                let _ = write!(
+                    text,
+                    "TextureWindow mask=({},{}) offset=({},{})",
+                    mask_x, mask_y, offset_x, offset_y
+                );
+            }
+            GPUCommand::DrawAreaTopLeft { x, y } => {
+                let _ = write!(text, "DrawAreaTopLeft ({},{})", x, y);
+            }
+            GPUCommand::DrawAreaBottomRight { x, y } => {
+                let _ = write!(text, "DrawAreaBottomRight ({},{})", x, y);
+            }
+            GPUCommand::DrawOffset { x, y } => {
+                let _ = write!(text, "DrawOffset ({},{})", x, y);
+            }
+            GPUCommand::MaskSettings {
+                set_mask,
+                check_mask,
+            } => {
+                let _ = write!(
+                    text,
+                    "MaskBits set={} check={}",
+                    *set_mask as u8, *check_mask as u8
+                );
+            }
+            GPUCommand::ClearCache => text.push_str("ClearCache"),
+            GPUCommand::InterruptRequest => text.push_str("Irq"),
+            GPUCommand::Nop(word) => {
+                let _ = write!(text, "Nop 0x{:08X}", word);
+            }
+        }
+        text
+    }
+
+    /// Write the primitive name and flags
+    fn header(
+        text: &mut String,
+        name: &str,
+        shaded: bool,
+        textured: bool,
+        raw_texture: bool,
+        semi_transparent: bool,
+    ) {
+        text.push_str(name);
+        text.push_str(if shaded { " gouraud" } else { " flat" });
+        if textured {
+            text.push_str(" textured");
+        }
+        if raw_texture {
+            text.push_str(" raw");
+        }
+        let _ = write!(text, " semi={}", semi_transparent as u8);
+    }
+
+    /// Write a numbered vertex position
+    fn vertex(text: &mut String, index: usize, vertex: &Vertex) {
+        let _ = write!(text, " v{}=({},{})", index, vertex.x, vertex.y);
+    }
+
+    /// Write a color
+    fn color(text: &mut String, color: &Color) {
+        let _ = write!(text, " rgb=({},{},{})", color.r, color.g, color.b);
+    }
+
+    /// Write a texture coordinate
+    fn texcoord(text: &mut String, texcoord: &TexCoord) {
+        let _ = write!(text, " uv=({},{})", texcoord.u, texcoord.v);
+    }
+
+    /// Write Gouraud-shaded polygon vertices
+    fn shaded_vertices(text: &mut String, vertices: &[(Vertex, Color)]) {
+        for (i, (vertex, color)) in vertices.iter().enumerate() {
+            Self::vertex(text, i, vertex);
+            Self::color(text, color);
+        }
+    }
+
+    /// Write textured polygon vertices; flat polygons list the color once
+    fn textured_vertices(text: &mut String, vertices: &[(Vertex, Color, TexCoord)], shaded: bool) {
+        for (i, (vertex, color, texcoord)) in vertices.iter().enumerate() {
+            Self::vertex(text, i, vertex);
+            Self::texcoord(text, texcoord);
+            if shaded || i == 0 {
+                Self::color(text, color);
+            }
+        }
+    }
+
+    /// Write line or polyline points; flat lines list the color once
+    fn line_vertices(text: &mut String, vertices: &[(Vertex, Color)], shaded: bool) {
+        if !shaded {
+            Self::color(text, &vertices[0].1);
+        }
+        for (i, (vertex, color)) in vertices.iter().enumerate() {
+            Self::vertex(text, i, vertex);
+            if shaded {
+                Self::color(text, color);
+            }
+        }
+    }
+
+    /// Write texture page, CLUT and blending information
+    fn texture(text: &mut String, texture: &TextureInfo, blend: &BlendMode) {
+        if texture.depth != TextureDepth::T15Bit {
+            let _ = write!(text, " clut=({},{})", texture.clut_x, texture.clut_y);
+        }
+        let _ = write!(
+            text,
+            " tpage=({},{}) {} blend={}",
+            texture.page_x,
+            texture.page_y,
+            Self::depth_name(texture.depth),
+            Self::blend_name(blend)
+        );
+    }
+
+    /// Short name of a texture depth
+    fn depth_name(depth: TextureDepth) -> &'static str {
+        match depth {
+            TextureDepth::T4Bit => "4bpp",
+            TextureDepth::T8Bit => "8bpp",
+            TextureDepth::T15Bit => "15bpp",
+        }
+    }
+
+    /// Short name of a blend mode
+    fn blend_name(blend: &BlendMode) -> &'static str {
+        match blend {
+            BlendMode::Average => "average",
+            BlendMode::Additive => "add",
+            BlendMode::Subtractive => "sub",
+            BlendMode::AddQuarter => "add_quarter",
+        }
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU command FIFO and draw timing
+//!
+//! GP0 commands are executed as soon as all of their words have arrived,
+//! but the real GPU needs time to rasterize them. This module estimates
+//! that time for every primitive from its clipped size and rendering mode
+//! and keeps the GPU busy for as long. While the GPU is busy, incoming
+//! words fill up the 16-word command FIFO, and the GPUSTAT ready flags and
+//! DMA request follow that state.
+//!
+//! Busy time counts down in [`GPU::tick`], and through the one-shot
+//! "GPU Draw" timing event once the GPU timing events are registered.
+//!
+//! The same estimates feed the per-frame [`GpuStats`](super::GpuStats).
+//!
+//! # References
+//!
+//! - [PSX-SPX: GPU Timings](http://problemkaputt.de/psx-spx.htm#gputimings)
+//! - [PSX-SPX: GPU Status Register](http://problemkaputt.de/psx-spx.htm#gpustatusregister)
+
+use super::stats::PrimitiveKind;
+use super::GPU;
+use crate::core::timing::{EventHandle, TimingEventManager};
+
+impl GPU {
+    /// GP0 command FIFO depth in words
+    pub const FIFO_DEPTH: usize = 16;
+
+    /// Check whether the GPU is still busy drawing
+    ///
+    /// # Returns
+    ///
+    /// true while previously submitted primitives are still being rendered
+    pub fn is_busy(&self) -> bool {
+        self.busy_cycles > 0
+    }
+
+    /// Get the remaining draw time
+    ///
+    /// # Returns
+    ///
+    /// CPU cycles until the GPU finishes the queued drawing work
+    pub fn busy_cycles(&self) -> u32 {
+        self.busy_cycles
+    }
+
+    /// Get the number of words waiting in the command FIFO
+    pub fn fifo_level(&self) -> usize {
+        self.fifo_words
+    }
+
+    /// Get the number of GP0 words written while the FIFO was full
+    pub fn fifo_overruns(&self) -> u64 {
+        self.fifo_overruns
+    }
+
+    /// Account for a GP0 word entering the command FIFO
+    ///
+    /// Words only stay in the FIFO while the GPU is busy. Writing to a full
+    /// FIFO from the CPU is a game timing bug on hardware; it is counted and
+    /// logged, and the word is still processed.
+    ///
+    /// GPU DMA waits for the ready-for-DMA flag on hardware instead of
+    /// overrunning. That stall is not modelled (the whole transfer runs at
+    /// once), so DMA words arriving at a full FIFO are processed without
+    /// being counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_dma` - Whether the word was fed by GPU DMA
+    pub(crate) fn queue_fifo_word(&mut self, from_dma: bool) {
+        if !self.is_busy() {
+            return;
+        }
+
+        if self.fifo_words >= Self::FIFO_DEPTH {
+            if from_dma {
+                return;
+            }
+            self.fifo_overruns += 1;
+            log::warn!(
+                "GP0 FIFO overrun ({} cycles of drawing pending)",
+                self.busy_cycles
+            );
+        } else {
+            self.fifo_words += 1;
+        }
+        self.update_ready_flags();
+    }
+
+    /// Discard the words waiting in the command FIFO (GP1(01h))
+    pub(crate) fn clear_fifo(&mut self) {
+        self.fifo_words = 0;
+        self.update_ready_flags();
+    }
+
+    /// Advance drawing by the given number of CPU cycles
+    ///
+    /// The FIFO drains once all queued drawing has finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Elapsed CPU cycles
+    pub(crate) fn advance_draw(&mut self, cycles: u32) {
+        if !self.is_busy() {
+            return;
+        }
+
+        self.busy_cycles = self.busy_cycles.saturating_sub(cycles);
+        if !self.is_busy() {
+            self.fifo_words = 0;
+        }
+        self.update_ready_flags();
+    }
+
+    /// Update the GPUSTAT ready flags and DMA request from the busy state
+    ///
+    /// - Bit 26 (ready for command): not drawing and FIFO empty
+    /// - Bit 28 (ready for DMA block): FIFO not full
+    /// - Bit 25 (DMA request): depends on the GP1(04h) DMA direction
+    pub(crate) fn update_ready_flags(&mut self) {
+        let fifo_full = self.fifo_words >= Self::FIFO_DEPTH;
+
+        self.status.ready_to_receive_cmd = !self.is_busy() && self.fifo_words == 0;
+        self.status.ready_to_receive_dma = !fifo_full;
+        self.status.dma_request = match self.status.dma_direction {
+            1 => !fifo_full,
+            2 => self.status.ready_to_receive_dma,
+            3 => self.status.ready_to_send_vram,
+            _ => false,
+        };
+    }
+
+    /// Add draw time for a command
+    ///
+    /// # Arguments
+    ///
+    /// * `gpu_cycles` - Estimated cost in GPU clock cycles
+    fn add_draw_cycles(&mut self, gpu_cycles: u64) {
+        // The GPU clock runs at 11/7 of the CPU clock
+        let cpu_cycles = (gpu_cycles * 7).div_ceil(11);
+        self.busy_cycles = self
+            .busy_cycles
+            .saturating_add(cpu_cycles.min(u32::MAX as u64) as u32);
+        self.update_ready_flags();
+    }
+
+    /// Clip a bounding box to the drawing area
+    ///
+    /// # Returns
+    ///
+    /// `(width, height)` of the visible part in pixels
+    fn clipped_size(&self, left: i32, top: i32, right: i32, bottom: i32) -> (u64, u64) {
+        let left = left.max(self.draw_area.left as i32);
+        let top = top.max(self.draw_area.top as i32);
+        let right = right.min(self.draw_area.right as i32);
+        let bottom = bottom.min(self.draw_area.bottom as i32);
+
+        if right < left || bottom < top {
+            return (0, 0);
+        }
+        ((right - left + 1) as u64, (bottom - top + 1) as u64)
+    }
+
+    /// Estimate how many of a primitive's pixels are drawn
+    ///
+    /// Only half the lines are drawn while the displayed interlace field
+    /// is being skipped.
+    fn drawn_pixels(&self, pixels: u64) -> u64 {
+        if self.is_interlaced_480() && !self.draw_mode.draw_to_display {
+            pixels.div_ceil(2)
+        } else {
+            pixels
+        }
+    }
+
+    /// Estimate the cost of filling pixels
+    ///
+    /// Each pixel costs one cycle, texture lookups add one more, and
+    /// reading the destination for blending or mask checking adds half.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - Pixels drawn (see [`GPU::drawn_pixels`])
+    fn pixel_cycles(&self, pixels: u64, textured: bool, semi_transparent: bool) -> u64 {
+        let mut half_cycles = 2;
+        if textured {
+            half_cycles += 2;
+        }
+        if semi_transparent || self.status.draw_pixels {
+            half_cycles += 1;
+        }
+
+        (pixels * half_cycles).div_ceil(2)
+    }
+
+    /// Add draw time and statistics for a triangle
+    ///
+    /// The pixel count is estimated as half of the clipped bounding box.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Screen positions (drawing offset applied)
+    /// * `textured` - Whether the triangle samples a texture
+    /// * `semi_transparent` - Whether the triangle is blended
+    pub(crate) fn add_triangle_cycles(
+        &mut self,
+        vertices: [(i16, i16); 3],
+        textured: bool,
+        semi_transparent: bool,
+    ) {
+        let xs = vertices.map(|v| v.0 as i32);
+        let ys = vertices.map(|v| v.1 as i32);
+        let bounds = (
+            *xs.iter().min().unwrap(),
+            *ys.iter().min().unwrap(),
+            *xs.iter().max().unwrap(),
+            *ys.iter().max().unwrap(),
+        );
+        let (width, height) = self.clipped_size(bounds.0, bounds.1, bounds.2, bounds.3);
+
+        let pixels = self.drawn_pixels((width * height).div_ceil(2));
+        let cycles = self.pixel_cycles(pixels, textured, semi_transparent);
+        self.add_draw_cycles(cycles);
+        self.count_primitive(
+            PrimitiveKind::Triangle,
+            textured,
+            semi_transparent,
+            pixels,
+            bounds,
+        );
+    }
+
+    /// Add draw time and statistics for a rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * `x`, `y` - Top-left corner (drawing offset applied)
+    /// * `width`, `height` - Size in pixels
+    /// * `textured` - Whether the rectangle samples a texture
+    /// * `semi_transparent` - Whether the rectangle is blended
+    pub(crate) fn add_rectangle_cycles(
+        &mut self,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        textured: bool,
+        semi_transparent: bool,
+    ) {
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let (x, y) = (x as i32, y as i32);
+        let bounds = (x, y, x + width as i32 - 1, y + height as i32 - 1);
+        let (width, height) = self.clipped_size(bounds.0, bounds.1, bounds.2, bounds.3);
+        let pixels = self.drawn_pixels(width * height);
+        let cycles = self.pixel_cycles(pixels, textured, semi_transparent);
+        self.add_draw_cycles(cycles);
+        self.count_primitive(
+            PrimitiveKind::Rectangle,
+            textured,
+            semi_transparent,
+            pixels,
+            bounds,
+        );
+    }
+
+    /// Add draw time and statistics for a line segment
+    ///
+    /// Lines take one cycle per pixel along their major axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `v0`, `v1` - End points (drawing offset applied)
+    /// * `semi_transparent` - Whether the command requested blending
+    pub(crate) fn add_line_cycles(
+        &mut self,
+        v0: (i16, i16),
+        v1: (i16, i16),
+        semi_transparent: bool,
+    ) {
+        let (x0, y0, x1, y1) = (v0.0 as i32, v0.1 as i32, v1.0 as i32, v1.1 as i32);
+        let length = (x1 - x0).unsigned_abs().max((y1 - y0).unsigned_abs()) as u64 + 1;
+        self.add_draw_cycles(length);
+
+        let bounds = (x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1));
+        let pixels = self.drawn_pixels(length);
+        self.count_primitive(PrimitiveKind::Line, false, semi_transparent, pixels, bounds);
+    }
+
+    /// Add draw time and statistics for a GP0(02h) fill
+    ///
+    /// Fills write 8 pixels per cycle plus a fixed per-line overhead.
+    pub(crate) fn add_fill_cycles(&mut self, width: u16, height: u16) {
+        let (width, height) = (width as u64, height as u64);
+        self.add_draw_cycles(46 + (width / 8 + 9) * height);
+
+        self.frame_stats.fills += 1;
+        self.frame_stats.estimated_pixels += width * height;
+    }
+
+    /// Add draw time and statistics for a GP0(80h) VRAM-to-VRAM copy
+    ///
+    /// Every pixel is read and written once.
+    pub(crate) fn add_copy_cycles(&mut self, width: u16, height: u16) {
+        let pixels = width as u64 * height as u64;
+        self.add_draw_cycles(pixels * 2);
+
+        self.frame_stats.copies += 1;
+        self.frame_stats.estimated_pixels += pixels;
+        self.frame_stats.vram_to_vram_bytes += pixels * 2;
+    }
+
+    /// Register the draw completion event
+    pub(crate) fn register_draw_event(&mut self, timing: &mut TimingEventManager) {
+        self.draw_event = Some(timing.register_event("GPU Draw"));
+        self.draw_event_cycles = 0;
+    }
+
+    /// Handle the draw completion event and schedule the next one
+    ///
+    /// The event is scheduled for the busy time outstanding when it was
+    /// armed. Work queued in the meantime is picked up by re-arming it.
+    ///
+    /// # Arguments
+    ///
+    /// * `timing` - Timing event manager
+    /// * `triggered_events` - List of event handles that have fired
+    pub(crate) fn process_draw_event(
+        &mut self,
+        timing: &mut TimingEventManager,
+        triggered_events: &[EventHandle],
+    ) {
+        let Some(handle) = self.draw_event else {
+            return;
+        };
+
+        if self.draw_event_cycles > 0 && triggered_events.contains(&handle) {
+            self.advance_draw(self.draw_event_cycles);
+            self.draw_event_cycles = 0;
+        }
+
+        if self.is_busy() && self.draw_event_cycles == 0 {
+            self.draw_event_cycles = self.busy_cycles;
+            timing.schedule(handle, self.busy_cycles.min(i32::MAX as u32) as i32);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! VRAM, texture page and CLUT export
+//!
+//! Converts VRAM contents into RGB images for debugging texture problems:
+//! the full 1024×512 VRAM, a single texture page decoded at 4-bit, 8-bit or
+//! 15-bit depth through a CLUT, and the palettes stored in VRAM. Images
+//! can be written as PNG or binary PPM.
+//!
+//! Pixels are converted from 15-bit to 24-bit color by shifting each channel
+//! left by 3, like the rasterizer. The mask bit is ignored, so transparent
+//! texels (color 0) export as black.
+
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::primitives::{TextureDepth, TextureInfo};
+use super::GPU;
+use crate::core::error::{GpuError, Result};
+
+/// Image file format for exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Portable Network Graphics
+    Png,
+    /// Binary portable pixmap (P6)
+    Ppm,
+}
+
+impl ImageFormat {
+    /// Pick the format from a file extension
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output path ending in `.png` or `.ppm`
+    ///
+    /// # Returns
+    ///
+    /// The matching format, or None for other extensions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::ImageFormat;
+    ///
+    /// assert_eq!(ImageFormat::from_path("vram.PNG"), Some(ImageFormat::Png));
+    /// assert_eq!(ImageFormat::from_path("vram.ppm"), Some(ImageFormat::Ppm));
+    /// assert_eq!(ImageFormat::from_path("vram.bmp"), None);
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        extension.parse().ok()
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "ppm" => Ok(Self::Ppm),
+            _ => Err(format!(
+                "unknown image format '{}' (expected png or ppm)",
+                s
+            )),
+        }
+    }
+}
+
+/// An RGB image exported from VRAM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VramImage {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Pixels in row-major order, 3 bytes (R, G, B) per pixel
+    pub pixels: Vec<u8>,
+}
+
+impl VramImage {
+    /// Build an image from 15-bit VRAM colors
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    /// * `colors` - `width * height` colors in 5-5-5 RGB format, row-major
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::VramImage;
+    ///
+    /// let image = VramImage::from_rgb15(2, 1, [0x001F, 0x7C00]);
+    /// assert_eq!(image.pixels, [248, 0, 0, 0, 0, 248]);
+    /// ```
+    pub fn from_rgb15(width: u32, height: u32, colors: impl IntoIterator<Item = u16>) -> Self {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 3);
+        for color in colors {
+            pixels.push(((color & 0x1F) << 3) as u8);
+            pixels.push((((color >> 5) & 0x1F) << 3) as u8);
+            pixels.push((((color >> 10) & 0x1F) << 3) as u8);
+        }
+        debug_assert_eq!(pixels.len(), width as usize * height as usize * 3);
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Build an image with one palette per line
+    ///
+    /// Each color is drawn as a `swatch`×`swatch` square. Lines are as wide
+    /// as the longest palette; shorter palettes are padded with black.
+    ///
+    /// # Arguments
+    ///
+    /// * `palettes` - Palettes to draw, top to bottom
+    /// * `swatch` - Size of each color square in pixels (at least 1)
+    pub fn from_palettes(palettes: &[Palette], swatch: u32) -> Self {
+        let swatch = swatch.max(1) as usize;
+        let entries = palettes.iter().map(|p| p.colors.len()).max().unwrap_or(0);
+        let width = entries * swatch;
+        let height = palettes.len() * swatch;
+
+        let mut colors = vec![0u16; width * height];
+        for (line, palette) in palettes.iter().enumerate() {
+            for (i, &color) in palette.colors.iter().enumerate() {
+                for y in 0..swatch {
+                    let row = (line * swatch + y) * width;
+                    colors[row + i * swatch..row + (i + 1) * swatch].fill(color);
+                }
+            }
+        }
+
+        Self::from_rgb15(width as u32, height as u32, colors)
+    }
+
+    /// Encode the image
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Output format
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(bytes)` with the encoded file contents
+    /// - `Err(EmulatorError::Gpu)` if PNG encoding fails
+    pub fn encode(&self, format: ImageFormat) -> Result<Vec<u8>> {
+        match format {
+            ImageFormat::Ppm => {
+                let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+                data.extend_from_slice(&self.pixels);
+                Ok(data)
+            }
+            ImageFormat::Png => {
+                let error = |e: png::EncodingError| GpuError::ImageExport(e.to_string());
+                let mut data = Vec::new();
+                let mut encoder = png::Encoder::new(&mut data, self.width, self.height);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let mut writer = encoder.write_header().map_err(error)?;
+                writer.write_image_data(&self.pixels).map_err(error)?;
+                writer.finish().map_err(error)?;
+                Ok(data)
+            }
+        }
+    }
+
+    /// Write the image to a file, choosing the format from the extension
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output path ending in `.png` or `.ppm`
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the extension is not recognized, encoding fails or
+    /// the file cannot be written
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            GpuError::ImageExport(format!(
+                "cannot tell image format of '{}' (use .png or .ppm)",
+                path.display()
+            ))
+        })?;
+        fs::write(path, self.encode(format)?)?;
+        Ok(())
+    }
+}
+
+/// A color lookup table read from VRAM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// CLUT X position in VRAM
+    pub x: u16,
+    /// CLUT Y position in VRAM
+    pub y: u16,
+    /// Colors in 5-5-5 RGB format (16 or 256 entries)
+    pub colors: Vec<u16>,
+}
+
+impl GPU {
+    /// Export the full 1024×512 VRAM as an image
+    ///
+    /// Every VRAM word is shown as a 15-bit color, so 4-bit and 8-bit
+    /// texture data appears as noise; use [`GPU::export_texture_page`] to
+    /// view it decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.write_vram(1, 0, 0x7FFF);
+    ///
+    /// let image = gpu.export_vram();
+    /// assert_eq!((image.width, image.height), (1024, 512));
+    /// assert_eq!(&image.pixels[3..6], &[248, 248, 248]);
+    /// ```
+    pub fn export_vram(&mut self) -> VramImage {
+        self.sync_renderer();
+        let colors = (0..Self::VRAM_HEIGHT as u16)
+            .flat_map(|y| (0..Self::VRAM_WIDTH as u16).map(move |x| (x, y)))
+            .map(|(x, y)| self.read_vram(x, y));
+        VramImage::from_rgb15(Self::VRAM_WIDTH as u32, Self::VRAM_HEIGHT as u32, colors)
+    }
+
+    /// Decode a texture page as a 256×256 texel image
+    ///
+    /// Texels are looked up the way textured primitives sample them, without
+    /// the texture window. The CLUT position is ignored for 15-bit pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `texture` - Texture page position, CLUT position and color depth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    /// use psrx::core::gpu::{TextureDepth, TextureInfo};
+    ///
+    /// let mut gpu = GPU::new();
+    /// // 4-bit texels 1, 0, 0, 0 and CLUT entry 1 = red
+    /// gpu.write_vram(64, 0, 0x0001);
+    /// gpu.write_vram(1, 480, 0x001F);
+    ///
+    /// let texture = TextureInfo {
+    ///     page_x: 64,
+    ///     page_y: 0,
+    ///     clut_x: 0,
+    ///     clut_y: 480,
+    ///     depth: TextureDepth::T4Bit,
+    /// };
+    /// let image = gpu.export_texture_page(&texture);
+    /// assert_eq!((image.width, image.height), (256, 256));
+    /// assert_eq!(&image.pixels[0..6], &[248, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn export_texture_page(&mut self, texture: &TextureInfo) -> VramImage {
+        self.sync_renderer();
+        let colors = (0..256u16)
+            .flat_map(|v| (0..256u16).map(move |u| (u, v)))
+            .map(|(u, v)| {
+                let y = texture.page_y + v;
+                match texture.depth {
+                    TextureDepth::T4Bit => {
+                        let word = self.read_vram(texture.page_x + u / 4, y);
+                        let index = (word >> ((u % 4) * 4)) & 0xF;
+                        self.read_vram(texture.clut_x + index, texture.clut_y)
+                    }
+                    TextureDepth::T8Bit => {
+                        let word = self.read_vram(texture.page_x + u / 2, y);
+                        let index = (word >> ((u % 2) * 8)) & 0xFF;
+                        self.read_vram(texture.clut_x + index, texture.clut_y)
+                    }
+                    TextureDepth::T15Bit => self.read_vram(texture.page_x + u, y),
+                }
+            });
+        VramImage::from_rgb15(256, 256, colors)
+    }
+
+    /// Read one CLUT from VRAM
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - CLUT X position
+    /// * `y` - CLUT Y position
+    /// * `depth` - Texture depth the CLUT is used with (16 entries for
+    ///   4-bit, 256 for 8-bit; 15-bit textures have no CLUT)
+    pub fn read_clut(&mut self, x: u16, y: u16, depth: TextureDepth) -> Palette {
+        self.sync_renderer();
+        let entries = match depth {
+            TextureDepth::T4Bit => 16,
+            TextureDepth::T8Bit => 256,
+            TextureDepth::T15Bit => 0,
+        };
+        Palette {
+            x,
+            y,
+            colors: (0..entries).map(|i| self.read_vram(x + i, y)).collect(),
+        }
+    }
+
+    /// Read all palettes stored in VRAM
+    ///
+    /// Every VRAM row is split into consecutive CLUTs of 16 (4-bit) or 256
+    /// (8-bit) entries. CLUTs that are entirely zero are skipped, which
+    /// leaves the palettes a game has uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - Texture depth the CLUTs are used with
+    ///
+    /// # Returns
+    ///
+    /// Non-empty palettes in VRAM order (top to bottom, left to right)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    /// use psrx::core::gpu::{TextureDepth, VramImage};
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.write_vram(32, 480, 0x7FFF);
+    ///
+    /// let palettes = gpu.clut_palettes(TextureDepth::T4Bit);
+    /// assert_eq!(palettes.len(), 1);
+    /// assert_eq!((palettes[0].x, palettes[0].y), (32, 480));
+    ///
+    /// let image = VramImage::from_palettes(&palettes, 8);
+    /// assert_eq!((image.width, image.height), (128, 8));
+    /// ```
+    pub fn clut_palettes(&mut self, depth: TextureDepth) -> Vec<Palette> {
+        let entries = match depth {
+            TextureDepth::T4Bit => 16,
+            TextureDepth::T8Bit => 256,
+            TextureDepth::T15Bit => return Vec::new(),
+        };
+
+        let mut palettes = Vec::new();
+        for y in 0..Self::VRAM_HEIGHT as u16 {
+            for x in (0..Self::VRAM_WIDTH as u16).step_by(entries) {
+                let palette = self.read_clut(x, y, depth);
+                if palette.colors.iter().any(|&color| color != 0) {
+                    palettes.push(palette);
+                }
+            }
+        }
+        palettes
+    }
+}
@@ -26,12 +26,13 @@
 //! - 0xE4: Set Drawing Area Bottom-Right
 //! - 0xE5: Set Drawing Offset
 //! - 0xE6: Mask Bit Setting
+//! - 0x01: Clear Texture Cache
 //!
 //! # References
 //!
 //! - [PSX-SPX: GP0 Drawing Settings](http://problemkaputt.de/psx-spx.htm#gpurenderattributes)
 
-use crate::core::gpu::GPU;
+use crate::core::gpu::{RasterizerSetting, GPU};
 
 impl GPU {
     /// GP0(E1h) - Draw Mode Setting (aka "Texpage")
@@ -103,6 +104,8 @@ impl GPU {
         self.draw_mode.texture_disable = texture_disable;
         self.draw_mode.texture_x_flip = texture_x_flip;
         self.draw_mode.texture_y_flip = texture_y_flip;
+        self.update_rasterizer_dithering();
+        self.update_rasterizer_interlace();
 
         // Update GPU status to mirror draw mode (GPUSTAT must reflect GP0 settings)
         self.status.texture_page_x_base = (cmd & 0xF) as u8;
@@ -330,6 +333,10 @@ impl GPU {
     /// - When "set mask bit" is enabled, all drawn pixels have bit 15 set to 1
     /// - When "check mask bit" is enabled, pixels with bit 15=1 are not overwritten
     /// - This is used to prevent certain areas from being drawn over
+    /// - Applies to polygons, lines, rectangles, CPU→VRAM and VRAM→VRAM
+    ///   transfers, but not to GP0(02h) fills
+    ///
+    /// Both bits are mirrored to GPUSTAT bits 11-12.
     ///
     /// # Examples
     ///
@@ -351,7 +358,8 @@ impl GPU {
         let check_mask_before_draw = ((cmd >> 1) & 1) != 0;
 
         self.status.set_mask_bit = set_mask_while_drawing;
-        self.status.draw_pixels = !check_mask_before_draw;
+        self.status.draw_pixels = check_mask_before_draw;
+        self.update_rasterizer_mask();
 
         log::debug!(
             "Mask settings: set={} check={}",
@@ -359,4 +367,34 @@ impl GPU {
             check_mask_before_draw
         );
     }
+    /// GP0(01h) - Clear Cache
+    ///
+    /// Invalidates the texture cache so that the next textured primitive
+    /// fetches its texels from VRAM again. Games issue this after uploading
+    /// new texture data to a page that may already be cached.
+    ///
+    /// Has no visible effect unless texture cache emulation is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use psrx::core::GPU;
+    /// let mut gpu = GPU::new();
+    ///
+    /// gpu.write_gp0(0x01000000);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GP0(01h) Clear Cache](http://problemkaputt.de/psx-spx.htm#gpumemorytransfercommands)
+    pub(crate) fn gp0_clear_cache(&mut self) {
+        if self.command_fifo.is_empty() {
+            return;
+        }
+
+        self.command_fifo.pop_front();
+        self.configure_renderer(RasterizerSetting::InvalidateTextureCache);
+
+        log::trace!("Texture cache cleared");
+    }
 }
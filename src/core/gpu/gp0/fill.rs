@@ -130,13 +130,15 @@ impl GPU {
         );
 
         // Perform the fill operation
+        self.add_fill_cycles(aligned_width, height);
         self.fill_vram_rect(x, y, aligned_width, height, color);
     }
 
     /// Fill a rectangular region of VRAM with a solid color
     ///
     /// This is a direct VRAM operation that bypasses all drawing settings
-    /// (drawing area, offset, mask bits, etc.).
+    /// (drawing area, offset, mask bits, etc.). The rendering backend
+    /// performs the fill.
     ///
     /// # Arguments
     ///
@@ -151,15 +153,8 @@ impl GPU {
     /// Coordinates automatically wrap at VRAM boundaries (1024×512).
     #[inline]
     fn fill_vram_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) {
-        // Fill rectangle row by row
-        for dy in 0..height {
-            let vram_y = (y + dy) & 0x1FF; // Wrap Y coordinate
-
-            for dx in 0..width {
-                let vram_x = (x + dx) & 0x3FF; // Wrap X coordinate
-                self.write_vram(vram_x, vram_y, color);
-            }
-        }
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.fill(vram, x, y, width, height, color);
     }
 }
 
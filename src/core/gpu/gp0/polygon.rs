@@ -17,6 +17,10 @@
 //!
 //! Implements parsing for triangle and quadrilateral rendering commands,
 //! including both flat-shaded, Gouraud-shaded, and textured primitives.
+//!
+//! When precision geometry is enabled and every vertex word of a polygon has
+//! a precise vertex, the polygon is drawn with sub-pixel positions instead.
+//! Semi-transparent monochrome polygons always use the integer path.
 
 use super::super::primitives::{Color, TexCoord, TextureInfo, Vertex};
 use super::super::GPU;
@@ -31,6 +35,8 @@ impl GPU {
             return; // Need more words
         }
 
+        let precise = self.precise_vertices([1, 2, 3]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let v2 = self.command_fifo.pop_front().unwrap();
@@ -43,7 +49,10 @@ impl GPU {
             Vertex::from_u32(v3),
         ];
 
-        self.render_monochrome_triangle(&vertices, &color, false);
+        match precise {
+            Some(precise) => self.render_precise_monochrome_triangle(&precise, &color),
+            None => self.render_monochrome_triangle(&vertices, &color, false),
+        }
     }
 
     /// GP0(0x22): Monochrome Triangle (Semi-Transparent)
@@ -79,6 +88,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 2, 3, 4]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let v2 = self.command_fifo.pop_front().unwrap();
@@ -93,7 +104,10 @@ impl GPU {
             Vertex::from_u32(v4),
         ];
 
-        self.render_monochrome_quad(&vertices, &color, false);
+        match precise {
+            Some(precise) => self.render_precise_monochrome_quad(&precise, &color),
+            None => self.render_monochrome_quad(&vertices, &color, false),
+        }
     }
 
     /// GP0(0x2A): Monochrome Quad (Semi-Transparent)
@@ -146,6 +160,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5]);
+
         let c0v0 = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let c1v1 = self.command_fifo.pop_front().unwrap();
@@ -164,7 +180,10 @@ impl GPU {
             Vertex::from_u32(v3),
         ];
 
-        self.render_gradient_triangle(&vertices, &colors, false);
+        match precise {
+            Some(precise) => self.render_precise_gradient_triangle(&precise, &colors),
+            None => self.render_gradient_triangle(&vertices, &colors, false),
+        }
     }
 
     /// GP0(0x32): Gouraud-Shaded Triangle (Semi-Transparent)
@@ -176,6 +195,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5]);
+
         let c0v0 = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let c1v1 = self.command_fifo.pop_front().unwrap();
@@ -194,7 +215,10 @@ impl GPU {
             Vertex::from_u32(v3),
         ];
 
-        self.render_gradient_triangle(&vertices, &colors, true);
+        match precise {
+            Some(precise) => self.render_precise_gradient_triangle(&precise, &colors),
+            None => self.render_gradient_triangle(&vertices, &colors, true),
+        }
     }
 
     /// GP0(0x38): Gouraud-Shaded Quad (Opaque)
@@ -219,6 +243,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5, 7]);
+
         let c0v0 = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let c1v1 = self.command_fifo.pop_front().unwrap();
@@ -241,7 +267,10 @@ impl GPU {
             Vertex::from_u32(v4),
         ];
 
-        self.render_gradient_quad(&vertices, &colors, false);
+        match precise {
+            Some(precise) => self.render_precise_gradient_quad(&precise, &colors),
+            None => self.render_gradient_quad(&vertices, &colors, false),
+        }
     }
 
     /// GP0(0x3A): Gouraud-Shaded Quad (Semi-Transparent)
@@ -253,6 +282,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5, 7]);
+
         let c0v0 = self.command_fifo.pop_front().unwrap();
         let v1 = self.command_fifo.pop_front().unwrap();
         let c1v1 = self.command_fifo.pop_front().unwrap();
@@ -275,7 +306,10 @@ impl GPU {
             Vertex::from_u32(v4),
         ];
 
-        self.render_gradient_quad(&vertices, &colors, true);
+        match precise {
+            Some(precise) => self.render_precise_gradient_quad(&precise, &colors),
+            None => self.render_gradient_quad(&vertices, &colors, true),
+        }
     }
 
     /// GP0(0x24): Textured Triangle (Opaque)
@@ -315,6 +349,8 @@ impl GPU {
             return; // Need more words
         }
 
+        let precise = self.precise_vertices([1, 3, 5]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v0 = self.command_fifo.pop_front().unwrap();
         let t0clut = self.command_fifo.pop_front().unwrap();
@@ -352,7 +388,14 @@ impl GPU {
             depth: tex_depth.into(),
         };
 
-        self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        match precise {
+            Some(precise) => {
+                self.render_precise_textured_triangle(&precise, &texcoords, &texture_info, &color)
+            }
+            None => {
+                self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false)
+            }
+        }
     }
 
     /// GP0(0x26): Textured Triangle (Semi-Transparent)
@@ -364,6 +407,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v0 = self.command_fifo.pop_front().unwrap();
         let t0clut = self.command_fifo.pop_front().unwrap();
@@ -398,7 +443,14 @@ impl GPU {
             depth: tex_depth.into(),
         };
 
-        self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, true);
+        match precise {
+            Some(precise) => {
+                self.render_precise_textured_triangle(&precise, &texcoords, &texture_info, &color)
+            }
+            None => {
+                self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, true)
+            }
+        }
     }
 
     /// GP0(0x2C): Textured Quadrilateral (Opaque)
@@ -424,6 +476,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5, 7]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v0 = self.command_fifo.pop_front().unwrap();
         let t0clut = self.command_fifo.pop_front().unwrap();
@@ -462,7 +516,12 @@ impl GPU {
             depth: tex_depth.into(),
         };
 
-        self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false);
+        match precise {
+            Some(precise) => {
+                self.render_precise_textured_quad(&precise, &texcoords, &texture_info, &color)
+            }
+            None => self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false),
+        }
     }
 
     /// GP0(0x2E): Textured Quadrilateral (Semi-Transparent)
@@ -474,6 +533,8 @@ impl GPU {
             return;
         }
 
+        let precise = self.precise_vertices([1, 3, 5, 7]);
+
         let cmd = self.command_fifo.pop_front().unwrap();
         let v0 = self.command_fifo.pop_front().unwrap();
         let t0clut = self.command_fifo.pop_front().unwrap();
@@ -512,6 +573,11 @@ impl GPU {
             depth: tex_depth.into(),
         };
 
-        self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, true);
+        match precise {
+            Some(precise) => {
+                self.render_precise_textured_quad(&precise, &texcoords, &texture_info, &color)
+            }
+            None => self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, true),
+        }
     }
 }
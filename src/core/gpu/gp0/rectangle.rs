@@ -21,7 +21,7 @@
 //! - Variable size and fixed size (1×1, 8×8, 16×16)
 
 use super::super::primitives::{Color, TexCoord, TextureInfo, Vertex};
-use super::super::GPU;
+use super::super::{RenderCommand, GPU};
 
 impl GPU {
     // =========================================================================
@@ -852,18 +852,23 @@ impl GPU {
         color: &Color,
         semi_transparent: bool,
     ) {
-        self.rasterizer.draw_rectangle(
-            &mut self.vram,
-            &self.draw_mode,
-            &self.draw_area,
-            self.draw_offset,
-            x,
-            y,
+        self.add_rectangle_cycles(
+            x.wrapping_add(self.draw_offset.0),
+            y.wrapping_add(self.draw_offset.1),
             width,
             height,
-            color,
+            false,
             semi_transparent,
         );
+        self.submit_render_command(RenderCommand::Rectangle {
+            draw_mode: self.draw_mode,
+            draw_area: self.draw_area,
+            draw_offset: self.draw_offset,
+            position: (x, y),
+            size: (width, height),
+            color: *color,
+            semi_transparent,
+        });
     }
 
     /// Render a textured rectangle
@@ -894,21 +899,25 @@ impl GPU {
         semi_transparent: bool,
         modulated: bool,
     ) {
-        self.rasterizer.draw_textured_rectangle(
-            &mut self.vram,
-            &self.draw_mode,
-            &self.draw_area,
-            self.draw_offset,
-            x,
-            y,
+        self.add_rectangle_cycles(
+            x.wrapping_add(self.draw_offset.0),
+            y.wrapping_add(self.draw_offset.1),
             width,
             height,
-            tex_u,
-            tex_v,
-            texture_info,
-            color,
+            true,
             semi_transparent,
-            modulated,
         );
+        self.submit_render_command(RenderCommand::TexturedRectangle {
+            draw_mode: self.draw_mode,
+            draw_area: self.draw_area,
+            draw_offset: self.draw_offset,
+            position: (x, y),
+            size: (width, height),
+            texcoord: (tex_u, tex_v),
+            texture: *texture_info,
+            color: *color,
+            semi_transparent,
+            modulated,
+        });
     }
 }
@@ -84,6 +84,7 @@ impl GPU {
             Some(t) => t,
             None => return,
         };
+        self.frame_stats.cpu_to_vram_bytes += 4;
 
         // Each u32 contains two 16-bit pixels
         let pixel1 = (value & 0xFFFF) as u16;
@@ -92,7 +93,7 @@ impl GPU {
         // Write first pixel
         let vram_x = (transfer.x + transfer.current_x) & 0x3FF;
         let vram_y = (transfer.y + transfer.current_y) & 0x1FF;
-        self.write_vram(vram_x, vram_y, pixel1);
+        self.write_vram_masked(vram_x, vram_y, pixel1);
 
         transfer.current_x += 1;
         if transfer.current_x >= transfer.width {
@@ -104,7 +105,7 @@ impl GPU {
         if transfer.current_y < transfer.height {
             let vram_x = (transfer.x + transfer.current_x) & 0x3FF;
             let vram_y = (transfer.y + transfer.current_y) & 0x1FF;
-            self.write_vram(vram_x, vram_y, pixel2);
+            self.write_vram_masked(vram_x, vram_y, pixel2);
 
             transfer.current_x += 1;
             if transfer.current_x >= transfer.width {
@@ -166,6 +167,7 @@ impl GPU {
 
         // Update status to indicate data is ready
         self.status.ready_to_send_vram = true;
+        self.update_ready_flags();
     }
 
     /// GP0(0x80): VRAM→VRAM Transfer
@@ -186,6 +188,7 @@ impl GPU {
         let src_coords = self.command_fifo.pop_front().unwrap();
         let dst_coords = self.command_fifo.pop_front().unwrap();
         let size = self.command_fifo.pop_front().unwrap();
+        self.sync_renderer();
 
         let src_x = (src_coords & 0xFFFF) as u16 & 0x3FF;
         let src_y = ((src_coords >> 16) & 0xFFFF) as u16 & 0x1FF;
@@ -204,28 +207,18 @@ impl GPU {
             height
         );
 
-        // Copy rectangle
-        // Note: Need to handle overlapping regions correctly
-        let mut temp_buffer = vec![0u16; (width as usize) * (height as usize)];
-
-        // Read source
-        for y in 0..height {
-            for x in 0..width {
-                let sx = (src_x + x) & 0x3FF;
-                let sy = (src_y + y) & 0x1FF;
-                temp_buffer[(y as usize) * (width as usize) + (x as usize)] =
-                    self.read_vram(sx, sy);
-            }
-        }
-
-        // Write destination
-        for y in 0..height {
-            for x in 0..width {
-                let dx = (dst_x + x) & 0x3FF;
-                let dy = (dst_y + y) & 0x1FF;
-                let pixel = temp_buffer[(y as usize) * (width as usize) + (x as usize)];
-                self.write_vram(dx, dy, pixel);
-            }
-        }
+        self.add_copy_cycles(width, height);
+
+        // The backend copies native VRAM and any state of its own
+        let (mask_set, mask_check) = (self.status.set_mask_bit, self.status.draw_pixels);
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.copy_vram(
+            vram,
+            (src_x, src_y),
+            (dst_x, dst_y),
+            (width, height),
+            mask_set,
+            mask_check,
+        );
     }
 }
@@ -17,7 +17,7 @@
 //!
 //! Implements GPU control operations including reset, interrupt, and DMA.
 
-use super::super::GPU;
+use super::super::{GpuVersion, GPU};
 
 impl GPU {
     /// GP1(0x00): Reset GPU
@@ -43,6 +43,8 @@ impl GPU {
 
         // Cancel any ongoing VRAM transfer
         self.vram_transfer = None;
+        self.clear_fifo();
+        self.disassembler.reset();
 
         log::debug!("Command buffer reset");
     }
@@ -66,6 +68,7 @@ impl GPU {
     pub(crate) fn gp1_dma_direction(&mut self, value: u32) {
         let direction = (value & 3) as u8;
         self.status.dma_direction = direction;
+        self.update_ready_flags();
 
         match direction {
             0 => log::debug!("DMA off"),
@@ -76,30 +79,66 @@ impl GPU {
         }
     }
 
-    /// GP1(0x10): GPU Info
+    /// GP1(0x10-0x1F): GPU Info
     ///
-    /// Requests GPU information to be returned via the GPUREAD register.
-    /// Different info types return different GPU state information.
+    /// Latches GPU information into the GPUREAD register, where it can be
+    /// read immediately. Info types that return nothing leave the previous
+    /// GPUREAD value unchanged.
     ///
     /// # Arguments
     ///
-    /// * `value` - Bits 0-7: Info type
-    ///   - 0x02: Texture window settings
-    ///   - 0x03: Draw area top left
-    ///   - 0x04: Draw area bottom right
-    ///   - 0x05: Draw offset
-    ///   - 0x07: GPU version (returns 2 for PSX)
+    /// * `value` - Bits 0-23: Info type (mirrored every 8 on the old GPU,
+    ///   every 16 on the new GPU)
+    ///   - 0x02: Texture window settings (GP0(E2h), 20 bits)
+    ///   - 0x03: Draw area top left (GP0(E3h), 19/20 bits)
+    ///   - 0x04: Draw area bottom right (GP0(E4h), 19/20 bits)
+    ///   - 0x05: Draw offset (GP0(E5h), 22 bits)
+    ///   - 0x07: GPU type (new GPU only, returns 2)
+    ///   - 0x08: Unknown (new GPU only, returns 0)
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GPU Status Register](http://problemkaputt.de/psx-spx.htm#gpuioportsdmachannelscommandsvram)
     pub(crate) fn gp1_get_gpu_info(&mut self, value: u32) {
-        let info_type = value & 0xFF;
+        let info_type = match self.version {
+            GpuVersion::Old => value & 0x07,
+            GpuVersion::New => value & 0x0F,
+        };
 
-        log::debug!("GPU info request: type {}", info_type);
+        // Draw area Y is 9 bits on the old GPU, 10 bits on the new one
+        let area_y_bits = match self.version {
+            GpuVersion::Old => 0x1FF,
+            GpuVersion::New => 0x3FF,
+        };
+        let draw_area = |x: u16, y: u16| (x as u32 & 0x3FF) | ((y as u32 & area_y_bits) << 10);
 
-        // TODO: Implement proper GPU info responses via GPUREAD register
-        // Info types:
-        // 0x02 - Texture window
-        // 0x03 - Draw area top left
-        // 0x04 - Draw area bottom right
-        // 0x05 - Draw offset
-        // 0x07 - GPU version (2 for PSX)
+        let response = match info_type {
+            0x02 => {
+                let window = &self.texture_window;
+                Some(
+                    (window.mask_x as u32 & 0x1F)
+                        | ((window.mask_y as u32 & 0x1F) << 5)
+                        | ((window.offset_x as u32 & 0x1F) << 10)
+                        | ((window.offset_y as u32 & 0x1F) << 15),
+                )
+            }
+            0x03 => Some(draw_area(self.draw_area.left, self.draw_area.top)),
+            0x04 => Some(draw_area(self.draw_area.right, self.draw_area.bottom)),
+            0x05 => {
+                let (x, y) = self.draw_offset;
+                Some((x as u32 & 0x7FF) | ((y as u32 & 0x7FF) << 11))
+            }
+            0x07 if self.version == GpuVersion::New => Some(2),
+            0x08 if self.version == GpuVersion::New => Some(0),
+            _ => None,
+        };
+
+        match response {
+            Some(response) => {
+                self.gpuread = response;
+                log::debug!("GPU info request: type {} -> 0x{:08X}", info_type, response);
+            }
+            None => log::debug!("GPU info request: type {} (no response)", info_type),
+        }
     }
 }
@@ -50,12 +50,17 @@ impl GPU {
         self.display_area.x = x;
         self.display_area.y = y;
 
+        // Displayed field lines are counted from the display start
+        self.update_rasterizer_interlace();
+
         log::debug!("Display area start: ({}, {})", x, y);
     }
 
     /// GP1(0x06): Horizontal Display Range
     ///
     /// Sets the horizontal display range on screen (scanline timing).
+    /// The range is given in video clock cycles; the display width in
+    /// pixels follows from the dot clock divider of the current mode.
     ///
     /// # Arguments
     ///
@@ -64,8 +69,9 @@ impl GPU {
         let x1 = (value & 0xFFF) as u16;
         let x2 = ((value >> 12) & 0xFFF) as u16;
 
-        // Store as width
-        self.display_area.width = x2.saturating_sub(x1);
+        self.display_range.x1 = x1;
+        self.display_range.x2 = x2;
+        self.update_display_size();
 
         log::debug!(
             "Horizontal display range: {} to {} (width: {})",
@@ -86,8 +92,9 @@ impl GPU {
         let y1 = (value & 0x3FF) as u16;
         let y2 = ((value >> 10) & 0x3FF) as u16;
 
-        // Store as height
-        self.display_area.height = y2.saturating_sub(y1);
+        self.display_range.y1 = y1;
+        self.display_range.y2 = y2;
+        self.update_display_size();
 
         log::debug!(
             "Vertical display range: {} to {} (height: {})",
@@ -129,6 +136,7 @@ impl GPU {
         // Update status register horizontal resolution bits
         self.status.horizontal_res_1 = hr1;
         self.status.horizontal_res_2 = hr2;
+        self.update_display_size();
 
         // Vertical resolution
         let vres = ((value >> 2) & 1) != 0;
@@ -161,6 +169,10 @@ impl GPU {
         let interlaced = ((value >> 5) & 1) != 0;
         self.display_mode.interlaced = interlaced;
         self.status.vertical_interlace = interlaced;
+        if !interlaced {
+            self.status.interlace_field = false;
+        }
+        self.update_rasterizer_interlace();
 
         // Reverse flag (rarely used)
         self.status.reverse_flag = ((value >> 7) & 1) != 0;
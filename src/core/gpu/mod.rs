@@ -49,21 +49,42 @@
 
 use std::collections::VecDeque;
 
+use super::precision::{PrecisionCache, PrecisionVertex};
 use super::timing::EventHandle;
 
 // Module declarations
+mod backend;
+mod capture;
+mod disassembler;
+mod draw_timing;
+mod export;
 mod gp0;
 mod gp1;
+mod precision;
 mod primitives;
 mod registers;
 mod render;
+mod stats;
 #[cfg(test)]
 mod tests;
 
 // Public re-exports
+pub use backend::{
+    NullRenderer, RecordingRenderer, RenderCall, RenderLog, Renderer, RendererKind,
+    SoftwareRenderer,
+};
+use capture::CaptureRecorder;
+pub use capture::{CaptureEvent, CaptureRegisters, GpuCapture, GPU_CAPTURE_VERSION};
+pub use disassembler::GpuCommandDisassembler;
+pub use export::{ImageFormat, Palette, VramImage};
 pub use primitives::*;
 pub use registers::*;
-pub use render::Rasterizer;
+use render::{widen, RenderThread};
+pub use render::{
+    Rasterizer, RasterizerSetting, RenderCommand, ReplacementTexture, TextureCache, TextureKey,
+    TextureReplacementConfig, WidescreenSurface,
+};
+pub use stats::GpuStats;
 
 /// GPU state representing the CXD8561 graphics processor
 ///
@@ -90,10 +111,19 @@ pub struct GPU {
     /// (left-to-right, top-to-bottom). Each pixel is a 16-bit value in 5-5-5 RGB format.
     pub(crate) vram: Vec<u16>,
 
-    /// Software rasterizer for drawing primitives
+    /// Rendering backend that draws primitives and produces display output
     ///
-    /// Handles the actual pixel-level rasterization of triangles and other primitives.
-    pub(crate) rasterizer: Rasterizer,
+    /// None while the render thread owns it.
+    pub(crate) renderer: Option<Box<dyn Renderer>>,
+
+    /// Render worker used in threaded mode (None = draw synchronously)
+    pub(crate) render_thread: Option<RenderThread>,
+
+    /// Internal resolution scale factor requested from the backend (1 = native)
+    pub(crate) resolution_scale: u16,
+
+    /// Whether texture cache emulation is enabled in the backend
+    pub(crate) texture_cache_enabled: bool,
 
     /// Drawing mode state
     pub(crate) draw_mode: DrawMode,
@@ -118,6 +148,12 @@ pub struct GPU {
     /// Defines the region of VRAM that is output to the display.
     pub(crate) display_area: DisplayArea,
 
+    /// Display range on screen (GP1(06h)/GP1(07h))
+    ///
+    /// Determines the size of the display area and where it sits within
+    /// the visible TV picture.
+    pub(crate) display_range: DisplayRange,
+
     /// Display mode (resolution, color depth, etc.)
     pub(crate) display_mode: DisplayMode,
 
@@ -134,6 +170,16 @@ pub struct GPU {
     /// Tracks the state of ongoing VRAM-to-CPU or CPU-to-VRAM transfers.
     pub(crate) vram_transfer: Option<VRAMTransfer>,
 
+    /// GPUREAD latch
+    ///
+    /// Last value returned through GPUREAD, either a VRAM→CPU transfer word
+    /// or a GP1(10h) info response. Reads without a pending transfer return
+    /// this value again.
+    pub(crate) gpuread: u32,
+
+    /// GPU hardware revision (affects GP1(10h) responses)
+    pub(crate) version: GpuVersion,
+
     /// Scanline counter (0-262 for NTSC)
     ///
     /// Tracks the current scanline being rendered. NTSC mode uses 263 scanlines total,
@@ -166,12 +212,78 @@ pub struct GPU {
     /// HBlank event handle
     hblank_event: Option<EventHandle>,
 
+    /// Video mode the timing event intervals were configured for
+    timing_mode: VideoMode,
+
     // Interrupt flags
     /// VBlank interrupt pending flag
     vblank_interrupt_pending: bool,
 
     /// HBlank interrupt pending flag
     hblank_interrupt_pending: bool,
+
+    // Precision geometry
+    /// Precision geometry enabled (off by default)
+    precision_enabled: bool,
+
+    /// Precise vertices stored to RAM by SWC2, keyed by address
+    pub(crate) precision_cache: PrecisionCache,
+
+    /// Total number of words ever queued in the command FIFO
+    ///
+    /// Used to give every FIFO word a sequence number, so that the precise
+    /// vertex fetched along with a word can be found again when the command
+    /// is parsed.
+    gp0_words_queued: u64,
+
+    /// Precise vertices of queued FIFO words, tagged with their sequence number
+    precision_words: VecDeque<(u64, PrecisionVertex)>,
+
+    /// Widescreen output enabled (off by default)
+    ///
+    /// Frames are 4/3 as wide as the display area and shown at 16:9. Used
+    /// together with the GTE widescreen projection.
+    widescreen: bool,
+
+    /// Dithering allowed by the user (on by default)
+    ///
+    /// Dithering is only applied when both this and the GP0(E1h) dither
+    /// bit are set. Turning it off gives a cleaner true-color look.
+    dithering_enabled: bool,
+
+    /// How the two fields of 480-line interlaced output are combined
+    deinterlace: Deinterlace,
+
+    /// Part of the video signal returned by the framebuffer
+    crop_mode: CropMode,
+
+    // Draw timing
+    /// CPU cycles until the queued drawing work is finished
+    busy_cycles: u32,
+
+    /// Words waiting in the command FIFO while the GPU is busy
+    fifo_words: usize,
+
+    /// Number of GP0 words written while the FIFO was full
+    fifo_overruns: u64,
+
+    /// Draw completion event handle
+    draw_event: Option<EventHandle>,
+
+    /// Busy cycles covered by the currently scheduled draw event (0 = none)
+    draw_event_cycles: u32,
+
+    /// Input capture in progress (None = not capturing)
+    capture: Option<Box<CaptureRecorder>>,
+
+    /// GP0 stream disassembler for trace logging
+    disassembler: GpuCommandDisassembler,
+
+    /// Statistics of the frame being drawn
+    frame_stats: GpuStats,
+
+    /// Statistics of the last completed frame
+    last_frame_stats: GpuStats,
 }
 
 impl GPU {
@@ -187,16 +299,19 @@ impl GPU {
     /// Total scanlines per frame (NTSC)
     ///
     /// NTSC video uses 263 scanlines per frame (0-262 inclusive).
+    /// See [`VideoMode::scanlines_per_frame`] for the PAL value.
     pub const SCANLINES_PER_FRAME: u16 = 263;
 
-    /// Dots per scanline
+    /// Dots per scanline (NTSC)
     ///
     /// Each scanline consists of 3413 dots at the GPU pixel clock rate.
+    /// See [`VideoMode::dots_per_scanline`] for the PAL value.
     pub const DOTS_PER_SCANLINE: u16 = 3413;
 
     /// VBlank start scanline (NTSC)
     ///
     /// VBlank begins at scanline 243 in NTSC mode.
+    /// See [`VideoMode::vblank_start`] for the PAL value.
     pub const VBLANK_START: u16 = 243;
 
     /// VBlank end scanline (NTSC)
@@ -214,42 +329,85 @@ impl GPU {
     ///
     /// # Returns
     ///
-    /// A new GPU instance ready for operation
+    /// A new GPU instance ready for operation, drawing with the
+    /// [`SoftwareRenderer`]
     ///
     /// # Examples
     ///
     /// ```
     /// use psrx::core::GPU;
     ///
-    /// let gpu = GPU::new();
+    /// let mut gpu = GPU::new();
     /// assert_eq!(gpu.read_vram(0, 0), 0x0000); // Black
     /// ```
     pub fn new() -> Self {
-        // Create GPU with rasterizer
+        Self::with_renderer(Box::new(SoftwareRenderer::new()))
+    }
+
+    /// Create a new GPU instance drawing with the given backend
+    ///
+    /// # Arguments
+    ///
+    /// * `renderer` - Rendering backend
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{RendererKind, GPU};
+    ///
+    /// let mut gpu = GPU::with_renderer(RendererKind::Null.create());
+    /// assert_eq!(gpu.renderer_name(), "null");
+    /// ```
+    pub fn with_renderer(renderer: Box<dyn Renderer>) -> Self {
         let mut gpu = Self {
             vram: vec![0x0000; Self::VRAM_SIZE],
-            rasterizer: Rasterizer::new(),
+            renderer: Some(renderer),
+            render_thread: None,
+            resolution_scale: 1,
+            texture_cache_enabled: false,
             draw_mode: DrawMode::default(),
             draw_area: DrawingArea::default(),
             draw_offset: (0, 0),
             texture_window: TextureWindow::default(),
             display_area: DisplayArea::default(),
+            display_range: DisplayRange::default(),
             display_mode: DisplayMode::default(),
             command_fifo: VecDeque::new(),
             status: GPUStatus::default(),
             vram_transfer: None,
+            gpuread: 0,
+            version: GpuVersion::default(),
             scanline: 0,
             dots: 0,
             in_vblank: false,
             in_hblank: false,
             vblank_event: None,
             hblank_event: None,
+            timing_mode: VideoMode::NTSC,
             vblank_interrupt_pending: false,
             hblank_interrupt_pending: false,
+            precision_enabled: false,
+            precision_cache: PrecisionCache::new(),
+            gp0_words_queued: 0,
+            precision_words: VecDeque::new(),
+            widescreen: false,
+            dithering_enabled: true,
+            deinterlace: Deinterlace::default(),
+            crop_mode: CropMode::default(),
+            busy_cycles: 0,
+            fifo_words: 0,
+            fifo_overruns: 0,
+            draw_event: None,
+            draw_event_cycles: 0,
+            capture: None,
+            disassembler: GpuCommandDisassembler::new(),
+            frame_stats: GpuStats::default(),
+            last_frame_stats: GpuStats::default(),
         };
 
-        // Initialize rasterizer with default clip rect
+        // Initialize the backend with the default clip rect
         gpu.update_rasterizer_clip_rect();
+        gpu.update_rasterizer_dithering();
         gpu
     }
 
@@ -273,7 +431,9 @@ impl GPU {
         self.reset_state_preserving_vram();
 
         // Clear VRAM to black (separate from state reset)
-        self.vram.fill(0x0000);
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.clear(vram);
+        self.precision_cache.clear();
     }
 
     /// Reset GPU state without clearing VRAM
@@ -287,14 +447,24 @@ impl GPU {
         self.draw_offset = (0, 0);
         self.texture_window = TextureWindow::default();
         self.display_area = DisplayArea::default();
+        self.display_range = DisplayRange::default();
         self.display_mode = DisplayMode::default();
         self.command_fifo.clear();
+        self.precision_words.clear();
+        self.disassembler.reset();
         self.status = GPUStatus::default();
         self.vram_transfer = None;
         self.scanline = 0;
         self.dots = 0;
         self.in_vblank = false;
         self.in_hblank = false;
+        self.busy_cycles = 0;
+        self.fifo_words = 0;
+        self.draw_event_cycles = 0;
+        self.update_rasterizer_dithering();
+        self.update_rasterizer_mask();
+        self.update_rasterizer_interlace();
+        self.configure_renderer(RasterizerSetting::InvalidateTextureCache);
     }
 
     /// Read a 16-bit pixel from VRAM
@@ -313,16 +483,19 @@ impl GPU {
     /// Coordinates are automatically wrapped to valid VRAM ranges
     /// (0-1023 for X, 0-511 for Y), matching PlayStation hardware behavior.
     ///
+    /// Waits for the render thread if it currently owns VRAM.
+    ///
     /// # Examples
     ///
     /// ```
     /// use psrx::core::GPU;
     ///
-    /// let gpu = GPU::new();
+    /// let mut gpu = GPU::new();
     /// let pixel = gpu.read_vram(100, 100);
     /// ```
     #[inline(always)]
-    pub fn read_vram(&self, x: u16, y: u16) -> u16 {
+    pub fn read_vram(&mut self, x: u16, y: u16) -> u16 {
+        self.sync_renderer();
         let index = self.vram_index(x, y);
         self.vram[index]
     }
@@ -351,8 +524,35 @@ impl GPU {
     /// ```
     #[inline(always)]
     pub fn write_vram(&mut self, x: u16, y: u16, value: u16) {
-        let index = self.vram_index(x, y);
-        self.vram[index] = value;
+        self.record_capture_event(CaptureEvent::VramWrite { x, y, value });
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.write_vram(vram, x, y, value);
+    }
+
+    /// Write a 16-bit pixel to VRAM, honoring the mask bit settings
+    ///
+    /// Used by CPU→VRAM and VRAM→VRAM transfers, which obey GP0(E6h) like
+    /// the rasterizer does. Direct [`write_vram`](Self::write_vram) calls
+    /// and GP0(02h) fills bypass the mask settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate (0-1023)
+    /// * `y` - Y coordinate (0-511)
+    /// * `value` - 16-bit pixel value in 5-5-5 RGB format
+    pub(crate) fn write_vram_masked(&mut self, x: u16, y: u16, value: u16) {
+        self.sync_renderer();
+        if self.status.draw_pixels && (self.read_vram(x, y) & 0x8000) != 0 {
+            return;
+        }
+
+        let value = if self.status.set_mask_bit {
+            value | 0x8000
+        } else {
+            value
+        };
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.write_vram(vram, x, y, value);
     }
 
     /// Get VRAM index from coordinates
@@ -381,24 +581,291 @@ impl GPU {
     /// This should be called whenever the drawing area is modified
     /// to keep the rasterizer's clip rect in sync.
     pub(crate) fn update_rasterizer_clip_rect(&mut self) {
-        self.rasterizer.set_clip_rect(
+        self.configure_renderer(RasterizerSetting::ClipRect(
             self.draw_area.left as i16,
             self.draw_area.top as i16,
             self.draw_area.right as i16,
             self.draw_area.bottom as i16,
-        );
+        ));
+    }
+
+    /// Update rasterizer mask bit settings from GPUSTAT
+    ///
+    /// This should be called whenever GP0(E6h) changes the mask settings
+    /// or the GPU status is reset.
+    pub(crate) fn update_rasterizer_mask(&mut self) {
+        self.configure_renderer(RasterizerSetting::Mask {
+            set: self.status.set_mask_bit,
+            check: self.status.draw_pixels,
+        });
+    }
+
+    /// Update rasterizer field skipping from the display and draw modes
+    ///
+    /// In 480-line interlaced mode, when GP0(E1h) prohibits drawing to the
+    /// display area, the lines of the currently displayed field are left
+    /// untouched so the field being shown does not tear. Fields count lines
+    /// from the display start, so with an odd display start Y the odd field
+    /// lies on even VRAM rows. This should be called whenever the draw mode,
+    /// display mode, display start or displayed field changes.
+    pub(crate) fn update_rasterizer_interlace(&mut self) {
+        let odd_start = self.display_area.y & 1 == 1;
+        let skip = (self.is_interlaced_480() && !self.draw_mode.draw_to_display)
+            .then_some(self.status.interlace_field ^ odd_start);
+        self.configure_renderer(RasterizerSetting::SkipField(skip));
+    }
+
+    /// Check whether the display is in 480-line interlaced mode
+    ///
+    /// # Returns
+    ///
+    /// true when GP1(08h) selects both 480 lines and interlace
+    pub fn is_interlaced_480(&self) -> bool {
+        self.display_mode.interlaced && self.display_mode.vertical_res == VerticalRes::R480
+    }
+
+    /// Get the interlace field currently being displayed
+    ///
+    /// # Returns
+    ///
+    /// true for the odd-line field, false for the even-line field
+    pub fn interlace_field(&self) -> bool {
+        self.status.interlace_field
+    }
+
+    /// Advance the interlace field at the start of VBlank
+    ///
+    /// The displayed field alternates every frame while interlace is
+    /// enabled; otherwise it stays on the even field.
+    fn advance_field(&mut self) {
+        self.status.interlace_field = self.display_mode.interlaced && !self.status.interlace_field;
+        self.update_rasterizer_interlace();
+    }
+
+    /// Finish the frame at the start of VBlank
+    ///
+    /// Advances the interlace field, makes the frame's VRAM visible to the
+    /// frontend, closes the frame's statistics and marks the frame boundary
+    /// in a running capture.
+    pub(crate) fn begin_vblank(&mut self) {
+        self.advance_field();
+        self.sync_renderer();
+        self.end_stats_frame();
+        self.record_capture_event(CaptureEvent::VBlank);
+    }
+
+    /// Update rasterizer dithering from the draw mode and user setting
+    ///
+    /// This should be called whenever the GP0(E1h) dither bit or the
+    /// user dithering option changes.
+    pub(crate) fn update_rasterizer_dithering(&mut self) {
+        self.configure_renderer(RasterizerSetting::Dithering(
+            self.draw_mode.dithering && self.dithering_enabled,
+        ));
+    }
+
+    /// Allow or suppress ordered dithering
+    ///
+    /// Dithering is still controlled by the GP0(E1h) dither bit; disabling
+    /// it here overrides the game setting and renders shaded primitives
+    /// without the dither pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether dithering may be applied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert!(gpu.dithering_enabled());
+    ///
+    /// gpu.set_dithering_enabled(false);
+    /// assert!(!gpu.dithering_enabled());
+    /// ```
+    pub fn set_dithering_enabled(&mut self, enabled: bool) {
+        self.dithering_enabled = enabled;
+        self.update_rasterizer_dithering();
+    }
+
+    /// Check whether ordered dithering is allowed
+    pub fn dithering_enabled(&self) -> bool {
+        self.dithering_enabled
+    }
+
+    /// Select how 480-line interlaced frames are deinterlaced
+    ///
+    /// Only affects [`get_framebuffer`](Self::get_framebuffer) output in
+    /// 480i mode; VRAM contents and emulation are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Deinterlacing method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{Deinterlace, GPU};
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert_eq!(gpu.deinterlace_mode(), Deinterlace::Weave);
+    ///
+    /// gpu.set_deinterlace_mode(Deinterlace::Bob);
+    /// assert_eq!(gpu.deinterlace_mode(), Deinterlace::Bob);
+    /// ```
+    pub fn set_deinterlace_mode(&mut self, mode: Deinterlace) {
+        self.deinterlace = mode;
+    }
+
+    /// Get the current deinterlacing method
+    pub fn deinterlace_mode(&self) -> Deinterlace {
+        self.deinterlace
+    }
+
+    /// Select whether frames include the overscan borders
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - [`CropMode::Cropped`] for the display range only,
+    ///   [`CropMode::Overscan`] for the full visible TV picture
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{CropMode, GPU};
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert_eq!(gpu.get_framebuffer().width, 320);
+    ///
+    /// // The visible NTSC picture is 2800 video cycles, or 350 pixels at 320 mode
+    /// gpu.set_crop_mode(CropMode::Overscan);
+    /// assert_eq!(gpu.get_framebuffer().width, 350);
+    /// ```
+    pub fn set_crop_mode(&mut self, mode: CropMode) {
+        self.crop_mode = mode;
+    }
+
+    /// Get the current framebuffer cropping mode
+    pub fn crop_mode(&self) -> CropMode {
+        self.crop_mode
+    }
+
+    /// Enable or disable texture cache emulation
+    ///
+    /// With the cache enabled, textures overwritten in VRAM keep drawing
+    /// from stale cache lines until GP0(01h) or a texture page change
+    /// flushes them, as on hardware. Disabled by default, in which case
+    /// texels are always read straight from VRAM.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the texture cache should be emulated
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert!(!gpu.texture_cache_enabled());
+    ///
+    /// gpu.set_texture_cache_enabled(true);
+    /// assert!(gpu.texture_cache_enabled());
+    /// ```
+    pub fn set_texture_cache_enabled(&mut self, enabled: bool) {
+        self.texture_cache_enabled = enabled;
+        self.configure_renderer(RasterizerSetting::TextureCache(enabled));
+    }
+
+    /// Check whether texture cache emulation is enabled
+    pub fn texture_cache_enabled(&self) -> bool {
+        self.texture_cache_enabled
+    }
+
+    /// Get the name of the rendering backend
+    ///
+    /// Waits for the render thread if it currently owns the backend.
+    pub fn renderer_name(&mut self) -> &'static str {
+        self.renderer_and_vram().0.name()
+    }
+
+    /// Recompute the display area size from the display range
+    ///
+    /// The number of pixels per line is the horizontal range divided by the
+    /// dot clock divider, rounded to a multiple of 4 as on hardware. This
+    /// should be called whenever the display range or horizontal
+    /// resolution changes.
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GP1(06h) Horizontal Display Range](http://problemkaputt.de/psx-spx.htm#gpudisplaycontrolcommandsgp1)
+    pub(crate) fn update_display_size(&mut self) {
+        let range = self.display_range;
+        let divider = self.display_mode.horizontal_res.dot_clock_divider();
+        let cycles = range.x2.saturating_sub(range.x1);
+
+        self.display_area.width = if cycles == 0 {
+            0
+        } else {
+            (cycles / divider + 2) & !3
+        };
+        self.display_area.height = range.y2.saturating_sub(range.y1);
+    }
+
+    /// Compute the pixel aspect ratio of the current display mode
+    ///
+    /// The visible TV picture is shown at 4:3, so a pixel is as wide as the
+    /// visible picture height divided by its width in pixels.
+    fn pixel_aspect_ratio(&self) -> f32 {
+        let mode = self.display_mode.video_mode;
+        let (h_start, h_end) = mode.visible_cycles();
+        let (v_start, v_end) = mode.visible_lines();
+        let divider = self.display_mode.horizontal_res.dot_clock_divider() as f32;
+        let line_scale = if self.is_interlaced_480() { 2.0 } else { 1.0 };
+
+        let visible_width = (h_end - h_start) as f32 / divider;
+        let visible_height = (v_end - v_start) as f32 * line_scale;
+        Framebuffer::ASPECT_4_3 * visible_height / visible_width
     }
 
     /// Generate RGB24 framebuffer for display
     ///
     /// Extracts the display area from VRAM and converts it to 24-bit RGB
-    /// format suitable for display. Each pixel is converted from 15-bit
-    /// (5-5-5 RGB) to 24-bit (8-8-8 RGB) by left-shifting each channel.
+    /// format suitable for display. In 15-bit display mode each pixel is
+    /// converted from 5-5-5 RGB to 8-8-8 RGB by left-shifting each channel;
+    /// in 24-bit display mode packed RGB888 triplets are read directly
+    /// (used by MDEC movies and 24-bit still images).
+    ///
+    /// With [widescreen output](Self::set_widescreen) the frame is 4/3 as
+    /// wide as the display area and meant to be shown at 16:9. Its width is
+    /// taken from the backend's widescreen surface for the display area,
+    /// which is drawn at that width. Without such a surface (24-bit
+    /// display, backends that do not draw surfaces, display areas that are
+    /// not a drawing area) the display area is stretched using nearest
+    /// neighbour sampling instead.
+    ///
+    /// In 480-line interlaced mode the frame has twice as many lines as the
+    /// vertical display range, and the two fields are combined according to
+    /// the [`Deinterlace`] mode.
+    ///
+    /// With [`CropMode::Overscan`] the frame covers the whole visible TV
+    /// picture. The display area is placed according to the GP1(06h)/(07h)
+    /// display ranges and the borders around it are black.
+    ///
+    /// With [internal resolution upscaling](Self::set_resolution_scale) the
+    /// frame is scaled in both directions and 15-bit pixels come from the
+    /// high-resolution shadow VRAM. 24-bit images are upscaled with nearest
+    /// neighbour sampling.
+    ///
+    /// Waits for the render thread if it currently owns VRAM.
     ///
     /// # Returns
     ///
-    /// A Vec<u8> containing RGB24 data (width × height × 3 bytes).
-    /// Pixels are in row-major order (left-to-right, top-to-bottom).
+    /// A [`Framebuffer`] with the frame size, its aspect ratios and
+    /// the RGB24 data (width × height × 3 bytes). Pixels are in row-major
+    /// order (left-to-right, top-to-bottom).
     ///
     /// # Examples
     ///
@@ -410,35 +877,280 @@ impl GPU {
     ///
     /// let framebuffer = gpu.get_framebuffer();
     /// // framebuffer is 320 × 240 × 3 = 230,400 bytes
-    /// assert_eq!(framebuffer.len(), 320 * 240 * 3);
+    /// assert_eq!((framebuffer.width, framebuffer.height), (320, 240));
+    /// assert_eq!(framebuffer.pixels.len(), 320 * 240 * 3);
+    ///
+    /// gpu.set_widescreen(true);
+    /// let framebuffer = gpu.get_framebuffer();
+    /// assert_eq!(framebuffer.width, 426);
+    /// assert_eq!(framebuffer.aspect_ratio, 16.0 / 9.0);
     /// ```
-    pub fn get_framebuffer(&self) -> Vec<u8> {
-        let width = self.display_area.width as usize;
-        let height = self.display_area.height as usize;
-        let mut framebuffer = vec![0u8; width * height * 3];
+    pub fn get_framebuffer(&mut self) -> Framebuffer {
+        self.sync_renderer();
+        let interlaced = self.is_interlaced_480();
+        let line_scale = if interlaced { 2 } else { 1 };
+        let surface = self.widescreen_surface();
+        let scale = match (&self.renderer, surface) {
+            (Some(renderer), None) => renderer.resolution_scale() as isize,
+            _ => 1,
+        };
+        let display_width = self.display_area.width as isize;
+        let display_height = self.display_area.height as isize * line_scale;
+
+        // Frame size and position of the display area within the frame
+        let (frame_width, frame_height, left, top) = match self.crop_mode {
+            CropMode::Cropped => (display_width, display_height, 0, 0),
+            CropMode::Overscan => {
+                let mode = self.display_mode.video_mode;
+                let (h_start, h_end) = mode.visible_cycles();
+                let (v_start, v_end) = mode.visible_lines();
+                let divider = self.display_mode.horizontal_res.dot_clock_divider() as isize;
+                (
+                    (h_end - h_start) as isize / divider,
+                    (v_end - v_start) as isize * line_scale,
+                    (self.display_range.x1 as isize - h_start as isize) / divider,
+                    (self.display_range.y1 as isize - v_start as isize) * line_scale,
+                )
+            }
+        };
+
+        let (display_width, display_height) = (display_width * scale, display_height * scale);
+        let (left, top) = (left * scale, top * scale);
+        let frame_width = (frame_width * scale) as usize;
+        let height = (frame_height * scale) as usize;
+        let (width, aspect_ratio) = if self.widescreen {
+            (widen(frame_width), Framebuffer::ASPECT_16_9)
+        } else {
+            (frame_width, Framebuffer::ASPECT_4_3)
+        };
+        // Position and width of the widescreen surface within the frame
+        let surface_left = widen(left.max(0) as usize) as isize;
+        let surface_width = surface.map_or(0, |surface| surface.width() as isize);
+        let mut pixels = vec![0u8; width * height * 3];
+
+        for row in 0..height {
+            // Rows outside the display area are border (black)
+            let line = row as isize - top;
+            if !(0..display_height).contains(&line) {
+                continue;
+            }
+            let (y, sub_y) = ((line / scale) as usize, (line % scale) as usize);
+
+            // Pick the display lines this output row is built from
+            let (first, second) = match self.deinterlace {
+                Deinterlace::Bob if interlaced => {
+                    let line = (y & !1) | self.status.interlace_field as usize;
+                    (line, line)
+                }
+                Deinterlace::Blend if interlaced => (y & !1, y | 1),
+                _ => (y, y),
+            };
+            let vram_first = (self.display_area.y as usize + first) % 512;
+            let vram_second = (self.display_area.y as usize + second) % 512;
 
-        for y in 0..height {
             for x in 0..width {
-                // Calculate VRAM coordinates with wrapping
-                let vram_x = (self.display_area.x as usize + x) % 1024;
-                let vram_y = (self.display_area.y as usize + y) % 512;
-                let vram_index = vram_y * 1024 + vram_x;
-                let pixel = self.vram[vram_index];
-
-                // Convert 15-bit (5-5-5) to 24-bit (8-8-8) RGB
-                // Left-shift by 3 to expand from 5-bit to 8-bit
-                let r = ((pixel & 0x1F) << 3) as u8;
-                let g = (((pixel >> 5) & 0x1F) << 3) as u8;
-                let b = (((pixel >> 10) & 0x1F) << 3) as u8;
-
-                let fb_index = (y * width + x) * 3;
-                framebuffer[fb_index] = r;
-                framebuffer[fb_index + 1] = g;
-                framebuffer[fb_index + 2] = b;
+                // Map output column back into the surface or display area
+                let pixel = |vram_y: usize| match &surface {
+                    Some(surface) => {
+                        let column = x as isize - surface_left;
+                        (0..surface_width).contains(&column).then(|| {
+                            let pixel = self.widescreen_pixel(surface, column as usize, vram_y);
+                            Self::rgb15_to_rgb24(pixel)
+                        })
+                    }
+                    None => {
+                        let column = (x * frame_width / width) as isize - left;
+                        (0..display_width).contains(&column).then(|| {
+                            let display_x = (column / scale) as usize;
+                            let sub = ((column % scale) as usize, sub_y);
+                            self.display_pixel(display_x, vram_y, sub)
+                        })
+                    }
+                };
+
+                let (r, g, b) = if vram_first == vram_second {
+                    match pixel(vram_first) {
+                        Some(rgb) => rgb,
+                        None => continue,
+                    }
+                } else {
+                    let (Some((r1, g1, b1)), Some((r2, g2, b2))) =
+                        (pixel(vram_first), pixel(vram_second))
+                    else {
+                        continue;
+                    };
+                    (
+                        ((r1 as u16 + r2 as u16) / 2) as u8,
+                        ((g1 as u16 + g2 as u16) / 2) as u8,
+                        ((b1 as u16 + b2 as u16) / 2) as u8,
+                    )
+                };
+
+                let fb_index = (row * width + x) * 3;
+                pixels[fb_index] = r;
+                pixels[fb_index + 1] = g;
+                pixels[fb_index + 2] = b;
             }
         }
 
-        framebuffer
+        Framebuffer {
+            width,
+            height,
+            aspect_ratio,
+            pixel_aspect_ratio: self.pixel_aspect_ratio(),
+            pixels,
+        }
+    }
+
+    /// Decode a display pixel in the current display color depth
+    ///
+    /// # Arguments
+    ///
+    /// * `display_x` - Column within the display area
+    /// * `vram_y` - VRAM row (already offset and wrapped)
+    /// * `sub` - Position within the upscaled pixel (x, y)
+    fn display_pixel(&self, display_x: usize, vram_y: usize, sub: (usize, usize)) -> (u8, u8, u8) {
+        match self.display_mode.display_area_color_depth {
+            ColorDepth::C15Bit => self.display_pixel_15bit(display_x, vram_y, sub),
+            ColorDepth::C24Bit => self.display_pixel_24bit(display_x, vram_y),
+        }
+    }
+
+    /// Decode a 15-bit display pixel
+    ///
+    /// Converts the 5-5-5 RGB halfword at the given display column to
+    /// 8-8-8 RGB by left-shifting each channel. The halfword comes from the
+    /// rendering backend, which reads its shadow VRAM when upscaling.
+    ///
+    /// # Arguments
+    ///
+    /// * `display_x` - Column within the display area
+    /// * `vram_y` - VRAM row (already offset and wrapped)
+    /// * `sub` - Position within the upscaled pixel (x, y)
+    fn display_pixel_15bit(
+        &self,
+        display_x: usize,
+        vram_y: usize,
+        sub: (usize, usize),
+    ) -> (u8, u8, u8) {
+        let vram_x = (self.display_area.x as usize + display_x) % 1024;
+        let pixel = match &self.renderer {
+            Some(renderer) => renderer.display_pixel(&self.vram, vram_x, vram_y, sub),
+            None => self.vram[vram_y * 1024 + vram_x],
+        };
+        Self::rgb15_to_rgb24(pixel)
+    }
+
+    /// Convert a 5-5-5 RGB halfword to 8-8-8 RGB by left-shifting each channel
+    fn rgb15_to_rgb24(pixel: u16) -> (u8, u8, u8) {
+        let r = ((pixel & 0x1F) << 3) as u8;
+        let g = (((pixel >> 5) & 0x1F) << 3) as u8;
+        let b = (((pixel >> 10) & 0x1F) << 3) as u8;
+        (r, g, b)
+    }
+
+    /// Find the backend's widescreen surface for the display area
+    ///
+    /// # Returns
+    ///
+    /// The surface, or None outside widescreen mode, in 24-bit display
+    /// mode, or when the backend has no surface for the display area
+    fn widescreen_surface(&self) -> Option<WidescreenSurface> {
+        if !self.widescreen || self.display_mode.display_area_color_depth == ColorDepth::C24Bit {
+            return None;
+        }
+        let area = self.display_area;
+        self.renderer
+            .as_ref()?
+            .widescreen_surface(area.x, area.y, area.width)
+    }
+
+    /// Read a 15-bit pixel of a widescreen surface
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - Surface of the display area
+    /// * `column` - Column within the surface
+    /// * `vram_y` - VRAM row (already offset and wrapped)
+    fn widescreen_pixel(&self, surface: &WidescreenSurface, column: usize, vram_y: usize) -> u16 {
+        self.renderer.as_ref().map_or(0, |renderer| {
+            renderer.widescreen_pixel(surface, column, vram_y)
+        })
+    }
+
+    /// Decode a 24-bit display pixel
+    ///
+    /// In 24-bit mode VRAM holds packed RGB888 triplets, so each pixel spans
+    /// one and a half halfwords. The display start X is still given in
+    /// halfwords, which means pixel N starts at byte `start_x * 2 + N * 3`
+    /// and every other pixel begins in the upper byte of a halfword.
+    /// Bytes are stored little-endian within each halfword and wrap around
+    /// the 2048-byte VRAM row.
+    ///
+    /// # Arguments
+    ///
+    /// * `display_x` - Column within the display area
+    /// * `vram_y` - VRAM row (already offset and wrapped)
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: 24bit Direct Display](http://problemkaputt.de/psx-spx.htm#gpuvideomemoryvram)
+    fn display_pixel_24bit(&self, display_x: usize, vram_y: usize) -> (u8, u8, u8) {
+        let start = self.display_area.x as usize * 2 + display_x * 3;
+        let row = &self.vram[vram_y * 1024..(vram_y + 1) * 1024];
+        let byte_at = |offset: usize| {
+            let offset = offset % 2048;
+            (row[offset / 2] >> ((offset & 1) * 8)) as u8
+        };
+
+        (byte_at(start), byte_at(start + 1), byte_at(start + 2))
+    }
+
+    /// Enable or disable widescreen output
+    ///
+    /// Meant to be used with the GTE widescreen projection, which fits a
+    /// 16:9 field of view into the game's drawing area by compressing X by
+    /// 3/4. The backend then also draws every primitive into a widescreen
+    /// surface: the drawing area widened by 4/3, with its clip rectangle
+    /// widened to match. [`get_framebuffer`](Self::get_framebuffer) returns
+    /// the surface of the display area, so frames are 4/3 as wide as the
+    /// display area and shown at 16:9. Native VRAM is not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether widescreen frames should be produced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.set_widescreen(true);
+    ///
+    /// // Drawing area (0, 0)-(319, 239), the display area
+    /// gpu.write_gp0(0xE3000000);
+    /// gpu.write_gp0(0xE4000000 | (239 << 10) | 319);
+    ///
+    /// // 6x6 white rectangle at (6, 8) is drawn 8 pixels wide
+    /// gpu.write_gp0(0x60FFFFFF);
+    /// gpu.write_gp0(0x00080006);
+    /// gpu.write_gp0(0x00060006);
+    ///
+    /// let framebuffer = gpu.get_framebuffer();
+    /// assert_eq!(framebuffer.width, 426);
+    /// let white = |x: usize| framebuffer.pixels[(8 * 426 + x) * 3] == 0xF8;
+    /// assert_eq!((0..32).filter(|&x| white(x)).collect::<Vec<_>>(), (8..16).collect::<Vec<_>>());
+    /// ```
+    pub fn set_widescreen(&mut self, enabled: bool) {
+        self.widescreen = enabled;
+        let (renderer, vram) = self.renderer_and_vram();
+        renderer.set_widescreen(vram, enabled);
+    }
+
+    /// Check whether widescreen output is enabled
+    pub fn widescreen(&self) -> bool {
+        self.widescreen
     }
 
     /// Get current GPU status register value
@@ -460,7 +1172,9 @@ impl GPU {
         status |= (self.status.draw_to_display as u32) << 10;
         status |= (self.status.set_mask_bit as u32) << 11;
         status |= (self.status.draw_pixels as u32) << 12;
-        status |= (self.status.interlace_field as u32) << 13;
+        // Bit 13: interlace field (always 1 when interlace is off)
+        let field_bit = !self.display_mode.interlaced || self.status.interlace_field;
+        status |= (field_bit as u32) << 13;
         status |= (self.status.reverse_flag as u32) << 14;
         status |= (self.status.texture_disable as u32) << 15;
         status |= ((self.status.horizontal_res_2 as u32) & 0x01) << 16;
@@ -477,11 +1191,18 @@ impl GPU {
         status |= (self.status.ready_to_receive_dma as u32) << 28;
         status |= ((self.status.dma_direction as u32) & 0x03) << 29;
 
-        // Bit 31: VBlank flag
-        // Set to 1 when in VBlank period (scanlines 243-262 for NTSC)
-        if self.in_vblank {
-            status |= 1 << 31;
-        }
+        // Bit 31: drawing even/odd lines in interlace mode
+        // Always 0 during VBlank. In 480-line interlaced mode it reflects
+        // the displayed field and changes once per frame; otherwise it
+        // toggles on every scanline.
+        let odd_line = if self.in_vblank {
+            false
+        } else if self.is_interlaced_480() {
+            self.status.interlace_field
+        } else {
+            self.scanline & 1 != 0
+        };
+        status |= (odd_line as u32) << 31;
 
         status
     }
@@ -593,29 +1314,32 @@ impl GPU {
     pub fn tick(&mut self, cycles: u32) -> (bool, bool) {
         let mut vblank_interrupt = false;
         let mut hblank_interrupt = false;
+        let mode = self.display_mode.video_mode;
+
+        self.advance_draw(cycles);
 
         for _ in 0..cycles {
             self.dots += 1;
 
-            if self.dots >= Self::DOTS_PER_SCANLINE {
+            if self.dots >= mode.dots_per_scanline() {
                 self.dots = 0;
                 self.scanline += 1;
 
                 // HBlank occurs at end of each scanline
                 hblank_interrupt = true;
 
-                if self.scanline >= Self::SCANLINES_PER_FRAME {
+                if self.scanline >= mode.scanlines_per_frame() {
                     self.scanline = 0;
                 }
 
                 // Check VBlank region
                 let was_in_vblank = self.in_vblank;
-                self.in_vblank =
-                    self.scanline >= Self::VBLANK_START && self.scanline < Self::VBLANK_END;
+                self.in_vblank = self.scanline >= mode.vblank_start();
 
                 // VBlank interrupt at start of VBlank
                 if self.in_vblank && !was_in_vblank {
                     vblank_interrupt = true;
+                    self.begin_vblank();
                 }
             }
 
@@ -636,6 +1360,16 @@ impl GPU {
     ///
     /// * `value` - 32-bit GP0 command word
     pub fn write_gp0(&mut self, value: u32) {
+        self.push_gp0(value, false);
+    }
+
+    /// Buffer and process a GP0 word from the CPU or GPU DMA
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - 32-bit GP0 command word
+    /// * `from_dma` - Whether the word was fed by GPU DMA
+    pub(crate) fn push_gp0(&mut self, value: u32, from_dma: bool) {
         // Log GP0 writes for debugging
         use std::sync::atomic::{AtomicU32, Ordering};
         static GP0_COUNT: AtomicU32 = AtomicU32::new(0);
@@ -646,6 +1380,14 @@ impl GPU {
             log::info!("GP0 write #{}: 0x{:08X} (cmd=0x{:02X})", count, value, cmd);
         }
 
+        self.record_capture_event(CaptureEvent::Gp0(value));
+        if log::log_enabled!(log::Level::Trace) {
+            if let Some(text) = self.disassembler.feed_gp0(value) {
+                log::trace!("GP0 {}", text);
+            }
+        }
+        self.queue_fifo_word(from_dma);
+
         // If we're in the middle of a CPU→VRAM transfer, handle it
         if let Some(ref transfer) = self.vram_transfer {
             if transfer.direction == VRAMTransferDirection::CpuToVram {
@@ -656,9 +1398,15 @@ impl GPU {
 
         // Otherwise, buffer the command
         self.command_fifo.push_back(value);
+        self.gp0_words_queued += 1;
 
         // Try to process command
         self.try_process_command();
+
+        // Precise vertices only live as long as their FIFO words
+        if self.command_fifo.is_empty() {
+            self.precision_words.clear();
+        }
     }
 
     /// Try to process the next command in the FIFO
@@ -674,6 +1422,9 @@ impl GPU {
         let command = (first_word >> 24) & 0xFF;
 
         match command {
+            // Texture cache
+            0x01 => self.gp0_clear_cache(),
+
             // Fill commands
             0x02 => self.gp0_fill_rectangle(),
 
@@ -774,13 +1525,20 @@ impl GPU {
     /// Read from GPUREAD register (0x1F801810)
     ///
     /// Returns pixel data during VRAM→CPU transfers. Each read returns
-    /// two 16-bit pixels packed into a 32-bit word.
+    /// two 16-bit pixels packed into a 32-bit word. Outside of transfers,
+    /// returns the latched value (the last transfer word or GP1(10h) info
+    /// response).
     pub fn read_gpuread(&mut self) -> u32 {
         // Extract transfer state to avoid borrowing issues
         let mut transfer = match self.vram_transfer.take() {
-            Some(t) => t,
-            None => return 0,
+            Some(t) if t.direction == VRAMTransferDirection::VramToCpu => t,
+            other => {
+                self.vram_transfer = other;
+                return self.gpuread;
+            }
         };
+        self.sync_renderer();
+        self.frame_stats.vram_to_cpu_bytes += 4;
 
         // Read two pixels and pack into u32
         let vram_x1 = (transfer.x + transfer.current_x) & 0x3FF;
@@ -812,16 +1570,34 @@ impl GPU {
         // Check if complete
         if transfer.current_y >= transfer.height {
             self.status.ready_to_send_vram = false;
+            self.update_ready_flags();
             log::debug!("VRAM→CPU transfer complete");
         } else {
             self.vram_transfer = Some(transfer);
         }
 
-        (pixel1 as u32) | ((pixel2 as u32) << 16)
+        self.gpuread = (pixel1 as u32) | ((pixel2 as u32) << 16);
+        self.gpuread
+    }
+
+    /// Get the emulated GPU hardware revision
+    pub fn version(&self) -> GpuVersion {
+        self.version
+    }
+
+    /// Set the emulated GPU hardware revision
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Old (180-pin) or new (208-pin) GPU
+    pub fn set_version(&mut self, version: GpuVersion) {
+        self.version = version;
     }
 
     /// Process GP1 command (control commands)
     pub fn write_gp1(&mut self, value: u32) {
+        self.record_capture_event(CaptureEvent::Gp1(value));
+        log::trace!("GP1 {}", GpuCommandDisassembler::disassemble_gp1(value));
         let command = (value >> 24) & 0xFF;
 
         match command {
@@ -834,7 +1610,7 @@ impl GPU {
             0x06 => self.gp1_horizontal_display_range(value),
             0x07 => self.gp1_vertical_display_range(value),
             0x08 => self.gp1_display_mode(value),
-            0x10 => self.gp1_get_gpu_info(value),
+            0x10..=0x1F => self.gp1_get_gpu_info(value),
             _ => {
                 log::warn!("Unknown GP1 command: 0x{:02X}", command);
             }
@@ -850,38 +1626,85 @@ impl GPU {
     ///
     /// * `timing` - Timing event manager
     pub fn register_events(&mut self, timing: &mut super::timing::TimingEventManager) {
-        // VBlank event: fires every frame (~16.67ms for NTSC 60Hz)
-        // Total cycles per frame = DOTS_PER_SCANLINE * SCANLINES_PER_FRAME
-        // = 3413 * 263 = 897,619 cycles
-        // But PSX CPU runs at 33.8688 MHz, and at 60fps that's 564,480 cycles/frame
-        // Using the standard PSX timing
-        const CYCLES_PER_FRAME: i32 = 564_480;
+        // Event intervals follow the current video mode:
+        // NTSC 263 × 2153 = 566,239 cycles/frame (~59.81 Hz)
+        // PAL  314 × 2168 = 680,752 cycles/frame (~49.75 Hz)
+        let mode = self.display_mode.video_mode;
+        let cycles_per_frame = mode.cpu_cycles_per_frame() as i32;
+        let cycles_per_scanline = mode.cpu_cycles_per_scanline() as i32;
 
-        self.vblank_event = Some(timing.register_periodic_event("GPU VBlank", CYCLES_PER_FRAME));
+        // VBlank event: fires once per frame
+        self.vblank_event = Some(timing.register_periodic_event("GPU VBlank", cycles_per_frame));
 
         // Activate VBlank event
         if let Some(handle) = self.vblank_event {
-            timing.schedule(handle, CYCLES_PER_FRAME);
+            timing.schedule(handle, cycles_per_frame);
         }
 
         // HBlank event: fires every scanline
-        // = CYCLES_PER_FRAME / SCANLINES_PER_FRAME = 564_480 / 263 ≈ 2146 cycles
-        const CYCLES_PER_SCANLINE: i32 = 2_146;
-
-        self.hblank_event = Some(timing.register_periodic_event("GPU HBlank", CYCLES_PER_SCANLINE));
+        self.hblank_event = Some(timing.register_periodic_event("GPU HBlank", cycles_per_scanline));
 
         // Activate HBlank event
         if let Some(handle) = self.hblank_event {
-            timing.schedule(handle, CYCLES_PER_SCANLINE);
+            timing.schedule(handle, cycles_per_scanline);
         }
 
+        // Draw event: one-shot, armed while the GPU is busy
+        self.register_draw_event(timing);
+
+        self.timing_mode = mode;
+
         log::info!(
             "GPU: Timing events registered and activated (VBlank={} cycles, HBlank={} cycles)",
-            CYCLES_PER_FRAME,
-            CYCLES_PER_SCANLINE
+            cycles_per_frame,
+            cycles_per_scanline
+        );
+    }
+
+    /// Update timing event intervals after a video mode change
+    ///
+    /// GP1(08h) can switch between NTSC and PAL at any time. The new
+    /// frame and scanline lengths take effect from the next VBlank and
+    /// HBlank events onward.
+    ///
+    /// # Arguments
+    ///
+    /// * `timing` - Timing event manager
+    pub fn sync_timing(&mut self, timing: &mut super::timing::TimingEventManager) {
+        let mode = self.display_mode.video_mode;
+        if mode == self.timing_mode {
+            return;
+        }
+
+        if let Some(handle) = self.vblank_event {
+            timing.set_interval(handle, mode.cpu_cycles_per_frame() as i32);
+        }
+        if let Some(handle) = self.hblank_event {
+            timing.set_interval(handle, mode.cpu_cycles_per_scanline() as i32);
+        }
+        self.timing_mode = mode;
+
+        log::info!(
+            "GPU: Video timing switched to {:?} ({:.2} Hz)",
+            mode,
+            mode.refresh_rate()
         );
     }
 
+    /// Get the VBlank timing event handle
+    ///
+    /// # Returns
+    ///
+    /// The handle if [`register_events`](Self::register_events) has been called
+    pub fn vblank_event(&self) -> Option<EventHandle> {
+        self.vblank_event
+    }
+
+    /// Get the current video mode (NTSC or PAL)
+    pub fn video_mode(&self) -> VideoMode {
+        self.display_mode.video_mode
+    }
+
     /// Process GPU timing events
     ///
     /// This should be called by System when timing events fire.
@@ -892,9 +1715,12 @@ impl GPU {
     /// * `triggered_events` - List of event handles that have fired
     pub fn process_events(
         &mut self,
-        _timing: &mut super::timing::TimingEventManager,
+        timing: &mut super::timing::TimingEventManager,
         triggered_events: &[EventHandle],
     ) {
+        // Pick up video mode changes before handling this batch
+        self.sync_timing(timing);
+
         // Check if VBlank event fired
         if let Some(handle) = self.vblank_event {
             if triggered_events.contains(&handle) {
@@ -908,17 +1734,24 @@ impl GPU {
                 self.hblank_callback();
             }
         }
+
+        // Finish drawing work and arm the draw event for new work
+        self.process_draw_event(timing, triggered_events);
     }
 
     /// VBlank callback (called when vblank_event fires)
     ///
-    /// Triggered at the start of vertical blanking period (scanline 243).
+    /// Triggered at the start of vertical blanking period (scanline 243
+    /// for NTSC, 291 for PAL).
     fn vblank_callback(&mut self) {
         // Reset scanline counter at start of VBlank
-        self.scanline = Self::VBLANK_START;
+        self.scanline = self.display_mode.video_mode.vblank_start();
         self.in_vblank = true;
         self.dots = 0;
 
+        // The frame is complete; make its VRAM visible to the frontend
+        self.begin_vblank();
+
         // Set VBlank interrupt pending
         self.vblank_interrupt_pending = true;
 
@@ -932,13 +1765,15 @@ impl GPU {
         self.scanline += 1;
         self.dots = 0;
 
+        let mode = self.display_mode.video_mode;
+
         // Wrap scanline counter
-        if self.scanline >= Self::SCANLINES_PER_FRAME {
+        if self.scanline >= mode.scanlines_per_frame() {
             self.scanline = 0;
         }
 
         // Update VBlank status
-        self.in_vblank = self.scanline >= Self::VBLANK_START && self.scanline < Self::VBLANK_END;
+        self.in_vblank = self.scanline >= mode.vblank_start();
 
         // Set HBlank interrupt pending (always signal for timer synchronization)
         self.hblank_interrupt_pending = true;
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precision geometry support
+//!
+//! Connects the [`PrecisionCache`](crate::core::precision::PrecisionCache)
+//! to the GP0 command stream. Words that arrive through DMA carry the RAM
+//! address they were read from; if that address holds a vertex recorded by
+//! SWC2, its precise position is queued alongside the word and picked up by
+//! the polygon parsers.
+
+use super::{VRAMTransferDirection, GPU};
+use crate::core::precision::PrecisionVertex;
+
+impl GPU {
+    /// Enable or disable precision geometry
+    ///
+    /// Disabling the feature drops all cached vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether polygons should use precise vertices when available
+    pub fn set_precision_enabled(&mut self, enabled: bool) {
+        self.precision_enabled = enabled;
+        if !enabled {
+            self.precision_cache.clear();
+            self.precision_words.clear();
+        }
+    }
+
+    /// Check whether precision geometry is enabled
+    pub fn precision_enabled(&self) -> bool {
+        self.precision_enabled
+    }
+
+    /// Record a precise vertex stored to RAM
+    ///
+    /// Ignored while precision geometry is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - RAM address the SXY value was stored to
+    /// * `value` - Integer SXY value that was stored
+    /// * `vertex` - Precise vertex for that value
+    pub fn record_precision_vertex(&mut self, address: u32, value: u32, vertex: PrecisionVertex) {
+        if self.precision_enabled {
+            self.precision_cache.record(address, value, vertex);
+        }
+    }
+
+    /// Process a GP0 word read from RAM
+    ///
+    /// Same as [`write_gp0`](Self::write_gp0), but also looks up a precise
+    /// vertex for the word's source address. Used by GPU DMA, so the word
+    /// never counts as a FIFO overrun.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - 32-bit GP0 command word
+    /// * `address` - RAM address the word was read from
+    pub fn write_gp0_from_address(&mut self, value: u32, address: u32) {
+        let to_vram = self
+            .vram_transfer
+            .as_ref()
+            .is_some_and(|transfer| transfer.direction == VRAMTransferDirection::CpuToVram);
+
+        if self.precision_enabled && !to_vram {
+            if let Some(vertex) = self.precision_cache.lookup(address, value) {
+                self.precision_words
+                    .push_back((self.gp0_words_queued, vertex));
+            }
+        }
+
+        self.push_gp0(value, true);
+    }
+
+    /// Get the precise vertices of the command at the front of the FIFO
+    ///
+    /// Must be called before the command's words are popped.
+    ///
+    /// # Arguments
+    ///
+    /// * `offsets` - Positions of the vertex words within the command
+    ///
+    /// # Returns
+    ///
+    /// The precise vertices if every vertex word has one, `None` otherwise
+    /// (the primitive is then drawn with its integer coordinates)
+    pub(crate) fn precise_vertices<const N: usize>(
+        &mut self,
+        offsets: [usize; N],
+    ) -> Option<[PrecisionVertex; N]> {
+        if self.precision_words.is_empty() {
+            return None;
+        }
+
+        // Forget vertices of words that have already been consumed
+        let front = self.gp0_words_queued - self.command_fifo.len() as u64;
+        while self
+            .precision_words
+            .front()
+            .is_some_and(|&(sequence, _)| sequence < front)
+        {
+            self.precision_words.pop_front();
+        }
+
+        let mut vertices = [PrecisionVertex::default(); N];
+        for (vertex, offset) in vertices.iter_mut().zip(offsets) {
+            let sequence = front + offset as u64;
+            *vertex = self
+                .precision_words
+                .iter()
+                .find(|&&(s, _)| s == sequence)
+                .map(|&(_, v)| v)?;
+        }
+
+        Some(vertices)
+    }
+}
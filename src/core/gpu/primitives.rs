@@ -246,7 +246,7 @@ impl TexCoord {
 /// For 4-bit and 8-bit textures, the texture data contains palette indices
 /// that are looked up in a CLUT stored elsewhere in VRAM. Each CLUT entry
 /// is a 16-bit color in 5-5-5 RGB format.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TextureDepth {
     /// 4-bit indexed color (16 colors, uses CLUT)
     T4Bit,
@@ -543,15 +543,16 @@ impl BlendMode {
 
 /// GPU rendering command
 ///
-/// Represents a fully parsed GPU drawing command ready for execution.
-/// These commands are created by parsing GP0 command sequences.
+/// Represents a fully parsed GP0 packet. Packets are decoded from raw GP0
+/// words with [`GPUCommand::decode`], which is what the
+/// [`GpuCommandDisassembler`](super::GpuCommandDisassembler) builds on.
 ///
 /// # Polygon Rendering
 ///
 /// Polygons are the fundamental 3D rendering primitive. The PSX GPU supports:
 /// - **Monochrome (flat-shaded)**: Single color for entire polygon
-/// - **Gouraud-shaded**: Color interpolated across vertices (future)
-/// - **Textured**: Textured polygons (future)
+/// - **Gouraud-shaded**: Color interpolated across vertices
+/// - **Textured**: Texture mapped, optionally modulated by the vertex colors
 ///
 /// Quadrilaterals are rendered as two triangles internally.
 ///
@@ -628,4 +629,612 @@ pub enum GPUCommand {
         /// Semi-transparency enabled
         semi_transparent: bool,
     },
+
+    /// Textured triangle
+    ///
+    /// GP0 commands: 0x24-0x27 (flat), 0x34-0x37 (Gouraud)
+    /// Requires 7 words (flat) or 9 words (Gouraud)
+    TexturedTriangle {
+        /// Triangle vertices with colors and texture coordinates
+        vertices: [(Vertex, Color, TexCoord); 3],
+        /// Texture page and CLUT from the packet
+        texture: TextureInfo,
+        /// Semi-transparency mode from the texture page attribute
+        blend: BlendMode,
+        /// Colors are interpolated per vertex
+        shaded: bool,
+        /// Texture is drawn without color modulation
+        raw_texture: bool,
+        /// Semi-transparency enabled
+        semi_transparent: bool,
+    },
+
+    /// Textured quadrilateral
+    ///
+    /// GP0 commands: 0x2C-0x2F (flat), 0x3C-0x3F (Gouraud)
+    /// Requires 9 words (flat) or 12 words (Gouraud)
+    TexturedQuad {
+        /// Quad vertices with colors and texture coordinates
+        vertices: [(Vertex, Color, TexCoord); 4],
+        /// Texture page and CLUT from the packet
+        texture: TextureInfo,
+        /// Semi-transparency mode from the texture page attribute
+        blend: BlendMode,
+        /// Colors are interpolated per vertex
+        shaded: bool,
+        /// Texture is drawn without color modulation
+        raw_texture: bool,
+        /// Semi-transparency enabled
+        semi_transparent: bool,
+    },
+
+    /// Single line segment
+    ///
+    /// GP0 commands: 0x40-0x47 (flat), 0x50-0x57 (Gouraud)
+    /// Requires 3 words (flat) or 4 words (Gouraud)
+    Line {
+        /// End points with colors
+        vertices: [(Vertex, Color); 2],
+        /// Colors are interpolated along the line
+        shaded: bool,
+        /// Semi-transparency enabled
+        semi_transparent: bool,
+    },
+
+    /// Connected line segments
+    ///
+    /// GP0 commands: 0x48-0x4F (flat), 0x58-0x5F (Gouraud)
+    /// Variable length, ended by a terminator word (0x50005000 or 0x55555555)
+    Polyline {
+        /// Points with colors (at least 2)
+        vertices: Vec<(Vertex, Color)>,
+        /// Terminator word that ended the packet
+        terminator: u32,
+        /// Colors are interpolated along each segment
+        shaded: bool,
+        /// Semi-transparency enabled
+        semi_transparent: bool,
+    },
+
+    /// Rectangle (sprite)
+    ///
+    /// GP0 commands: 0x60-0x7F
+    /// Requires 2-4 words depending on texturing and size
+    Rectangle {
+        /// Top-left corner
+        position: Vertex,
+        /// Width in pixels (fixed by the command for 1×1, 8×8 and 16×16)
+        width: u16,
+        /// Height in pixels
+        height: u16,
+        /// Rectangle color (modulation color when textured)
+        color: Color,
+        /// Texture coordinate and CLUT position, for textured rectangles
+        texture: Option<(TexCoord, u16, u16)>,
+        /// Texture is drawn without color modulation
+        raw_texture: bool,
+        /// Semi-transparency enabled
+        semi_transparent: bool,
+    },
+
+    /// GP0(0x02): Fill VRAM rectangle
+    FillRectangle {
+        /// Top-left X in VRAM
+        x: u16,
+        /// Top-left Y in VRAM
+        y: u16,
+        /// Width in pixels
+        width: u16,
+        /// Height in pixels
+        height: u16,
+        /// Fill color
+        color: Color,
+    },
+
+    /// GP0(0x80): VRAM to VRAM copy
+    CopyRectangle {
+        /// Source top-left corner (x, y)
+        src: (u16, u16),
+        /// Destination top-left corner (x, y)
+        dst: (u16, u16),
+        /// Width in pixels
+        width: u16,
+        /// Height in pixels
+        height: u16,
+    },
+
+    /// GP0(0xA0): CPU to VRAM transfer
+    ///
+    /// The packet is followed by [`GPUCommand::data_words`] words of pixel data.
+    CpuToVram {
+        /// Destination X in VRAM
+        x: u16,
+        /// Destination Y in VRAM
+        y: u16,
+        /// Width in pixels
+        width: u16,
+        /// Height in pixels
+        height: u16,
+    },
+
+    /// GP0(0xC0): VRAM to CPU transfer
+    VramToCpu {
+        /// Source X in VRAM
+        x: u16,
+        /// Source Y in VRAM
+        y: u16,
+        /// Width in pixels
+        width: u16,
+        /// Height in pixels
+        height: u16,
+    },
+
+    /// GP0(0xE1): Draw mode (texture page) setting
+    DrawMode {
+        /// Texture page base X (in pixels)
+        page_x: u16,
+        /// Texture page base Y (0 or 256)
+        page_y: u16,
+        /// Semi-transparency mode
+        blend: BlendMode,
+        /// Texture color depth
+        depth: TextureDepth,
+        /// Dithering enabled
+        dithering: bool,
+        /// Drawing to the display area allowed
+        draw_to_display: bool,
+        /// Textures disabled
+        texture_disable: bool,
+    },
+
+    /// GP0(0xE2): Texture window setting (in 8-pixel steps)
+    TextureWindow {
+        /// Texture window mask X
+        mask_x: u8,
+        /// Texture window mask Y
+        mask_y: u8,
+        /// Texture window offset X
+        offset_x: u8,
+        /// Texture window offset Y
+        offset_y: u8,
+    },
+
+    /// GP0(0xE3): Drawing area top-left corner
+    DrawAreaTopLeft {
+        /// Left edge
+        x: u16,
+        /// Top edge
+        y: u16,
+    },
+
+    /// GP0(0xE4): Drawing area bottom-right corner
+    DrawAreaBottomRight {
+        /// Right edge
+        x: u16,
+        /// Bottom edge
+        y: u16,
+    },
+
+    /// GP0(0xE5): Drawing offset
+    DrawOffset {
+        /// X offset (signed 11-bit)
+        x: i16,
+        /// Y offset (signed 11-bit)
+        y: i16,
+    },
+
+    /// GP0(0xE6): Mask bit setting
+    MaskSettings {
+        /// Set bit 15 on drawn pixels
+        set_mask: bool,
+        /// Skip pixels that have bit 15 set
+        check_mask: bool,
+    },
+
+    /// GP0(0x01): Clear texture cache
+    ClearCache,
+
+    /// GP0(0x1F): Interrupt request
+    InterruptRequest,
+
+    /// No-op or unrecognized command word
+    Nop(u32),
+}
+
+impl GPUCommand {
+    /// Polyline terminator words recognized by the GPU
+    pub const POLYLINE_TERMINATORS: [u32; 2] = [0x5000_5000, 0x5555_5555];
+
+    /// Decode one GP0 packet from the start of `words`
+    ///
+    /// Packets are as long as the words the GP0 command handlers consume.
+    /// Opcodes the handlers skip one word at a time (see
+    /// [`GPUCommand::is_executed`]) decode as [`GPUCommand::Nop`]. Fields
+    /// are decoded as the hardware defines them. CPU→VRAM pixel data is not
+    /// part of the packet; see [`GPUCommand::data_words`].
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - GP0 words, starting with a command word
+    ///
+    /// # Returns
+    ///
+    /// - `Some((command, words used))` if a complete packet was decoded
+    /// - `None` if `words` is empty or ends before the packet is complete
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPUCommand;
+    ///
+    /// let words = [0x200000FF, 0x00000000, 0x00000040, 0x00400000];
+    /// let (command, used) = GPUCommand::decode(&words).unwrap();
+    /// assert_eq!(used, 4);
+    /// assert!(matches!(command, GPUCommand::MonochromeTriangle { .. }));
+    ///
+    /// // Incomplete packet
+    /// assert!(GPUCommand::decode(&words[..3]).is_none());
+    /// ```
+    pub fn decode(words: &[u32]) -> Option<(Self, usize)> {
+        let cmd = *words.first()?;
+        let opcode = cmd >> 24;
+
+        match opcode {
+            _ if !Self::is_executed(opcode) => Some((Self::Nop(cmd), 1)),
+            0x01 => Some((Self::ClearCache, 1)),
+            0x02 => {
+                // Zero encodes the full span; the 16-pixel width alignment
+                // is applied at execution and not shown here
+                let (coords, size) = (*words.get(1)?, *words.get(2)?);
+                let width = ((size >> 16) as u16).wrapping_sub(1) & 0x3FF;
+                let height = (size as u16).wrapping_sub(1) & 0x1FF;
+                let command = Self::FillRectangle {
+                    x: (coords >> 16) as u16 & 0x3FF,
+                    y: coords as u16 & 0x1FF,
+                    width: width + 1,
+                    height: height + 1,
+                    color: Color::from_u32(cmd),
+                };
+                Some((command, 3))
+            }
+            0x1F => Some((Self::InterruptRequest, 1)),
+            0x20..=0x3F => Self::decode_polygon(words),
+            0x40..=0x5F => Self::decode_line(words),
+            0x60..=0x7F => Self::decode_rectangle(words),
+            0x80..=0x9F => {
+                let (src, dst, size) = (*words.get(1)?, *words.get(2)?, *words.get(3)?);
+                let (width, height) = Self::transfer_size(size);
+                let command = Self::CopyRectangle {
+                    src: (src as u16 & 0x3FF, (src >> 16) as u16 & 0x1FF),
+                    dst: (dst as u16 & 0x3FF, (dst >> 16) as u16 & 0x1FF),
+                    width,
+                    height,
+                };
+                Some((command, 4))
+            }
+            0xA0..=0xDF => {
+                let (dest, size) = (*words.get(1)?, *words.get(2)?);
+                let (width, height) = Self::transfer_size(size);
+                let (x, y) = (dest as u16 & 0x3FF, (dest >> 16) as u16 & 0x1FF);
+                let command = if opcode < 0xC0 {
+                    Self::CpuToVram {
+                        x,
+                        y,
+                        width,
+                        height,
+                    }
+                } else {
+                    Self::VramToCpu {
+                        x,
+                        y,
+                        width,
+                        height,
+                    }
+                };
+                Some((command, 3))
+            }
+            0xE1 => Some((
+                Self::DrawMode {
+                    page_x: (cmd & 0xF) as u16 * 64,
+                    page_y: ((cmd >> 4) & 1) as u16 * 256,
+                    blend: BlendMode::from_bits((cmd >> 5) as u8),
+                    depth: (((cmd >> 7) & 3) as u8).into(),
+                    dithering: (cmd >> 9) & 1 != 0,
+                    draw_to_display: (cmd >> 10) & 1 != 0,
+                    texture_disable: (cmd >> 11) & 1 != 0,
+                },
+                1,
+            )),
+            0xE2 => Some((
+                Self::TextureWindow {
+                    mask_x: (cmd & 0x1F) as u8,
+                    mask_y: ((cmd >> 5) & 0x1F) as u8,
+                    offset_x: ((cmd >> 10) & 0x1F) as u8,
+                    offset_y: ((cmd >> 15) & 0x1F) as u8,
+                },
+                1,
+            )),
+            0xE3 => Some((
+                Self::DrawAreaTopLeft {
+                    x: (cmd & 0x3FF) as u16,
+                    y: ((cmd >> 10) & 0x1FF) as u16,
+                },
+                1,
+            )),
+            0xE4 => Some((
+                Self::DrawAreaBottomRight {
+                    x: (cmd & 0x3FF) as u16,
+                    y: ((cmd >> 10) & 0x1FF) as u16,
+                },
+                1,
+            )),
+            0xE5 => Some((
+                Self::DrawOffset {
+                    x: ((cmd & 0x7FF) as i16) << 5 >> 5,
+                    y: (((cmd >> 11) & 0x7FF) as i16) << 5 >> 5,
+                },
+                1,
+            )),
+            0xE6 => Some((
+                Self::MaskSettings {
+                    set_mask: cmd & 1 != 0,
+                    check_mask: (cmd >> 1) & 1 != 0,
+                },
+                1,
+            )),
+            _ => Some((Self::Nop(cmd), 1)),
+        }
+    }
+
+    /// Check whether the GP0 command handlers execute a command byte
+    ///
+    /// Raw-textured and Gouraud-textured polygons, lines with texture bits,
+    /// untextured rectangles with the raw texture bit and the mirrors of
+    /// the VRAM transfer commands are not implemented; the handlers skip
+    /// their first word like any other unknown command.
+    ///
+    /// # Arguments
+    ///
+    /// * `opcode` - GP0 command byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPUCommand;
+    ///
+    /// assert!(GPUCommand::is_executed(0x2C));
+    /// assert!(!GPUCommand::is_executed(0x3C));
+    /// ```
+    pub fn is_executed(opcode: u32) -> bool {
+        match opcode {
+            0x20..=0x3F => matches!(
+                opcode,
+                0x20 | 0x22 | 0x24 | 0x26 | 0x28 | 0x2A | 0x2C | 0x2E | 0x30 | 0x32 | 0x38 | 0x3A
+            ),
+            0x40..=0x5F => opcode & 0x05 == 0,
+            0x60..=0x7F => opcode & 0x05 != 0x01,
+            0x80..=0xDF => opcode & 0x1F == 0,
+            _ => true,
+        }
+    }
+
+    /// Number of pixel data words that follow the packet
+    ///
+    /// Only CPU→VRAM transfers carry data: two pixels per word, rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPUCommand;
+    ///
+    /// let (upload, _) = GPUCommand::decode(&[0xA0000000, 0, 0x00030003]).unwrap();
+    /// assert_eq!(upload.data_words(), 5);
+    /// ```
+    pub fn data_words(&self) -> usize {
+        match *self {
+            Self::CpuToVram { width, height, .. } => (width as usize * height as usize).div_ceil(2),
+            _ => 0,
+        }
+    }
+
+    /// Decode a transfer size word, where 0 encodes the full VRAM span
+    fn transfer_size(size: u32) -> (u16, u16) {
+        let width = ((size as u16).wrapping_sub(1) & 0x3FF) + 1;
+        let height = (((size >> 16) as u16).wrapping_sub(1) & 0x1FF) + 1;
+        (width, height)
+    }
+
+    /// Decode a polygon packet (GP0 0x20-0x3F)
+    fn decode_polygon(words: &[u32]) -> Option<(Self, usize)> {
+        let cmd = words[0];
+        let opcode = cmd >> 24;
+        let shaded = opcode & 0x10 != 0;
+        let quad = opcode & 0x08 != 0;
+        let textured = opcode & 0x04 != 0;
+        let semi_transparent = opcode & 0x02 != 0;
+        let raw_texture = textured && opcode & 0x01 != 0;
+
+        let count = if quad { 4 } else { 3 };
+        let words_per_vertex = 1 + textured as usize + shaded as usize;
+        let len = 1 + count * words_per_vertex - shaded as usize;
+        if words.len() < len {
+            return None;
+        }
+
+        // Each vertex is [color,] position[, texcoord]; the first color is
+        // in the command word
+        let mut points = [(Vertex { x: 0, y: 0 }, Color::from_u32(cmd), 0u32); 4];
+        let mut index = 1;
+        for (i, point) in points.iter_mut().take(count).enumerate() {
+            if shaded && i > 0 {
+                point.1 = Color::from_u32(words[index]);
+                index += 1;
+            }
+            point.0 = Vertex::from_u32(words[index]);
+            index += 1;
+            if textured {
+                point.2 = words[index];
+                index += 1;
+            }
+        }
+
+        if !textured {
+            let command = match (quad, shaded) {
+                (false, false) => Self::MonochromeTriangle {
+                    vertices: [points[0].0, points[1].0, points[2].0],
+                    color: points[0].1,
+                    semi_transparent,
+                },
+                (true, false) => Self::MonochromeQuad {
+                    vertices: [points[0].0, points[1].0, points[2].0, points[3].0],
+                    color: points[0].1,
+                    semi_transparent,
+                },
+                (false, true) => Self::ShadedTriangle {
+                    vertices: [
+                        (points[0].0, points[0].1),
+                        (points[1].0, points[1].1),
+                        (points[2].0, points[2].1),
+                    ],
+                    semi_transparent,
+                },
+                (true, true) => Self::ShadedQuad {
+                    vertices: [
+                        (points[0].0, points[0].1),
+                        (points[1].0, points[1].1),
+                        (points[2].0, points[2].1),
+                        (points[3].0, points[3].1),
+                    ],
+                    semi_transparent,
+                },
+            };
+            return Some((command, len));
+        }
+
+        // CLUT is in the upper half of the first texcoord word, the texture
+        // page attribute in the upper half of the second
+        let clut = points[0].2;
+        let page = points[1].2;
+        let texture = TextureInfo {
+            page_x: ((page >> 16) & 0xF) as u16 * 64,
+            page_y: ((page >> 20) & 1) as u16 * 256,
+            clut_x: ((clut >> 16) & 0x3F) as u16 * 16,
+            clut_y: ((clut >> 22) & 0x1FF) as u16,
+            depth: (((page >> 23) & 3) as u8).into(),
+        };
+        let blend = BlendMode::from_bits((page >> 21) as u8);
+        let vertex = |i: usize| {
+            let (position, color, texcoord) = points[i];
+            (position, color, TexCoord::from_u32(texcoord))
+        };
+
+        let command = if quad {
+            Self::TexturedQuad {
+                vertices: [vertex(0), vertex(1), vertex(2), vertex(3)],
+                texture,
+                blend,
+                shaded,
+                raw_texture,
+                semi_transparent,
+            }
+        } else {
+            Self::TexturedTriangle {
+                vertices: [vertex(0), vertex(1), vertex(2)],
+                texture,
+                blend,
+                shaded,
+                raw_texture,
+                semi_transparent,
+            }
+        };
+        Some((command, len))
+    }
+
+    /// Decode a line or polyline packet (GP0 0x40-0x5F)
+    fn decode_line(words: &[u32]) -> Option<(Self, usize)> {
+        let cmd = words[0];
+        let opcode = cmd >> 24;
+        let shaded = opcode & 0x10 != 0;
+        let polyline = opcode & 0x08 != 0;
+        let semi_transparent = opcode & 0x02 != 0;
+
+        // Each point is [color,] position; the first color is in the
+        // command word
+        let mut vertices = Vec::new();
+        let mut index = 1;
+        loop {
+            if polyline && vertices.len() >= 2 {
+                let word = *words.get(index)?;
+                if Self::POLYLINE_TERMINATORS.contains(&word) {
+                    let command = Self::Polyline {
+                        vertices,
+                        terminator: word,
+                        shaded,
+                        semi_transparent,
+                    };
+                    return Some((command, index + 1));
+                }
+            } else if !polyline && vertices.len() == 2 {
+                let command = Self::Line {
+                    vertices: [vertices[0], vertices[1]],
+                    shaded,
+                    semi_transparent,
+                };
+                return Some((command, index));
+            }
+
+            let color = if shaded && !vertices.is_empty() {
+                index += 1;
+                Color::from_u32(*words.get(index - 1)?)
+            } else {
+                Color::from_u32(cmd)
+            };
+            let position = Vertex::from_u32(*words.get(index)?);
+            index += 1;
+            vertices.push((position, color));
+        }
+    }
+
+    /// Decode a rectangle packet (GP0 0x60-0x7F)
+    fn decode_rectangle(words: &[u32]) -> Option<(Self, usize)> {
+        let cmd = words[0];
+        let opcode = cmd >> 24;
+        let textured = opcode & 0x04 != 0;
+        let semi_transparent = opcode & 0x02 != 0;
+        let raw_texture = textured && opcode & 0x01 != 0;
+
+        let position = Vertex::from_u32(*words.get(1)?);
+        let mut len = 2;
+        let texture = if textured {
+            let word = *words.get(2)?;
+            len += 1;
+            let clut_x = ((word >> 16) & 0x3F) as u16 * 16;
+            let clut_y = ((word >> 22) & 0x1FF) as u16;
+            Some((TexCoord::from_u32(word), clut_x, clut_y))
+        } else {
+            None
+        };
+
+        let (width, height) = match (opcode >> 3) & 3 {
+            0 => {
+                let size = *words.get(len)?;
+                len += 1;
+                ((size >> 16) as u16, size as u16)
+            }
+            1 => (1, 1),
+            2 => (8, 8),
+            _ => (16, 16),
+        };
+
+        let command = Self::Rectangle {
+            position,
+            width,
+            height,
+            color: Color::from_u32(cmd),
+            texture,
+            raw_texture,
+            semi_transparent,
+        };
+        Some((command, len))
+    }
 }
@@ -89,6 +89,30 @@ impl Default for DrawingArea {
     }
 }
 
+/// GPU hardware revision
+///
+/// The GP1(10h) info responses differ between the original GPU and the
+/// later revision used by most consoles.
+///
+/// # References
+///
+/// - [PSX-SPX: GPU Versions](http://problemkaputt.de/psx-spx.htm#gpuversions)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GpuVersion {
+    /// Old 180-pin GPU (CXD8514Q)
+    ///
+    /// Draw area info is 19 bits wide, and GPU type / info types 8-15 are
+    /// not available (info types mirror every 8).
+    Old,
+
+    /// New 208-pin GPU (CXD8561Q and later)
+    ///
+    /// Draw area info is 20 bits wide, info type 7 returns GPU type 2, and
+    /// info types mirror every 16.
+    #[default]
+    New,
+}
+
 /// Texture window settings
 ///
 /// Controls texture coordinate wrapping and masking within a specified window.
@@ -118,10 +142,10 @@ pub struct DisplayArea {
     /// Display area Y coordinate in VRAM
     pub y: u16,
 
-    /// Display width in pixels
+    /// Display width in pixels (derived from the horizontal display range)
     pub width: u16,
 
-    /// Display height in pixels
+    /// Display height in lines per field (derived from the vertical display range)
     pub height: u16,
 }
 
@@ -136,6 +160,107 @@ impl Default for DisplayArea {
     }
 }
 
+/// Display range on screen (GP1(06h)/GP1(07h))
+///
+/// Defines where the picture starts and ends within each scanline (in
+/// video clock cycles) and within each field (in scanlines). The number
+/// of visible pixels follows from the horizontal range and the dot clock
+/// divider of the current horizontal resolution.
+///
+/// # References
+///
+/// - [PSX-SPX: GP1(06h) Horizontal Display Range](http://problemkaputt.de/psx-spx.htm#gpudisplaycontrolcommandsgp1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayRange {
+    /// Horizontal start in video clock cycles
+    pub x1: u16,
+
+    /// Horizontal end in video clock cycles
+    pub x2: u16,
+
+    /// Vertical start scanline
+    pub y1: u16,
+
+    /// Vertical end scanline
+    pub y2: u16,
+}
+
+impl Default for DisplayRange {
+    /// Values set by GP1(00h) reset
+    fn default() -> Self {
+        Self {
+            x1: 0x200,
+            x2: 0xC00,
+            y1: 0x010,
+            y2: 0x100,
+        }
+    }
+}
+
+/// Framebuffer cropping mode
+///
+/// Selects which part of the video signal
+/// [`GPU::get_framebuffer`](crate::core::GPU::get_framebuffer) returns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CropMode {
+    /// Only the display range set by the game (no borders)
+    #[default]
+    Cropped,
+
+    /// The full visible area of a TV picture, with the display range
+    /// placed inside it and the borders filled black
+    Overscan,
+}
+
+/// RGB24 frame ready for display
+///
+/// Produced by [`GPU::get_framebuffer`](crate::core::GPU::get_framebuffer).
+/// In widescreen mode the frame is wider than the display area, so the
+/// frontend should size its output from `width`, `height` and `aspect_ratio`
+/// instead of the display area.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Framebuffer {
+    /// Width in pixels
+    pub width: usize,
+
+    /// Height in pixels
+    pub height: usize,
+
+    /// Intended aspect ratio of the full TV picture, 4:3 or 16:9
+    pub aspect_ratio: f32,
+
+    /// Width of one pixel relative to its height
+    ///
+    /// Depends on the dot clock divider, the video mode and the number of
+    /// lines, so frames should be shown `width × pixel_aspect_ratio` wide
+    /// for each `height` lines.
+    pub pixel_aspect_ratio: f32,
+
+    /// RGB24 pixel data (width × height × 3 bytes, row-major)
+    pub pixels: Vec<u8>,
+}
+
+impl Framebuffer {
+    /// Standard 4:3 display aspect ratio
+    pub const ASPECT_4_3: f32 = 4.0 / 3.0;
+
+    /// Widescreen 16:9 display aspect ratio
+    pub const ASPECT_16_9: f32 = 16.0 / 9.0;
+
+    /// Aspect ratio the frame should be shown at
+    ///
+    /// # Returns
+    ///
+    /// Displayed width divided by height, taking the pixel aspect ratio
+    /// into account (1.0 for an empty frame)
+    pub fn display_aspect_ratio(&self) -> f32 {
+        if self.height == 0 {
+            return 1.0;
+        }
+        self.width as f32 * self.pixel_aspect_ratio / self.height as f32
+    }
+}
+
 /// Display mode settings
 ///
 /// Controls the display output format including resolution, video mode, and color depth.
@@ -197,6 +322,27 @@ pub enum HorizontalRes {
     R384,
 }
 
+impl HorizontalRes {
+    /// Video clock cycles per displayed pixel
+    ///
+    /// # Returns
+    ///
+    /// 10 for 256, 8 for 320, 7 for 368/384, 5 for 512 and 4 for 640 pixels
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GPU Timings](http://problemkaputt.de/psx-spx.htm#gputimings)
+    pub fn dot_clock_divider(self) -> u16 {
+        match self {
+            HorizontalRes::R256 => 10,
+            HorizontalRes::R320 => 8,
+            HorizontalRes::R368 | HorizontalRes::R384 => 7,
+            HorizontalRes::R512 => 5,
+            HorizontalRes::R640 => 4,
+        }
+    }
+}
+
 /// Vertical resolution modes
 ///
 /// The GPU supports two vertical resolutions, with different values for NTSC and PAL.
@@ -209,9 +355,51 @@ pub enum VerticalRes {
     R480,
 }
 
+/// Deinterlacing method for 480-line interlaced output
+///
+/// In 480i mode the GPU only displays one field (every other line) per
+/// frame, alternating between even and odd lines. Both fields live in
+/// VRAM, so the frontend can choose how to combine them.
+///
+/// # References
+///
+/// - [PSX-SPX: Interlace](http://problemkaputt.de/psx-spx.htm#gpudisplaycontrolcommandsgp1)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Deinterlace {
+    /// Show both fields as they are in VRAM (full resolution, may comb)
+    #[default]
+    Weave,
+
+    /// Line-double the field that was drawn most recently
+    Bob,
+
+    /// Average each even/odd line pair
+    Blend,
+}
+
+impl std::str::FromStr for Deinterlace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "weave" => Ok(Self::Weave),
+            "bob" => Ok(Self::Bob),
+            "blend" => Ok(Self::Blend),
+            _ => Err(format!(
+                "unknown deinterlace mode '{}' (expected weave, bob or blend)",
+                s
+            )),
+        }
+    }
+}
+
 /// Video mode (refresh rate)
 ///
 /// Determines the video timing: NTSC (60Hz) or PAL (50Hz).
+///
+/// # References
+///
+/// - [PSX-SPX: GPU Timings](http://problemkaputt.de/psx-spx.htm#gputimings)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VideoMode {
     /// NTSC mode: 60Hz refresh rate
@@ -221,6 +409,129 @@ pub enum VideoMode {
     PAL,
 }
 
+impl VideoMode {
+    /// CPU clock frequency in Hz (identical in both video modes)
+    pub const CPU_CLOCK_HZ: u64 = 33_868_800;
+
+    /// Horizontal range visible on a typical TV, in video clock cycles
+    ///
+    /// This area is shown at 4:3 and is used for overscan output.
+    ///
+    /// # Returns
+    ///
+    /// `(start, end)` of 488..3288 for NTSC, 487..3282 for PAL
+    pub fn visible_cycles(self) -> (u16, u16) {
+        match self {
+            VideoMode::NTSC => (488, 3288),
+            VideoMode::PAL => (487, 3282),
+        }
+    }
+
+    /// Vertical range visible on a typical TV, in scanlines
+    ///
+    /// # Returns
+    ///
+    /// `(start, end)` of 16..256 for NTSC, 20..308 for PAL
+    pub fn visible_lines(self) -> (u16, u16) {
+        match self {
+            VideoMode::NTSC => (16, 256),
+            VideoMode::PAL => (20, 308),
+        }
+    }
+
+    /// GPU video (dot) clock frequency in Hz
+    ///
+    /// # Returns
+    ///
+    /// 53.693175 MHz for NTSC, 53.203425 MHz for PAL
+    pub fn video_clock_hz(self) -> u64 {
+        match self {
+            VideoMode::NTSC => 53_693_175,
+            VideoMode::PAL => 53_203_425,
+        }
+    }
+
+    /// Total scanlines per frame
+    ///
+    /// # Returns
+    ///
+    /// 263 for NTSC, 314 for PAL
+    pub fn scanlines_per_frame(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 263,
+            VideoMode::PAL => 314,
+        }
+    }
+
+    /// Video clock cycles per scanline
+    ///
+    /// # Returns
+    ///
+    /// 3413 for NTSC, 3406 for PAL
+    pub fn dots_per_scanline(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 3413,
+            VideoMode::PAL => 3406,
+        }
+    }
+
+    /// Scanline at which vertical blanking starts
+    ///
+    /// VBlank lasts until the scanline counter wraps at
+    /// [`scanlines_per_frame`](Self::scanlines_per_frame).
+    ///
+    /// # Returns
+    ///
+    /// 243 for NTSC, 291 for PAL
+    pub fn vblank_start(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 243,
+            VideoMode::PAL => 291,
+        }
+    }
+
+    /// CPU cycles per scanline
+    ///
+    /// Converts the scanline length from video clock cycles to CPU cycles,
+    /// rounded to the nearest cycle.
+    ///
+    /// # Returns
+    ///
+    /// 2153 for NTSC, 2168 for PAL
+    pub fn cpu_cycles_per_scanline(self) -> u32 {
+        let video_cycles = self.dots_per_scanline() as u64 * Self::CPU_CLOCK_HZ;
+        let clock = self.video_clock_hz();
+        ((video_cycles + clock / 2) / clock) as u32
+    }
+
+    /// CPU cycles per frame
+    ///
+    /// # Returns
+    ///
+    /// 566,239 for NTSC, 680,752 for PAL
+    pub fn cpu_cycles_per_frame(self) -> u32 {
+        self.cpu_cycles_per_scanline() * self.scanlines_per_frame() as u32
+    }
+
+    /// Frame rate in Hz
+    ///
+    /// # Returns
+    ///
+    /// About 59.81 Hz for NTSC and 49.75 Hz for PAL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::VideoMode;
+    ///
+    /// assert!((VideoMode::NTSC.refresh_rate() - 59.81).abs() < 0.01);
+    /// assert!((VideoMode::PAL.refresh_rate() - 49.75).abs() < 0.01);
+    /// ```
+    pub fn refresh_rate(self) -> f64 {
+        Self::CPU_CLOCK_HZ as f64 / self.cpu_cycles_per_frame() as f64
+    }
+}
+
 /// Display color depth
 ///
 /// Specifies the color depth used for display output.
@@ -259,10 +570,10 @@ pub struct GPUStatus {
     /// Set mask bit when drawing
     pub set_mask_bit: bool,
 
-    /// Check mask bit before drawing (don't draw if set)
+    /// Check mask bit before drawing (GPUSTAT bit 12: 0=Always, 1=Not to masked areas)
     pub draw_pixels: bool,
 
-    /// Interlace field (even/odd)
+    /// Interlace field currently displayed (false=even lines, true=odd lines)
     pub interlace_field: bool,
 
     /// Reverse flag (used for debugging)
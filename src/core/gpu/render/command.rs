@@ -0,0 +1,311 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Decoded rasterizer commands
+//!
+//! GP0 packets are parsed on the emulation thread into [`RenderCommand`]s:
+//! self-contained primitives with the drawing offset applied and all draw
+//! state they depend on resolved. Executing a command against a
+//! [`Rasterizer`] and VRAM is therefore independent of the rest of the GPU
+//! state, which lets the commands be run later on the render thread with
+//! exactly the same result as drawing them immediately.
+
+use super::super::primitives::{BlendMode, Color, TextureInfo};
+use super::super::registers::{DrawMode, DrawingArea, TextureWindow};
+use super::Rasterizer;
+
+/// Rasterizer state change
+///
+/// Settings are applied in order with the draw commands around them, so a
+/// primitive always sees the state that was current when it was submitted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RasterizerSetting {
+    /// Drawing area as (left, top, right, bottom), inclusive
+    ClipRect(i16, i16, i16, i16),
+
+    /// Ordered dithering on or off
+    Dithering(bool),
+
+    /// Mask bit set and check flags (GP0(E6h))
+    Mask { set: bool, check: bool },
+
+    /// Interlaced field whose lines are skipped
+    SkipField(Option<bool>),
+
+    /// Texture cache emulation on or off
+    TextureCache(bool),
+
+    /// Flush the texture cache (GP0(01h))
+    InvalidateTextureCache,
+}
+
+impl RasterizerSetting {
+    /// Apply the setting to a rasterizer
+    ///
+    /// # Arguments
+    ///
+    /// * `rasterizer` - Rasterizer to update
+    pub fn apply(self, rasterizer: &mut Rasterizer) {
+        match self {
+            Self::ClipRect(left, top, right, bottom) => {
+                rasterizer.set_clip_rect(left, top, right, bottom)
+            }
+            Self::Dithering(enabled) => rasterizer.set_dithering(enabled),
+            Self::Mask { set, check } => rasterizer.set_mask_settings(set, check),
+            Self::SkipField(field) => rasterizer.set_skip_field(field),
+            Self::TextureCache(enabled) => rasterizer.set_texture_cache_enabled(enabled),
+            Self::InvalidateTextureCache => rasterizer.invalidate_texture_cache(),
+        }
+    }
+}
+
+/// A primitive ready to be rasterized
+///
+/// Each variant maps onto one [`Rasterizer`] draw call. Vertex positions
+/// already include the drawing offset, except for rectangles which carry
+/// the offset alongside their own draw state.
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{Rasterizer, RenderCommand};
+///
+/// let mut vram = vec![0u16; 1024 * 512];
+/// let mut rasterizer = Rasterizer::new();
+///
+/// RenderCommand::Line {
+///     from: (0, 0),
+///     to: (7, 0),
+///     color: 0x7FFF,
+/// }
+/// .execute(&mut rasterizer, &mut vram);
+///
+/// assert_eq!(vram[7], 0x7FFF);
+/// ```
+#[derive(Debug, Clone)]
+pub enum RenderCommand {
+    /// Rasterizer state change
+    Configure(RasterizerSetting),
+
+    /// Flat-shaded opaque triangle
+    Triangle {
+        vertices: [(i16, i16); 3],
+        color: u16,
+    },
+
+    /// Flat-shaded semi-transparent triangle
+    TriangleBlended {
+        vertices: [(i16, i16); 3],
+        color: u16,
+        blend_mode: BlendMode,
+    },
+
+    /// Gouraud-shaded triangle
+    GradientTriangle {
+        vertices: [(i16, i16); 3],
+        colors: [(u8, u8, u8); 3],
+    },
+
+    /// Textured triangle
+    TexturedTriangle {
+        vertices: [(i16, i16); 3],
+        texcoords: [(u8, u8); 3],
+        texture: TextureInfo,
+        window: TextureWindow,
+        tint: (u8, u8, u8),
+    },
+
+    /// Flat-shaded triangle with sub-pixel vertices
+    TrianglePrecise {
+        vertices: [(f32, f32); 3],
+        color: u16,
+    },
+
+    /// Gouraud-shaded triangle with sub-pixel vertices
+    GradientTrianglePrecise {
+        vertices: [(f32, f32); 3],
+        colors: [(u8, u8, u8); 3],
+    },
+
+    /// Perspective-correct textured triangle (x, y, w per vertex)
+    TexturedTrianglePrecise {
+        vertices: [(f32, f32, f32); 3],
+        texcoords: [(u8, u8); 3],
+        texture: TextureInfo,
+        window: TextureWindow,
+        tint: (u8, u8, u8),
+    },
+
+    /// Monochrome line
+    Line {
+        from: (i16, i16),
+        to: (i16, i16),
+        color: u16,
+    },
+
+    /// Monochrome polyline
+    Polyline { points: Vec<(i16, i16)>, color: u16 },
+
+    /// Gouraud-shaded line
+    GradientLine {
+        from: (i16, i16),
+        from_color: (u8, u8, u8),
+        to: (i16, i16),
+        to_color: (u8, u8, u8),
+    },
+
+    /// Gouraud-shaded polyline
+    GradientPolyline {
+        points: Vec<(i16, i16)>,
+        colors: Vec<(u8, u8, u8)>,
+    },
+
+    /// Monochrome rectangle
+    Rectangle {
+        draw_mode: DrawMode,
+        draw_area: DrawingArea,
+        draw_offset: (i16, i16),
+        position: (i16, i16),
+        size: (u16, u16),
+        color: Color,
+        semi_transparent: bool,
+    },
+
+    /// Textured rectangle
+    TexturedRectangle {
+        draw_mode: DrawMode,
+        draw_area: DrawingArea,
+        draw_offset: (i16, i16),
+        position: (i16, i16),
+        size: (u16, u16),
+        texcoord: (u8, u8),
+        texture: TextureInfo,
+        color: Color,
+        semi_transparent: bool,
+        modulated: bool,
+    },
+}
+
+impl RenderCommand {
+    /// Rasterize the command into VRAM
+    ///
+    /// # Arguments
+    ///
+    /// * `rasterizer` - Rasterizer holding the current draw state
+    /// * `vram` - VRAM buffer to draw into
+    pub fn execute(self, rasterizer: &mut Rasterizer, vram: &mut [u16]) {
+        match self {
+            Self::Configure(setting) => setting.apply(rasterizer),
+            Self::Triangle {
+                vertices: [v0, v1, v2],
+                color,
+            } => rasterizer.draw_triangle(vram, v0, v1, v2, color),
+            Self::TriangleBlended {
+                vertices: [v0, v1, v2],
+                color,
+                blend_mode,
+            } => rasterizer.draw_triangle_blended(vram, v0, v1, v2, color, blend_mode),
+            Self::GradientTriangle {
+                vertices: [v0, v1, v2],
+                colors: [c0, c1, c2],
+            } => rasterizer.draw_gradient_triangle(vram, v0, c0, v1, c1, v2, c2),
+            Self::TexturedTriangle {
+                vertices: [v0, v1, v2],
+                texcoords: [t0, t1, t2],
+                texture,
+                window,
+                tint,
+            } => rasterizer
+                .draw_textured_triangle(vram, v0, t0, v1, t1, v2, t2, &texture, &window, tint),
+            Self::TrianglePrecise {
+                vertices: [v0, v1, v2],
+                color,
+            } => rasterizer.draw_triangle_precise(vram, v0, v1, v2, color),
+            Self::GradientTrianglePrecise {
+                vertices: [v0, v1, v2],
+                colors: [c0, c1, c2],
+            } => rasterizer.draw_gradient_triangle_precise(vram, v0, c0, v1, c1, v2, c2),
+            Self::TexturedTrianglePrecise {
+                vertices: [v0, v1, v2],
+                texcoords: [t0, t1, t2],
+                texture,
+                window,
+                tint,
+            } => rasterizer.draw_textured_triangle_precise(
+                vram, v0, t0, v1, t1, v2, t2, &texture, &window, tint,
+            ),
+            Self::Line { from, to, color } => {
+                rasterizer.draw_line(vram, from.0, from.1, to.0, to.1, color)
+            }
+            Self::Polyline { points, color } => rasterizer.draw_polyline(vram, &points, color),
+            Self::GradientLine {
+                from,
+                from_color,
+                to,
+                to_color,
+            } => rasterizer
+                .draw_gradient_line(vram, from.0, from.1, from_color, to.0, to.1, to_color),
+            Self::GradientPolyline { points, colors } => {
+                rasterizer.draw_gradient_polyline(vram, &points, &colors)
+            }
+            Self::Rectangle {
+                draw_mode,
+                draw_area,
+                draw_offset,
+                position,
+                size,
+                color,
+                semi_transparent,
+            } => rasterizer.draw_rectangle(
+                vram,
+                &draw_mode,
+                &draw_area,
+                draw_offset,
+                position.0,
+                position.1,
+                size.0,
+                size.1,
+                &color,
+                semi_transparent,
+            ),
+            Self::TexturedRectangle {
+                draw_mode,
+                draw_area,
+                draw_offset,
+                position,
+                size,
+                texcoord,
+                texture,
+                color,
+                semi_transparent,
+                modulated,
+            } => rasterizer.draw_textured_rectangle(
+                vram,
+                &draw_mode,
+                &draw_area,
+                draw_offset,
+                position.0,
+                position.1,
+                size.0,
+                size.1,
+                texcoord.0,
+                texcoord.1,
+                &texture,
+                &color,
+                semi_transparent,
+                modulated,
+            ),
+        }
+    }
+}
@@ -19,6 +19,7 @@
 
 use super::super::primitives::{Color, Vertex};
 use super::super::GPU;
+use super::RenderCommand;
 
 impl GPU {
     /// Render a gradient (Gouraud-shaded) triangle
@@ -61,6 +62,7 @@ impl GPU {
             vertices[2].x.wrapping_add(self.draw_offset.0),
             vertices[2].y.wrapping_add(self.draw_offset.1),
         );
+        self.add_triangle_cycles([v0, v1, v2], false, semi_transparent);
 
         log::trace!(
             "Rendering {}gradient triangle: ({}, {}), ({}, {}), ({}, {}) colors=({},{},{}), ({},{},{}), ({},{},{})",
@@ -94,8 +96,10 @@ impl GPU {
         let _ = semi_transparent;
 
         // Rasterize the gradient triangle
-        self.rasterizer
-            .draw_gradient_triangle(&mut self.vram, v0, c0, v1, c1, v2, c2);
+        self.submit_render_command(RenderCommand::GradientTriangle {
+            vertices: [v0, v1, v2],
+            colors: [c0, c1, c2],
+        });
     }
 
     /// Render a gradient (Gouraud-shaded) quadrilateral
@@ -19,6 +19,7 @@
 
 use super::super::primitives::{Color, Vertex};
 use super::super::GPU;
+use super::RenderCommand;
 
 impl GPU {
     /// Render a monochrome line
@@ -49,6 +50,7 @@ impl GPU {
         let y0 = v0.y.wrapping_add(self.draw_offset.1);
         let x1 = v1.x.wrapping_add(self.draw_offset.0);
         let y1 = v1.y.wrapping_add(self.draw_offset.1);
+        self.add_line_cycles((x0, y0), (x1, y1), semi_transparent);
 
         log::trace!(
             "Rendering {}line: ({}, {}) -> ({}, {}) color=({},{},{})",
@@ -73,8 +75,11 @@ impl GPU {
         let _ = semi_transparent;
 
         // Rasterize the line
-        self.rasterizer
-            .draw_line(&mut self.vram, x0, y0, x1, y1, color_15bit);
+        self.submit_render_command(RenderCommand::Line {
+            from: (x0, y0),
+            to: (x1, y1),
+            color: color_15bit,
+        });
     }
 
     /// Render a polyline (connected line segments)
@@ -131,10 +136,15 @@ impl GPU {
                 )
             })
             .collect();
+        for segment in points.windows(2) {
+            self.add_line_cycles(segment[0], segment[1], semi_transparent);
+        }
 
         // Rasterize the polyline
-        self.rasterizer
-            .draw_polyline(&mut self.vram, &points, color_15bit);
+        self.submit_render_command(RenderCommand::Polyline {
+            points,
+            color: color_15bit,
+        });
     }
 
     /// Render a shaded line with Gouraud shading
@@ -167,6 +177,7 @@ impl GPU {
         let y0 = v0.y.wrapping_add(self.draw_offset.1);
         let x1 = v1.x.wrapping_add(self.draw_offset.0);
         let y1 = v1.y.wrapping_add(self.draw_offset.1);
+        self.add_line_cycles((x0, y0), (x1, y1), semi_transparent);
 
         log::trace!(
             "Rendering {}shaded line: ({}, {}) color=({},{},{}) -> ({}, {}) color=({},{},{})",
@@ -191,15 +202,12 @@ impl GPU {
         let _ = semi_transparent;
 
         // Rasterize the line with color interpolation
-        self.rasterizer.draw_gradient_line(
-            &mut self.vram,
-            x0,
-            y0,
-            (c0.r, c0.g, c0.b),
-            x1,
-            y1,
-            (c1.r, c1.g, c1.b),
-        );
+        self.submit_render_command(RenderCommand::GradientLine {
+            from: (x0, y0),
+            from_color: (c0.r, c0.g, c0.b),
+            to: (x1, y1),
+            to_color: (c1.r, c1.g, c1.b),
+        });
     }
 
     /// Render a shaded polyline (connected line segments with per-vertex colors)
@@ -250,12 +258,17 @@ impl GPU {
                 )
             })
             .collect();
+        for segment in points.windows(2) {
+            self.add_line_cycles(segment[0], segment[1], semi_transparent);
+        }
 
         // Convert colors to tuples
         let color_tuples: Vec<(u8, u8, u8)> = colors.iter().map(|c| (c.r, c.g, c.b)).collect();
 
         // Rasterize the shaded polyline
-        self.rasterizer
-            .draw_gradient_polyline(&mut self.vram, &points, &color_tuples);
+        self.submit_render_command(RenderCommand::GradientPolyline {
+            points,
+            colors: color_tuples,
+        });
     }
 }
@@ -20,13 +20,33 @@
 //! - Quadrilateral rendering (as two triangles)
 //! - Line and polyline rendering
 //! - Texture-mapped primitives (4-bit, 8-bit, 15-bit)
+//! - Precision geometry (sub-pixel, perspective-correct) polygons
+//! - Optional rasterization on a dedicated render thread
+//! - Internal resolution upscaling into a shadow VRAM
+//! - Widescreen rendering into widened drawing areas
+//! - Hash-based texture dumping and replacement
 
+mod command;
 mod gradient;
 mod line;
+mod precise;
 mod quad;
 mod rasterizer;
+mod replacement;
 mod texture;
+mod texture_cache;
+mod thread;
 mod triangle;
+mod upscale;
+mod widescreen;
 
 // Public re-exports
+pub use command::{RasterizerSetting, RenderCommand};
 pub use rasterizer::Rasterizer;
+pub use replacement::{ReplacementTexture, TextureKey, TextureReplacementConfig};
+pub use texture_cache::TextureCache;
+pub use widescreen::WidescreenSurface;
+
+pub(crate) use thread::RenderThread;
+pub(crate) use upscale::Upscaler;
+pub(crate) use widescreen::{widen, Widescreen};
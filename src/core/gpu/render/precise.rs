@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precision geometry rendering
+//!
+//! Polygon rendering with sub-pixel vertex positions recovered from the
+//! precision vertex cache. Primitives are split and offset exactly like
+//! their integer counterparts; only the rasterizer input differs.
+
+use super::super::primitives::{Color, TexCoord, TextureInfo};
+use super::super::GPU;
+use super::RenderCommand;
+use crate::core::precision::PrecisionVertex;
+
+impl GPU {
+    /// Apply the drawing offset to a precise vertex
+    fn offset_precise(&self, vertex: &PrecisionVertex) -> (f32, f32, f32) {
+        (
+            vertex.x + self.draw_offset.0 as f32,
+            vertex.y + self.draw_offset.1 as f32,
+            vertex.w,
+        )
+    }
+
+    /// Render an opaque monochrome triangle with precise vertices
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 3 precise vertices
+    /// * `color` - Flat color for the entire triangle
+    pub(crate) fn render_precise_monochrome_triangle(
+        &mut self,
+        vertices: &[PrecisionVertex; 3],
+        color: &Color,
+    ) {
+        let [v0, v1, v2] = vertices.map(|v| self.offset_precise(&v));
+        self.add_triangle_cycles([v0, v1, v2].map(|v| (v.0 as i16, v.1 as i16)), false, false);
+
+        self.submit_render_command(RenderCommand::TrianglePrecise {
+            vertices: [(v0.0, v0.1), (v1.0, v1.1), (v2.0, v2.1)],
+            color: color.to_rgb15(),
+        });
+    }
+
+    /// Render an opaque monochrome quad with precise vertices
+    ///
+    /// Split into (v0, v1, v2) and (v0, v2, v3) like
+    /// [`render_monochrome_quad`](Self::render_monochrome_quad).
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 4 precise vertices
+    /// * `color` - Flat color for the entire quad
+    pub(crate) fn render_precise_monochrome_quad(
+        &mut self,
+        vertices: &[PrecisionVertex; 4],
+        color: &Color,
+    ) {
+        self.render_precise_monochrome_triangle(&[vertices[0], vertices[1], vertices[2]], color);
+        self.render_precise_monochrome_triangle(&[vertices[0], vertices[2], vertices[3]], color);
+    }
+
+    /// Render a gradient triangle with precise vertices
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 3 precise vertices
+    /// * `colors` - Array of 3 colors, one per vertex
+    pub(crate) fn render_precise_gradient_triangle(
+        &mut self,
+        vertices: &[PrecisionVertex; 3],
+        colors: &[Color; 3],
+    ) {
+        let [v0, v1, v2] = vertices.map(|v| self.offset_precise(&v));
+        self.add_triangle_cycles([v0, v1, v2].map(|v| (v.0 as i16, v.1 as i16)), false, false);
+        let [c0, c1, c2] = colors.map(|c| (c.r, c.g, c.b));
+
+        self.submit_render_command(RenderCommand::GradientTrianglePrecise {
+            vertices: [(v0.0, v0.1), (v1.0, v1.1), (v2.0, v2.1)],
+            colors: [c0, c1, c2],
+        });
+    }
+
+    /// Render a gradient quad with precise vertices
+    ///
+    /// Split into (v0, v1, v2) and (v1, v2, v3) like
+    /// [`render_gradient_quad`](Self::render_gradient_quad).
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 4 precise vertices
+    /// * `colors` - Array of 4 colors, one per vertex
+    pub(crate) fn render_precise_gradient_quad(
+        &mut self,
+        vertices: &[PrecisionVertex; 4],
+        colors: &[Color; 4],
+    ) {
+        self.render_precise_gradient_triangle(
+            &[vertices[0], vertices[1], vertices[2]],
+            &[colors[0], colors[1], colors[2]],
+        );
+        self.render_precise_gradient_triangle(
+            &[vertices[1], vertices[2], vertices[3]],
+            &[colors[1], colors[2], colors[3]],
+        );
+    }
+
+    /// Render a textured triangle with precise vertices
+    ///
+    /// Texture coordinates are interpolated perspective-correctly using
+    /// each vertex's depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 3 precise vertices
+    /// * `texcoords` - Array of 3 texture coordinates corresponding to vertices
+    /// * `texture_info` - Texture page and CLUT information
+    /// * `color` - Color tint to modulate with texture
+    pub(crate) fn render_precise_textured_triangle(
+        &mut self,
+        vertices: &[PrecisionVertex; 3],
+        texcoords: &[TexCoord; 3],
+        texture_info: &TextureInfo,
+        color: &Color,
+    ) {
+        let [v0, v1, v2] = vertices.map(|v| self.offset_precise(&v));
+        self.add_triangle_cycles([v0, v1, v2].map(|v| (v.0 as i16, v.1 as i16)), true, false);
+        let [t0, t1, t2] = texcoords.map(|t| (t.u, t.v));
+
+        self.submit_render_command(RenderCommand::TexturedTrianglePrecise {
+            vertices: [v0, v1, v2],
+            texcoords: [t0, t1, t2],
+            texture: *texture_info,
+            window: self.texture_window,
+            tint: (color.r, color.g, color.b),
+        });
+    }
+
+    /// Render a textured quad with precise vertices
+    ///
+    /// Split into (v0, v1, v2) and (v1, v2, v3) like
+    /// [`render_textured_quad`](Self::render_textured_quad).
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - Array of 4 precise vertices
+    /// * `texcoords` - Array of 4 texture coordinates corresponding to vertices
+    /// * `texture_info` - Texture page and CLUT information
+    /// * `color` - Color tint to modulate with texture
+    pub(crate) fn render_precise_textured_quad(
+        &mut self,
+        vertices: &[PrecisionVertex; 4],
+        texcoords: &[TexCoord; 4],
+        texture_info: &TextureInfo,
+        color: &Color,
+    ) {
+        self.render_precise_textured_triangle(
+            &[vertices[0], vertices[1], vertices[2]],
+            &[texcoords[0], texcoords[1], texcoords[2]],
+            texture_info,
+            color,
+        );
+        self.render_precise_textured_triangle(
+            &[vertices[1], vertices[2], vertices[3]],
+            &[texcoords[1], texcoords[2], texcoords[3]],
+            texture_info,
+            color,
+        );
+    }
+}
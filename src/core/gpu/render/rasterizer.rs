@@ -41,8 +41,30 @@
 //! - [Triangle Rasterization Tutorial](https://www.sunshine2k.de/coding/java/TriangleRasterization/TriangleRasterization.html)
 //! - [Scratchapixel: Rasterization](https://www.scratchapixel.com/lessons/3d-basic-rendering/rasterization-practical-implementation)
 
+use std::sync::Arc;
+
 use super::super::primitives::{Color, TextureDepth, TextureInfo};
-use super::super::registers::{DrawMode, DrawingArea};
+use super::super::registers::{DrawMode, DrawingArea, TextureWindow};
+use super::replacement::{
+    ReplacementTexture, TextureHasher, TextureKey, TextureRegion, TextureReplacementConfig,
+    TextureReplacements,
+};
+use super::texture_cache::TextureCache;
+
+/// PS1 4x4 ordered dither matrix
+///
+/// Offsets added to each 8-bit color channel before it is truncated to
+/// 5 bits, indexed as `DITHER_MATRIX[y & 3][x & 3]`.
+///
+/// # References
+///
+/// - [PSX-SPX: GPU Dithering](http://problemkaputt.de/psx-spx.htm#gpurenderingattributes)
+const DITHER_MATRIX: [[i16; 4]; 4] = [
+    [-4, 0, -3, 1],
+    [2, -2, 3, -1],
+    [-3, 1, -4, 0],
+    [3, -1, 2, -2],
+];
 
 /// Triangle rasterizer using scanline algorithm
 ///
@@ -67,12 +89,42 @@ use super::super::registers::{DrawMode, DrawingArea};
 ///     0x001F  // Red in 5-5-5 RGB
 /// );
 /// ```
+#[derive(Clone)]
 pub struct Rasterizer {
     /// Drawing area (clipping rectangle)
     ///
     /// All pixels are clipped to this rectangle.
     /// Format: (left, top, right, bottom) - all inclusive
     clip_rect: (i16, i16, i16, i16),
+
+    /// Apply ordered dithering to shaded and texture-blended pixels
+    dithering: bool,
+
+    /// Set bit 15 on every written pixel (GP0(E6h) bit 0)
+    mask_set: bool,
+
+    /// Skip pixels whose destination has bit 15 set (GP0(E6h) bit 1)
+    mask_check: bool,
+
+    /// VRAM row parity to leave untouched (interlaced 480-line drawing)
+    ///
+    /// `Some(true)` skips odd rows, `Some(false)` skips even rows.
+    skip_field: Option<bool>,
+
+    /// Route texel fetches through the emulated texture cache
+    texture_cache_enabled: bool,
+
+    /// Texture cache state (only used when enabled)
+    texture_cache: TextureCache,
+
+    /// Internal resolution scale of the target VRAM (1 = native)
+    scale: u16,
+
+    /// Target is twice as wide as VRAM, with widescreen surfaces on the right
+    widescreen_target: bool,
+
+    /// Texture dumping and replacement state
+    textures: TextureReplacements,
 }
 
 impl Rasterizer {
@@ -84,6 +136,15 @@ impl Rasterizer {
     pub fn new() -> Self {
         Self {
             clip_rect: (0, 0, 1023, 511),
+            dithering: false,
+            mask_set: false,
+            mask_check: false,
+            skip_field: None,
+            texture_cache_enabled: false,
+            texture_cache: TextureCache::new(),
+            scale: 1,
+            widescreen_target: false,
+            textures: TextureReplacements::default(),
         }
     }
 
@@ -102,6 +163,165 @@ impl Rasterizer {
         self.clip_rect = (left, top, right, bottom);
     }
 
+    /// Get the clipping rectangle as (left, top, right, bottom)
+    pub fn clip_rect(&self) -> (i16, i16, i16, i16) {
+        self.clip_rect
+    }
+
+    /// Enable or disable ordered dithering
+    ///
+    /// When enabled, Gouraud-shaded and texture-blended primitives add the
+    /// PS1 4x4 dither matrix to each 24-bit color before truncating it to
+    /// 15 bits. Flat-shaded primitives and rectangles are never dithered.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether dithering should be applied
+    pub fn set_dithering(&mut self, enabled: bool) {
+        self.dithering = enabled;
+    }
+
+    /// Check whether ordered dithering is enabled
+    pub fn dithering(&self) -> bool {
+        self.dithering
+    }
+
+    /// Set the mask bit settings
+    ///
+    /// Applies to every pixel the rasterizer writes, including blended ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - Force bit 15 on in every written pixel
+    /// * `check` - Leave destination pixels with bit 15 set untouched
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GP0(E6h) Mask Bit Setting](http://problemkaputt.de/psx-spx.htm#gpurenderingattributes)
+    pub fn set_mask_settings(&mut self, set: bool, check: bool) {
+        self.mask_set = set;
+        self.mask_check = check;
+    }
+
+    /// Set the interlaced field whose lines must not be drawn
+    ///
+    /// In 480-line interlaced mode with drawing to the display area
+    /// prohibited, the GPU skips the lines belonging to the field that is
+    /// currently being displayed. The parity is that of the VRAM rows to
+    /// skip; the caller maps the displayed field onto it using the display
+    /// start Y.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - `Some(odd)` to skip odd (`true`) or even (`false`) VRAM
+    ///   rows, `None` to draw every line
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GP0(E1h) Draw Mode](http://problemkaputt.de/psx-spx.htm#gpurenderingattributes)
+    pub fn set_skip_field(&mut self, field: Option<bool>) {
+        self.skip_field = field;
+    }
+
+    /// Enable or disable texture cache emulation
+    ///
+    /// When enabled, texel fetches go through a 2 KB cache that is only
+    /// refreshed on GP0(01h) and texture page changes, so textures
+    /// overwritten in VRAM keep sampling stale data like on hardware.
+    /// When disabled, texels are always read straight from VRAM.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the texture cache should be emulated
+    ///
+    /// # References
+    ///
+    /// - [PSX-SPX: GPU Texture Caching](http://problemkaputt.de/psx-spx.htm#gputexturecaching)
+    pub fn set_texture_cache_enabled(&mut self, enabled: bool) {
+        self.texture_cache_enabled = enabled;
+        self.texture_cache.invalidate();
+    }
+
+    /// Check whether texture cache emulation is enabled
+    pub fn texture_cache_enabled(&self) -> bool {
+        self.texture_cache_enabled
+    }
+
+    /// Invalidate the texture cache (GP0(01h))
+    pub fn invalidate_texture_cache(&mut self) {
+        self.texture_cache.invalidate();
+    }
+
+    /// Set the internal resolution scale of the target VRAM
+    ///
+    /// With a scale of N, the VRAM buffer passed to the draw functions is
+    /// `1024*N` by `512*N` pixels, and every native VRAM pixel covers an
+    /// NxN block. Textures and CLUTs are sampled from the top-left pixel of
+    /// each block, dithering and field skipping follow native coordinates.
+    ///
+    /// Polygon and line vertices, and the clip rectangle, are expected in
+    /// scaled coordinates. Rectangles take native coordinates and are scaled
+    /// here, so their texture coordinates keep stepping once per native pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Scale factor (1 = native resolution)
+    pub fn set_scale(&mut self, scale: u16) {
+        self.scale = scale.max(1);
+    }
+
+    /// Get the internal resolution scale
+    pub fn scale(&self) -> u16 {
+        self.scale
+    }
+
+    /// Double the width of the target VRAM for widescreen rendering
+    ///
+    /// The target is then `2048*scale` pixels wide. Its left half is VRAM,
+    /// from which textures and CLUTs are still sampled, and the right half
+    /// holds widened drawing areas that primitives are redrawn into.
+    /// Texture cache emulation assumes a VRAM-wide target and must be off.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the target has a widescreen half
+    pub(crate) fn set_widescreen_target(&mut self, enabled: bool) {
+        self.widescreen_target = enabled;
+    }
+
+    /// Set the texture dumping and replacement directories
+    ///
+    /// Textures drawn at native resolution (scale 1) are dumped, and
+    /// replacements are only sampled when drawing upscaled (scale above 1),
+    /// so with upscaling the native rasterizer dumps while the shadow
+    /// rasterizer draws the replacements. Textures sampled through a texture
+    /// window are neither dumped nor replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Dump and replacement directories
+    pub fn set_texture_replacement(&mut self, config: TextureReplacementConfig) {
+        self.textures.configure(config);
+    }
+
+    /// Get the texture dumping and replacement directories
+    pub fn texture_replacement(&self) -> &TextureReplacementConfig {
+        self.textures.config()
+    }
+
+    /// Width of the target VRAM in pixels
+    #[inline(always)]
+    fn target_width(&self) -> i16 {
+        let columns = if self.widescreen_target { 2048 } else { 1024 };
+        columns * self.scale as i16
+    }
+
+    /// Height of the target VRAM in pixels
+    #[inline(always)]
+    fn target_height(&self) -> i16 {
+        512 * self.scale as i16
+    }
+
     /// Rasterize a solid color triangle
     ///
     /// Uses a scanline algorithm to fill the triangle with the specified color.
@@ -289,7 +509,7 @@ impl Rasterizer {
 
         // Draw pixels
         for x in x1..=x2 {
-            Self::write_pixel(vram, x, y, color);
+            self.write_pixel(vram, x, y, color);
         }
     }
 
@@ -367,17 +587,51 @@ impl Rasterizer {
     /// * `y` - Y coordinate
     /// * `color` - Pixel color
     #[inline(always)]
-    fn write_pixel(vram: &mut [u16], x: i16, y: i16, color: u16) {
+    fn write_pixel(&self, vram: &mut [u16], x: i16, y: i16, color: u16) {
         // Bounds check using range contains
-        if !(0..1024).contains(&x) || !(0..512).contains(&y) {
+        if !(0..self.target_width()).contains(&x) || !(0..self.target_height()).contains(&y) {
             return;
         }
 
-        let index = (y as usize) * 1024 + (x as usize);
+        let index = (y as usize) * (self.target_width() as usize) + (x as usize);
 
-        // Write pixel to VRAM
         // Bounds are checked above, so this is safe
-        vram[index] = color;
+        self.store_pixel(vram, index, color);
+    }
+
+    /// Store a pixel at a VRAM index, honoring the mask bit settings
+    ///
+    /// All rasterizer writes funnel through here. With mask check enabled,
+    /// destination pixels that have bit 15 set are left untouched; with
+    /// mask set enabled, bit 15 is ORed into the stored color. Lines of the
+    /// displayed interlace field are skipped when field skipping is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `index` - Linear VRAM index (must be in bounds)
+    /// * `color` - Pixel color
+    #[inline(always)]
+    fn store_pixel(&self, vram: &mut [u16], index: usize, color: u16) {
+        if self.is_masked(vram[index]) || self.is_skipped_line(index) {
+            return;
+        }
+
+        vram[index] = if self.mask_set { color | 0x8000 } else { color };
+    }
+
+    /// Check whether a destination pixel is protected by the mask bit
+    #[inline(always)]
+    fn is_masked(&self, destination: u16) -> bool {
+        self.mask_check && (destination & 0x8000) != 0
+    }
+
+    /// Check whether a VRAM index lies on a line of the skipped field
+    #[inline(always)]
+    fn is_skipped_line(&self, index: usize) -> bool {
+        self.skip_field.is_some_and(|odd| {
+            ((index / (self.target_width() as usize) / self.scale as usize) & 1 == 1) == odd
+        })
     }
 
     /// Write a blended pixel to VRAM with semi-transparency
@@ -398,7 +652,7 @@ impl Rasterizer {
     /// 1. Check bounds (return early if out of bounds)
     /// 2. Read existing background pixel from VRAM
     /// 3. Blend background with foreground using blend mode
-    /// 4. Write blended result back to VRAM
+    /// 4. Write blended result back to VRAM, honoring the mask bit settings
     ///
     /// # Examples
     ///
@@ -426,18 +680,18 @@ impl Rasterizer {
         blend_mode: crate::core::gpu::BlendMode,
     ) {
         // Bounds check
-        if !(0..1024).contains(&x) || !(0..512).contains(&y) {
+        if !(0..self.target_width()).contains(&x) || !(0..self.target_height()).contains(&y) {
             return;
         }
 
-        let index = (y as usize) * 1024 + (x as usize);
+        let index = (y as usize) * (self.target_width() as usize) + (x as usize);
 
         // Read background pixel
         let background = vram[index];
 
         // Blend and write
         let blended = blend_mode.blend(background, color);
-        vram[index] = blended;
+        self.store_pixel(vram, index, blended);
     }
 
     /// Rasterize a semi-transparent solid color triangle
@@ -653,6 +907,8 @@ impl Rasterizer {
     /// rasterizer.draw_line(&mut vram, 0, 0, 100, 100, 0x7FFF);
     /// ```
     pub fn draw_line(&mut self, vram: &mut [u16], x0: i16, y0: i16, x1: i16, y1: i16, color: u16) {
+        // Step in 32 bits so lines spanning the whole i16 range cannot overflow
+        let (x0, y0, x1, y1) = (x0 as i32, y0 as i32, x1 as i32, y1 as i32);
         let dx = (x1 - x0).abs();
         let dy = -(y1 - y0).abs();
         let sx = if x0 < x1 { 1 } else { -1 };
@@ -664,9 +920,8 @@ impl Rasterizer {
 
         loop {
             // Check clipping bounds before drawing
-            let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
-            if x >= clip_left && x <= clip_right && y >= clip_top && y <= clip_bottom {
-                Self::write_pixel(vram, x, y, color);
+            if self.line_pixel_visible(x, y) {
+                self.write_pixel(vram, x as i16, y as i16, color);
             }
 
             if x == x1 && y == y1 {
@@ -685,6 +940,18 @@ impl Rasterizer {
         }
     }
 
+    /// Check whether a line pixel lies inside the clip rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate
+    /// * `y` - Y coordinate
+    fn line_pixel_visible(&self, x: i32, y: i32) -> bool {
+        let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
+        (clip_left as i32..=clip_right as i32).contains(&x)
+            && (clip_top as i32..=clip_bottom as i32).contains(&y)
+    }
+
     /// Draw a polyline (connected line segments)
     ///
     /// Draws multiple connected line segments by calling `draw_line` for each pair
@@ -773,6 +1040,8 @@ impl Rasterizer {
         y1: i16,
         c1: (u8, u8, u8),
     ) {
+        // Step in 32 bits so lines spanning the whole i16 range cannot overflow
+        let (x0, y0, x1, y1) = (x0 as i32, y0 as i32, x1 as i32, y1 as i32);
         let dx = (x1 - x0).abs();
         let dy = -(y1 - y0).abs();
         let sx = if x0 < x1 { 1 } else { -1 };
@@ -780,13 +1049,13 @@ impl Rasterizer {
         let mut err = dx + dy;
 
         // Calculate total distance for interpolation
-        let total_distance = ((dx * dx + dy * dy) as f32).sqrt();
+        let total_distance = ((dx as i64 * dx as i64 + dy as i64 * dy as i64) as f32).sqrt();
         if total_distance == 0.0 {
             // Single point - just draw it with the start color
-            let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
-            if x0 >= clip_left && x0 <= clip_right && y0 >= clip_top && y0 <= clip_bottom {
-                let color = Self::rgb_to_rgb15(c0.0, c0.1, c0.2);
-                Self::write_pixel(vram, x0, y0, color);
+            if self.line_pixel_visible(x0, y0) {
+                let (x0, y0) = (x0 as i16, y0 as i16);
+                let color = self.shade_to_rgb15(x0, y0, c0.0, c0.1, c0.2);
+                self.write_pixel(vram, x0, y0, color);
             }
             return;
         }
@@ -796,11 +1065,10 @@ impl Rasterizer {
 
         loop {
             // Check clipping bounds before drawing
-            let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
-            if x >= clip_left && x <= clip_right && y >= clip_top && y <= clip_bottom {
+            if self.line_pixel_visible(x, y) {
                 // Calculate interpolation factor (0.0 at start, 1.0 at end)
-                let dist_x = x - x0;
-                let dist_y = y - y0;
+                let dist_x = (x - x0) as i64;
+                let dist_y = (y - y0) as i64;
                 let current_distance = ((dist_x * dist_x + dist_y * dist_y) as f32).sqrt();
                 let t = current_distance / total_distance;
 
@@ -809,8 +1077,9 @@ impl Rasterizer {
                 let g = (c0.1 as f32 * (1.0 - t) + c1.1 as f32 * t) as u8;
                 let b = (c0.2 as f32 * (1.0 - t) + c1.2 as f32 * t) as u8;
 
-                let color = Self::rgb_to_rgb15(r, g, b);
-                Self::write_pixel(vram, x, y, color);
+                let (x, y) = (x as i16, y as i16);
+                let color = self.shade_to_rgb15(x, y, r, g, b);
+                self.write_pixel(vram, x, y, color);
             }
 
             if x == x1 && y == y1 {
@@ -967,8 +1236,8 @@ impl Rasterizer {
                     let g = (c0.1 as f32 * w0 + c1.1 as f32 * w1 + c2.1 as f32 * w2) as u8;
                     let b = (c0.2 as f32 * w0 + c1.2 as f32 * w1 + c2.2 as f32 * w2) as u8;
 
-                    let color = Self::rgb_to_rgb15(r, g, b);
-                    Self::write_pixel(vram, x, y, color);
+                    let color = self.shade_to_rgb15(x, y, r, g, b);
+                    self.write_pixel(vram, x, y, color);
                 }
             }
         }
@@ -1065,6 +1334,38 @@ impl Rasterizer {
         (b << 10) | (g << 5) | r
     }
 
+    /// Convert a shaded 24-bit color to 15-bit RGB, dithering if enabled
+    ///
+    /// Used by all primitives that the hardware dithers (Gouraud shading and
+    /// texture blending). The dither offset for the pixel position is added
+    /// to each channel and the result is clamped to 0-255 before truncation.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Pixel X coordinate (selects the dither matrix column)
+    /// * `y` - Pixel Y coordinate (selects the dither matrix row)
+    /// * `r` - Red channel (0-255)
+    /// * `g` - Green channel (0-255)
+    /// * `b` - Blue channel (0-255)
+    ///
+    /// # Returns
+    ///
+    /// 16-bit color in 5-5-5 RGB format (bit 15 is 0)
+    fn shade_to_rgb15(&self, x: i16, y: i16, r: u8, g: u8, b: u8) -> u16 {
+        if !self.dithering {
+            return Self::rgb_to_rgb15(r, g, b);
+        }
+
+        // The dither pattern follows native pixels when upscaling
+        let (x, y) = (
+            x.div_euclid(self.scale as i16),
+            y.div_euclid(self.scale as i16),
+        );
+        let offset = DITHER_MATRIX[(y & 3) as usize][(x & 3) as usize];
+        let dither = |c: u8| (c as i16 + offset).clamp(0, 255) as u8;
+        Self::rgb_to_rgb15(dither(r), dither(g), dither(b))
+    }
+
     /// Convert 15-bit RGB to 24-bit RGB format
     ///
     /// Converts PlayStation's 5-bit per channel RGB to 8-bit per channel
@@ -1102,20 +1403,170 @@ impl Rasterizer {
     /// # Arguments
     ///
     /// * `vram` - Reference to VRAM buffer
-    /// * `x` - X coordinate (0-1023)
-    /// * `y` - Y coordinate (0-511)
+    /// * `x` - Native X coordinate (0-1023)
+    /// * `y` - Native Y coordinate (0-511)
     ///
     /// # Returns
     ///
     /// 16-bit pixel value, or 0 if out of bounds
-    fn read_vram_pixel(vram: &[u16], x: i16, y: i16) -> u16 {
+    fn read_vram_pixel(&self, vram: &[u16], x: i16, y: i16) -> u16 {
         if !(0..1024).contains(&x) || !(0..512).contains(&y) {
             return 0;
         }
-        let index = (y as usize) * 1024 + (x as usize);
+        let scale = self.scale as usize;
+        let index = (y as usize * scale) * (self.target_width() as usize) + x as usize * scale;
         vram[index]
     }
 
+    /// Fetch a texture halfword, through the texture cache when enabled
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Reference to VRAM buffer
+    /// * `x` - X coordinate (0-1023)
+    /// * `y` - Y coordinate (0-511)
+    /// * `depth` - Texture depth of the primitive being drawn
+    ///
+    /// # Returns
+    ///
+    /// 16-bit texture word
+    fn read_texel(&mut self, vram: &[u16], x: u16, y: u16, depth: TextureDepth) -> u16 {
+        if self.texture_cache_enabled {
+            self.texture_cache
+                .read_scaled(vram, x, y, depth, self.scale)
+        } else {
+            self.read_vram_pixel(vram, x as i16, y as i16)
+        }
+    }
+
+    /// Select the texture page for the next textured primitive
+    ///
+    /// Flushes the texture cache when the page or color depth changes.
+    fn use_texture_page(&mut self, info: &TextureInfo) {
+        if self.texture_cache_enabled {
+            self.texture_cache.use_page(info);
+        }
+    }
+
+    /// Read the raw value of a texel: a CLUT index, or a 15-bit color
+    ///
+    /// Reads VRAM directly, bypassing the texture cache.
+    fn texel_value(&self, vram: &[u16], info: &TextureInfo, u: u8, v: u8) -> u16 {
+        let tex_y = (info.page_y + v as u16) & 0x1FF;
+        let (tex_x, shift, mask) = match info.depth {
+            TextureDepth::T4Bit => (info.page_x + u as u16 / 4, (u % 4) * 4, 0xF),
+            TextureDepth::T8Bit => (info.page_x + u as u16 / 2, (u % 2) * 8, 0xFF),
+            TextureDepth::T15Bit => (info.page_x + u as u16, 0, 0xFFFF),
+        };
+        let word = self.read_vram_pixel(vram, (tex_x & 0x3FF) as i16, tex_y as i16);
+        (word >> shift) & mask
+    }
+
+    /// Number of CLUT entries used by a texture depth
+    fn clut_size(depth: TextureDepth) -> u16 {
+        match depth {
+            TextureDepth::T4Bit => 16,
+            TextureDepth::T8Bit => 256,
+            TextureDepth::T15Bit => 0,
+        }
+    }
+
+    /// Identify the texture in a region by hashing its texels and CLUT
+    fn texture_key(&self, vram: &[u16], info: &TextureInfo, region: TextureRegion) -> TextureKey {
+        let mut hasher = TextureHasher::new();
+        for dv in 0..region.height {
+            for du in 0..region.width {
+                let (u, v) = (region.u + du as u8, region.v + dv as u8);
+                hasher.write_u16(self.texel_value(vram, info, u, v));
+            }
+        }
+        for index in 0..Self::clut_size(info.depth) {
+            let clut_x = (info.clut_x + index) as i16;
+            hasher.write_u16(self.read_vram_pixel(vram, clut_x, info.clut_y as i16));
+        }
+
+        TextureKey {
+            hash: hasher.finish(),
+            width: region.width,
+            height: region.height,
+            depth: info.depth,
+        }
+    }
+
+    /// Decode the texture in a region to RGBA for dumping
+    ///
+    /// Color 0x0000, which the GPU treats as transparent, gets alpha 0.
+    fn decode_texture(&self, vram: &[u16], info: &TextureInfo, region: TextureRegion) -> Vec<u8> {
+        let mut rgba = Vec::with_capacity(region.width as usize * region.height as usize * 4);
+        for dv in 0..region.height {
+            for du in 0..region.width {
+                let (u, v) = (region.u + du as u8, region.v + dv as u8);
+                let value = self.texel_value(vram, info, u, v);
+                let color = match info.depth {
+                    TextureDepth::T15Bit => value,
+                    _ => {
+                        self.read_vram_pixel(vram, (info.clut_x + value) as i16, info.clut_y as i16)
+                    }
+                };
+
+                let channel = |shift: u16| {
+                    let c = ((color >> shift) & 0x1F) as u8;
+                    (c << 3) | (c >> 2)
+                };
+                let alpha = if color == 0x0000 { 0 } else { 0xFF };
+                rgba.extend_from_slice(&[channel(0), channel(5), channel(10), alpha]);
+            }
+        }
+        rgba
+    }
+
+    /// Dump a primitive's texture and look up its replacement
+    ///
+    /// Only hashes the texture when dumping (native resolution) or
+    /// replacement (upscaled) is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Reference to VRAM buffer
+    /// * `info` - Texture page and CLUT of the primitive
+    /// * `window` - Texture window of the primitive
+    /// * `region` - Texels sampled by the primitive (None = not trackable)
+    ///
+    /// # Returns
+    ///
+    /// The replacement texture to sample instead of VRAM, if any
+    fn track_texture(
+        &mut self,
+        vram: &[u16],
+        info: &TextureInfo,
+        window: &TextureWindow,
+        region: Option<TextureRegion>,
+    ) -> Option<Arc<ReplacementTexture>> {
+        let dumping = self.scale == 1 && self.textures.config().dump_dir.is_some();
+        let replacing = self.scale > 1 && self.textures.replacing();
+        if !dumping && !replacing {
+            return None;
+        }
+
+        // A texture window repeats part of the region, so it no longer
+        // describes what is drawn
+        if window.mask_x != 0 || window.mask_y != 0 {
+            return None;
+        }
+
+        let region = region?;
+        let key = self.texture_key(vram, info, region);
+        if dumping && self.textures.needs_dump(&key) {
+            let rgba = self.decode_texture(vram, info, region);
+            self.textures.dump(key, &rgba);
+        }
+        if replacing {
+            self.textures.replacement(key)
+        } else {
+            None
+        }
+    }
+
     /// Draw a textured triangle with perspective-correct interpolation
     ///
     /// Renders a triangle with texture mapping, interpolating texture coordinates
@@ -1197,6 +1648,10 @@ impl Rasterizer {
         texture_window: &crate::core::gpu::TextureWindow,
         tint_color: (u8, u8, u8),
     ) {
+        self.use_texture_page(texture_info);
+        let region = TextureRegion::from_texcoords(&[t0, t1, t2]);
+        let replacement = self.track_texture(vram, texture_info, texture_window, Some(region));
+
         // Compute bounding box clipped to drawing area
         let min_x = v0.0.min(v1.0).min(v2.0).max(self.clip_rect.0);
         let max_x = v0.0.max(v1.0).max(v2.0).min(self.clip_rect.2);
@@ -1211,11 +1666,21 @@ impl Rasterizer {
                 // Check if inside triangle
                 if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
                     // Interpolate texture coordinates
-                    let u = (t0.0 as f32 * w0 + t1.0 as f32 * w1 + t2.0 as f32 * w2) as u8;
-                    let v = (t0.1 as f32 * w0 + t1.1 as f32 * w1 + t2.1 as f32 * w2) as u8;
-
-                    // Sample texture with texture window
-                    let tex_color = self.sample_texture(vram, u, v, texture_info, texture_window);
+                    let u = t0.0 as f32 * w0 + t1.0 as f32 * w1 + t2.0 as f32 * w2;
+                    let v = t0.1 as f32 * w0 + t1.1 as f32 * w1 + t2.1 as f32 * w2;
+
+                    // Sample the replacement, or the texture with texture window
+                    // (transparent replacement texels draw like texel 0x0000)
+                    let tex_color = match &replacement {
+                        Some(texture) => texture.sample(region, u, v).unwrap_or((0, 0, 0)),
+                        None => self.sample_texture(
+                            vram,
+                            u as u8,
+                            v as u8,
+                            texture_info,
+                            texture_window,
+                        ),
+                    };
 
                     // Apply tint (modulate)
                     // Multiply by tint and divide by 128 (shift right by 7)
@@ -1223,13 +1688,206 @@ impl Rasterizer {
                     let g = ((tex_color.1 as u16 * tint_color.1 as u16) >> 7) as u8;
                     let b = ((tex_color.2 as u16 * tint_color.2 as u16) >> 7) as u8;
 
-                    let color = Self::rgb_to_rgb15(r, g, b);
-                    Self::write_pixel(vram, x, y, color);
+                    let color = self.shade_to_rgb15(x, y, r, g, b);
+                    self.write_pixel(vram, x, y, color);
                 }
             }
         }
     }
 
+    /// Visit every pixel covered by a triangle with sub-pixel vertices
+    ///
+    /// Shared traversal for the precision geometry paths. Pixels are sampled
+    /// at their integer coordinates, like the integer rasterizer, but the
+    /// edges are evaluated against the unrounded vertex positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_rect` - Drawing area (left, top, right, bottom), inclusive
+    /// * `v0` - First vertex position (x, y)
+    /// * `v1` - Second vertex position (x, y)
+    /// * `v2` - Third vertex position (x, y)
+    /// * `plot` - Called with (x, y, w0, w1, w2) for each covered pixel
+    fn rasterize_precise<F>(
+        clip_rect: (i16, i16, i16, i16),
+        v0: (f32, f32),
+        v1: (f32, f32),
+        v2: (f32, f32),
+        mut plot: F,
+    ) where
+        F: FnMut(i16, i16, f32, f32, f32),
+    {
+        let denom = (v1.1 - v2.1) * (v0.0 - v2.0) + (v2.0 - v1.0) * (v0.1 - v2.1);
+        if denom.abs() < 0.001 {
+            return; // Degenerate triangle
+        }
+
+        // Bounding box clipped to drawing area
+        let min_x = (v0.0.min(v1.0).min(v2.0).ceil() as i32).max(clip_rect.0 as i32);
+        let max_x = (v0.0.max(v1.0).max(v2.0).floor() as i32).min(clip_rect.2 as i32);
+        let min_y = (v0.1.min(v1.1).min(v2.1).ceil() as i32).max(clip_rect.1 as i32);
+        let max_y = (v0.1.max(v1.1).max(v2.1).floor() as i32).min(clip_rect.3 as i32);
+
+        for y in min_y..=max_y {
+            let py = y as f32;
+            for x in min_x..=max_x {
+                let px = x as f32;
+                let w0 = ((v1.1 - v2.1) * (px - v2.0) + (v2.0 - v1.0) * (py - v2.1)) / denom;
+                let w1 = ((v2.1 - v0.1) * (px - v2.0) + (v0.0 - v2.0) * (py - v2.1)) / denom;
+                let w2 = 1.0 - w0 - w1;
+
+                if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
+                    plot(x as i16, y as i16, w0, w1, w2);
+                }
+            }
+        }
+    }
+
+    /// Rasterize a solid color triangle with sub-pixel vertices
+    ///
+    /// Precision geometry counterpart of [`draw_triangle`](Self::draw_triangle).
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to the VRAM buffer
+    /// * `v0` - First vertex (x, y)
+    /// * `v1` - Second vertex (x, y)
+    /// * `v2` - Third vertex (x, y)
+    /// * `color` - 16-bit color in 5-5-5 RGB format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::Rasterizer;
+    ///
+    /// let mut vram = vec![0u16; 1024 * 512];
+    /// let mut rasterizer = Rasterizer::new();
+    ///
+    /// rasterizer.draw_triangle_precise(&mut vram, (10.5, 10.5), (20.25, 10.5), (15.0, 20.75), 0x001F);
+    /// assert_eq!(vram[15 * 1024 + 15], 0x001F);
+    /// assert_eq!(vram[10 * 1024 + 15], 0x0000); // Edge lies below row 10
+    /// ```
+    pub fn draw_triangle_precise(
+        &mut self,
+        vram: &mut [u16],
+        v0: (f32, f32),
+        v1: (f32, f32),
+        v2: (f32, f32),
+        color: u16,
+    ) {
+        Self::rasterize_precise(self.clip_rect, v0, v1, v2, |x, y, _, _, _| {
+            self.write_pixel(vram, x, y, color);
+        });
+    }
+
+    /// Draw a gradient triangle with sub-pixel vertices
+    ///
+    /// Precision geometry counterpart of
+    /// [`draw_gradient_triangle`](Self::draw_gradient_triangle).
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `v0` - First vertex position (x, y)
+    /// * `c0` - First vertex color (r, g, b)
+    /// * `v1` - Second vertex position (x, y)
+    /// * `c1` - Second vertex color (r, g, b)
+    /// * `v2` - Third vertex position (x, y)
+    /// * `c2` - Third vertex color (r, g, b)
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_gradient_triangle_precise(
+        &mut self,
+        vram: &mut [u16],
+        v0: (f32, f32),
+        c0: (u8, u8, u8),
+        v1: (f32, f32),
+        c1: (u8, u8, u8),
+        v2: (f32, f32),
+        c2: (u8, u8, u8),
+    ) {
+        Self::rasterize_precise(self.clip_rect, v0, v1, v2, |x, y, w0, w1, w2| {
+            let r = (c0.0 as f32 * w0 + c1.0 as f32 * w1 + c2.0 as f32 * w2) as u8;
+            let g = (c0.1 as f32 * w0 + c1.1 as f32 * w1 + c2.1 as f32 * w2) as u8;
+            let b = (c0.2 as f32 * w0 + c1.2 as f32 * w1 + c2.2 as f32 * w2) as u8;
+
+            self.write_pixel(vram, x, y, self.shade_to_rgb15(x, y, r, g, b));
+        });
+    }
+
+    /// Draw a textured triangle with sub-pixel vertices and perspective correction
+    ///
+    /// Precision geometry counterpart of
+    /// [`draw_textured_triangle`](Self::draw_textured_triangle). Texture
+    /// coordinates are interpolated as U/W and V/W alongside 1/W and divided
+    /// per pixel, which removes the affine texture warping of the hardware.
+    /// If any vertex has no usable depth (W ≤ 0), the interpolation falls back
+    /// to affine.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `v0` - First vertex position and depth (x, y, w)
+    /// * `t0` - First vertex texture coordinates (u, v)
+    /// * `v1` - Second vertex position and depth (x, y, w)
+    /// * `t1` - Second vertex texture coordinates (u, v)
+    /// * `v2` - Third vertex position and depth (x, y, w)
+    /// * `t2` - Third vertex texture coordinates (u, v)
+    /// * `texture_info` - Texture page and CLUT information
+    /// * `texture_window` - Texture window settings
+    /// * `tint_color` - Color to modulate with texture (r, g, b)
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_textured_triangle_precise(
+        &mut self,
+        vram: &mut [u16],
+        v0: (f32, f32, f32),
+        t0: (u8, u8),
+        v1: (f32, f32, f32),
+        t1: (u8, u8),
+        v2: (f32, f32, f32),
+        t2: (u8, u8),
+        texture_info: &crate::core::gpu::TextureInfo,
+        texture_window: &crate::core::gpu::TextureWindow,
+        tint_color: (u8, u8, u8),
+    ) {
+        // Reciprocal depth per vertex (1.0 everywhere = affine)
+        let (q0, q1, q2) = if v0.2 > 0.0 && v1.2 > 0.0 && v2.2 > 0.0 {
+            (1.0 / v0.2, 1.0 / v1.2, 1.0 / v2.2)
+        } else {
+            (1.0, 1.0, 1.0)
+        };
+
+        self.use_texture_page(texture_info);
+        let region = TextureRegion::from_texcoords(&[t0, t1, t2]);
+        let replacement = self.track_texture(vram, texture_info, texture_window, Some(region));
+
+        Self::rasterize_precise(
+            self.clip_rect,
+            (v0.0, v0.1),
+            (v1.0, v1.1),
+            (v2.0, v2.1),
+            |x, y, w0, w1, w2| {
+                let (w0, w1, w2) = (w0 * q0, w1 * q1, w2 * q2);
+                let q = w0 + w1 + w2;
+
+                let u = (t0.0 as f32 * w0 + t1.0 as f32 * w1 + t2.0 as f32 * w2) / q;
+                let v = (t0.1 as f32 * w0 + t1.1 as f32 * w1 + t2.1 as f32 * w2) / q;
+
+                let tex_color = match &replacement {
+                    Some(texture) => texture.sample(region, u, v).unwrap_or((0, 0, 0)),
+                    None => {
+                        self.sample_texture(vram, u as u8, v as u8, texture_info, texture_window)
+                    }
+                };
+
+                let r = ((tex_color.0 as u16 * tint_color.0 as u16) >> 7) as u8;
+                let g = ((tex_color.1 as u16 * tint_color.1 as u16) >> 7) as u8;
+                let b = ((tex_color.2 as u16 * tint_color.2 as u16) >> 7) as u8;
+
+                self.write_pixel(vram, x, y, self.shade_to_rgb15(x, y, r, g, b));
+            },
+        );
+    }
+
     /// Apply texture window masking to texture coordinates
     ///
     /// The texture window controls how texture coordinates wrap within a specified
@@ -1298,7 +1956,7 @@ impl Rasterizer {
     ///
     /// Tuple (r, g, b) with 8-bit RGB values
     fn sample_texture(
-        &self,
+        &mut self,
         vram: &[u16],
         u: u8,
         v: u8,
@@ -1340,7 +1998,7 @@ impl Rasterizer {
     ///
     /// Tuple (r, g, b) with 8-bit RGB values from CLUT
     fn sample_4bit_texture(
-        &self,
+        &mut self,
         vram: &[u16],
         u: u8,
         v: u8,
@@ -1352,7 +2010,7 @@ impl Rasterizer {
         let tex_y = (info.page_y + v as u16) & 0x1FF;
 
         // Read 16-bit word containing 4 indices
-        let index_word = Self::read_vram_pixel(vram, tex_x as i16, tex_y as i16);
+        let index_word = self.read_texel(vram, tex_x, tex_y, info.depth);
 
         // Extract 4-bit index (which of the 4 pixels in this word)
         let shift = (u % 4) * 4;
@@ -1361,7 +2019,7 @@ impl Rasterizer {
         // Look up color in CLUT
         let clut_x = info.clut_x + index;
         let clut_y = info.clut_y;
-        let color = Self::read_vram_pixel(vram, clut_x as i16, clut_y as i16);
+        let color = self.read_vram_pixel(vram, clut_x as i16, clut_y as i16);
 
         Self::rgb15_to_rgb24(color)
     }
@@ -1388,7 +2046,7 @@ impl Rasterizer {
     ///
     /// Tuple (r, g, b) with 8-bit RGB values from CLUT
     fn sample_8bit_texture(
-        &self,
+        &mut self,
         vram: &[u16],
         u: u8,
         v: u8,
@@ -1400,7 +2058,7 @@ impl Rasterizer {
         let tex_y = (info.page_y + v as u16) & 0x1FF;
 
         // Read 16-bit word containing 2 indices
-        let index_word = Self::read_vram_pixel(vram, tex_x as i16, tex_y as i16);
+        let index_word = self.read_texel(vram, tex_x, tex_y, info.depth);
 
         // Extract 8-bit index (lower or upper byte depending on odd/even U)
         let index = if u.is_multiple_of(2) {
@@ -1412,7 +2070,7 @@ impl Rasterizer {
         // Look up color in CLUT
         let clut_x = info.clut_x + index;
         let clut_y = info.clut_y;
-        let color = Self::read_vram_pixel(vram, clut_x as i16, clut_y as i16);
+        let color = self.read_vram_pixel(vram, clut_x as i16, clut_y as i16);
 
         Self::rgb15_to_rgb24(color)
     }
@@ -1437,7 +2095,7 @@ impl Rasterizer {
     ///
     /// Tuple (r, g, b) with 8-bit RGB values
     fn sample_15bit_texture(
-        &self,
+        &mut self,
         vram: &[u16],
         u: u8,
         v: u8,
@@ -1449,7 +2107,7 @@ impl Rasterizer {
         let tex_y = (info.page_y + v as u16) & 0x1FF;
 
         // Read color directly
-        let color = Self::read_vram_pixel(vram, tex_x as i16, tex_y as i16);
+        let color = self.read_texel(vram, tex_x, tex_y, info.depth);
         Self::rgb15_to_rgb24(color)
     }
 
@@ -1528,17 +2186,18 @@ impl Rasterizer {
         // Convert color to 15-bit RGB
         let color15 = color.to_rgb15();
 
-        // Calculate rectangle bounds
-        let x1 = x;
-        let y1 = y;
-        let x2 = x.saturating_add(width as i16);
-        let y2 = y.saturating_add(height as i16);
+        // Calculate rectangle bounds (in target pixels when upscaling)
+        let scale = self.scale as i16;
+        let x1 = x.saturating_mul(scale);
+        let y1 = y.saturating_mul(scale);
+        let x2 = x.saturating_add(width as i16).saturating_mul(scale);
+        let y2 = y.saturating_add(height as i16).saturating_mul(scale);
 
         // Clip to drawing area
-        let clip_x1 = x1.max(draw_area.left as i16);
-        let clip_y1 = y1.max(draw_area.top as i16);
-        let clip_x2 = x2.min((draw_area.right as i16) + 1);
-        let clip_y2 = y2.min((draw_area.bottom as i16) + 1);
+        let clip_x1 = x1.max(draw_area.left as i16 * scale);
+        let clip_y1 = y1.max(draw_area.top as i16 * scale);
+        let clip_x2 = x2.min((draw_area.right as i16 + 1) * scale);
+        let clip_y2 = y2.min((draw_area.bottom as i16 + 1) * scale);
 
         // Check if rectangle is completely outside drawing area
         if clip_x1 >= clip_x2 || clip_y1 >= clip_y2 {
@@ -1547,16 +2206,16 @@ impl Rasterizer {
 
         // Fill rectangle scanline by scanline
         for py in clip_y1..clip_y2 {
-            if !(0..512).contains(&py) {
+            if !(0..self.target_height()).contains(&py) {
                 continue;
             }
 
             for px in clip_x1..clip_x2 {
-                if !(0..1024).contains(&px) {
+                if !(0..self.target_width()).contains(&px) {
                     continue;
                 }
 
-                let vram_index = (py as usize) * 1024 + (px as usize);
+                let vram_index = (py as usize) * (self.target_width() as usize) + (px as usize);
 
                 if semi_transparent {
                     // Apply semi-transparency blending
@@ -1564,9 +2223,9 @@ impl Rasterizer {
                     let blend_mode =
                         crate::core::gpu::BlendMode::from_bits(draw_mode.semi_transparency);
                     let blended = blend_mode.blend(bg_color, color15);
-                    vram[vram_index] = blended;
+                    self.store_pixel(vram, vram_index, blended);
                 } else {
-                    vram[vram_index] = color15;
+                    self.store_pixel(vram, vram_index, color15);
                 }
             }
         }
@@ -1608,21 +2267,26 @@ impl Rasterizer {
         semi_transparent: bool,
         modulated: bool,
     ) {
+        self.use_texture_page(texture_info);
+        let region = TextureRegion::from_rectangle(tex_u, tex_v, width, height);
+        let replacement = self.track_texture(vram, texture_info, &TextureWindow::default(), region);
+
         // Apply drawing offset
         let x = x.wrapping_add(draw_offset.0);
         let y = y.wrapping_add(draw_offset.1);
 
-        // Calculate rectangle bounds
-        let x1 = x;
-        let y1 = y;
-        let x2 = x.saturating_add(width as i16);
-        let y2 = y.saturating_add(height as i16);
+        // Calculate rectangle bounds (in target pixels when upscaling)
+        let scale = self.scale as i16;
+        let x1 = x.saturating_mul(scale);
+        let y1 = y.saturating_mul(scale);
+        let x2 = x.saturating_add(width as i16).saturating_mul(scale);
+        let y2 = y.saturating_add(height as i16).saturating_mul(scale);
 
         // Clip to drawing area
-        let clip_x1 = x1.max(draw_area.left as i16);
-        let clip_y1 = y1.max(draw_area.top as i16);
-        let clip_x2 = x2.min((draw_area.right as i16) + 1);
-        let clip_y2 = y2.min((draw_area.bottom as i16) + 1);
+        let clip_x1 = x1.max(draw_area.left as i16 * scale);
+        let clip_y1 = y1.max(draw_area.top as i16 * scale);
+        let clip_x2 = x2.min((draw_area.right as i16 + 1) * scale);
+        let clip_y2 = y2.min((draw_area.bottom as i16 + 1) * scale);
 
         // Check if rectangle is completely outside drawing area
         if clip_x1 >= clip_x2 || clip_y1 >= clip_y2 {
@@ -1631,40 +2295,61 @@ impl Rasterizer {
 
         // Render each pixel
         for py in clip_y1..clip_y2 {
-            if !(0..512).contains(&py) {
+            if !(0..self.target_height()).contains(&py) {
                 continue;
             }
 
             // Calculate texture V coordinate for this scanline
-            let v_offset = (py - y1) as u8;
+            let v_offset = ((py - y1) / scale) as u8;
             let v = tex_v.wrapping_add(v_offset);
 
             for px in clip_x1..clip_x2 {
-                if !(0..1024).contains(&px) {
+                if !(0..self.target_width()).contains(&px) {
                     continue;
                 }
 
                 // Calculate texture U coordinate for this pixel
-                let u_offset = (px - x1) as u8;
+                let u_offset = ((px - x1) / scale) as u8;
                 let u = tex_u.wrapping_add(u_offset);
 
-                // Sample texture
-                let tex_color = match texture_info.depth {
-                    TextureDepth::T4Bit => self.sample_4bit_texture(vram, u, v, texture_info),
-                    TextureDepth::T8Bit => self.sample_8bit_texture(vram, u, v, texture_info),
-                    TextureDepth::T15Bit => self.sample_15bit_texture(vram, u, v, texture_info),
+                let (tex_color, tex_color15) = match (&replacement, region) {
+                    (Some(texture), Some(region)) => {
+                        // Sample the replacement at the pixel center, its
+                        // alpha decides transparency
+                        let u = tex_u as f32 + ((px - x1) as f32 + 0.5) / scale as f32;
+                        let v = tex_v as f32 + ((py - y1) as f32 + 0.5) / scale as f32;
+                        let Some((r, g, b)) = texture.sample(region, u, v) else {
+                            continue;
+                        };
+                        ((r, g, b), Self::rgb_to_rgb15(r, g, b))
+                    }
+                    _ => {
+                        // Sample texture
+                        let tex_color = match texture_info.depth {
+                            TextureDepth::T4Bit => {
+                                self.sample_4bit_texture(vram, u, v, texture_info)
+                            }
+                            TextureDepth::T8Bit => {
+                                self.sample_8bit_texture(vram, u, v, texture_info)
+                            }
+                            TextureDepth::T15Bit => {
+                                self.sample_15bit_texture(vram, u, v, texture_info)
+                            }
+                        };
+
+                        // Check for transparent black (0x0000 in 15-bit texture)
+                        let tex_color15 = ((tex_color.2 as u16 >> 3) << 10)
+                            | ((tex_color.1 as u16 >> 3) << 5)
+                            | (tex_color.0 as u16 >> 3);
+
+                        if tex_color15 == 0x0000 && texture_info.depth != TextureDepth::T15Bit {
+                            // Skip transparent pixels in paletted textures
+                            continue;
+                        }
+                        (tex_color, tex_color15)
+                    }
                 };
 
-                // Check for transparent black (0x0000 in 15-bit texture)
-                let tex_color15 = ((tex_color.2 as u16 >> 3) << 10)
-                    | ((tex_color.1 as u16 >> 3) << 5)
-                    | (tex_color.0 as u16 >> 3);
-
-                if tex_color15 == 0x0000 && texture_info.depth != TextureDepth::T15Bit {
-                    // Skip transparent pixels in paletted textures
-                    continue;
-                }
-
                 // Apply modulation if enabled
                 let final_color = if modulated {
                     let r = ((tex_color.0 as u16 * color.r as u16) / 128) as u8;
@@ -1675,7 +2360,7 @@ impl Rasterizer {
                     tex_color15
                 };
 
-                let vram_index = (py as usize) * 1024 + (px as usize);
+                let vram_index = (py as usize) * (self.target_width() as usize) + (px as usize);
 
                 if semi_transparent {
                     // Apply semi-transparency blending
@@ -1683,9 +2368,9 @@ impl Rasterizer {
                     let blend_mode =
                         crate::core::gpu::BlendMode::from_bits(draw_mode.semi_transparency);
                     let blended = blend_mode.blend(bg_color, final_color);
-                    vram[vram_index] = blended;
+                    self.store_pixel(vram, vram_index, blended);
                 } else {
-                    vram[vram_index] = final_color;
+                    self.store_pixel(vram, vram_index, final_color);
                 }
             }
         }
@@ -1987,7 +2672,7 @@ mod tests {
         use crate::core::gpu::{TextureDepth, TextureInfo};
 
         let mut vram = vec![0u16; 1024 * 512];
-        let rasterizer = Rasterizer::new();
+        let mut rasterizer = Rasterizer::new();
 
         // Setup CLUT at (0, 0) with 16 colors
         for (i, pixel) in vram.iter_mut().enumerate().take(16) {
@@ -2036,7 +2721,7 @@ mod tests {
         use crate::core::gpu::{TextureDepth, TextureInfo};
 
         let mut vram = vec![0u16; 1024 * 512];
-        let rasterizer = Rasterizer::new();
+        let mut rasterizer = Rasterizer::new();
 
         // Setup CLUT at (0, 0) with 256 colors
         for (i, pixel) in vram.iter_mut().enumerate().take(256) {
@@ -2075,7 +2760,7 @@ mod tests {
         use crate::core::gpu::{TextureDepth, TextureInfo};
 
         let mut vram = vec![0u16; 1024 * 512];
-        let rasterizer = Rasterizer::new();
+        let mut rasterizer = Rasterizer::new();
 
         // Setup 15-bit texture at (64, 0) with direct colors
         vram[64] = 0x001F; // Red
@@ -0,0 +1,540 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Texture dumping and replacement
+//!
+//! Textured primitives are identified by the texels they sample: the
+//! rectangle spanned by their texture coordinates, hashed together with the
+//! CLUT they are drawn with. The hash only depends on VRAM contents, so the
+//! same sprite or surface gets the same name every time it is drawn, across
+//! sessions and machines.
+//!
+//! - Dumping writes every texture drawn at native resolution to a PNG the
+//!   first time it is seen. Texels with color 0x0000 are written fully
+//!   transparent.
+//! - Replacement looks for a PNG with the same name in the replacement
+//!   directory and samples it instead of VRAM, at any size, while drawing
+//!   into the upscaled shadow VRAM. Texels with alpha below 50% are
+//!   transparent. Native VRAM always keeps the original texture.
+//!
+//! The replacement directory is listed once when it is configured, so
+//! drawing never probes the filesystem for missing files. Dumped textures
+//! and replacement lookups are remembered in bounded LRU caches.
+//!
+//! File names have the form `<depth>-<width>x<height>-<hash>.png`, e.g.
+//! `4bit-64x32-0123456789abcdef.png`.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::super::primitives::TextureDepth;
+use crate::core::error::{GpuError, Result};
+
+/// Directories used for texture dumping and replacement
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::TextureReplacementConfig;
+///
+/// let config = TextureReplacementConfig {
+///     dump_dir: Some("dump".into()),
+///     ..Default::default()
+/// };
+/// assert!(config.is_enabled());
+/// assert!(!TextureReplacementConfig::default().is_enabled());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextureReplacementConfig {
+    /// Directory textures are dumped to (None = no dumping)
+    pub dump_dir: Option<PathBuf>,
+
+    /// Directory replacement PNGs are loaded from (None = no replacement)
+    pub replacement_dir: Option<PathBuf>,
+}
+
+impl TextureReplacementConfig {
+    /// Check whether dumping or replacement is configured
+    pub fn is_enabled(&self) -> bool {
+        self.dump_dir.is_some() || self.replacement_dir.is_some()
+    }
+}
+
+/// Texel rectangle sampled by a primitive, relative to its texture page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TextureRegion {
+    /// Left texel column
+    pub u: u8,
+
+    /// Top texel row
+    pub v: u8,
+
+    /// Width in texels (1-256)
+    pub width: u16,
+
+    /// Height in texels (1-256)
+    pub height: u16,
+}
+
+impl TextureRegion {
+    /// Region spanned by a polygon's texture coordinates
+    pub(crate) fn from_texcoords(texcoords: &[(u8, u8)]) -> Self {
+        let min_u = texcoords.iter().map(|t| t.0).min().unwrap_or(0);
+        let max_u = texcoords.iter().map(|t| t.0).max().unwrap_or(0);
+        let min_v = texcoords.iter().map(|t| t.1).min().unwrap_or(0);
+        let max_v = texcoords.iter().map(|t| t.1).max().unwrap_or(0);
+
+        Self {
+            u: min_u,
+            v: min_v,
+            width: (max_u - min_u) as u16 + 1,
+            height: (max_v - min_v) as u16 + 1,
+        }
+    }
+
+    /// Region sampled by a textured rectangle
+    ///
+    /// # Returns
+    ///
+    /// `None` if the rectangle is empty or its texture coordinates wrap
+    /// around the texture page
+    pub(crate) fn from_rectangle(u: u8, v: u8, width: u16, height: u16) -> Option<Self> {
+        let fits = |start: u8, size: u16| size > 0 && start as u16 + size <= 256;
+        (fits(u, width) && fits(v, height)).then_some(Self {
+            u,
+            v,
+            width,
+            height,
+        })
+    }
+}
+
+/// Identity of a texture
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{TextureDepth, TextureKey};
+///
+/// let key = TextureKey {
+///     hash: 0x0123_4567_89AB_CDEF,
+///     width: 64,
+///     height: 32,
+///     depth: TextureDepth::T4Bit,
+/// };
+/// assert_eq!(key.file_name(), "4bit-64x32-0123456789abcdef.png");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextureKey {
+    /// FNV-1a hash of the texels and CLUT
+    pub hash: u64,
+
+    /// Width in texels
+    pub width: u16,
+
+    /// Height in texels
+    pub height: u16,
+
+    /// Texture color depth
+    pub depth: TextureDepth,
+}
+
+impl TextureKey {
+    /// Name of the dumped or replacement PNG file
+    pub fn file_name(&self) -> String {
+        let depth = match self.depth {
+            TextureDepth::T4Bit => "4bit",
+            TextureDepth::T8Bit => "8bit",
+            TextureDepth::T15Bit => "15bit",
+        };
+        format!(
+            "{}-{}x{}-{:016x}.png",
+            depth, self.width, self.height, self.hash
+        )
+    }
+}
+
+/// 64-bit FNV-1a hasher
+///
+/// Used instead of the standard library hasher, whose output may change
+/// between Rust releases, so file names stay valid.
+pub(crate) struct TextureHasher(u64);
+
+impl TextureHasher {
+    /// Start a new hash
+    pub(crate) fn new() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+
+    /// Add a halfword, low byte first
+    pub(crate) fn write_u16(&mut self, value: u16) {
+        for byte in value.to_le_bytes() {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+
+    /// Get the hash value
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Replacement texture loaded from a PNG file
+#[derive(Debug, Clone)]
+pub struct ReplacementTexture {
+    /// Width in pixels
+    width: u32,
+
+    /// Height in pixels
+    height: u32,
+
+    /// RGBA pixels, row-major
+    pixels: Vec<[u8; 4]>,
+}
+
+impl ReplacementTexture {
+    /// Decode a replacement texture from PNG data
+    ///
+    /// Grayscale, indexed and 16-bit images are converted to 8-bit RGBA.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - PNG file contents
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the data is not a valid PNG image
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let error = |e: png::DecodingError| GpuError::TextureReplacement(e.to_string());
+        let mut decoder = png::Decoder::new(std::io::Cursor::new(data));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(error)?;
+
+        let size = reader.output_buffer_size().ok_or_else(|| {
+            GpuError::TextureReplacement("replacement image is too large".to_string())
+        })?;
+        let mut buffer = vec![0; size];
+        let info = reader.next_frame(&mut buffer).map_err(error)?;
+
+        let pixel = |bytes: &[u8]| match info.color_type {
+            png::ColorType::Grayscale => [bytes[0], bytes[0], bytes[0], 0xFF],
+            png::ColorType::GrayscaleAlpha => [bytes[0], bytes[0], bytes[0], bytes[1]],
+            png::ColorType::Rgb => [bytes[0], bytes[1], bytes[2], 0xFF],
+            _ => [bytes[0], bytes[1], bytes[2], bytes[3]],
+        };
+        let channels = info.color_type.samples();
+        let pixels = buffer[..info.line_size * info.height as usize]
+            .chunks_exact(info.line_size)
+            .flat_map(|line| {
+                line[..info.width as usize * channels]
+                    .chunks_exact(channels)
+                    .map(pixel)
+            })
+            .collect();
+
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    /// Load a replacement texture from a PNG file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PNG file path
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or decoded
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path).map_err(|e| {
+            GpuError::TextureReplacement(format!("cannot read {}: {}", path.display(), e))
+        })?;
+        Self::decode(&data)
+    }
+
+    /// Get the image size as (width, height)
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Get a pixel as RGBA
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate (below the width)
+    /// * `y` - Y coordinate (below the height)
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.pixels[y as usize * self.width as usize + x as usize]
+    }
+
+    /// Sample the replacement for a texel position
+    ///
+    /// The image is stretched over the texture's region, so it may have any
+    /// resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Region of the original texture
+    /// * `u` - U texture coordinate, with fraction
+    /// * `v` - V texture coordinate, with fraction
+    ///
+    /// # Returns
+    ///
+    /// RGB color, or `None` for a transparent pixel
+    pub(crate) fn sample(&self, region: TextureRegion, u: f32, v: f32) -> Option<(u8, u8, u8)> {
+        let position = |coord: f32, start: u8, size: u16, pixels: u32| {
+            let offset = (coord - start as f32) / size as f32;
+            ((offset * pixels as f32) as i64).clamp(0, pixels as i64 - 1) as usize
+        };
+        let x = position(u, region.u, region.width, self.width);
+        let y = position(v, region.v, region.height, self.height);
+
+        let [r, g, b, a] = self.pixels[y * self.width as usize + x];
+        (a >= 0x80).then_some((r, g, b))
+    }
+}
+
+/// Encode RGBA pixels as a PNG file
+///
+/// # Arguments
+///
+/// * `width` - Image width
+/// * `height` - Image height
+/// * `rgba` - RGBA pixels, row-major
+pub(crate) fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>> {
+    let error = |e: png::EncodingError| GpuError::TextureReplacement(e.to_string());
+    let mut data = Vec::new();
+    let mut encoder = png::Encoder::new(&mut data, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(error)?;
+    writer.write_image_data(rgba).map_err(error)?;
+    writer.finish().map_err(error)?;
+    Ok(data)
+}
+
+/// Map holding at most a fixed number of entries
+///
+/// Inserting into a full cache evicts the least recently used entry.
+#[derive(Debug, Clone)]
+pub(crate) struct LruCache<K, V> {
+    /// Values with the tick they were last used at
+    entries: HashMap<K, (V, u64)>,
+
+    /// Maximum number of entries
+    capacity: usize,
+
+    /// Use counter
+    tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Create an empty cache
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of entries (at least 1)
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            tick: 0,
+        }
+    }
+
+    /// Get an entry, marking it as recently used
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries.get_mut(key).map(|(value, used)| {
+            *used = tick;
+            &*value
+        })
+    }
+
+    /// Insert an entry, evicting the least recently used one if full
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    /// Remove all entries
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Texture dumping and replacement state of a rasterizer
+#[derive(Debug, Clone)]
+pub(crate) struct TextureReplacements {
+    /// Configured directories
+    config: TextureReplacementConfig,
+
+    /// Textures dumped recently (or found in the dump directory)
+    dumped: LruCache<TextureKey, ()>,
+
+    /// File names in the replacement directory when it was configured
+    available: HashSet<String>,
+
+    /// Replacements looked up recently (`None` = no replacement)
+    loaded: LruCache<TextureKey, Option<Arc<ReplacementTexture>>>,
+}
+
+impl Default for TextureReplacements {
+    fn default() -> Self {
+        Self {
+            config: TextureReplacementConfig::default(),
+            dumped: LruCache::new(Self::DUMPED_CAPACITY),
+            available: HashSet::new(),
+            loaded: LruCache::new(Self::LOADED_CAPACITY),
+        }
+    }
+}
+
+impl TextureReplacements {
+    /// Number of dumped textures remembered
+    const DUMPED_CAPACITY: usize = 4096;
+
+    /// Number of replacement lookups remembered, including misses
+    const LOADED_CAPACITY: usize = 256;
+
+    /// Change the directories, forgetting dumped and loaded textures
+    ///
+    /// Lists the replacement directory, so replacements added later are
+    /// only picked up when the directories are configured again.
+    pub(crate) fn configure(&mut self, config: TextureReplacementConfig) {
+        self.available = config
+            .replacement_dir
+            .as_ref()
+            .and_then(|dir| std::fs::read_dir(dir).ok())
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.config = config;
+        self.dumped.clear();
+        self.loaded.clear();
+    }
+
+    /// Get the configured directories
+    pub(crate) fn config(&self) -> &TextureReplacementConfig {
+        &self.config
+    }
+
+    /// Check whether a texture still has to be dumped
+    pub(crate) fn needs_dump(&mut self, key: &TextureKey) -> bool {
+        self.config.dump_dir.is_some() && self.dumped.get(key).is_none()
+    }
+
+    /// Write a texture to the dump directory
+    ///
+    /// Existing files are kept, so textures are only written once even
+    /// across sessions. The file is created exclusively instead of probed
+    /// first. Failures are logged and not retried while the texture stays
+    /// in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Texture identity
+    /// * `rgba` - Decoded texels, `key.width` by `key.height`
+    pub(crate) fn dump(&mut self, key: TextureKey, rgba: &[u8]) {
+        let Some(dir) = &self.config.dump_dir else {
+            return;
+        };
+        self.dumped.insert(key, ());
+
+        let path = dir.join(key.file_name());
+        let error = |e: std::io::Error| GpuError::TextureReplacement(e.to_string()).into();
+        let file = std::fs::create_dir_all(dir).and_then(|_| {
+            std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+        });
+        let mut file = match file {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => return,
+            Err(e) => {
+                log::warn!("Failed to dump texture {}: {}", path.display(), e);
+                return;
+            }
+        };
+        let result = encode_png(key.width as u32, key.height as u32, rgba)
+            .and_then(|data| file.write_all(&data).map_err(error));
+        match result {
+            Ok(()) => log::debug!("Dumped texture {}", path.display()),
+            Err(e) => log::warn!("Failed to dump texture {}: {}", path.display(), e),
+        }
+    }
+
+    /// Check whether replacements are configured
+    pub(crate) fn replacing(&self) -> bool {
+        self.config.replacement_dir.is_some()
+    }
+
+    /// Find the replacement for a texture, loading it on first use
+    ///
+    /// Only files listed when the directory was configured are loaded.
+    /// Unreadable files are logged and dropped from that list, so they are
+    /// not retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Texture identity
+    pub(crate) fn replacement(&mut self, key: TextureKey) -> Option<Arc<ReplacementTexture>> {
+        let dir = self.config.replacement_dir.as_ref()?;
+        if let Some(texture) = self.loaded.get(&key) {
+            return texture.clone();
+        }
+
+        let name = key.file_name();
+        let texture = if self.available.contains(&name) {
+            let path = dir.join(&name);
+            match ReplacementTexture::load(&path) {
+                Ok(texture) => {
+                    log::debug!("Loaded texture replacement {}", path.display());
+                    Some(Arc::new(texture))
+                }
+                Err(e) => {
+                    log::warn!("Failed to load texture replacement: {}", e);
+                    self.available.remove(&name);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        self.loaded.insert(key, texture.clone());
+        texture
+    }
+}
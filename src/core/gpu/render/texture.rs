@@ -20,6 +20,7 @@
 
 use super::super::primitives::{Color, TexCoord, TextureInfo, Vertex};
 use super::super::GPU;
+use super::RenderCommand;
 
 impl GPU {
     /// Render a textured triangle
@@ -40,6 +41,8 @@ impl GPU {
     /// The texture coordinates are interpolated across the triangle using
     /// barycentric coordinates. The texture is sampled from VRAM at the
     /// specified texture page with the given color depth (4-bit, 8-bit, or 15-bit).
+    /// When texture cache emulation is enabled, texels are fetched through the
+    /// cache, which is flushed if `texture_info` selects a different page or depth.
     ///
     /// # Color Modulation
     ///
@@ -71,6 +74,7 @@ impl GPU {
             vertices[2].x.wrapping_add(self.draw_offset.0),
             vertices[2].y.wrapping_add(self.draw_offset.1),
         );
+        self.add_triangle_cycles([v0, v1, v2], true, semi_transparent);
 
         let t0 = (texcoords[0].u, texcoords[0].v);
         let t1 = (texcoords[1].u, texcoords[1].v);
@@ -88,18 +92,13 @@ impl GPU {
         let _ = semi_transparent;
 
         // Rasterize the textured triangle with texture window
-        self.rasterizer.draw_textured_triangle(
-            &mut self.vram,
-            v0,
-            t0,
-            v1,
-            t1,
-            v2,
-            t2,
-            texture_info,
-            &self.texture_window,
-            (color.r, color.g, color.b),
-        );
+        self.submit_render_command(RenderCommand::TexturedTriangle {
+            vertices: [v0, v1, v2],
+            texcoords: [t0, t1, t2],
+            texture: *texture_info,
+            window: self.texture_window,
+            tint: (color.r, color.g, color.b),
+        });
     }
 
     /// Render a textured quadrilateral
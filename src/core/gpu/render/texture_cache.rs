@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU texture cache
+//!
+//! The GPU reads textures through a 2 KB cache of 256 lines, each holding
+//! four consecutive VRAM halfwords. A line is picked from the texel's VRAM
+//! address, so the cached area is 64x64 texels at 4-bit depth and 32x32
+//! texels at 8-bit and 15-bit depth. Lines are tagged with the address they
+//! were loaded from, but VRAM writes do not update them: a texture uploaded
+//! over cached data keeps drawing from the stale lines until the cache is
+//! flushed by GP0(01h) or by switching texture pages.
+//!
+//! # References
+//!
+//! - [PSX-SPX: GPU Texture Caching](http://problemkaputt.de/psx-spx.htm#gputexturecaching)
+
+use crate::core::gpu::{TextureDepth, TextureInfo};
+
+/// Number of cache lines (2 KB / 8 bytes)
+const LINES: usize = 256;
+
+/// One cache line: four VRAM halfwords and the address they came from
+#[derive(Debug, Clone, Copy, Default)]
+struct CacheLine {
+    /// Halfword address of the first word (`None` = invalid)
+    tag: Option<u32>,
+
+    /// Cached VRAM halfwords
+    data: [u16; 4],
+}
+
+/// Texture cache state
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::{TextureCache, TextureDepth};
+///
+/// let mut vram = vec![0u16; 1024 * 512];
+/// let mut cache = TextureCache::new();
+///
+/// vram[0] = 0x1234;
+/// assert_eq!(cache.read(&vram, 0, 0, TextureDepth::T15Bit), 0x1234);
+///
+/// // Stale until the cache is flushed
+/// vram[0] = 0x5678;
+/// assert_eq!(cache.read(&vram, 0, 0, TextureDepth::T15Bit), 0x1234);
+///
+/// cache.invalidate();
+/// assert_eq!(cache.read(&vram, 0, 0, TextureDepth::T15Bit), 0x5678);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextureCache {
+    /// Cache lines
+    lines: [CacheLine; LINES],
+
+    /// Texture page and depth the cache contents belong to
+    page: Option<(u16, u16, TextureDepth)>,
+}
+
+impl TextureCache {
+    /// Create an empty texture cache
+    pub fn new() -> Self {
+        Self {
+            lines: [CacheLine::default(); LINES],
+            page: None,
+        }
+    }
+
+    /// Invalidate every cache line
+    pub fn invalidate(&mut self) {
+        self.lines = [CacheLine::default(); LINES];
+    }
+
+    /// Select the texture page used by the next primitive
+    ///
+    /// Switching to a different page or color depth flushes the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - Texture page and depth of the primitive
+    pub fn use_page(&mut self, info: &TextureInfo) {
+        let page = Some((info.page_x, info.page_y, info.depth));
+        if self.page != page {
+            self.invalidate();
+            self.page = page;
+        }
+    }
+
+    /// Read a VRAM halfword through the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - VRAM buffer
+    /// * `x` - VRAM X coordinate (0-1023)
+    /// * `y` - VRAM Y coordinate (0-511)
+    /// * `depth` - Texture depth, which selects the cache layout
+    ///
+    /// # Returns
+    ///
+    /// The cached halfword, loading its line from VRAM on a miss
+    pub fn read(&mut self, vram: &[u16], x: u16, y: u16, depth: TextureDepth) -> u16 {
+        self.read_scaled(vram, x, y, depth, 1)
+    }
+
+    /// Read a halfword through the cache from upscaled VRAM
+    ///
+    /// Same as [`read`](Self::read), but `vram` holds every native pixel
+    /// as a `scale`×`scale` block and lines are filled from the top-left
+    /// pixel of each block.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Upscaled VRAM buffer
+    /// * `x` - Native VRAM X coordinate (0-1023)
+    /// * `y` - Native VRAM Y coordinate (0-511)
+    /// * `depth` - Texture depth, which selects the cache layout
+    /// * `scale` - Internal resolution scale of `vram`
+    pub fn read_scaled(
+        &mut self,
+        vram: &[u16],
+        x: u16,
+        y: u16,
+        depth: TextureDepth,
+        scale: u16,
+    ) -> u16 {
+        let address = (y as u32 & 0x1FF) * 1024 + (x as u32 & 0x3FF);
+        let tag = address & !3;
+
+        // 4-bit: 4 lines per row (16 halfwords) x 64 rows
+        // 8/15-bit: 8 lines per row (32 halfwords) x 32 rows
+        let index = match depth {
+            TextureDepth::T4Bit => ((address >> 2) & 0x3) | ((address >> 8) & 0xFC),
+            TextureDepth::T8Bit | TextureDepth::T15Bit => {
+                ((address >> 2) & 0x7) | ((address >> 7) & 0xF8)
+            }
+        } as usize;
+
+        let line = &mut self.lines[index];
+        if line.tag != Some(tag) {
+            let scale = scale as usize;
+            let row = (tag as usize / 1024) * scale * 1024 * scale;
+            let column = (tag as usize % 1024) * scale;
+            for (i, word) in line.data.iter_mut().enumerate() {
+                *word = vram[row + column + i * scale];
+            }
+            line.tag = Some(tag);
+        }
+
+        line.data[(address & 3) as usize]
+    }
+}
+
+impl Default for TextureCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
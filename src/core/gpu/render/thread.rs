@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU render thread
+//!
+//! In threaded mode, [`RenderCommand`]s decoded by `GPU::write_gp0` are sent
+//! to a dedicated worker thread instead of being drawn on the emulation
+//! thread. The worker owns VRAM and the rendering backend while it has
+//! work queued: both are handed over with the first command after a
+//! synchronization point and handed back when the GPU needs them again, so
+//! the two threads never touch them at the same time.
+//!
+//! The GPU synchronizes before every direct VRAM access (fills, CPU↔VRAM
+//! and VRAM↔VRAM transfers, GPUREAD, reset) and at VBlank. Commands and
+//! state changes run in submission order, so the output is bit-identical
+//! to the synchronous path. CPU-visible state such as GPUSTAT is computed
+//! on the emulation thread from the draw timing model and never waits for
+//! the worker.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+use super::super::backend::Renderer;
+use super::super::GPU;
+use super::command::{RasterizerSetting, RenderCommand};
+
+/// VRAM and backend while owned by the worker
+type RenderTarget = (Vec<u16>, Box<dyn Renderer>);
+
+/// Messages sent to the render thread
+enum Message {
+    /// Take ownership of VRAM and the backend
+    Target(RenderTarget),
+
+    /// Draw a command or apply a state change
+    Command(RenderCommand),
+
+    /// Finish queued work and hand VRAM and the backend back
+    Return,
+}
+
+/// Handle to the GPU render worker
+pub(crate) struct RenderThread {
+    /// Command queue (None once shut down)
+    sender: Option<Sender<Message>>,
+
+    /// VRAM and backend returned by the worker
+    target_receiver: Receiver<RenderTarget>,
+
+    /// Worker thread
+    handle: Option<JoinHandle<()>>,
+
+    /// Whether the worker currently owns VRAM and the backend
+    holds_target: bool,
+}
+
+impl RenderThread {
+    /// Start a render thread
+    pub(crate) fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let (target_sender, target_receiver) = mpsc::channel();
+
+        let handle = thread::Builder::new()
+            .name("psrx-gpu".to_string())
+            .spawn(move || Self::run(receiver, target_sender))
+            .expect("failed to spawn GPU render thread");
+
+        Self {
+            sender: Some(sender),
+            target_receiver,
+            handle: Some(handle),
+            holds_target: false,
+        }
+    }
+
+    /// Worker loop
+    ///
+    /// Runs until the command queue is closed.
+    fn run(receiver: Receiver<Message>, target_sender: Sender<RenderTarget>) {
+        let mut target: Option<RenderTarget> = None;
+
+        for message in receiver {
+            match message {
+                Message::Target(owned) => target = Some(owned),
+                Message::Command(command) => {
+                    if let Some((vram, renderer)) = &mut target {
+                        match command {
+                            RenderCommand::Configure(setting) => renderer.configure(setting),
+                            command => renderer.draw(vram, command),
+                        }
+                    }
+                }
+                Message::Return => {
+                    let returned = target.take().expect("render thread holds no VRAM");
+                    if target_sender.send(returned).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn send(&self, message: Message) {
+        self.sender
+            .as_ref()
+            .and_then(|sender| sender.send(message).ok())
+            .expect("GPU render thread terminated");
+    }
+
+    /// Queue a command, handing VRAM and the backend to the worker first if
+    /// needed
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - GPU VRAM, left empty while the worker owns it
+    /// * `renderer` - GPU backend slot, left empty while the worker owns it
+    /// * `command` - Command to draw or state change to apply
+    pub(crate) fn submit(
+        &mut self,
+        vram: &mut Vec<u16>,
+        renderer: &mut Option<Box<dyn Renderer>>,
+        command: RenderCommand,
+    ) {
+        if !self.holds_target {
+            let backend = renderer.take().expect("GPU renderer missing");
+            self.send(Message::Target((std::mem::take(vram), backend)));
+            self.holds_target = true;
+        }
+        self.send(Message::Command(command));
+    }
+
+    /// Wait for queued commands to finish and take VRAM and the backend back
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - GPU VRAM slot to restore
+    /// * `renderer` - GPU backend slot to restore
+    pub(crate) fn sync(&mut self, vram: &mut Vec<u16>, renderer: &mut Option<Box<dyn Renderer>>) {
+        if !self.holds_target {
+            return;
+        }
+
+        self.send(Message::Return);
+        let (buffer, backend) = self
+            .target_receiver
+            .recv()
+            .expect("GPU render thread terminated");
+        *vram = buffer;
+        *renderer = Some(backend);
+        self.holds_target = false;
+    }
+}
+
+impl Drop for RenderThread {
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl GPU {
+    /// Enable or disable threaded rendering
+    ///
+    /// When enabled, primitives are drawn on a dedicated worker thread
+    /// while the emulation thread keeps processing GP0 commands. Output is
+    /// identical to synchronous rendering.
+    ///
+    /// VRAM belongs to the worker while it has queued work. Accessors such
+    /// as [`read_vram`](Self::read_vram) and
+    /// [`get_framebuffer`](Self::get_framebuffer) wait for it to finish
+    /// first, and the GPU syncs by itself at VBlank.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to draw on a worker thread
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.set_threaded_rendering(true);
+    ///
+    /// // Red triangle
+    /// gpu.write_gp0(0x200000FF);
+    /// gpu.write_gp0(0x00000000);
+    /// gpu.write_gp0(0x00000040);
+    /// gpu.write_gp0(0x00400000);
+    ///
+    /// assert_eq!(gpu.read_vram(1, 1), 0x001F);
+    /// ```
+    pub fn set_threaded_rendering(&mut self, enabled: bool) {
+        if enabled && self.render_thread.is_none() {
+            self.render_thread = Some(RenderThread::spawn());
+        } else if !enabled && self.render_thread.is_some() {
+            self.sync_renderer();
+            self.render_thread = None;
+        }
+    }
+
+    /// Check whether threaded rendering is enabled
+    pub fn threaded_rendering(&self) -> bool {
+        self.render_thread.is_some()
+    }
+
+    /// Wait for the render thread to finish all queued primitives
+    ///
+    /// Returns VRAM and the backend to the GPU so VRAM can be read
+    /// directly. Does nothing in synchronous mode.
+    pub fn sync_renderer(&mut self) {
+        if let Some(thread) = &mut self.render_thread {
+            thread.sync(&mut self.vram, &mut self.renderer);
+        }
+    }
+
+    /// Draw a decoded primitive
+    ///
+    /// Draws immediately in synchronous mode, otherwise queues the command
+    /// on the render thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Primitive to draw
+    pub(crate) fn submit_render_command(&mut self, command: RenderCommand) {
+        match (&mut self.render_thread, &mut self.renderer) {
+            (Some(thread), renderer) => thread.submit(&mut self.vram, renderer, command),
+            (None, Some(renderer)) => renderer.draw(&mut self.vram, command),
+            (None, None) => unreachable!("GPU renderer missing"),
+        }
+    }
+
+    /// Change a draw state setting
+    ///
+    /// Applied directly when the GPU holds the backend, otherwise queued
+    /// for the worker so it takes effect between the same primitives.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - Setting to apply
+    pub(crate) fn configure_renderer(&mut self, setting: RasterizerSetting) {
+        match (&mut self.renderer, &mut self.render_thread) {
+            (Some(renderer), _) => renderer.configure(setting),
+            (None, Some(thread)) => {
+                thread.send(Message::Command(RenderCommand::Configure(setting)))
+            }
+            (None, None) => unreachable!("GPU renderer missing"),
+        }
+    }
+
+    /// Get the backend and native VRAM, waiting for the render thread
+    pub(crate) fn renderer_and_vram(&mut self) -> (&mut dyn Renderer, &mut [u16]) {
+        self.sync_renderer();
+        let renderer = self.renderer.as_deref_mut().expect("GPU renderer missing");
+        (renderer, &mut self.vram)
+    }
+}
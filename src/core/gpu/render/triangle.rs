@@ -19,6 +19,7 @@
 
 use super::super::primitives::{BlendMode, Color, Vertex};
 use super::super::GPU;
+use super::RenderCommand;
 
 impl GPU {
     /// Render a monochrome (flat-shaded) triangle
@@ -86,6 +87,7 @@ impl GPU {
             vertices[2].x.wrapping_add(self.draw_offset.0),
             vertices[2].y.wrapping_add(self.draw_offset.1),
         );
+        self.add_triangle_cycles([v0, v1, v2], false, semi_transparent);
 
         log::trace!(
             "Rendering {}triangle: ({}, {}), ({}, {}), ({}, {}) color=({},{},{}){}",
@@ -117,18 +119,17 @@ impl GPU {
         if semi_transparent {
             // Use blending mode from draw_mode
             let blend_mode = BlendMode::from_bits(self.draw_mode.semi_transparency);
-            self.rasterizer.draw_triangle_blended(
-                &mut self.vram,
-                v0,
-                v1,
-                v2,
-                color_15bit,
+            self.submit_render_command(RenderCommand::TriangleBlended {
+                vertices: [v0, v1, v2],
+                color: color_15bit,
                 blend_mode,
-            );
+            });
         } else {
             // Opaque rendering
-            self.rasterizer
-                .draw_triangle(&mut self.vram, v0, v1, v2, color_15bit);
+            self.submit_render_command(RenderCommand::Triangle {
+                vertices: [v0, v1, v2],
+                color: color_15bit,
+            });
         }
     }
 }
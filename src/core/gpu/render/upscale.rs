@@ -0,0 +1,348 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Internal resolution upscaling
+//!
+//! When a resolution scale above 1 is selected, every primitive is drawn
+//! twice: once into native VRAM, which stays bit-identical to unscaled
+//! rendering and serves CPU readbacks and texture fetches, and once into a
+//! shadow VRAM that is `scale` times larger in each direction. Display
+//! output is read from the shadow copy.
+//!
+//! The shadow VRAM is kept coherent with native VRAM: CPU uploads and fills
+//! write whole `scale`×`scale` blocks, and VRAM-to-VRAM copies move the
+//! high-resolution blocks so rendered images keep their detail when copied.
+//! Textures are sampled from the top-left pixel of each block, which holds
+//! the native texel value. Texture replacements are only drawn here.
+
+use super::command::{RasterizerSetting, RenderCommand};
+use super::{Rasterizer, TextureReplacementConfig};
+
+/// Shadow VRAM and rasterizer for upscaled rendering
+#[derive(Clone)]
+pub(crate) struct Upscaler {
+    /// Scale factor (2-8)
+    scale: u16,
+
+    /// Rasterizer drawing at the scaled resolution
+    rasterizer: Rasterizer,
+
+    /// Shadow VRAM, `1024*scale` by `512*scale` pixels
+    vram: Vec<u16>,
+}
+
+impl Upscaler {
+    /// Create a shadow VRAM from the current native VRAM
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Scale factor
+    /// * `native` - Native VRAM to upscale
+    /// * `rasterizer` - Native rasterizer whose draw state is copied
+    pub(crate) fn new(scale: u16, native: &[u16], rasterizer: &Rasterizer) -> Self {
+        let mut upscaler = Self {
+            scale,
+            rasterizer: rasterizer.clone(),
+            vram: vec![0; native.len() * scale as usize * scale as usize],
+        };
+        upscaler.rasterizer.set_scale(scale);
+
+        let (left, top, right, bottom) = rasterizer.clip_rect();
+        upscaler.configure(RasterizerSetting::ClipRect(left, top, right, bottom));
+
+        for y in 0..512 {
+            for x in 0..1024 {
+                upscaler.write_native(x, y, native[y as usize * 1024 + x as usize]);
+            }
+        }
+        upscaler
+    }
+
+    /// Get the scale factor
+    pub(crate) fn scale(&self) -> u16 {
+        self.scale
+    }
+
+    /// Width of the shadow VRAM in pixels
+    fn width(&self) -> usize {
+        1024 * self.scale as usize
+    }
+
+    /// Read a shadow VRAM pixel
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Scaled X coordinate (wrapped to the shadow VRAM)
+    /// * `y` - Scaled Y coordinate (wrapped to the shadow VRAM)
+    pub(crate) fn pixel(&self, x: usize, y: usize) -> u16 {
+        let scale = self.scale as usize;
+        self.vram[(y % (512 * scale)) * self.width() + x % (1024 * scale)]
+    }
+
+    /// Fill the block covering one native pixel
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Native X coordinate (0-1023)
+    /// * `y` - Native Y coordinate (0-511)
+    /// * `value` - Pixel value
+    pub(crate) fn write_native(&mut self, x: u16, y: u16, value: u16) {
+        let scale = self.scale as usize;
+        let width = self.width();
+        let left = (x as usize & 0x3FF) * scale;
+        let top = (y as usize & 0x1FF) * scale;
+        for row in top..top + scale {
+            self.vram[row * width + left..row * width + left + scale].fill(value);
+        }
+    }
+
+    /// Clear the shadow VRAM
+    pub(crate) fn clear(&mut self) {
+        self.vram.fill(0);
+    }
+
+    /// Copy a rectangle of native pixels at full shadow resolution
+    ///
+    /// Mirrors GP0(80h): coordinates wrap around VRAM and the mask bit
+    /// settings apply to every destination pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Native source position (x, y)
+    /// * `dst` - Native destination position (x, y)
+    /// * `size` - Native size (width, height)
+    /// * `mask_set` - Force bit 15 on in written pixels
+    /// * `mask_check` - Skip destination pixels with bit 15 set
+    pub(crate) fn copy_rect(
+        &mut self,
+        src: (u16, u16),
+        dst: (u16, u16),
+        size: (u16, u16),
+        mask_set: bool,
+        mask_check: bool,
+    ) {
+        let scale = self.scale as usize;
+        let width = self.width();
+        let (copy_width, copy_height) = (size.0 as usize * scale, size.1 as usize * scale);
+        let index = |origin: (u16, u16), x: usize, y: usize| {
+            let vx = ((origin.0 as usize + x / scale) & 0x3FF) * scale + x % scale;
+            let vy = ((origin.1 as usize + y / scale) & 0x1FF) * scale + y % scale;
+            vy * width + vx
+        };
+
+        // Read the whole source first so overlapping copies behave
+        let mut buffer = Vec::with_capacity(copy_width * copy_height);
+        for y in 0..copy_height {
+            for x in 0..copy_width {
+                buffer.push(self.vram[index(src, x, y)]);
+            }
+        }
+
+        for y in 0..copy_height {
+            for x in 0..copy_width {
+                let target = index(dst, x, y);
+                if mask_check && self.vram[target] & 0x8000 != 0 {
+                    continue;
+                }
+                let pixel = buffer[y * copy_width + x];
+                self.vram[target] = if mask_set { pixel | 0x8000 } else { pixel };
+            }
+        }
+    }
+
+    /// Apply a rasterizer setting, scaling the clip rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - Native setting
+    pub(crate) fn configure(&mut self, setting: RasterizerSetting) {
+        let setting = match setting {
+            RasterizerSetting::ClipRect(left, top, right, bottom) => {
+                let scale = self.scale as i16;
+                RasterizerSetting::ClipRect(
+                    left * scale,
+                    top * scale,
+                    (right + 1) * scale - 1,
+                    (bottom + 1) * scale - 1,
+                )
+            }
+            other => other,
+        };
+        setting.apply(&mut self.rasterizer);
+    }
+
+    /// Set the texture dumping and replacement directories
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Dump and replacement directories
+    pub(crate) fn set_texture_replacement(&mut self, config: TextureReplacementConfig) {
+        self.rasterizer.set_texture_replacement(config);
+    }
+
+    /// Draw a native command into the shadow VRAM
+    ///
+    /// Vertex positions are scaled; lines are drawn `scale` pixels thick so
+    /// they keep their native weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Command in native coordinates
+    pub(crate) fn execute(&mut self, command: RenderCommand) {
+        let s = self.scale as i16;
+        let point = |(x, y): (i16, i16)| (x.saturating_mul(s), y.saturating_mul(s));
+        let precise = |(x, y): (f32, f32)| (x * s as f32, y * s as f32);
+
+        let command = match command {
+            RenderCommand::Configure(setting) => return self.configure(setting),
+            RenderCommand::Triangle { vertices, color } => RenderCommand::Triangle {
+                vertices: vertices.map(point),
+                color,
+            },
+            RenderCommand::TriangleBlended {
+                vertices,
+                color,
+                blend_mode,
+            } => RenderCommand::TriangleBlended {
+                vertices: vertices.map(point),
+                color,
+                blend_mode,
+            },
+            RenderCommand::GradientTriangle { vertices, colors } => {
+                RenderCommand::GradientTriangle {
+                    vertices: vertices.map(point),
+                    colors,
+                }
+            }
+            RenderCommand::TexturedTriangle {
+                vertices,
+                texcoords,
+                texture,
+                window,
+                tint,
+            } => RenderCommand::TexturedTriangle {
+                vertices: vertices.map(point),
+                texcoords,
+                texture,
+                window,
+                tint,
+            },
+            RenderCommand::TrianglePrecise { vertices, color } => RenderCommand::TrianglePrecise {
+                vertices: vertices.map(precise),
+                color,
+            },
+            RenderCommand::GradientTrianglePrecise { vertices, colors } => {
+                RenderCommand::GradientTrianglePrecise {
+                    vertices: vertices.map(precise),
+                    colors,
+                }
+            }
+            RenderCommand::TexturedTrianglePrecise {
+                vertices,
+                texcoords,
+                texture,
+                window,
+                tint,
+            } => RenderCommand::TexturedTrianglePrecise {
+                vertices: vertices.map(|(x, y, w)| (x * s as f32, y * s as f32, w)),
+                texcoords,
+                texture,
+                window,
+                tint,
+            },
+            RenderCommand::Line { from, to, color } => {
+                return self.draw_thick_line(from, to, |from, to| RenderCommand::Line {
+                    from,
+                    to,
+                    color,
+                });
+            }
+            RenderCommand::Polyline { points, color } => {
+                for segment in points.windows(2) {
+                    self.draw_thick_line(segment[0], segment[1], |from, to| RenderCommand::Line {
+                        from,
+                        to,
+                        color,
+                    });
+                }
+                return;
+            }
+            RenderCommand::GradientLine {
+                from,
+                from_color,
+                to,
+                to_color,
+            } => {
+                return self.draw_thick_line(from, to, |from, to| RenderCommand::GradientLine {
+                    from,
+                    from_color,
+                    to,
+                    to_color,
+                });
+            }
+            RenderCommand::GradientPolyline { points, colors } => {
+                for (segment, colors) in points.windows(2).zip(colors.windows(2)) {
+                    self.draw_thick_line(segment[0], segment[1], |from, to| {
+                        RenderCommand::GradientLine {
+                            from,
+                            from_color: colors[0],
+                            to,
+                            to_color: colors[1],
+                        }
+                    });
+                }
+                return;
+            }
+            // Rectangles are scaled by the rasterizer itself
+            rectangle @ (RenderCommand::Rectangle { .. }
+            | RenderCommand::TexturedRectangle { .. }) => rectangle,
+        };
+
+        command.execute(&mut self.rasterizer, &mut self.vram);
+    }
+
+    /// Draw a line `scale` pixels thick
+    ///
+    /// The scaled line is repeated with offsets across its minor axis.
+    /// Scaling is done in 32 bits and the results are clamped to the i16
+    /// coordinate range, so far-off end points cannot overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Native start point
+    /// * `to` - Native end point
+    /// * `line` - Builds the command for one scaled copy of the line
+    fn draw_thick_line<F>(&mut self, from: (i16, i16), to: (i16, i16), line: F)
+    where
+        F: Fn((i16, i16), (i16, i16)) -> RenderCommand,
+    {
+        let s = self.scale as i32;
+        let from = (from.0 as i32 * s, from.1 as i32 * s);
+        let to = (to.0 as i32 * s, to.1 as i32 * s);
+        let horizontal = (to.0 - from.0).abs() >= (to.1 - from.1).abs();
+        let point = |(x, y): (i32, i32)| {
+            let clamp = |v: i32| v.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+            (clamp(x), clamp(y))
+        };
+
+        for offset in 0..s {
+            let (dx, dy) = if horizontal { (0, offset) } else { (offset, 0) };
+            line(
+                point((from.0 + dx, from.1 + dy)),
+                point((to.0 + dx, to.1 + dy)),
+            )
+            .execute(&mut self.rasterizer, &mut self.vram);
+        }
+    }
+}
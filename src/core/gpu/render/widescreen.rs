@@ -0,0 +1,484 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Widescreen rendering
+//!
+//! With the widescreen hack, the GTE compresses the X coordinate of every
+//! projected vertex by 3/4, so a 16:9 field of view fits into the game's
+//! 4:3 drawing area. To show that picture at full resolution instead of
+//! stretching it, every primitive is drawn a second time into a widescreen
+//! surface: the drawing area widened by 4/3, the inverse of the GTE scale,
+//! with a clip rectangle to match. The display output is read from the
+//! surface drawn for the display area, so frames are wider than the display
+//! area and hold one rendered pixel per output column.
+//!
+//! Surfaces live in the right half of a target twice as wide as VRAM. The
+//! left half mirrors native VRAM, so textures and CLUTs are sampled from
+//! the same data as in the native pass. CPU uploads, fills and copies are
+//! stretched into the surfaces they touch, which keeps movies and 2D
+//! screens visible. Only drawing areas up to 768 pixels wide get a surface.
+
+use super::super::registers::DrawingArea;
+use super::command::{RasterizerSetting, RenderCommand};
+use super::{Rasterizer, TextureReplacementConfig};
+
+/// Horizontal scale of widescreen surfaces as (numerator, denominator)
+///
+/// The inverse of the GTE widescreen projection scale.
+const WIDESCREEN_SCALE: (i32, i32) = (4, 3);
+
+/// Width of the target, in pixels
+const TARGET_WIDTH: usize = 2048;
+
+/// Most surfaces kept at once
+///
+/// Double buffered games draw into two areas; the others are usually
+/// off-screen render targets.
+const MAX_SURFACES: usize = 4;
+
+/// Widen a native width to a widescreen surface width
+///
+/// # Arguments
+///
+/// * `width` - Width in native pixels
+///
+/// # Returns
+///
+/// Width in widescreen pixels (4/3 of the native width, rounded down)
+pub(crate) fn widen(width: usize) -> usize {
+    width * WIDESCREEN_SCALE.0 as usize / WIDESCREEN_SCALE.1 as usize
+}
+
+/// A drawing area widened for widescreen output
+///
+/// Obtained from [`Renderer::widescreen_surface`](crate::core::gpu::Renderer::widescreen_surface)
+/// and used to read the surface back for display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidescreenSurface {
+    /// Native drawing area as (left, top, right, bottom), inclusive
+    area: (u16, u16, u16, u16),
+
+    /// Left edge within the widescreen half of the target
+    x: u16,
+}
+
+impl WidescreenSurface {
+    /// Place a surface for a drawing area
+    ///
+    /// Surfaces keep the X position of their drawing area scaled by 4/3,
+    /// pushed left as far as needed to fit.
+    ///
+    /// # Returns
+    ///
+    /// The surface, or None if the drawing area is empty or too wide
+    fn new(left: u16, top: u16, right: u16, bottom: u16) -> Option<Self> {
+        if right < left || bottom < top || right > 1023 || bottom > 511 {
+            return None;
+        }
+        let width = widen((right - left + 1) as usize);
+        let room = (TARGET_WIDTH / 2).checked_sub(width)?;
+        Some(Self {
+            area: (left, top, right, bottom),
+            x: widen(left as usize).min(room) as u16,
+        })
+    }
+
+    /// Width of the surface in pixels
+    pub fn width(&self) -> usize {
+        widen((self.area.2 - self.area.0 + 1) as usize)
+    }
+
+    /// Target column that a native X coordinate is drawn at
+    ///
+    /// Native pixel column `x` covers the target columns from
+    /// `column(x)` up to `column(x + 1)`.
+    fn column(&self, x: i32) -> i32 {
+        let (num, den) = WIDESCREEN_SCALE;
+        (TARGET_WIDTH / 2) as i32 + self.x as i32 + ((x - self.area.0 as i32) * num).div_euclid(den)
+    }
+
+    /// Target clip rectangle covering the surface
+    fn clip_rect(&self) -> (i16, i16, i16, i16) {
+        let (left, top, right, bottom) = self.area;
+        (
+            self.column(left as i32) as i16,
+            top as i16,
+            (self.column(right as i32 + 1) - 1) as i16,
+            bottom as i16,
+        )
+    }
+
+    /// Check whether a native VRAM pixel lies in the drawing area
+    fn contains(&self, x: u16, y: u16) -> bool {
+        let (left, top, right, bottom) = self.area;
+        (left..=right).contains(&x) && (top..=bottom).contains(&y)
+    }
+
+    /// Check whether two surfaces share target pixels
+    fn overlaps(&self, other: &Self) -> bool {
+        let columns = |s: &Self| (s.x as usize, s.x as usize + s.width());
+        let (a, b) = (columns(self), columns(other));
+        a.0 < b.1 && b.0 < a.1 && self.area.1 <= other.area.3 && other.area.1 <= self.area.3
+    }
+}
+
+/// Widescreen target and rasterizer
+#[derive(Clone)]
+pub(crate) struct Widescreen {
+    /// Rasterizer drawing into the double-width target
+    rasterizer: Rasterizer,
+
+    /// Target, 2048 by 512 pixels: native VRAM mirror, then surfaces
+    vram: Vec<u16>,
+
+    /// Native clip rectangle
+    clip_rect: (i16, i16, i16, i16),
+
+    /// Surface of the current drawing area
+    current: Option<WidescreenSurface>,
+
+    /// Surfaces drawn so far, most recently used last
+    surfaces: Vec<WidescreenSurface>,
+}
+
+impl Widescreen {
+    /// Create a widescreen target from the current native VRAM
+    ///
+    /// Texture dumping and replacement are left to the native pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `native` - Native VRAM to mirror
+    /// * `rasterizer` - Native rasterizer whose draw state is copied
+    pub(crate) fn new(native: &[u16], rasterizer: &Rasterizer) -> Self {
+        let mut widescreen = Self {
+            rasterizer: rasterizer.clone(),
+            vram: vec![0; TARGET_WIDTH * 512],
+            clip_rect: rasterizer.clip_rect(),
+            current: None,
+            surfaces: Vec::new(),
+        };
+        widescreen.rasterizer.set_widescreen_target(true);
+        widescreen.rasterizer.set_texture_cache_enabled(false);
+        widescreen
+            .rasterizer
+            .set_texture_replacement(TextureReplacementConfig::default());
+
+        for (y, row) in native.chunks_exact(1024).enumerate() {
+            widescreen.vram[y * TARGET_WIDTH..y * TARGET_WIDTH + 1024].copy_from_slice(row);
+        }
+
+        let (left, top, right, bottom) = widescreen.clip_rect;
+        widescreen.configure(RasterizerSetting::ClipRect(left, top, right, bottom));
+        widescreen
+    }
+
+    /// Find the surface drawn for a display area
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Display area X coordinate in VRAM
+    /// * `y` - Display area Y coordinate in VRAM
+    /// * `width` - Display area width
+    ///
+    /// # Returns
+    ///
+    /// The surface whose drawing area starts at (x, y) and is as wide as the
+    /// display area, if any
+    pub(crate) fn surface(&self, x: u16, y: u16, width: u16) -> Option<WidescreenSurface> {
+        self.surfaces
+            .iter()
+            .rev()
+            .find(|surface| {
+                let (left, top, right, _) = surface.area;
+                left == x && top == y && right - left + 1 == width
+            })
+            .copied()
+    }
+
+    /// Read a surface pixel
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - Surface to read
+    /// * `column` - Column within the surface (below its width)
+    /// * `y` - VRAM row (wrapped to 0-511)
+    pub(crate) fn pixel(&self, surface: &WidescreenSurface, column: usize, y: usize) -> u16 {
+        let x = TARGET_WIDTH / 2 + surface.x as usize + column;
+        self.vram[(y % 512) * TARGET_WIDTH + x % TARGET_WIDTH]
+    }
+
+    /// Store a native pixel in the mirror and every surface showing it
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Native X coordinate (wrapped to 0-1023)
+    /// * `y` - Native Y coordinate (wrapped to 0-511)
+    /// * `value` - Pixel value
+    pub(crate) fn write_native(&mut self, x: u16, y: u16, value: u16) {
+        let (x, y) = (x & 0x3FF, y & 0x1FF);
+        let row = y as usize * TARGET_WIDTH;
+        self.vram[row + x as usize] = value;
+
+        for surface in &self.surfaces {
+            if surface.contains(x, y) {
+                let columns = surface.column(x as i32)..surface.column(x as i32 + 1);
+                self.vram[row + columns.start as usize..row + columns.end as usize].fill(value);
+            }
+        }
+    }
+
+    /// Clear the target
+    pub(crate) fn clear(&mut self) {
+        self.vram.fill(0);
+    }
+
+    /// Apply a rasterizer setting
+    ///
+    /// A new clip rectangle selects the surface drawn into, creating it
+    /// from the current VRAM contents if needed. Texture cache emulation
+    /// stays off.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - Native setting
+    pub(crate) fn configure(&mut self, setting: RasterizerSetting) {
+        match setting {
+            RasterizerSetting::ClipRect(left, top, right, bottom) => {
+                self.clip_rect = (left, top, right, bottom);
+                self.current = self.use_surface(left, top, right, bottom);
+            }
+            RasterizerSetting::TextureCache(_) | RasterizerSetting::InvalidateTextureCache => {}
+            other => other.apply(&mut self.rasterizer),
+        }
+    }
+
+    /// Look up or create the surface for a drawing area
+    ///
+    /// New surfaces start as a stretched copy of the drawing area and evict
+    /// surfaces they overlap, as well as the least recently used one when
+    /// there are too many.
+    fn use_surface(
+        &mut self,
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    ) -> Option<WidescreenSurface> {
+        let [left, top, right, bottom] = [left, top, right, bottom].map(|v| v.max(0) as u16);
+        let surface = WidescreenSurface::new(left, top, right, bottom)?;
+
+        if let Some(index) = self.surfaces.iter().position(|s| *s == surface) {
+            let surface = self.surfaces.remove(index);
+            self.surfaces.push(surface);
+            return Some(surface);
+        }
+
+        self.surfaces.retain(|s| !s.overlaps(&surface));
+        if self.surfaces.len() == MAX_SURFACES {
+            self.surfaces.remove(0);
+        }
+        self.surfaces.push(surface);
+
+        for y in top..=bottom {
+            let row = y as usize * TARGET_WIDTH;
+            for x in left..=right {
+                let value = self.vram[row + x as usize];
+                let columns = surface.column(x as i32)..surface.column(x as i32 + 1);
+                self.vram[row + columns.start as usize..row + columns.end as usize].fill(value);
+            }
+        }
+        Some(surface)
+    }
+
+    /// Draw a native command into the mirror and the current surface
+    ///
+    /// Vertex X coordinates are scaled by 4/3 around the drawing area.
+    /// Textured rectangles are drawn one target column at a time, so their
+    /// texture coordinates keep stepping once per native pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Command in native coordinates
+    pub(crate) fn execute(&mut self, command: RenderCommand) {
+        if let RenderCommand::Configure(setting) = command {
+            return self.configure(setting);
+        }
+
+        let (left, top, right, bottom) = self.clip_rect;
+        self.rasterizer.set_clip_rect(left, top, right, bottom);
+        command
+            .clone()
+            .execute(&mut self.rasterizer, &mut self.vram);
+
+        let Some(surface) = self.current else {
+            return;
+        };
+        let (left, top, right, bottom) = surface.clip_rect();
+        self.rasterizer.set_clip_rect(left, top, right, bottom);
+
+        let clamp = |v: i32| v.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let point = |(x, y): (i16, i16)| (clamp(surface.column(x as i32)), y);
+        let precise = |x: f32| {
+            let (num, den) = WIDESCREEN_SCALE;
+            surface.column(surface.area.0 as i32) as f32
+                + (x - surface.area.0 as f32) * num as f32 / den as f32
+        };
+        let widen_area = |area: DrawingArea| DrawingArea {
+            left: surface.column(area.left as i32).max(0) as u16,
+            right: (surface.column(area.right as i32 + 1) - 1).max(0) as u16,
+            ..area
+        };
+
+        let command = match command {
+            RenderCommand::Configure(setting) => return self.configure(setting),
+            RenderCommand::Triangle { vertices, color } => RenderCommand::Triangle {
+                vertices: vertices.map(point),
+                color,
+            },
+            RenderCommand::TriangleBlended {
+                vertices,
+                color,
+                blend_mode,
+            } => RenderCommand::TriangleBlended {
+                vertices: vertices.map(point),
+                color,
+                blend_mode,
+            },
+            RenderCommand::GradientTriangle { vertices, colors } => {
+                RenderCommand::GradientTriangle {
+                    vertices: vertices.map(point),
+                    colors,
+                }
+            }
+            RenderCommand::TexturedTriangle {
+                vertices,
+                texcoords,
+                texture,
+                window,
+                tint,
+            } => RenderCommand::TexturedTriangle {
+                vertices: vertices.map(point),
+                texcoords,
+                texture,
+                window,
+                tint,
+            },
+            RenderCommand::TrianglePrecise { vertices, color } => RenderCommand::TrianglePrecise {
+                vertices: vertices.map(|(x, y)| (precise(x), y)),
+                color,
+            },
+            RenderCommand::GradientTrianglePrecise { vertices, colors } => {
+                RenderCommand::GradientTrianglePrecise {
+                    vertices: vertices.map(|(x, y)| (precise(x), y)),
+                    colors,
+                }
+            }
+            RenderCommand::TexturedTrianglePrecise {
+                vertices,
+                texcoords,
+                texture,
+                window,
+                tint,
+            } => RenderCommand::TexturedTrianglePrecise {
+                vertices: vertices.map(|(x, y, w)| (precise(x), y, w)),
+                texcoords,
+                texture,
+                window,
+                tint,
+            },
+            RenderCommand::Line { from, to, color } => RenderCommand::Line {
+                from: point(from),
+                to: point(to),
+                color,
+            },
+            RenderCommand::Polyline { points, color } => RenderCommand::Polyline {
+                points: points.into_iter().map(point).collect(),
+                color,
+            },
+            RenderCommand::GradientLine {
+                from,
+                from_color,
+                to,
+                to_color,
+            } => RenderCommand::GradientLine {
+                from: point(from),
+                from_color,
+                to: point(to),
+                to_color,
+            },
+            RenderCommand::GradientPolyline { points, colors } => RenderCommand::GradientPolyline {
+                points: points.into_iter().map(point).collect(),
+                colors,
+            },
+            RenderCommand::Rectangle {
+                draw_mode,
+                draw_area,
+                draw_offset,
+                position,
+                size,
+                color,
+                semi_transparent,
+            } => {
+                let x = position.0.wrapping_add(draw_offset.0) as i32;
+                let (start, end) = (surface.column(x), surface.column(x + size.0 as i32));
+                RenderCommand::Rectangle {
+                    draw_mode,
+                    draw_area: widen_area(draw_area),
+                    draw_offset: (0, draw_offset.1),
+                    position: (clamp(start), position.1),
+                    size: ((end - start) as u16, size.1),
+                    color,
+                    semi_transparent,
+                }
+            }
+            RenderCommand::TexturedRectangle {
+                draw_mode,
+                draw_area,
+                draw_offset,
+                position,
+                size,
+                texcoord,
+                texture,
+                color,
+                semi_transparent,
+                modulated,
+            } => {
+                let x = position.0.wrapping_add(draw_offset.0) as i32;
+                let first = x.max(surface.area.0 as i32);
+                let last = (x + size.0 as i32).min(surface.area.2 as i32 + 1);
+                for native_x in first..last {
+                    let u = texcoord.0.wrapping_add((native_x - x) as u8);
+                    for column in surface.column(native_x)..surface.column(native_x + 1) {
+                        RenderCommand::TexturedRectangle {
+                            draw_mode,
+                            draw_area: widen_area(draw_area),
+                            draw_offset: (0, draw_offset.1),
+                            position: (column as i16, position.1),
+                            size: (1, size.1),
+                            texcoord: (u, texcoord.1),
+                            texture,
+                            color,
+                            semi_transparent,
+                            modulated,
+                        }
+                        .execute(&mut self.rasterizer, &mut self.vram);
+                    }
+                }
+                return;
+            }
+        };
+        command.execute(&mut self.rasterizer, &mut self.vram);
+    }
+}
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-frame GPU statistics
+//!
+//! Counts the drawing work the GPU is given each frame: primitives by type,
+//! estimated pixels drawn, texturing and blending, primitives clipped by the
+//! drawing area and VRAM transfer traffic. Primitives are counted where their
+//! draw time is estimated, so pixel counts are the draw timing model's
+//! estimates from clipped sizes (triangles are estimated as half their
+//! bounding box), not the pixels the rasterizer stores. They are independent
+//! of the rendering backend and resolution scale.
+//!
+//! Counters roll over at VBlank.
+
+use std::fmt;
+
+use super::GPU;
+
+/// GPU drawing statistics for one frame
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::GPU;
+///
+/// let mut gpu = GPU::new();
+/// // Red triangle
+/// gpu.write_gp0(0x200000FF);
+/// gpu.write_gp0(0x00000000);
+/// gpu.write_gp0(0x00000040);
+/// gpu.write_gp0(0x00400000);
+///
+/// let stats = gpu.frame_stats();
+/// assert_eq!(stats.triangles, 1);
+/// assert_eq!(stats.untextured, 1);
+/// assert!(stats.estimated_pixels > 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GpuStats {
+    /// Triangles drawn (quadrilaterals count as two)
+    pub triangles: u32,
+
+    /// Rectangles (sprites) drawn
+    pub rectangles: u32,
+
+    /// Line segments drawn (each polyline segment counts as one)
+    pub lines: u32,
+
+    /// GP0(02h) fills
+    pub fills: u32,
+
+    /// GP0(80h) VRAM-to-VRAM copies
+    pub copies: u32,
+
+    /// Textured triangles and rectangles
+    pub textured: u32,
+
+    /// Flat or Gouraud-shaded primitives without texture, including lines
+    pub untextured: u32,
+
+    /// Primitives drawn with semi-transparency
+    pub semi_transparent: u32,
+
+    /// Primitives that extend past the drawing area (partly or entirely)
+    pub clipped: u32,
+
+    /// Pixels drawn by primitives, fills and copies, as estimated by the
+    /// draw timing model (triangles count half their clipped bounding box)
+    pub estimated_pixels: u64,
+
+    /// Bytes uploaded through GP0 CPU→VRAM transfers
+    pub cpu_to_vram_bytes: u64,
+
+    /// Bytes read back through GPUREAD VRAM→CPU transfers
+    pub vram_to_cpu_bytes: u64,
+
+    /// Bytes copied by VRAM-to-VRAM copies
+    pub vram_to_vram_bytes: u64,
+}
+
+impl GpuStats {
+    /// Total number of triangles, rectangles and line segments
+    pub fn primitives(&self) -> u32 {
+        self.triangles + self.rectangles + self.lines
+    }
+}
+
+impl fmt::Display for GpuStats {
+    /// Compact one-line summary for debug overlays
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::GpuStats;
+    ///
+    /// let stats = GpuStats {
+    ///     triangles: 120,
+    ///     rectangles: 8,
+    ///     textured: 100,
+    ///     untextured: 28,
+    ///     estimated_pixels: 45_200,
+    ///     cpu_to_vram_bytes: 2048,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     stats.to_string(),
+    ///     "tri 120 rect 8 line 0 fill 0 | tex 100 flat 28 semi 0 clip 0 | est 45.2k px | up 2.0KB down 0B copy 0B"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tri {} rect {} line {} fill {} | tex {} flat {} semi {} clip {} | est {} px | up {} down {} copy {}",
+            self.triangles,
+            self.rectangles,
+            self.lines,
+            self.fills,
+            self.textured,
+            self.untextured,
+            self.semi_transparent,
+            self.clipped,
+            Count(self.estimated_pixels),
+            Bytes(self.cpu_to_vram_bytes),
+            Bytes(self.vram_to_cpu_bytes),
+            Bytes(self.vram_to_vram_bytes),
+        )
+    }
+}
+
+/// Count shortened with a k/M suffix
+struct Count(u64);
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            n if n >= 1_000_000 => write!(f, "{:.1}M", n as f64 / 1_000_000.0),
+            n if n >= 1_000 => write!(f, "{:.1}k", n as f64 / 1_000.0),
+            n => write!(f, "{}", n),
+        }
+    }
+}
+
+/// Byte count shortened with a KB/MB suffix
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            n if n >= 1024 * 1024 => write!(f, "{:.1}MB", n as f64 / (1024.0 * 1024.0)),
+            n if n >= 1024 => write!(f, "{:.1}KB", n as f64 / 1024.0),
+            n => write!(f, "{}B", n),
+        }
+    }
+}
+
+/// Kind of primitive being counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveKind {
+    Triangle,
+    Rectangle,
+    Line,
+}
+
+impl GPU {
+    /// Get the statistics of the frame being drawn
+    ///
+    /// # Returns
+    ///
+    /// Counters accumulated since the last frame boundary
+    pub fn frame_stats(&self) -> &GpuStats {
+        &self.frame_stats
+    }
+
+    /// Get the statistics of the last completed frame
+    ///
+    /// This is what frontends should display; it stays stable while the
+    /// next frame is drawn.
+    pub fn last_frame_stats(&self) -> &GpuStats {
+        &self.last_frame_stats
+    }
+
+    /// Close the current statistics frame
+    ///
+    /// Moves the current counters to [`GPU::last_frame_stats`] and starts
+    /// counting from zero.
+    pub fn end_stats_frame(&mut self) {
+        self.last_frame_stats = std::mem::take(&mut self.frame_stats);
+    }
+
+    /// Count a triangle, rectangle or line
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Primitive type
+    /// * `textured` - Whether the primitive samples a texture
+    /// * `semi_transparent` - Whether the primitive is blended
+    /// * `estimated_pixels` - Pixels drawn, as estimated for draw timing
+    /// * `bounds` - Bounding box as (left, top, right, bottom), inclusive
+    pub(crate) fn count_primitive(
+        &mut self,
+        kind: PrimitiveKind,
+        textured: bool,
+        semi_transparent: bool,
+        estimated_pixels: u64,
+        bounds: (i32, i32, i32, i32),
+    ) {
+        let area = &self.draw_area;
+        let (left, top, right, bottom) = bounds;
+        let clipped = left < area.left as i32
+            || top < area.top as i32
+            || right > area.right as i32
+            || bottom > area.bottom as i32;
+
+        let stats = &mut self.frame_stats;
+        match kind {
+            PrimitiveKind::Triangle => stats.triangles += 1,
+            PrimitiveKind::Rectangle => stats.rectangles += 1,
+            PrimitiveKind::Line => stats.lines += 1,
+        }
+        if textured {
+            stats.textured += 1;
+        } else {
+            stats.untextured += 1;
+        }
+        stats.semi_transparent += semi_transparent as u32;
+        stats.clipped += clipped as u32;
+        stats.estimated_pixels += estimated_pixels;
+    }
+}
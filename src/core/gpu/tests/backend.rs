@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Rendering backend tests
+//! Tests backend selection and the null and recording backends
+
+use super::super::*;
+use super::threaded::draw_scene;
+use crate::core::error::GpuError;
+
+/// Issue a red triangle, a blue fill and a 2x1 CPU→VRAM upload
+fn draw_primitives(gpu: &mut GPU) {
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    gpu.write_gp0(0x02FF0000);
+    gpu.write_gp0(0x00000100);
+    gpu.write_gp0(0x00100010);
+
+    gpu.write_gp0(0xA0000000);
+    gpu.write_gp0(0x01000200);
+    gpu.write_gp0(0x00010002);
+    gpu.write_gp0(0x7FFF1234);
+}
+
+/// Wrap a software renderer in a recorder and return the GPU and its log
+fn recording_gpu() -> (GPU, RenderLog) {
+    let recorder = RecordingRenderer::new(Box::new(SoftwareRenderer::new()), usize::MAX);
+    let log = recorder.log();
+    (GPU::with_renderer(Box::new(recorder)), log)
+}
+
+#[test]
+fn test_default_renderer_is_software() {
+    let mut gpu = GPU::new();
+    assert_eq!(gpu.renderer_name(), "software");
+}
+
+#[test]
+fn test_renderer_kind_parsing() {
+    assert_eq!("software".parse(), Ok(RendererKind::Software));
+    assert_eq!("NULL".parse(), Ok(RendererKind::Null));
+    assert_eq!("recording".parse(), Ok(RendererKind::Recording));
+    assert!("opengl".parse::<RendererKind>().is_err());
+
+    for kind in [
+        RendererKind::Software,
+        RendererKind::Null,
+        RendererKind::Recording,
+    ] {
+        let name = kind.create().name();
+        assert_eq!(name.parse(), Ok(kind));
+    }
+}
+
+#[test]
+fn test_null_renderer_discards_drawing() {
+    let mut gpu = GPU::with_renderer(Box::new(NullRenderer::new()));
+    draw_primitives(&mut gpu);
+
+    assert_eq!(gpu.read_vram(1, 1), 0x0000);
+    assert_eq!(gpu.read_vram(0, 256), 0x0000);
+}
+
+#[test]
+fn test_null_renderer_keeps_transfers() {
+    let mut gpu = GPU::with_renderer(Box::new(NullRenderer::new()));
+    draw_primitives(&mut gpu);
+
+    // Upload lands in VRAM and can be copied and read back
+    assert_eq!(gpu.read_vram(512, 256), 0x1234);
+    gpu.write_gp0(0x80000000);
+    gpu.write_gp0(0x01000200);
+    gpu.write_gp0(0x01800200);
+    gpu.write_gp0(0x00010002);
+
+    gpu.write_gp0(0xC0000000);
+    gpu.write_gp0(0x01800200);
+    gpu.write_gp0(0x00010002);
+    assert_eq!(gpu.read_gpuread(), 0x7FFF1234);
+}
+
+#[test]
+fn test_null_renderer_rejects_upscaling() {
+    let mut gpu = GPU::with_renderer(Box::new(NullRenderer::new()));
+
+    let result = gpu.set_resolution_scale(2);
+    assert!(matches!(result, Err(GpuError::BackendError(_))));
+    assert_eq!(gpu.resolution_scale(), 1);
+    assert_eq!(gpu.get_framebuffer().width, 320);
+}
+
+#[test]
+fn test_recording_renderer_records_calls_in_order() {
+    let (mut gpu, log) = recording_gpu();
+    log.clear();
+    draw_primitives(&mut gpu);
+
+    let calls = log.calls();
+    assert_eq!(calls.len(), 4);
+    assert!(matches!(
+        calls[0],
+        RenderCall::Draw(RenderCommand::Triangle {
+            vertices: [(0, 0), (64, 0), (0, 64)],
+            color: 0x001F
+        })
+    ));
+    assert!(matches!(
+        calls[1],
+        RenderCall::Fill {
+            x: 0,
+            y: 256,
+            width: 16,
+            height: 16,
+            color: 0x7C00
+        }
+    ));
+    assert!(matches!(
+        calls[2],
+        RenderCall::WriteVram {
+            x: 512,
+            y: 256,
+            value: 0x1234
+        }
+    ));
+    assert!(matches!(calls[3], RenderCall::WriteVram { x: 513, .. }));
+}
+
+#[test]
+fn test_recording_renderer_records_state_changes() {
+    let (mut gpu, log) = recording_gpu();
+    log.clear();
+
+    // Drawing area top-left, then mask settings
+    gpu.write_gp0(0xE3000000 | (8 << 10) | 16);
+    gpu.write_gp0(0xE6000001);
+
+    let calls = log.calls();
+    assert!(matches!(
+        calls[..],
+        [
+            RenderCall::Configure(RasterizerSetting::ClipRect(16, 8, _, _)),
+            RenderCall::Configure(RasterizerSetting::Mask {
+                set: true,
+                check: false
+            }),
+        ]
+    ));
+}
+
+#[test]
+fn test_recording_renderer_matches_software_output() {
+    let mut reference = GPU::new();
+    draw_scene(&mut reference);
+
+    let (mut gpu, log) = recording_gpu();
+    draw_scene(&mut gpu);
+
+    assert!(reference.vram == gpu.vram);
+    assert!(log
+        .calls()
+        .iter()
+        .any(|call| matches!(call, RenderCall::CopyVram { .. })));
+}
+
+#[test]
+fn test_recording_renderer_drops_oldest_calls() {
+    let recorder = RecordingRenderer::new(Box::new(NullRenderer::new()), 2);
+    let log = recorder.log();
+    let mut gpu = GPU::with_renderer(Box::new(recorder));
+    draw_primitives(&mut gpu);
+
+    assert_eq!(log.len(), 2);
+    assert!(matches!(
+        log.calls()[1],
+        RenderCall::WriteVram { x: 513, .. }
+    ));
+}
+
+#[test]
+fn test_threaded_recording_sees_same_calls() {
+    let (mut reference, reference_log) = recording_gpu();
+    draw_scene(&mut reference);
+
+    let (mut gpu, log) = recording_gpu();
+    gpu.set_threaded_rendering(true);
+    draw_scene(&mut gpu);
+    gpu.sync_renderer();
+
+    assert_eq!(log.len(), reference_log.len());
+    assert!(reference.vram == gpu.vram);
+}
@@ -110,8 +110,8 @@ fn test_gpu_tick() {
 
 #[test]
 fn test_default_trait() {
-    let gpu1 = GPU::new();
-    let gpu2 = GPU::default();
+    let mut gpu1 = GPU::new();
+    let mut gpu2 = GPU::default();
 
     // Both should have the same initial state
     assert_eq!(gpu1.vram.len(), gpu2.vram.len());
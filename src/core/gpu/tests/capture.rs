@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Frame capture and replay tests
+//! Tests that replaying a capture reproduces VRAM and that capture files
+//! round-trip
+
+use super::super::*;
+use super::threaded::{draw_scene, upload_texture};
+
+/// Run the GPU until the next VBlank starts
+fn end_frame(gpu: &mut GPU) {
+    while !gpu.tick(GPU::DOTS_PER_SCANLINE as u32).0 {}
+}
+
+/// Put the GPU in a non-default state with existing VRAM contents
+fn prepare_state(gpu: &mut GPU) {
+    upload_texture(gpu);
+    gpu.write_gp0(0x02203040);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x01000200);
+    gpu.write_gp0(0xE1000000 | 8 | (2 << 7) | (1 << 9));
+    gpu.write_gp0(0xE2000000 | 0x21);
+    gpu.write_gp0(0xE3000000 | (4 << 10) | 4);
+    gpu.write_gp0(0xE4000000 | (200 << 10) | 300);
+    gpu.write_gp0(0xE5000000 | (((-8i32) as u32 & 0x7FF) << 11) | 16);
+    gpu.write_gp0(0xE6000001);
+    gpu.write_gp1(0x05000000 | (16 << 10) | 32);
+    gpu.write_gp1(0x08000001);
+}
+
+#[test]
+fn test_replay_reproduces_vram() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(1);
+    draw_scene(&mut gpu);
+    end_frame(&mut gpu);
+
+    let capture = gpu.finish_capture().unwrap();
+    assert_eq!(capture.frames(), 1);
+    assert!(capture.replay().vram == gpu.vram);
+}
+
+#[test]
+fn test_replay_restores_start_state() {
+    let mut gpu = GPU::new();
+    prepare_state(&mut gpu);
+    // Let the drawing in prepare_state finish so the GPU reports idle
+    end_frame(&mut gpu);
+
+    gpu.start_capture(0);
+    let capture = gpu.finish_capture().unwrap();
+    assert!(capture.events.is_empty());
+
+    let replayed = capture.replay();
+    assert!(replayed.vram == gpu.vram);
+    assert_eq!(replayed.status(), gpu.status());
+    assert_eq!(replayed.display_area.x, 32);
+    assert_eq!(replayed.display_area.y, 16);
+    assert_eq!(replayed.draw_offset, (16, -8));
+}
+
+#[test]
+fn test_replay_from_modified_state() {
+    let mut gpu = GPU::new();
+    prepare_state(&mut gpu);
+
+    gpu.start_capture(0);
+    draw_scene(&mut gpu);
+    let capture = gpu.finish_capture().unwrap();
+
+    let replayed = capture.replay();
+    assert!(replayed.vram == gpu.vram);
+    assert_eq!(replayed.status(), gpu.status());
+    assert_eq!(replayed.draw_offset, gpu.draw_offset);
+}
+
+#[test]
+fn test_capture_stops_after_frame_count() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(2);
+
+    end_frame(&mut gpu);
+    assert!(gpu.capture_in_progress());
+    end_frame(&mut gpu);
+    assert!(!gpu.capture_in_progress());
+
+    // Drawing after the last frame is not part of the capture
+    gpu.write_gp0(0x60FFFFFF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    let capture = gpu.finish_capture().unwrap();
+    assert_eq!(capture.frames(), 2);
+    assert_eq!(
+        capture.events,
+        vec![CaptureEvent::VBlank, CaptureEvent::VBlank]
+    );
+    assert_eq!(capture.replay().read_vram(0, 0), 0x0000);
+    assert!(gpu.finish_capture().is_none());
+}
+
+#[test]
+fn test_capture_records_direct_vram_writes() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(0);
+    gpu.write_vram(100, 200, 0x1234);
+
+    let capture = gpu.finish_capture().unwrap();
+    assert_eq!(
+        capture.events,
+        vec![CaptureEvent::VramWrite {
+            x: 100,
+            y: 200,
+            value: 0x1234
+        }]
+    );
+    assert_eq!(capture.replay().read_vram(100, 200), 0x1234);
+}
+
+#[test]
+fn test_capture_started_mid_command() {
+    let mut gpu = GPU::new();
+
+    // Half of a triangle and half of an upload are pending at capture start
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.start_capture(0);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+    gpu.write_gp0(0xA0000000);
+    gpu.write_gp0(0x01000100);
+    gpu.write_gp0(0x00020002);
+    gpu.write_gp0(0x11112222);
+    let capture = gpu.finish_capture().unwrap();
+
+    let mut upload = GPU::new();
+    upload.write_gp0(0xA0000000);
+    upload.write_gp0(0x01000100);
+    upload.write_gp0(0x00020002);
+    upload.write_gp0(0x11112222);
+    upload.start_capture(0);
+    upload.write_gp0(0x33334444);
+    let upload_capture = upload.finish_capture().unwrap();
+
+    assert!(capture.replay().vram == gpu.vram);
+    assert!(upload_capture.replay().vram == upload.vram);
+    assert_eq!(upload_capture.replay().read_vram(257, 257), 0x3333);
+}
+
+#[test]
+fn test_replay_into_threaded_upscaled_gpu() {
+    let mut gpu = GPU::new();
+    prepare_state(&mut gpu);
+    gpu.start_capture(0);
+    draw_scene(&mut gpu);
+    let capture = gpu.finish_capture().unwrap();
+
+    let mut target = GPU::new();
+    target.set_threaded_rendering(true);
+    target.set_resolution_scale(2).unwrap();
+    capture.replay_into(&mut target);
+
+    assert!(target.vram == gpu.vram);
+    assert_eq!(
+        target.get_framebuffer().width,
+        gpu.get_framebuffer().width * 2
+    );
+}
+
+#[test]
+fn test_capture_file_round_trip() {
+    let mut gpu = GPU::new();
+    prepare_state(&mut gpu);
+    gpu.start_capture(1);
+    draw_scene(&mut gpu);
+    end_frame(&mut gpu);
+    let capture = gpu.finish_capture().unwrap();
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    capture.save_to_file(file.path()).unwrap();
+    let loaded = GpuCapture::load_from_file(file.path()).unwrap();
+
+    assert_eq!(loaded.version, GPU_CAPTURE_VERSION);
+    assert_eq!(loaded.registers, capture.registers);
+    assert_eq!(loaded.events, capture.events);
+    assert!(loaded.replay().vram == gpu.vram);
+}
+
+#[test]
+fn test_capture_file_version_mismatch() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(0);
+    let mut capture = gpu.finish_capture().unwrap();
+    capture.version = GPU_CAPTURE_VERSION + 1;
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    capture.save_to_file(file.path()).unwrap();
+    let error = GpuCapture::load_from_file(file.path()).unwrap_err();
+    assert!(error.to_string().contains("version"));
+}
+
+#[test]
+fn test_capture_file_rejects_bad_vram() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(0);
+    let mut capture = gpu.finish_capture().unwrap();
+    capture.vram.truncate(1024);
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    capture.save_to_file(file.path()).unwrap();
+    assert!(GpuCapture::load_from_file(file.path()).is_err());
+}
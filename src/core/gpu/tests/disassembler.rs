@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU command disassembler tests
+//! Tests GP0 packet decoding, text output and stream disassembly
+
+use super::super::*;
+
+/// Disassemble one complete packet, asserting that all words are used
+fn disasm(words: &[u32]) -> String {
+    let (text, used) = GpuCommandDisassembler::disassemble_gp0(words).unwrap();
+    assert_eq!(used, words.len());
+    text
+}
+
+#[test]
+fn test_monochrome_triangle() {
+    let text = disasm(&[0x200000FF, 0x00000000, 0x00000040, 0x00400000]);
+    assert_eq!(
+        text,
+        "Poly3 flat semi=0 rgb=(255,0,0) v0=(0,0) v1=(64,0) v2=(0,64)"
+    );
+}
+
+#[test]
+fn test_polygon_packet_lengths() {
+    let words = [0u32; 12];
+    let cases = [
+        (0x20, 4),
+        (0x28, 5),
+        (0x24, 7),
+        (0x2C, 9),
+        (0x30, 6),
+        (0x38, 8),
+    ];
+    for (opcode, len) in cases {
+        let mut packet = words;
+        packet[0] = opcode << 24;
+        let (_, used) = GPUCommand::decode(&packet).unwrap();
+        assert_eq!(used, len, "opcode 0x{:02X}", opcode);
+        assert!(GPUCommand::decode(&packet[..len - 1]).is_none());
+    }
+}
+
+#[test]
+fn test_decode_lengths_match_gp0_handlers() {
+    // 1×1 sizes and small coordinates, with a terminator for polylines
+    let mut words = vec![0x0001_0001u32; 16];
+    words.push(0x5555_5555);
+
+    for opcode in 0..=0xFFu32 {
+        words[0] = (opcode << 24) | 0x0080_8080;
+        let (command, used) = GPUCommand::decode(&words).unwrap();
+
+        let mut gpu = GPU::new();
+        for (i, &word) in words[..used].iter().enumerate() {
+            gpu.write_gp0(word);
+            assert_eq!(
+                gpu.command_fifo.is_empty(),
+                i == used - 1,
+                "GP0({:02X}h) word {} of {}",
+                opcode,
+                i,
+                used
+            );
+        }
+
+        // CPU→VRAM pixel data goes to the transfer, not the FIFO
+        for _ in 0..command.data_words() {
+            assert!(gpu.vram_transfer.is_some(), "GP0({:02X}h)", opcode);
+            gpu.write_gp0(0);
+        }
+        if command.data_words() > 0 {
+            assert!(gpu.vram_transfer.is_none(), "GP0({:02X}h)", opcode);
+        }
+    }
+}
+
+#[test]
+fn test_skipped_commands_are_nops() {
+    // Raw-textured quad, Gouraud-textured triangle and a transfer mirror
+    for word in [0x2D00_0000, 0x3400_0000, 0xA100_0000] {
+        let (command, used) = GPUCommand::decode(&[word, 0, 0, 0]).unwrap();
+        assert!(matches!(command, GPUCommand::Nop(w) if w == word));
+        assert_eq!(used, 1);
+    }
+    assert_eq!(disasm(&[0x2D00_0000]), "Nop 0x2D000000");
+}
+
+#[test]
+fn test_lines() {
+    let text = disasm(&[0x42FFFFFF, 0x00050005, 0x000A0014]);
+    assert_eq!(
+        text,
+        "Line flat semi=1 rgb=(255,255,255) v0=(5,5) v1=(20,10)"
+    );
+
+    let text = disasm(&[0x500000FF, 0x00000000, 0x0000FF00, 0x00100010]);
+    assert_eq!(
+        text,
+        "Line gouraud semi=0 v0=(0,0) rgb=(255,0,0) v1=(16,16) rgb=(0,255,0)"
+    );
+}
+
+#[test]
+fn test_polyline_terminator() {
+    let words = [0x48FFFFFF, 0x00000000, 0x00000010, 0x00100010, 0x55555555];
+    assert!(GPUCommand::decode(&words[..4]).is_none());
+
+    let text = disasm(&words);
+    assert_eq!(
+        text,
+        "Polyline flat semi=0 rgb=(255,255,255) v0=(0,0) v1=(16,0) v2=(16,16) end=0x55555555"
+    );
+
+    // Shaded polylines look for the terminator where the next color would be
+    let words = [0x580000FF, 0x00000000, 0x0000FF00, 0x00100010, 0x50005000];
+    let (command, used) = GPUCommand::decode(&words).unwrap();
+    assert_eq!(used, 5);
+    match command {
+        GPUCommand::Polyline {
+            vertices,
+            terminator,
+            shaded,
+            ..
+        } => {
+            assert_eq!(vertices.len(), 2);
+            assert_eq!(terminator, 0x5000_5000);
+            assert!(shaded);
+        }
+        other => panic!("unexpected command {:?}", other),
+    }
+}
+
+#[test]
+fn test_rectangles() {
+    let text = disasm(&[0x60FF0000, 0x00200010, 0x00080004]);
+    assert_eq!(text, "Rect semi=0 pos=(16,32) size=8x4 rgb=(0,0,255)");
+
+    let text = disasm(&[0x7E808080, 0x00000000, 0x78000804]);
+    assert_eq!(
+        text,
+        "Rect textured semi=1 pos=(0,0) size=16x16 uv=(4,8) clut=(0,480) rgb=(128,128,128)"
+    );
+}
+
+#[test]
+fn test_transfers_and_settings() {
+    assert_eq!(
+        disasm(&[0x02FF0000, 0x00000100, 0x00100010]),
+        "Fill pos=(0,256) size=16x16 rgb=(0,0,255)"
+    );
+    assert_eq!(
+        disasm(&[0x80000000, 0x00000000, 0x01000200, 0x00100020]),
+        "CopyVram src=(0,0) dst=(512,256) size=32x16"
+    );
+    assert_eq!(
+        disasm(&[0xA0000000, 0x01000200, 0x00010003]),
+        "CpuToVram pos=(512,256) size=3x1 words=2"
+    );
+    assert_eq!(
+        disasm(&[0xE1000000 | 8 | (1 << 5) | (1 << 7) | (1 << 9)]),
+        "DrawMode tpage=(512,0) 8bpp blend=add dither=1 draw_to_display=0 texture_disable=0"
+    );
+    assert_eq!(
+        disasm(&[0xE5000000 | ((-8i32 as u32 & 0x7FF) << 11) | 16]),
+        "DrawOffset (16,-8)"
+    );
+    assert_eq!(disasm(&[0xE6000002]), "MaskBits set=0 check=1");
+    assert_eq!(disasm(&[0x00000000]), "Nop 0x00000000");
+}
+
+#[test]
+fn test_gp1_commands() {
+    assert_eq!(
+        GpuCommandDisassembler::disassemble_gp1(0x03000001),
+        "DisplayEnable off"
+    );
+    assert_eq!(
+        GpuCommandDisassembler::disassemble_gp1(0x04000002),
+        "DmaDirection cpu_to_gp0"
+    );
+    assert_eq!(
+        GpuCommandDisassembler::disassemble_gp1(0x06000000 | (0xC60 << 12) | 0x260),
+        "HorizontalRange 608..3168"
+    );
+    assert_eq!(
+        GpuCommandDisassembler::disassemble_gp1(0x08000000 | 3 | 4 | 8 | 0x10 | 0x20),
+        "DisplayMode 640x480 pal 24bpp interlace=1 reverse=0"
+    );
+}
+
+#[test]
+fn test_stream_skips_upload_data() {
+    let mut disasm = GpuCommandDisassembler::new();
+    let words = [
+        0xA0000000, 0x00000000, 0x00010003, 0x20002000, 0x20002000, 0xE3000000,
+    ];
+    let lines: Vec<String> = words.iter().filter_map(|&w| disasm.feed_gp0(w)).collect();
+    assert_eq!(
+        lines,
+        [
+            "CpuToVram pos=(0,0) size=3x1 words=2",
+            "DrawAreaTopLeft (0,0)"
+        ]
+    );
+}
+
+#[test]
+fn test_stream_reset_drops_partial_packet() {
+    let mut disasm = GpuCommandDisassembler::new();
+    assert!(disasm.feed_gp0(0x200000FF).is_none());
+    assert!(disasm.feed_gp0(0x00000000).is_none());
+    disasm.reset();
+    assert_eq!(
+        disasm.feed_gp0(0xE6000001),
+        Some("MaskBits set=1 check=0".into())
+    );
+}
+
+#[test]
+fn test_capture_listing() {
+    let mut gpu = GPU::new();
+    gpu.start_capture(0);
+    gpu.write_gp1(0x05000000);
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+    while !gpu.tick(GPU::DOTS_PER_SCANLINE as u32).0 {}
+    let capture = gpu.finish_capture().unwrap();
+
+    let lines = GpuCommandDisassembler::disassemble_capture(&capture);
+    let stream: Vec<&str> = lines
+        .iter()
+        .filter(|line| !line.starts_with("start "))
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        stream,
+        [
+            "GP1 DisplayStart (0,0)",
+            "GP0 Poly3 flat semi=0 rgb=(255,0,0) v0=(0,0) v1=(64,0) v2=(0,64)",
+            "-- end of frame 0 --",
+        ]
+    );
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("start GP0 DrawMode")));
+}
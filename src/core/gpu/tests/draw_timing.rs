@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Draw timing tests
+//! Tests per-primitive draw cost, FIFO occupancy and the GPUSTAT busy flags
+
+use super::super::*;
+use crate::core::timing::TimingEventManager;
+
+const READY_CMD: u32 = 1 << 26;
+const READY_DMA: u32 = 1 << 28;
+const DMA_REQUEST: u32 = 1 << 25;
+
+/// Convert GPU clock cycles to CPU cycles (rounded up)
+fn cpu_cycles(gpu_cycles: u32) -> u32 {
+    (gpu_cycles * 7).div_ceil(11)
+}
+
+/// Draw a monochrome rectangle with the given GP0 command byte
+fn draw_rect(gpu: &mut GPU, command: u32, x: u16, y: u16, width: u16, height: u16) {
+    gpu.write_gp0((command << 24) | 0x0000_00FF);
+    gpu.write_gp0(((y as u32) << 16) | x as u32);
+    gpu.write_gp0(((height as u32) << 16) | width as u32);
+}
+
+#[test]
+fn test_idle_gpu_is_ready() {
+    let gpu = GPU::new();
+
+    assert!(!gpu.is_busy());
+    assert_eq!(gpu.fifo_level(), 0);
+    assert_ne!(gpu.status() & READY_CMD, 0);
+    assert_ne!(gpu.status() & READY_DMA, 0);
+}
+
+#[test]
+fn test_rectangle_keeps_gpu_busy() {
+    let mut gpu = GPU::new();
+
+    // 64x64 opaque pixels at one cycle each
+    draw_rect(&mut gpu, 0x60, 0, 0, 64, 64);
+
+    assert!(gpu.is_busy());
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(64 * 64));
+    assert_eq!(gpu.status() & READY_CMD, 0);
+    assert_ne!(gpu.status() & READY_DMA, 0);
+
+    // Drawing still happens immediately
+    assert_eq!(gpu.read_vram(63, 63), 0x001F);
+}
+
+#[test]
+fn test_cost_depends_on_draw_mode() {
+    let mut gpu = GPU::new();
+
+    // Semi-transparent pixels read the background (1.5 cycles each)
+    draw_rect(&mut gpu, 0x62, 0, 0, 16, 16);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(16 * 16 * 3 / 2));
+
+    // Mask checking also reads the background
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE600_0002);
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 16);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(16 * 16 * 3 / 2));
+
+    // Textured pixels cost two cycles each
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0x6400_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0010_0010);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(16 * 16 * 2));
+}
+
+#[test]
+fn test_triangle_cost_uses_half_bounding_box() {
+    let mut gpu = GPU::new();
+
+    gpu.write_gp0(0x2000_00FF);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0000_001F);
+    gpu.write_gp0(0x001F_0000);
+
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(32 * 32 / 2));
+}
+
+#[test]
+fn test_clipped_primitive_costs_nothing() {
+    let mut gpu = GPU::new();
+
+    // Drawing area 0,0 - 99,99
+    gpu.write_gp0(0xE300_0000);
+    gpu.write_gp0(0xE400_0000 | (99 << 10) | 99);
+
+    draw_rect(&mut gpu, 0x60, 200, 200, 16, 16);
+    assert!(!gpu.is_busy());
+
+    // Partially visible: only the 8x8 inside the area counts
+    draw_rect(&mut gpu, 0x60, 92, 92, 16, 16);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(8 * 8));
+}
+
+#[test]
+fn test_line_and_fill_costs() {
+    let mut gpu = GPU::new();
+
+    // Line from (0,0) to (40,10): 41 pixels along the major axis
+    gpu.write_gp0(0x4000_00FF);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x000A_0028);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(41));
+
+    // Fill 32x32: 46 + (32 / 8 + 9) * 32
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0x0200_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0020_0020);
+    assert_eq!(gpu.busy_cycles(), cpu_cycles(46 + 13 * 32));
+}
+
+#[test]
+fn test_fifo_fills_while_busy() {
+    let mut gpu = GPU::new();
+    draw_rect(&mut gpu, 0x60, 0, 0, 256, 256);
+
+    for i in 0..GPU::FIFO_DEPTH {
+        assert_ne!(gpu.status() & READY_DMA, 0, "word {}", i);
+        gpu.write_gp0(0xE100_0000);
+    }
+
+    assert_eq!(gpu.fifo_level(), GPU::FIFO_DEPTH);
+    assert_eq!(gpu.status() & READY_DMA, 0);
+    assert_eq!(gpu.fifo_overruns(), 0);
+
+    // Writing to a full FIFO is an overrun
+    gpu.write_gp0(0xE100_0000);
+    assert_eq!(gpu.fifo_overruns(), 1);
+    assert_eq!(gpu.fifo_level(), GPU::FIFO_DEPTH);
+}
+
+#[test]
+fn test_tick_finishes_drawing() {
+    let mut gpu = GPU::new();
+    draw_rect(&mut gpu, 0x60, 0, 0, 64, 64);
+    gpu.write_gp0(0xE100_0000);
+    assert_eq!(gpu.fifo_level(), 1);
+
+    let busy = gpu.busy_cycles();
+    gpu.tick(busy - 1);
+    assert!(gpu.is_busy());
+    assert_eq!(gpu.status() & READY_CMD, 0);
+
+    gpu.tick(1);
+    assert!(!gpu.is_busy());
+    assert_eq!(gpu.fifo_level(), 0);
+    assert_ne!(gpu.status() & READY_CMD, 0);
+}
+
+#[test]
+fn test_dma_request_follows_direction() {
+    let mut gpu = GPU::new();
+
+    // Off: never requests
+    assert_eq!(gpu.status() & DMA_REQUEST, 0);
+
+    // CPU to GP0: mirrors ready-for-DMA-block
+    gpu.write_gp1(0x0400_0002);
+    assert_ne!(gpu.status() & DMA_REQUEST, 0);
+
+    draw_rect(&mut gpu, 0x60, 0, 0, 256, 256);
+    for _ in 0..GPU::FIFO_DEPTH {
+        gpu.write_gp0(0xE100_0000);
+    }
+    assert_eq!(gpu.status() & DMA_REQUEST, 0);
+
+    // GP1(01h) empties the FIFO
+    gpu.write_gp1(0x0100_0000);
+    assert_eq!(gpu.fifo_level(), 0);
+    assert_ne!(gpu.status() & DMA_REQUEST, 0);
+
+    // GPUREAD to CPU: mirrors ready-to-send-VRAM
+    gpu.write_gp1(0x0400_0003);
+    gpu.write_gp0(0xC000_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0001_0002);
+    assert_ne!(gpu.status() & DMA_REQUEST, 0);
+
+    gpu.read_gpuread();
+    assert_eq!(gpu.status() & DMA_REQUEST, 0);
+}
+
+#[test]
+fn test_draw_event_finishes_drawing() {
+    let mut gpu = GPU::new();
+    let mut timing = TimingEventManager::new();
+    gpu.register_events(&mut timing);
+
+    draw_rect(&mut gpu, 0x60, 0, 0, 64, 64);
+    let busy = gpu.busy_cycles();
+
+    // Arm the draw event
+    gpu.process_events(&mut timing, &[]);
+
+    timing.pending_ticks = busy as i32 - 1;
+    let triggered = timing.run_events();
+    gpu.process_events(&mut timing, &triggered);
+    assert!(gpu.is_busy());
+
+    timing.pending_ticks = 1;
+    let triggered = timing.run_events();
+    gpu.process_events(&mut timing, &triggered);
+    assert!(!gpu.is_busy());
+    assert_ne!(gpu.status() & READY_CMD, 0);
+}
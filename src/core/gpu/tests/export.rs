@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! VRAM export tests
+//! Tests VRAM, texture page and palette images and their file encodings
+
+use super::super::*;
+use crate::core::error::{EmulatorError, GpuError};
+
+/// RGB bytes of pixel (x, y) in an image
+fn pixel(image: &VramImage, x: u32, y: u32) -> [u8; 3] {
+    let i = ((y * image.width + x) * 3) as usize;
+    [image.pixels[i], image.pixels[i + 1], image.pixels[i + 2]]
+}
+
+/// Texture page at (128, 256) with a CLUT at (16, 500)
+fn texture(depth: TextureDepth) -> TextureInfo {
+    TextureInfo {
+        page_x: 128,
+        page_y: 256,
+        clut_x: 16,
+        clut_y: 500,
+        depth,
+    }
+}
+
+#[test]
+fn test_export_vram() {
+    let mut gpu = GPU::new();
+    gpu.write_vram(0, 0, 0x001F);
+    gpu.write_vram(1023, 511, 0x83E0);
+
+    let image = gpu.export_vram();
+    assert_eq!((image.width, image.height), (1024, 512));
+    assert_eq!(image.pixels.len(), 1024 * 512 * 3);
+    assert_eq!(pixel(&image, 0, 0), [248, 0, 0]);
+    // Mask bit is ignored
+    assert_eq!(pixel(&image, 1023, 511), [0, 248, 0]);
+}
+
+#[test]
+fn test_export_vram_threaded() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+    gpu.write_gp0(0x02FF0000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    let image = gpu.export_vram();
+    assert_eq!(pixel(&image, 0, 0), [0, 0, 248]);
+}
+
+#[test]
+fn test_texture_page_4bit() {
+    let mut gpu = GPU::new();
+    // Texels 0..3 use indices 1, 2, 3, 15
+    gpu.write_vram(128, 256, 0xF321);
+    gpu.write_vram(17, 500, 0x001F);
+    gpu.write_vram(18, 500, 0x03E0);
+    gpu.write_vram(19, 500, 0x7C00);
+    gpu.write_vram(31, 500, 0x7FFF);
+
+    let image = gpu.export_texture_page(&texture(TextureDepth::T4Bit));
+    assert_eq!((image.width, image.height), (256, 256));
+    assert_eq!(pixel(&image, 0, 0), [248, 0, 0]);
+    assert_eq!(pixel(&image, 1, 0), [0, 248, 0]);
+    assert_eq!(pixel(&image, 2, 0), [0, 0, 248]);
+    assert_eq!(pixel(&image, 3, 0), [248, 248, 248]);
+    assert_eq!(pixel(&image, 4, 0), [0, 0, 0]);
+}
+
+#[test]
+fn test_texture_page_8bit() {
+    let mut gpu = GPU::new();
+    // Texel (2, 1) is the low byte of the second word in row 1
+    gpu.write_vram(129, 257, 0x00C8);
+    gpu.write_vram(16 + 200, 500, 0x03E0);
+
+    let image = gpu.export_texture_page(&texture(TextureDepth::T8Bit));
+    assert_eq!(pixel(&image, 2, 1), [0, 248, 0]);
+    assert_eq!(pixel(&image, 3, 1), [0, 0, 0]);
+}
+
+#[test]
+fn test_texture_page_15bit_ignores_clut() {
+    let mut gpu = GPU::new();
+    gpu.write_vram(128 + 255, 256 + 255, 0x7C00);
+    gpu.write_vram(16, 500, 0x7FFF);
+
+    let image = gpu.export_texture_page(&texture(TextureDepth::T15Bit));
+    assert_eq!(pixel(&image, 255, 255), [0, 0, 248]);
+    assert_eq!(pixel(&image, 0, 0), [0, 0, 0]);
+}
+
+#[test]
+fn test_clut_palettes() {
+    let mut gpu = GPU::new();
+    gpu.write_vram(32, 480, 0x001F);
+    gpu.write_vram(47, 480, 0x7FFF);
+    gpu.write_vram(300, 481, 0x03E0);
+
+    let palettes = gpu.clut_palettes(TextureDepth::T4Bit);
+    let positions: Vec<(u16, u16)> = palettes.iter().map(|p| (p.x, p.y)).collect();
+    assert_eq!(positions, [(32, 480), (288, 481)]);
+    assert_eq!(palettes[0].colors.len(), 16);
+    assert_eq!(palettes[0].colors[15], 0x7FFF);
+
+    // 8-bit CLUTs are read in 256-entry steps
+    let palettes = gpu.clut_palettes(TextureDepth::T8Bit);
+    let positions: Vec<(u16, u16)> = palettes.iter().map(|p| (p.x, p.y)).collect();
+    assert_eq!(positions, [(0, 480), (256, 481)]);
+    assert_eq!(palettes[1].colors[44], 0x03E0);
+
+    assert!(gpu.clut_palettes(TextureDepth::T15Bit).is_empty());
+}
+
+#[test]
+fn test_palette_image_layout() {
+    let palettes = [
+        Palette {
+            x: 0,
+            y: 0,
+            colors: vec![0x001F, 0x03E0],
+        },
+        Palette {
+            x: 0,
+            y: 1,
+            colors: vec![0x7C00],
+        },
+    ];
+
+    let image = VramImage::from_palettes(&palettes, 2);
+    assert_eq!((image.width, image.height), (4, 4));
+    assert_eq!(pixel(&image, 1, 1), [248, 0, 0]);
+    assert_eq!(pixel(&image, 2, 0), [0, 248, 0]);
+    assert_eq!(pixel(&image, 0, 3), [0, 0, 248]);
+    // Shorter palettes are padded with black
+    assert_eq!(pixel(&image, 3, 3), [0, 0, 0]);
+}
+
+#[test]
+fn test_encode_ppm() {
+    let image = VramImage::from_rgb15(2, 1, [0x001F, 0x7FFF]);
+    let data = image.encode(ImageFormat::Ppm).unwrap();
+    assert_eq!(&data[..11], b"P6\n2 1\n255\n");
+    assert_eq!(&data[11..], &[248, 0, 0, 248, 248, 248]);
+}
+
+#[test]
+fn test_encode_png_round_trip() {
+    let mut gpu = GPU::new();
+    gpu.write_vram(5, 7, 0x7C1F);
+    let image = gpu.export_vram();
+    let data = image.encode(ImageFormat::Png).unwrap();
+
+    let decoder = png::Decoder::new(std::io::Cursor::new(data));
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (1024, 512));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    assert_eq!(&pixels[..info.buffer_size()], &image.pixels[..]);
+}
+
+#[test]
+fn test_save_picks_format_from_extension() {
+    let image = VramImage::from_rgb15(1, 1, [0x7FFF]);
+
+    let file = tempfile::Builder::new().suffix(".ppm").tempfile().unwrap();
+    image.save(file.path()).unwrap();
+    assert!(std::fs::read(file.path()).unwrap().starts_with(b"P6\n"));
+
+    let file = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+    image.save(file.path()).unwrap();
+    assert!(std::fs::read(file.path()).unwrap().starts_with(b"\x89PNG"));
+
+    let result = image.save("vram.bmp");
+    assert!(matches!(
+        result,
+        Err(EmulatorError::Gpu(GpuError::ImageExport(_)))
+    ));
+}
@@ -445,17 +445,20 @@ fn test_mask_settings() {
 
     // Test check mask bit enabled
     gpu.write_gp0(0xE6000002); // Bit 1 = 1 (check mask)
-    assert!(!gpu.status.draw_pixels); // draw_pixels is inverted
+    assert!(gpu.status.draw_pixels);
+    assert_eq!(gpu.status() & (3 << 11), 1 << 12);
 
     // Test both enabled
     gpu.write_gp0(0xE6000003); // Both bits set
     assert!(gpu.status.set_mask_bit);
-    assert!(!gpu.status.draw_pixels);
+    assert!(gpu.status.draw_pixels);
+    assert_eq!(gpu.status() & (3 << 11), 3 << 11);
 
     // Test both disabled
     gpu.write_gp0(0xE6000000); // Both bits clear
     assert!(!gpu.status.set_mask_bit);
-    assert!(gpu.status.draw_pixels);
+    assert!(!gpu.status.draw_pixels);
+    assert_eq!(gpu.status() & (3 << 11), 0);
 }
 
 #[test]
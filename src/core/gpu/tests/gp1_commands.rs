@@ -128,13 +128,26 @@ fn test_gp1_display_area_start() {
 fn test_gp1_horizontal_display_range() {
     let mut gpu = GPU::new();
 
-    // Set horizontal range from 100 to 400 (width = 300)
-    gpu.write_gp1(0x06000064 | (0x190 << 12));
-    assert_eq!(gpu.display_area.width, 300);
+    // Standard BIOS range 0x260-0xC60 (2560 video cycles)
+    gpu.write_gp1(0x06000260 | (0xC60 << 12));
+    assert_eq!(gpu.display_range.x1, 0x260);
+    assert_eq!(gpu.display_range.x2, 0xC60);
 
-    // Test with different values
-    gpu.write_gp1(0x06000000 | (0x280 << 12));
+    // 320 mode: 8 cycles per pixel
+    assert_eq!(gpu.display_area.width, 320);
+
+    // 640 mode: 4 cycles per pixel
+    gpu.write_gp1(0x08000003);
     assert_eq!(gpu.display_area.width, 640);
+
+    // 368 mode: 7 cycles per pixel, rounded down to a multiple of 4
+    gpu.write_gp1(0x08000040);
+    assert_eq!(gpu.display_area.width, 364);
+
+    // Narrower range in 256 mode: (1000 / 10 + 2) & !3 = 100
+    gpu.write_gp1(0x08000000);
+    gpu.write_gp1(0x06000200 | (0x5E8 << 12));
+    assert_eq!(gpu.display_area.width, 100);
 }
 
 #[test]
@@ -277,12 +290,84 @@ fn test_gp1_display_mode_reverse_flag() {
 fn test_gp1_get_gpu_info() {
     let mut gpu = GPU::new();
 
-    // Test various info types (should not panic)
+    gpu.write_gp0(0xE2000000 | 0x03 | (0x05 << 5) | (0x07 << 10) | (0x09 << 15));
+    gpu.write_gp0(0xE3000000 | 16 | (32 << 10));
+    gpu.write_gp0(0xE4000000 | 639 | (479 << 10));
+    gpu.write_gp0(0xE5000000 | 100 | ((-50i32 as u32 & 0x7FF) << 11));
+
     gpu.write_gp1(0x10000002); // Texture window
+    assert_eq!(
+        gpu.read_gpuread(),
+        0x03 | (0x05 << 5) | (0x07 << 10) | (0x09 << 15)
+    );
+
     gpu.write_gp1(0x10000003); // Draw area top left
+    assert_eq!(gpu.read_gpuread(), 16 | (32 << 10));
+
     gpu.write_gp1(0x10000004); // Draw area bottom right
+    assert_eq!(gpu.read_gpuread(), 639 | (479 << 10));
+
     gpu.write_gp1(0x10000005); // Draw offset
+    assert_eq!(gpu.read_gpuread(), 100 | ((-50i32 as u32 & 0x7FF) << 11));
+
     gpu.write_gp1(0x10000007); // GPU version
+    assert_eq!(gpu.read_gpuread(), 2);
+
+    gpu.write_gp1(0x10000008);
+    assert_eq!(gpu.read_gpuread(), 0);
+}
+
+#[test]
+fn test_gp1_get_gpu_info_keeps_latch_for_empty_types() {
+    let mut gpu = GPU::new();
+
+    gpu.write_gp1(0x10000007);
+    assert_eq!(gpu.read_gpuread(), 2);
+
+    for info_type in [0x00, 0x01, 0x06, 0x09, 0x0F] {
+        gpu.write_gp1(0x10000000 | info_type);
+        assert_eq!(gpu.read_gpuread(), 2, "info type {:X}", info_type);
+    }
+
+    // Latch is stable across repeated reads
+    assert_eq!(gpu.read_gpuread(), 2);
+}
+
+#[test]
+fn test_gp1_get_gpu_info_mirrors() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE3000000 | 5 | (6 << 10));
+
+    // New GPU: info types mirror every 16
+    gpu.write_gp1(0x10000013);
+    assert_eq!(gpu.read_gpuread(), 5 | (6 << 10));
+
+    // GP1(11h-1Fh) are mirrors of GP1(10h)
+    gpu.write_gp1(0x10000007);
+    gpu.write_gp1(0x1F000003);
+    assert_eq!(gpu.read_gpuread(), 5 | (6 << 10));
+}
+
+#[test]
+fn test_gp1_get_gpu_info_old_gpu() {
+    let mut gpu = GPU::new();
+    gpu.set_version(GpuVersion::Old);
+    gpu.write_gp0(0xE4000000 | 1023 | (1023 << 10));
+
+    // Old GPU only reports 9-bit draw area Y coordinates
+    gpu.write_gp1(0x10000004);
+    assert_eq!(gpu.read_gpuread(), 1023 | (511 << 10));
+
+    // Old GPU mirrors info types every 8: 0x0C acts as 0x04
+    gpu.write_gp1(0x10000000);
+    gpu.write_gp1(0x1000000C);
+    assert_eq!(gpu.read_gpuread(), 1023 | (511 << 10));
+
+    // Types 7 and 8 return nothing on the old GPU
+    gpu.write_gp1(0x10000007);
+    assert_eq!(gpu.read_gpuread(), 1023 | (511 << 10));
+    gpu.write_gp1(0x10000008);
+    assert_eq!(gpu.read_gpuread(), 1023 | (511 << 10));
 }
 
 #[test]
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Interlace tests
+//! Tests field tracking, GPUSTAT bits 13/31, displayed field skipping and
+//! deinterlaced framebuffer output in 480-line mode
+
+use super::super::*;
+
+/// GP1(08h): 640x480, NTSC, 15-bit, interlaced
+const MODE_480I: u32 = 0x0800_0027;
+
+/// GP1(08h): 320x240, NTSC, 15-bit, interlaced
+const MODE_240I: u32 = 0x0800_0021;
+
+/// Draw a 4x4 white rectangle at the top-left of VRAM
+fn draw_rect(gpu: &mut GPU) {
+    gpu.write_gp0(0x60FF_FFFF);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0004_0004);
+}
+
+#[test]
+fn test_field_bit_set_when_not_interlaced() {
+    let mut gpu = GPU::new();
+
+    assert_ne!(gpu.status() & (1 << 13), 0);
+
+    gpu.vblank_callback();
+    assert_ne!(gpu.status() & (1 << 13), 0);
+    assert!(!gpu.interlace_field());
+}
+
+#[test]
+fn test_field_toggles_every_frame_when_interlaced() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    assert!(!gpu.interlace_field());
+    assert_eq!(gpu.status() & (1 << 13), 0);
+
+    gpu.vblank_callback();
+    assert!(gpu.interlace_field());
+    assert_ne!(gpu.status() & (1 << 13), 0);
+
+    gpu.vblank_callback();
+    assert!(!gpu.interlace_field());
+    assert_eq!(gpu.status() & (1 << 13), 0);
+}
+
+#[test]
+fn test_field_toggles_on_ticked_vblank() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    let frame = GPU::SCANLINES_PER_FRAME as u32 * GPU::DOTS_PER_SCANLINE as u32;
+    let cycles_to_vblank = GPU::VBLANK_START as u32 * GPU::DOTS_PER_SCANLINE as u32;
+
+    gpu.tick(cycles_to_vblank);
+    assert!(gpu.interlace_field());
+
+    gpu.tick(frame);
+    assert!(!gpu.interlace_field());
+}
+
+#[test]
+fn test_disabling_interlace_resets_field() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+    gpu.vblank_callback();
+    assert!(gpu.interlace_field());
+
+    gpu.write_gp1(0x0800_0001);
+    assert!(!gpu.interlace_field());
+}
+
+#[test]
+fn test_odd_line_bit_follows_field_in_480i() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    // Even field: bit 31 stays 0 on every scanline
+    for scanline in 0..4 {
+        gpu.scanline = scanline;
+        assert_eq!(gpu.status() & (1 << 31), 0, "scanline {}", scanline);
+    }
+
+    gpu.vblank_callback();
+
+    // Odd field: bit 31 is 0 in VBlank, then 1 on every scanline
+    assert_eq!(gpu.status() & (1 << 31), 0);
+
+    gpu.in_vblank = false;
+    for scanline in 0..4 {
+        gpu.scanline = scanline;
+        assert_ne!(gpu.status() & (1 << 31), 0, "scanline {}", scanline);
+    }
+}
+
+#[test]
+fn test_drawing_skips_displayed_field() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    // Even field displayed: only odd lines are drawn
+    draw_rect(&mut gpu);
+    for y in 0..4 {
+        let drawn = gpu.read_vram(0, y) != 0;
+        assert_eq!(drawn, y % 2 == 1, "line {}", y);
+    }
+
+    // Odd field displayed: only even lines are drawn
+    gpu.vblank_callback();
+    gpu.vram.fill(0);
+    draw_rect(&mut gpu);
+    for y in 0..4 {
+        let drawn = gpu.read_vram(0, y) != 0;
+        assert_eq!(drawn, y % 2 == 0, "line {}", y);
+    }
+}
+
+#[test]
+fn test_skipped_field_follows_odd_display_start() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    // Display starts at VRAM row 1: the even field displayed is on odd rows,
+    // so only even rows are drawn
+    gpu.write_gp1(0x0500_0000 | (1 << 10));
+    draw_rect(&mut gpu);
+    for y in 0..4 {
+        let drawn = gpu.read_vram(0, y) != 0;
+        assert_eq!(drawn, y % 2 == 0, "line {}", y);
+    }
+
+    // Odd field displayed: it lies on even rows, so odd rows are drawn
+    gpu.vblank_callback();
+    gpu.vram.fill(0);
+    draw_rect(&mut gpu);
+    for y in 0..4 {
+        let drawn = gpu.read_vram(0, y) != 0;
+        assert_eq!(drawn, y % 2 == 1, "line {}", y);
+    }
+
+    // Moving the display start back to an even row flips the rows again
+    gpu.write_gp1(0x0500_0000);
+    gpu.vram.fill(0);
+    draw_rect(&mut gpu);
+    for y in 0..4 {
+        let drawn = gpu.read_vram(0, y) != 0;
+        assert_eq!(drawn, y % 2 == 0, "line {}", y);
+    }
+}
+
+#[test]
+fn test_draw_to_display_draws_all_lines() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+
+    // GP0(E1h) bit 10: drawing to display area allowed
+    gpu.write_gp0(0xE100_0400);
+    draw_rect(&mut gpu);
+
+    for y in 0..4 {
+        assert_ne!(gpu.read_vram(0, y), 0, "line {}", y);
+    }
+}
+
+#[test]
+fn test_240_line_interlace_draws_all_lines() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_240I);
+
+    draw_rect(&mut gpu);
+
+    for y in 0..4 {
+        assert_ne!(gpu.read_vram(0, y), 0, "line {}", y);
+    }
+}
+
+/// Set up a 480i display with red even lines and blue odd lines
+fn striped_480i() -> GPU {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(MODE_480I);
+    gpu.display_area.width = 4;
+    gpu.display_area.height = 2;
+    for y in 0..4 {
+        let color = if y % 2 == 0 { 0x001F } else { 0x7C00 };
+        for x in 0..4 {
+            gpu.write_vram(x, y, color);
+        }
+    }
+    gpu
+}
+
+/// Read the RGB triplet of the first pixel of an output row
+fn row_color(framebuffer: &Framebuffer, y: usize) -> (u8, u8, u8) {
+    let i = y * framebuffer.width * 3;
+    let p = &framebuffer.pixels;
+    (p[i], p[i + 1], p[i + 2])
+}
+
+#[test]
+fn test_framebuffer_doubles_height_in_480i() {
+    let mut gpu = striped_480i();
+    let framebuffer = gpu.get_framebuffer();
+
+    assert_eq!((framebuffer.width, framebuffer.height), (4, 4));
+    assert_eq!(framebuffer.pixels.len(), 4 * 4 * 3);
+}
+
+#[test]
+fn test_deinterlace_weave() {
+    let mut gpu = striped_480i();
+    let framebuffer = gpu.get_framebuffer();
+
+    for y in 0..4 {
+        let expected = if y % 2 == 0 { (248, 0, 0) } else { (0, 0, 248) };
+        assert_eq!(row_color(&framebuffer, y), expected, "row {}", y);
+    }
+}
+
+#[test]
+fn test_deinterlace_bob() {
+    let mut gpu = striped_480i();
+    gpu.set_deinterlace_mode(Deinterlace::Bob);
+
+    // Even field: every row shows the red even lines
+    let framebuffer = gpu.get_framebuffer();
+    for y in 0..4 {
+        assert_eq!(row_color(&framebuffer, y), (248, 0, 0), "row {}", y);
+    }
+
+    // Odd field: every row shows the blue odd lines
+    gpu.vblank_callback();
+    let framebuffer = gpu.get_framebuffer();
+    for y in 0..4 {
+        assert_eq!(row_color(&framebuffer, y), (0, 0, 248), "row {}", y);
+    }
+}
+
+#[test]
+fn test_deinterlace_blend() {
+    let mut gpu = striped_480i();
+    gpu.set_deinterlace_mode(Deinterlace::Blend);
+
+    let framebuffer = gpu.get_framebuffer();
+    for y in 0..4 {
+        assert_eq!(row_color(&framebuffer, y), (124, 0, 124), "row {}", y);
+    }
+}
+
+#[test]
+fn test_deinterlace_mode_from_str() {
+    assert_eq!("weave".parse(), Ok(Deinterlace::Weave));
+    assert_eq!("Bob".parse(), Ok(Deinterlace::Bob));
+    assert_eq!("BLEND".parse(), Ok(Deinterlace::Blend));
+    assert!("linear".parse::<Deinterlace>().is_err());
+}
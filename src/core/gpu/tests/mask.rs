@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mask bit tests
+//! Tests that GP0(E6h) set/check mask settings apply to every draw path
+
+use super::super::*;
+
+/// Protect every other pixel of the top-left 16x16 block with bit 15
+fn protect_checkerboard(gpu: &mut GPU) {
+    for y in 0..16 {
+        for x in 0..16 {
+            if (x + y) % 2 == 0 {
+                gpu.write_vram(x, y, 0x8000);
+            }
+        }
+    }
+}
+
+/// Assert protected pixels survived and the rest hold `expected`
+fn assert_checkerboard(gpu: &mut GPU, width: u16, height: u16, expected: u16) {
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = gpu.read_vram(x, y);
+            if (x + y) % 2 == 0 {
+                assert_eq!(pixel, 0x8000, "protected pixel ({}, {}) overwritten", x, y);
+            } else {
+                assert_eq!(pixel, expected, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+}
+
+/// Fill a 15-bit texture page at (512, 0) with a single texel value
+fn setup_texture(gpu: &mut GPU, texel: u16) {
+    for y in 0..32 {
+        for x in 512..544 {
+            gpu.write_vram(x, y, texel);
+        }
+    }
+}
+
+#[test]
+fn test_mask_check_monochrome_triangle() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000002);
+
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x001F);
+}
+
+#[test]
+fn test_mask_set_and_check_monochrome_quad() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0x280000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000020);
+    gpu.write_gp0(0x00200000);
+    gpu.write_gp0(0x00200020);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+}
+
+#[test]
+fn test_mask_set_without_check_overwrites() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000001);
+
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    for y in 0..16 {
+        for x in 0..16 {
+            assert_eq!(gpu.read_vram(x, y), 0x801F);
+        }
+    }
+}
+
+#[test]
+fn test_mask_semi_transparent_triangle() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    // Average of black background and red: 31 >> 1 = 15
+    gpu.write_gp0(0x220000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x800F);
+}
+
+#[test]
+fn test_mask_gradient_triangle() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0x300000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x000000FF);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x000000FF);
+    gpu.write_gp0(0x00400000);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+}
+
+#[test]
+fn test_mask_textured_triangle() {
+    let mut gpu = GPU::new();
+    setup_texture(&mut gpu, 0x001F);
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    let texpage = 8 | (2 << 7); // Page X = 512, 15-bit
+    gpu.write_gp0(0x24808080);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0((texpage << 16) | 0x0008);
+    gpu.write_gp0(0x00400000);
+    gpu.write_gp0(0x00000800);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+}
+
+#[test]
+fn test_mask_lines() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    // Monochrome line along row 0
+    gpu.write_gp0(0x400000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x0000000F);
+    assert_checkerboard(&mut gpu, 16, 1, 0x801F);
+
+    // Shaded line along row 1
+    gpu.write_gp0(0x5000FF00);
+    gpu.write_gp0(0x00010000);
+    gpu.write_gp0(0x0000FF00);
+    gpu.write_gp0(0x0001000F);
+    for x in 0..16 {
+        let expected = if x % 2 == 1 { 0x8000 } else { 0x83E0 };
+        assert_eq!(gpu.read_vram(x, 1), expected);
+    }
+}
+
+#[test]
+fn test_mask_rectangles() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    // Variable-size monochrome rectangle
+    gpu.write_gp0(0x600000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+
+    // Semi-transparent rectangle blends only unprotected pixels:
+    // (31 + 31) >> 1 = 31, so the result is unchanged
+    gpu.write_gp0(0x620000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+}
+
+#[test]
+fn test_mask_textured_rectangle() {
+    let mut gpu = GPU::new();
+    setup_texture(&mut gpu, 0x03E0);
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE1000000 | 8 | (2 << 7)); // Page X = 512, 15-bit
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0x64808080);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x83E0);
+}
+
+#[test]
+fn test_mask_cpu_to_vram_transfer() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0xA0000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    for _ in 0..(16 * 16 / 2) {
+        gpu.write_gp0(0x7C007C00);
+    }
+
+    assert_checkerboard(&mut gpu, 16, 16, 0xFC00);
+}
+
+#[test]
+fn test_mask_vram_to_vram_copy() {
+    let mut gpu = GPU::new();
+    for y in 0..16 {
+        for x in 100..116 {
+            gpu.write_vram(x, y, 0x001F);
+        }
+    }
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0x80000000);
+    gpu.write_gp0(0x00000064); // Source (100, 0)
+    gpu.write_gp0(0x00000000); // Destination (0, 0)
+    gpu.write_gp0(0x00100010);
+
+    assert_checkerboard(&mut gpu, 16, 16, 0x801F);
+}
+
+#[test]
+fn test_mask_ignored_by_fill() {
+    let mut gpu = GPU::new();
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0xE6000003);
+
+    gpu.write_gp0(0x020000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    // Fill bypasses both check and set
+    for y in 0..16 {
+        for x in 0..16 {
+            assert_eq!(gpu.read_vram(x, y), 0x001F);
+        }
+    }
+}
+
+#[test]
+fn test_mask_settings_cleared_by_reset() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE6000003);
+    gpu.write_gp1(0x00000000);
+
+    protect_checkerboard(&mut gpu);
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    for y in 0..16 {
+        for x in 0..16 {
+            assert_eq!(gpu.read_vram(x, y), 0x001F);
+        }
+    }
+}
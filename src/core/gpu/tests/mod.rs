@@ -16,16 +16,44 @@
 //! GPU module tests
 //!
 //! Tests are organized into the following modules:
+//! - `backend`: Rendering backend selection, null and recording backends
 //! - `basic`: Basic GPU functionality (initialization, reset, register access)
+//! - `capture`: Frame capture, replay and capture files
+//! - `disassembler`: GP0/GP1 command decoding and disassembly
+//! - `draw_timing`: Draw cost estimation, command FIFO and busy flags
+//! - `export`: VRAM, texture page and palette image export
 //! - `vram`: VRAM operations (read, write, transfers, addressing)
 //! - `gp0_commands`: GP0 drawing commands and command buffering
 //! - `gp1_commands`: GP1 control commands (display control, DMA, etc.)
+//! - `interlace`: Interlaced field tracking, field skipping and deinterlacing
+//! - `mask`: Mask bit set/check across all draw paths
+//! - `precision`: Precision geometry (sub-pixel vertices, perspective-correct texturing)
 //! - `rendering`: Rendering primitives (triangles, lines, gradients)
+//! - `stats`: Per-frame draw counters and transfer statistics
+//! - `texture_cache`: Texture cache staleness and invalidation
+//! - `texture_replacement`: Texture dumping, hashing and replacement while upscaling
+//! - `threaded`: Render thread output and synchronization points
 //! - `timing`: GPU timing and synchronization (VBlank, HBlank, scanlines)
+//! - `upscale`: Internal resolution upscaling and shadow VRAM coherency
+//! - `widescreen`: Widescreen surfaces, their coherency and frame output
 
+mod backend;
 mod basic;
+mod capture;
+mod disassembler;
+mod draw_timing;
+mod export;
 mod gp0_commands;
 mod gp1_commands;
+mod interlace;
+mod mask;
+mod precision;
 mod rendering;
+mod stats;
+mod texture_cache;
+mod texture_replacement;
+mod threaded;
 mod timing;
+mod upscale;
 mod vram;
+mod widescreen;
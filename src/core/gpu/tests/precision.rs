@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precision geometry tests
+//!
+//! Tests for the precision vertex cache lookup on GP0 words and the
+//! sub-pixel / perspective-correct rasterization paths.
+
+use super::super::*;
+use crate::core::precision::PrecisionVertex;
+
+const RED: u16 = 0x001F;
+
+/// Packet base address in RAM
+const PACKET: u32 = 0x0001_0000;
+
+fn vertex_word(x: i16, y: i16) -> u32 {
+    ((y as u16 as u32) << 16) | (x as u16 as u32)
+}
+
+/// Send words as if DMA read them from consecutive RAM addresses
+fn send_packet(gpu: &mut GPU, words: &[u32]) {
+    for (i, &word) in words.iter().enumerate() {
+        gpu.write_gp0_from_address(word, PACKET + (i as u32) * 4);
+    }
+}
+
+/// Record precise vertices for the given packet word indices
+fn record(gpu: &mut GPU, words: &[u32], vertices: &[(usize, PrecisionVertex)]) {
+    for &(index, vertex) in vertices {
+        gpu.record_precision_vertex(PACKET + (index as u32) * 4, words[index], vertex);
+    }
+}
+
+/// Monochrome triangle whose integer vertices miss pixel row 10
+fn flat_triangle() -> [u32; 4] {
+    [
+        0x2000_00FF,
+        vertex_word(10, 11),
+        vertex_word(30, 11),
+        vertex_word(20, 30),
+    ]
+}
+
+/// Precise vertices for `flat_triangle`, slightly above row 11
+fn flat_triangle_precise() -> [(usize, PrecisionVertex); 3] {
+    [
+        (1, PrecisionVertex::new(10.0, 10.0, 100.0)),
+        (2, PrecisionVertex::new(30.0, 10.0, 100.0)),
+        (3, PrecisionVertex::new(20.0, 30.0, 100.0)),
+    ]
+}
+
+#[test]
+fn test_precision_disabled_by_default() {
+    let mut gpu = GPU::new();
+    let words = flat_triangle();
+
+    assert!(!gpu.precision_enabled());
+    record(&mut gpu, &words, &flat_triangle_precise());
+    assert!(gpu.precision_cache.is_empty());
+
+    send_packet(&mut gpu, &words);
+    assert_eq!(gpu.read_vram(20, 10), 0);
+    assert_eq!(gpu.read_vram(20, 11), RED);
+}
+
+#[test]
+fn test_precise_vertices_used_for_polygon() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    let words = flat_triangle();
+
+    record(&mut gpu, &words, &flat_triangle_precise());
+    send_packet(&mut gpu, &words);
+
+    assert_eq!(gpu.read_vram(20, 10), RED);
+}
+
+#[test]
+fn test_partial_precision_falls_back_to_integer() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    let words = flat_triangle();
+
+    // Only two of three vertices have precise positions
+    record(&mut gpu, &words, &flat_triangle_precise()[..2]);
+    send_packet(&mut gpu, &words);
+
+    assert_eq!(gpu.read_vram(20, 10), 0);
+    assert_eq!(gpu.read_vram(20, 11), RED);
+}
+
+#[test]
+fn test_stale_cache_entry_ignored() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    let words = flat_triangle();
+    record(&mut gpu, &words, &flat_triangle_precise());
+
+    // Memory was overwritten with a different vertex since the store
+    let mut changed = words;
+    changed[3] = vertex_word(21, 30);
+    send_packet(&mut gpu, &changed);
+
+    assert_eq!(gpu.read_vram(20, 10), 0);
+}
+
+#[test]
+fn test_cpu_gp0_writes_ignore_precision() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    let words = flat_triangle();
+    record(&mut gpu, &words, &flat_triangle_precise());
+
+    // GP0 port writes have no source address
+    for word in words {
+        gpu.write_gp0(word);
+    }
+
+    assert_eq!(gpu.read_vram(20, 10), 0);
+}
+
+#[test]
+fn test_precision_follows_fifo_position() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+
+    // A draw mode command ahead of the polygon shifts its FIFO position
+    let words = [
+        0xE100_0000,
+        0x2000_00FF,
+        vertex_word(10, 11),
+        vertex_word(30, 11),
+        vertex_word(20, 30),
+    ];
+    record(
+        &mut gpu,
+        &words,
+        &[
+            (2, PrecisionVertex::new(10.0, 10.0, 100.0)),
+            (3, PrecisionVertex::new(30.0, 10.0, 100.0)),
+            (4, PrecisionVertex::new(20.0, 30.0, 100.0)),
+        ],
+    );
+    send_packet(&mut gpu, &words);
+
+    assert_eq!(gpu.read_vram(20, 10), RED);
+    assert!(gpu.precision_words.is_empty());
+}
+
+#[test]
+fn test_precise_vertices_use_draw_offset() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    gpu.draw_offset = (100, 50);
+    let words = flat_triangle();
+
+    record(&mut gpu, &words, &flat_triangle_precise());
+    send_packet(&mut gpu, &words);
+
+    assert_eq!(gpu.read_vram(120, 60), RED);
+    assert_eq!(gpu.read_vram(20, 10), 0);
+}
+
+#[test]
+fn test_disabling_precision_clears_cache() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    record(&mut gpu, &flat_triangle(), &flat_triangle_precise());
+    assert_eq!(gpu.precision_cache.len(), 3);
+
+    gpu.set_precision_enabled(false);
+    assert!(gpu.precision_cache.is_empty());
+}
+
+#[test]
+fn test_cache_ignores_address_mirrors() {
+    let mut gpu = GPU::new();
+    gpu.set_precision_enabled(true);
+    let vertex = PrecisionVertex::new(1.5, 2.5, 10.0);
+
+    gpu.record_precision_vertex(0x8001_0004, 0x1234, vertex);
+
+    assert_eq!(
+        gpu.precision_cache.lookup(0x0001_0004, 0x1234),
+        Some(vertex)
+    );
+    assert_eq!(
+        gpu.precision_cache.lookup(0xA001_0004, 0x1234),
+        Some(vertex)
+    );
+    assert_eq!(gpu.precision_cache.lookup(0x0001_0008, 0x1234), None);
+}
+
+#[test]
+fn test_precise_textured_triangle_perspective_correct() {
+    let mut vram = vec![0u16; GPU::VRAM_SIZE];
+    let mut rasterizer = Rasterizer::new();
+
+    // 15-bit texture: column u holds color u
+    for u in 0..64u16 {
+        vram[u as usize] = u;
+    }
+    let info = TextureInfo {
+        page_x: 0,
+        page_y: 0,
+        clut_x: 0,
+        clut_y: 0,
+        depth: TextureDepth::T15Bit,
+    };
+    let window = TextureWindow::default();
+
+    // Left edge is 3x further away than the right edge
+    let left = 0.0;
+    let right = 60.0;
+    rasterizer.draw_textured_triangle_precise(
+        &mut vram,
+        (100.0 + left, 300.0, 300.0),
+        (0, 0),
+        (100.0 + right, 300.0, 100.0),
+        (60, 0),
+        (100.0 + left, 310.0, 300.0),
+        (0, 0),
+        &info,
+        &window,
+        (128, 128, 128),
+    );
+
+    // Halfway across the screen, perspective correction samples the texture
+    // three quarters of the way in (u = 60 * (0.5/100) / (0.5/300 + 0.5/100) = 45)
+    let sample = vram[300 * 1024 + 130] & 0x3F;
+    assert!((44..=45).contains(&sample), "sampled u={}", sample);
+}
+
+#[test]
+fn test_precise_textured_triangle_without_depth_is_affine() {
+    let mut vram = vec![0u16; GPU::VRAM_SIZE];
+    let mut rasterizer = Rasterizer::new();
+
+    for u in 0..64u16 {
+        vram[u as usize] = u;
+    }
+    let info = TextureInfo {
+        page_x: 0,
+        page_y: 0,
+        clut_x: 0,
+        clut_y: 0,
+        depth: TextureDepth::T15Bit,
+    };
+    let window = TextureWindow::default();
+
+    rasterizer.draw_textured_triangle_precise(
+        &mut vram,
+        (100.0, 300.0, 0.0),
+        (0, 0),
+        (160.0, 300.0, 100.0),
+        (60, 0),
+        (100.0, 310.0, 0.0),
+        (0, 0),
+        &info,
+        &window,
+        (128, 128, 128),
+    );
+
+    let sample = vram[300 * 1024 + 130] & 0x3F;
+    assert!((29..=30).contains(&sample), "sampled u={}", sample);
+}
+
+#[test]
+fn test_precise_gradient_triangle() {
+    let mut vram = vec![0u16; GPU::VRAM_SIZE];
+    let mut rasterizer = Rasterizer::new();
+
+    rasterizer.draw_gradient_triangle_precise(
+        &mut vram,
+        (10.5, 10.5),
+        (255, 0, 0),
+        (40.5, 10.5),
+        (255, 0, 0),
+        (25.5, 40.5),
+        (255, 0, 0),
+    );
+
+    assert_eq!(vram[10 * 1024 + 20], 0);
+    assert_eq!(vram[20 * 1024 + 25] & 0x1F, 31);
+}
@@ -186,7 +186,7 @@ fn test_framebuffer_generation() {
     gpu.write_vram(10, 10, 0x7FFF); // White in 15-bit RGB
 
     // Generate framebuffer
-    let fb = gpu.get_framebuffer();
+    let fb = gpu.get_framebuffer().pixels;
     assert_eq!(fb.len(), 320 * 240 * 3);
 
     // Check the white pixel was converted correctly
@@ -202,6 +202,207 @@ fn test_framebuffer_generation() {
     assert_eq!(fb[black_index + 2], 0);
 }
 
+#[test]
+fn test_framebuffer_reports_size_and_aspect() {
+    let mut gpu = GPU::new();
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (320, 240));
+    assert_eq!(fb.aspect_ratio, Framebuffer::ASPECT_4_3);
+}
+
+#[test]
+fn test_framebuffer_widescreen_stretch_fallback() {
+    let mut gpu = GPU::new();
+    gpu.display_area = DisplayArea {
+        x: 0,
+        y: 0,
+        width: 300,
+        height: 2,
+    };
+    gpu.set_widescreen(true);
+
+    // Columns 0 and 299 are the edges of the display area
+    gpu.write_vram(0, 0, 0x001F);
+    gpu.write_vram(299, 0, 0x7C00);
+    gpu.write_vram(300, 0, 0x7FFF); // Outside display area
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (400, 2));
+    assert_eq!(fb.aspect_ratio, Framebuffer::ASPECT_16_9);
+    assert_eq!(fb.pixels.len(), 400 * 2 * 3);
+
+    // The default drawing area is too wide for a widescreen surface, so
+    // display pixels cover 4/3 output pixels on average (nearest neighbour)
+    assert_eq!(&fb.pixels[0..3], &[248, 0, 0]);
+    assert_eq!(&fb.pixels[3..6], &[248, 0, 0]);
+    assert_eq!(&fb.pixels[6..9], &[0, 0, 0]);
+    assert_eq!(&fb.pixels[399 * 3..400 * 3], &[0, 0, 248]);
+    assert_eq!(&fb.pixels[398 * 3..399 * 3], &[0, 0, 0]);
+
+    gpu.set_widescreen(false);
+    assert_eq!(gpu.get_framebuffer().width, 300);
+}
+
+#[test]
+fn test_framebuffer_overscan_places_display_range() {
+    let mut gpu = GPU::new();
+    gpu.set_crop_mode(CropMode::Overscan);
+
+    // 320 mode (8 cycles per pixel): 10 pixels right of the visible start,
+    // 5 lines below it, 8 pixels x 3 lines large
+    gpu.write_gp1(0x0600_0000 | ((568 + 64) << 12) | 568);
+    gpu.write_gp1(0x0700_0000 | ((21 + 3) << 10) | 21);
+    assert_eq!((gpu.display_area.width, gpu.display_area.height), (8, 3));
+
+    gpu.write_vram(0, 0, 0x7FFF);
+    gpu.write_vram(7, 2, 0x7FFF);
+    gpu.write_vram(8, 2, 0x7FFF); // Outside the display area
+    gpu.write_vram(0, 3, 0x7FFF); // Outside the display area
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (350, 240));
+
+    let pixel = |x: usize, y: usize| {
+        let i = (y * fb.width + x) * 3;
+        fb.pixels[i]
+    };
+    assert_eq!(pixel(10, 5), 248);
+    assert_eq!(pixel(17, 7), 248);
+    assert_eq!(pixel(9, 5), 0);
+    assert_eq!(pixel(18, 7), 0);
+    assert_eq!(pixel(10, 8), 0);
+    assert_eq!(pixel(10, 4), 0);
+}
+
+#[test]
+fn test_framebuffer_overscan_size_per_mode() {
+    let mut gpu = GPU::new();
+    gpu.set_crop_mode(CropMode::Overscan);
+
+    // NTSC 256 mode: 2800 / 10 cycles
+    gpu.write_gp1(0x0800_0000);
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (280, 240));
+
+    // PAL 320 mode: 2795 / 8 cycles, 288 lines
+    gpu.write_gp1(0x0800_0009);
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (349, 288));
+
+    // NTSC 640x480 interlaced: both fields
+    gpu.write_gp1(0x0800_0027);
+    let fb = gpu.get_framebuffer();
+    assert_eq!((fb.width, fb.height), (700, 480));
+}
+
+#[test]
+fn test_framebuffer_pixel_aspect_ratio() {
+    let mut gpu = GPU::new();
+
+    // 320 mode pixels are slightly narrower than tall
+    let fb = gpu.get_framebuffer();
+    assert!((fb.pixel_aspect_ratio - 0.914).abs() < 0.001);
+
+    // 256 mode pixels are wider
+    gpu.write_gp1(0x0800_0000);
+    let fb = gpu.get_framebuffer();
+    assert!((fb.pixel_aspect_ratio - 1.143).abs() < 0.001);
+
+    // The full overscan picture is always shown at 4:3
+    gpu.set_crop_mode(CropMode::Overscan);
+    for mode in [
+        0x0800_0000,
+        0x0800_0001,
+        0x0800_0040,
+        0x0800_0027,
+        0x0800_000B,
+    ] {
+        gpu.write_gp1(mode);
+        let fb = gpu.get_framebuffer();
+        assert!(
+            (fb.display_aspect_ratio() - Framebuffer::ASPECT_4_3).abs() < 0.01,
+            "mode {:08X}: {}",
+            mode,
+            fb.display_aspect_ratio()
+        );
+    }
+
+    // Widescreen output keeps the pixel shape and widens the frame to 16:9
+    gpu.write_gp1(0x0800_0001);
+    gpu.set_widescreen(true);
+    let fb = gpu.get_framebuffer();
+    assert!((fb.display_aspect_ratio() - Framebuffer::ASPECT_16_9).abs() < 0.01);
+}
+
+#[test]
+fn test_framebuffer_24bit_decoding() {
+    let mut gpu = GPU::new();
+    gpu.display_area = DisplayArea {
+        x: 0,
+        y: 0,
+        width: 4,
+        height: 1,
+    };
+    gpu.display_mode.display_area_color_depth = ColorDepth::C24Bit;
+
+    // Bytes 11 22 33 | 44 55 66 | 77 88 99 | AA BB CC packed little-endian
+    for (i, halfword) in [0x2211, 0x4433, 0x6655, 0x8877, 0xAA99, 0xCCBB]
+        .into_iter()
+        .enumerate()
+    {
+        gpu.write_vram(i as u16, 0, halfword);
+    }
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!(fb.width, 4);
+    assert_eq!(
+        fb.pixels,
+        vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC]
+    );
+}
+
+#[test]
+fn test_framebuffer_24bit_start_offset() {
+    let mut gpu = GPU::new();
+    gpu.display_area = DisplayArea {
+        x: 101,
+        y: 7,
+        width: 2,
+        height: 1,
+    };
+    gpu.display_mode.display_area_color_depth = ColorDepth::C24Bit;
+
+    // Start X is in halfwords: first pixel begins at byte 202
+    gpu.write_vram(100, 7, 0xFFFF); // Before the display start
+    gpu.write_vram(101, 7, 0x0201);
+    gpu.write_vram(102, 7, 0x0403);
+    gpu.write_vram(103, 7, 0x0605);
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!(&fb.pixels[..], &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+}
+
+#[test]
+fn test_framebuffer_24bit_wraps_row() {
+    let mut gpu = GPU::new();
+    gpu.display_area = DisplayArea {
+        x: 1023,
+        y: 0,
+        width: 1,
+        height: 1,
+    };
+    gpu.display_mode.display_area_color_depth = ColorDepth::C24Bit;
+
+    // Pixel straddles the end of the row and wraps to column 0
+    gpu.write_vram(1023, 0, 0x2010);
+    gpu.write_vram(0, 0, 0x0030);
+    gpu.write_vram(0, 1, 0x00FF); // Next row must not be read
+
+    let fb = gpu.get_framebuffer();
+    assert_eq!(&fb.pixels[..], &[0x10, 0x20, 0x30]);
+}
+
 #[test]
 fn test_framebuffer_color_conversion() {
     let mut gpu = GPU::new();
@@ -215,14 +416,14 @@ fn test_framebuffer_color_conversion() {
 
     // Test red
     gpu.write_vram(0, 0, 0x001F); // Pure red in 15-bit
-    let fb = gpu.get_framebuffer();
+    let fb = gpu.get_framebuffer().pixels;
     assert_eq!(fb[0], 248); // R
     assert_eq!(fb[1], 0); // G
     assert_eq!(fb[2], 0); // B
 
     // Test green
     gpu.write_vram(1, 0, 0x03E0); // Pure green in 15-bit
-    let fb = gpu.get_framebuffer();
+    let fb = gpu.get_framebuffer().pixels;
     let idx = 3;
     assert_eq!(fb[idx], 0); // R
     assert_eq!(fb[idx + 1], 248); // G
@@ -230,7 +431,7 @@ fn test_framebuffer_color_conversion() {
 
     // Test blue
     gpu.write_vram(2, 0, 0x7C00); // Pure blue in 15-bit
-    let fb = gpu.get_framebuffer();
+    let fb = gpu.get_framebuffer().pixels;
     let idx = 6;
     assert_eq!(fb[idx], 0); // R
     assert_eq!(fb[idx + 1], 0); // G
@@ -591,3 +792,156 @@ fn test_shaded_polyline_rendering() {
     assert_ne!(gpu.read_vram(30, 10), 0); // On first segment
     assert_ne!(gpu.read_vram(50, 30), 0); // On second segment
 }
+
+/// Draw a flat-colored Gouraud triangle covering the top-left corner of VRAM
+fn draw_shaded_corner(gpu: &mut GPU, r: u32, g: u32, b: u32) {
+    let color = r | (g << 8) | (b << 16);
+    gpu.write_gp0(0x30000000 | color);
+    gpu.write_gp0(0x00000000); // (0, 0)
+    gpu.write_gp0(color);
+    gpu.write_gp0(0x00000040); // (64, 0)
+    gpu.write_gp0(color);
+    gpu.write_gp0(0x00400000); // (0, 64)
+}
+
+/// Read the 5-bit red channel of the top-left 4x4 block
+fn red_block(gpu: &mut GPU) -> [[u16; 4]; 4] {
+    let mut block = [[0; 4]; 4];
+    for (y, row) in block.iter_mut().enumerate() {
+        for (x, red) in row.iter_mut().enumerate() {
+            *red = gpu.read_vram(x as u16, y as u16) & 0x1F;
+        }
+    }
+    block
+}
+
+#[test]
+fn test_dithering_gouraud_golden_pixels() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000200); // Dither enabled
+
+    // 104 sits on a 5-bit boundary (104 >> 3 = 13), so the dither
+    // matrix sign decides between 12 and 13
+    draw_shaded_corner(&mut gpu, 104, 104, 104);
+
+    assert_eq!(
+        red_block(&mut gpu),
+        [
+            [12, 13, 12, 13],
+            [13, 12, 13, 12],
+            [12, 13, 12, 13],
+            [13, 12, 13, 12],
+        ]
+    );
+
+    // All channels are dithered alike
+    let pixel = gpu.read_vram(0, 0);
+    assert_eq!(pixel, 12 | (12 << 5) | (12 << 10));
+}
+
+#[test]
+fn test_dithering_matrix_offsets() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000200);
+
+    // 101 only reaches 104 (13) with the +3 offsets; the -4 offsets drop
+    // it to 97, which still truncates to 12
+    draw_shaded_corner(&mut gpu, 101, 0, 0);
+
+    assert_eq!(
+        red_block(&mut gpu),
+        [
+            [12, 12, 12, 12],
+            [12, 12, 13, 12],
+            [12, 12, 12, 12],
+            [13, 12, 12, 12],
+        ]
+    );
+}
+
+#[test]
+fn test_dithering_clamps_channels() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000200);
+
+    draw_shaded_corner(&mut gpu, 255, 0, 0);
+
+    // Neither end of the range wraps around
+    for y in 0..4 {
+        for x in 0..4 {
+            assert_eq!(gpu.read_vram(x, y), 0x001F);
+        }
+    }
+}
+
+#[test]
+fn test_dithering_disabled_by_draw_mode() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000000); // Dither bit clear
+
+    draw_shaded_corner(&mut gpu, 104, 104, 104);
+
+    assert_eq!(red_block(&mut gpu), [[13; 4]; 4]);
+}
+
+#[test]
+fn test_dithering_disabled_by_option() {
+    let mut gpu = GPU::new();
+    gpu.set_dithering_enabled(false);
+    gpu.write_gp0(0xE1000200);
+
+    draw_shaded_corner(&mut gpu, 104, 104, 104);
+    assert_eq!(red_block(&mut gpu), [[13; 4]; 4]);
+
+    // Re-enabling the option applies the game's dither bit again
+    gpu.set_dithering_enabled(true);
+    draw_shaded_corner(&mut gpu, 104, 104, 104);
+    assert_eq!(red_block(&mut gpu)[0], [12, 13, 12, 13]);
+}
+
+#[test]
+fn test_dithering_skips_flat_primitives() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000200);
+
+    // Monochrome triangle: GP0(20h)
+    gpu.write_gp0(0x20000000 | 104);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+
+    assert_eq!(red_block(&mut gpu), [[13; 4]; 4]);
+}
+
+#[test]
+fn test_dithering_texture_blended_golden_pixels() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE1000200);
+
+    // 15-bit texture page at (512, 0) filled with red = 26 (208 in 8-bit)
+    for y in 0..8 {
+        for x in 512..520 {
+            gpu.write_vram(x, y, 26);
+        }
+    }
+
+    // Textured triangle GP0(24h), tint 64 halves the texel: 208 * 64 >> 7 = 104
+    let texpage = 8 | (2 << 7); // Page X = 512, 15-bit
+    gpu.write_gp0(0x24000000 | 64 | (64 << 8) | (64 << 16));
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000000); // UV (0, 0), CLUT unused
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0((texpage << 16) | 0x0004); // UV (4, 0)
+    gpu.write_gp0(0x00400000);
+    gpu.write_gp0(0x00000400); // UV (0, 4)
+
+    assert_eq!(
+        red_block(&mut gpu),
+        [
+            [12, 13, 12, 13],
+            [13, 12, 13, 12],
+            [12, 13, 12, 13],
+            [13, 12, 13, 12],
+        ]
+    );
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! GPU statistics tests
+//! Tests per-frame draw counters, transfer byte counts and frame rollover
+
+use super::super::*;
+
+/// Run the GPU until the start of the next VBlank
+fn end_frame(gpu: &mut GPU) {
+    while !gpu.tick(GPU::DOTS_PER_SCANLINE as u32).0 {}
+}
+
+/// Draw a monochrome rectangle with the given GP0 command byte
+fn draw_rect(gpu: &mut GPU, command: u32, x: u16, y: u16, width: u16, height: u16) {
+    gpu.write_gp0((command << 24) | 0x0000_00FF);
+    gpu.write_gp0(((y as u32) << 16) | x as u32);
+    gpu.write_gp0(((height as u32) << 16) | width as u32);
+}
+
+/// Draw a flat triangle
+fn draw_triangle(gpu: &mut GPU, command: u32, v: [(i16, i16); 3]) {
+    gpu.write_gp0((command << 24) | 0x0000_00FF);
+    for (x, y) in v {
+        gpu.write_gp0(((y as u16 as u32) << 16) | x as u16 as u32);
+    }
+}
+
+#[test]
+fn test_new_gpu_has_empty_stats() {
+    let gpu = GPU::new();
+
+    assert_eq!(*gpu.frame_stats(), GpuStats::default());
+    assert_eq!(*gpu.last_frame_stats(), GpuStats::default());
+}
+
+#[test]
+fn test_counts_primitive_types() {
+    let mut gpu = GPU::new();
+
+    draw_triangle(&mut gpu, 0x20, [(0, 0), (32, 0), (0, 32)]);
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 16);
+    gpu.write_gp0(0x4000_00FF);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0000_0009);
+
+    let stats = gpu.frame_stats();
+    assert_eq!(stats.triangles, 1);
+    assert_eq!(stats.rectangles, 1);
+    assert_eq!(stats.lines, 1);
+    assert_eq!(stats.primitives(), 3);
+    assert_eq!(stats.untextured, 3);
+    assert_eq!(stats.textured, 0);
+    assert_eq!(stats.clipped, 0);
+}
+
+#[test]
+fn test_counts_estimated_pixels() {
+    let mut gpu = GPU::new();
+
+    // Rectangles count their full area, lines their major axis length
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 8);
+    gpu.write_gp0(0x4000_00FF);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0000_0009);
+
+    assert_eq!(gpu.frame_stats().estimated_pixels, 16 * 8 + 10);
+}
+
+#[test]
+fn test_counts_textured_and_semi_transparent() {
+    let mut gpu = GPU::new();
+
+    // Textured semi-transparent 8x8 sprite
+    gpu.write_gp0(0x7600_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0000_0000);
+    // Semi-transparent flat rectangle
+    draw_rect(&mut gpu, 0x62, 0, 0, 4, 4);
+
+    let stats = gpu.frame_stats();
+    assert_eq!(stats.rectangles, 2);
+    assert_eq!(stats.textured, 1);
+    assert_eq!(stats.untextured, 1);
+    assert_eq!(stats.semi_transparent, 2);
+}
+
+#[test]
+fn test_counts_clipped_primitives() {
+    let mut gpu = GPU::new();
+
+    // Drawing area (0, 0)-(63, 63)
+    gpu.write_gp0(0xE300_0000);
+    gpu.write_gp0(0xE400_0000 | (63 << 10) | 63);
+
+    draw_rect(&mut gpu, 0x60, 0, 0, 32, 32);
+    draw_rect(&mut gpu, 0x60, 48, 48, 32, 32);
+    draw_triangle(&mut gpu, 0x20, [(-8, 0), (32, 0), (0, 32)]);
+
+    let stats = gpu.frame_stats();
+    assert_eq!(stats.primitives(), 3);
+    assert_eq!(stats.clipped, 2);
+
+    // Only the visible parts are drawn: 16x16 of the second rectangle
+    // and half of the triangle's clipped 33x33 bounding box
+    assert_eq!(
+        stats.estimated_pixels,
+        32 * 32 + 16 * 16 + (33u64 * 33).div_ceil(2)
+    );
+}
+
+#[test]
+fn test_counts_fills_and_copies() {
+    let mut gpu = GPU::new();
+
+    // Fill 32x32
+    gpu.write_gp0(0x0200_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0020_0020);
+
+    // Copy 16x8 from (0, 0) to (100, 100)
+    gpu.write_gp0(0x8000_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0064_0064);
+    gpu.write_gp0(0x0008_0010);
+
+    let stats = gpu.frame_stats();
+    assert_eq!(stats.fills, 1);
+    assert_eq!(stats.copies, 1);
+    assert_eq!(stats.primitives(), 0);
+    assert_eq!(stats.estimated_pixels, 32 * 32 + 16 * 8);
+    assert_eq!(stats.vram_to_vram_bytes, 16 * 8 * 2);
+}
+
+#[test]
+fn test_counts_transfer_bytes() {
+    let mut gpu = GPU::new();
+
+    // CPU to VRAM: 4x2 pixels in 4 words
+    gpu.write_gp0(0xA000_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0002_0004);
+    for _ in 0..4 {
+        gpu.write_gp0(0x7FFF_7FFF);
+    }
+    assert_eq!(gpu.frame_stats().cpu_to_vram_bytes, 16);
+
+    // VRAM to CPU: 2x1 pixels in 1 word
+    gpu.write_gp0(0xC000_0000);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(0x0001_0002);
+    gpu.read_gpuread();
+    assert_eq!(gpu.frame_stats().vram_to_cpu_bytes, 4);
+
+    // Reads outside a transfer are not counted
+    gpu.read_gpuread();
+    assert_eq!(gpu.frame_stats().vram_to_cpu_bytes, 4);
+}
+
+#[test]
+fn test_vblank_rolls_over_stats() {
+    let mut gpu = GPU::new();
+
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 16);
+    end_frame(&mut gpu);
+
+    assert_eq!(gpu.last_frame_stats().rectangles, 1);
+    assert_eq!(*gpu.frame_stats(), GpuStats::default());
+
+    // A frame without drawing clears the displayed counters
+    end_frame(&mut gpu);
+    assert_eq!(*gpu.last_frame_stats(), GpuStats::default());
+}
+
+#[test]
+fn test_interlaced_field_halves_pixels() {
+    let mut gpu = GPU::new();
+
+    // 640x480 interlaced
+    gpu.write_gp1(0x0800_0027);
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 16);
+    assert_eq!(gpu.frame_stats().estimated_pixels, 16 * 8);
+
+    // Drawing to the displayed field draws every line
+    gpu.write_gp0(0xE100_0400);
+    draw_rect(&mut gpu, 0x60, 0, 0, 16, 16);
+    assert_eq!(gpu.frame_stats().estimated_pixels, 16 * 8 + 16 * 16);
+}
+
+#[test]
+fn test_stats_display() {
+    let stats = GpuStats {
+        lines: 3,
+        fills: 1,
+        untextured: 3,
+        semi_transparent: 1,
+        clipped: 2,
+        estimated_pixels: 2_500_000,
+        vram_to_cpu_bytes: 3 * 1024 * 1024,
+        vram_to_vram_bytes: 512,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        stats.to_string(),
+        "tri 0 rect 0 line 3 fill 1 | tex 0 flat 3 semi 1 clip 2 | est 2.5M px | up 0B down 3.0MB copy 512B"
+    );
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Texture cache tests
+//! Tests stale texel behavior and GP0(01h)/texture page invalidation
+
+use super::super::*;
+
+/// Texpage bits for a 15-bit page at X = 512
+const PAGE_512: u32 = 8 | (2 << 7);
+
+/// Texpage bits for a 15-bit page at X = 576
+const PAGE_576: u32 = 9 | (2 << 7);
+
+/// Draw a single texel (u, v) of the current texture page at (x, 0)
+fn draw_texel(gpu: &mut GPU, u: u32, v: u32, x: u32) {
+    gpu.write_gp0(0x6C808080);
+    gpu.write_gp0(x);
+    gpu.write_gp0((v << 8) | u);
+}
+
+/// Upload a texel to VRAM with a CPU→VRAM transfer
+fn upload_texel(gpu: &mut GPU, x: u32, y: u32, texel: u16) {
+    gpu.write_gp0(0xA0000000);
+    gpu.write_gp0((y << 16) | x);
+    gpu.write_gp0(0x00010001);
+    gpu.write_gp0(texel as u32);
+}
+
+fn cached_gpu() -> GPU {
+    let mut gpu = GPU::new();
+    gpu.set_texture_cache_enabled(true);
+    gpu.write_gp0(0xE1000000 | PAGE_512);
+    gpu
+}
+
+#[test]
+fn test_texture_cache_disabled_by_default() {
+    let mut gpu = GPU::new();
+    assert!(!gpu.texture_cache_enabled());
+    gpu.write_gp0(0xE1000000 | PAGE_512);
+
+    gpu.write_vram(512, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+    draw_texel(&mut gpu, 0, 0, 1);
+
+    // Without the cache, the new upload is visible immediately
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+    assert_eq!(gpu.read_vram(1, 0), 0x03E0);
+}
+
+#[test]
+fn test_texture_cache_keeps_stale_texels() {
+    let mut gpu = cached_gpu();
+
+    gpu.write_vram(512, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+    draw_texel(&mut gpu, 0, 0, 1);
+
+    assert_eq!(gpu.read_vram(512, 0), 0x03E0);
+    assert_eq!(gpu.read_vram(1, 0), 0x001F);
+}
+
+#[test]
+fn test_texture_cache_line_holds_neighbours() {
+    let mut gpu = cached_gpu();
+
+    // Texels 0-3 share a cache line, so fetching u=0 also caches u=3
+    gpu.write_vram(515, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    gpu.write_vram(515, 0, 0x03E0);
+    draw_texel(&mut gpu, 3, 0, 1);
+    assert_eq!(gpu.read_vram(1, 0), 0x001F);
+
+    // u=4 starts the next line and is loaded fresh
+    gpu.write_vram(516, 0, 0x7C00);
+    draw_texel(&mut gpu, 4, 0, 2);
+    assert_eq!(gpu.read_vram(2, 0), 0x7C00);
+}
+
+#[test]
+fn test_gp0_clear_cache_refreshes_texels() {
+    let mut gpu = cached_gpu();
+
+    gpu.write_vram(512, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+    gpu.write_gp0(0x01000000);
+    draw_texel(&mut gpu, 0, 0, 1);
+
+    assert_eq!(gpu.read_vram(1, 0), 0x03E0);
+}
+
+#[test]
+fn test_texture_page_change_refreshes_texels() {
+    let mut gpu = cached_gpu();
+
+    gpu.write_vram(512, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+
+    // Drawing from another page flushes the cache
+    gpu.write_gp0(0xE1000000 | PAGE_576);
+    draw_texel(&mut gpu, 0, 0, 1);
+    gpu.write_gp0(0xE1000000 | PAGE_512);
+    draw_texel(&mut gpu, 0, 0, 2);
+
+    assert_eq!(gpu.read_vram(2, 0), 0x03E0);
+}
+
+#[test]
+fn test_texture_depth_change_refreshes_texels() {
+    let mut gpu = cached_gpu();
+
+    gpu.write_vram(512, 0, 0x001F);
+    draw_texel(&mut gpu, 0, 0, 0);
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+
+    // Same page at 8-bit depth, then back to 15-bit
+    gpu.write_gp0(0xE1000000 | 8 | (1 << 7));
+    draw_texel(&mut gpu, 0, 0, 1);
+    gpu.write_gp0(0xE1000000 | PAGE_512);
+    draw_texel(&mut gpu, 0, 0, 2);
+
+    assert_eq!(gpu.read_vram(2, 0), 0x03E0);
+}
+
+#[test]
+fn test_texture_cache_conflicting_rows_evict() {
+    let mut gpu = cached_gpu();
+
+    // At 15-bit depth the cache covers 32 rows, so v=32 reuses v=0's lines
+    gpu.write_vram(512, 0, 0x001F);
+    gpu.write_vram(512, 32, 0x7C00);
+    draw_texel(&mut gpu, 0, 0, 0);
+    gpu.write_vram(512, 0, 0x03E0);
+    draw_texel(&mut gpu, 0, 32, 1);
+    draw_texel(&mut gpu, 0, 0, 2);
+
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+    assert_eq!(gpu.read_vram(1, 0), 0x7C00);
+    assert_eq!(gpu.read_vram(2, 0), 0x03E0);
+}
+
+#[test]
+fn test_texture_cache_polygon_texpage() {
+    let mut gpu = cached_gpu();
+    gpu.write_vram(512, 0, 0x001F);
+
+    let draw_triangle = |gpu: &mut GPU, texpage: u32| {
+        gpu.write_gp0(0x24808080);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00000010);
+        gpu.write_gp0(texpage << 16);
+        gpu.write_gp0(0x00100000);
+        gpu.write_gp0(0x00000000);
+    };
+
+    draw_triangle(&mut gpu, PAGE_512);
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+
+    // Stale until the polygon's own texpage switches away and back
+    upload_texel(&mut gpu, 512, 0, 0x03E0);
+    draw_triangle(&mut gpu, PAGE_512);
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+
+    draw_triangle(&mut gpu, PAGE_576);
+    draw_triangle(&mut gpu, PAGE_512);
+    assert_eq!(gpu.read_vram(0, 0), 0x03E0);
+}
@@ -0,0 +1,384 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Texture replacement tests
+//! Tests texture dumping, hash identity and replacement while upscaling
+
+use super::super::*;
+use std::path::Path;
+
+/// CLUT position (x, y) of the test texture
+const CLUT: (u16, u16) = (0, 480);
+
+/// Store a 16x16 4-bit texture at (512, 0) and its CLUT
+///
+/// Texel (u, v) has index `(u + v) % 15 + 1`, and CLUT entry `i` is
+/// `i * 0x0421` (gray levels).
+fn upload_texture(gpu: &mut GPU) {
+    for v in 0..16u16 {
+        for word in 0..4u16 {
+            let mut value = 0;
+            for nibble in 0..4 {
+                let u = word * 4 + nibble;
+                value |= ((u + v) % 15 + 1) << (nibble * 4);
+            }
+            gpu.write_vram(512 + word, v, value);
+        }
+    }
+    for i in 0..16 {
+        gpu.write_vram(CLUT.0 + i, CLUT.1, i * 0x0421);
+    }
+}
+
+/// Draw the test texture as an unmodulated 16x16 sprite at (x, y)
+fn draw_sprite(gpu: &mut GPU, x: u16, y: u16) {
+    // Texture page 8 (x = 512), 4-bit
+    gpu.write_gp0(0xE100_0008);
+    gpu.write_gp0(0x6480_8080);
+    gpu.write_gp0(((y as u32) << 16) | x as u32);
+    gpu.write_gp0(((CLUT.1 as u32) << 22) | ((CLUT.0 as u32 / 16) << 16));
+    gpu.write_gp0(0x0010_0010);
+}
+
+/// Draw the test texture on a triangle at normal brightness
+fn draw_triangle(gpu: &mut GPU) {
+    gpu.write_gp0(0x2480_8080);
+    gpu.write_gp0(0x0000_0000);
+    gpu.write_gp0(((CLUT.1 as u32) << 22) | ((CLUT.0 as u32 / 16) << 16));
+    gpu.write_gp0(0x0000_000F);
+    gpu.write_gp0(0x0008_000F);
+    gpu.write_gp0(0x000F_0000);
+    gpu.write_gp0(0x0000_0F00);
+}
+
+/// Names of the files in a directory, sorted
+fn files(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Write a solid RGBA PNG
+fn write_png(path: &Path, width: u32, height: u32, rgba: [u8; 4]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data = rgba.repeat((width * height) as usize);
+    encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(&data)
+        .unwrap();
+}
+
+/// RGB bytes of a framebuffer pixel
+fn pixel(framebuffer: &Framebuffer, x: usize, y: usize) -> [u8; 3] {
+    let index = (y * framebuffer.width + x) * 3;
+    framebuffer.pixels[index..index + 3].try_into().unwrap()
+}
+
+/// Dump the test sprite and return the file name it was given
+fn dumped_name(dir: &Path) -> String {
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    draw_sprite(&mut gpu, 0, 0);
+
+    let names = files(dir);
+    assert_eq!(names.len(), 1);
+    names[0].clone()
+}
+
+#[test]
+fn test_dump_writes_texture_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+
+    draw_sprite(&mut gpu, 0, 0);
+    draw_sprite(&mut gpu, 32, 0);
+
+    let names = files(dir.path());
+    assert_eq!(names.len(), 1);
+    assert!(names[0].starts_with("4bit-16x16-"), "{}", names[0]);
+    assert!(names[0].ends_with(".png"));
+}
+
+#[test]
+fn test_dump_contents() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    let texture = ReplacementTexture::load(dir.path().join(name)).unwrap();
+
+    assert_eq!(texture.size(), (16, 16));
+    // Texel (3, 2) has index 6: 0x18C6 = (6, 6, 6) in 5-bit channels
+    assert_eq!(texture.pixel(3, 2), [0x31, 0x31, 0x31, 0xFF]);
+    // Texel (14, 0) has index 15
+    assert_eq!(texture.pixel(14, 0), [0x7B, 0x7B, 0x7B, 0xFF]);
+}
+
+#[test]
+fn test_dump_marks_transparent_texels() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    gpu.write_vram(CLUT.0 + 1, CLUT.1, 0x0000);
+    draw_sprite(&mut gpu, 0, 0);
+
+    let name = &files(dir.path())[0];
+    let texture = ReplacementTexture::load(dir.path().join(name)).unwrap();
+    assert_eq!(texture.pixel(0, 0)[3], 0);
+    assert_eq!(texture.pixel(1, 0)[3], 0xFF);
+}
+
+#[test]
+fn test_texture_identity_includes_clut() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dumped_name(dir.path());
+
+    // Same texels with a different palette
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    gpu.write_vram(CLUT.0 + 5, CLUT.1, 0x001F);
+    draw_sprite(&mut gpu, 0, 0);
+
+    let names = files(dir.path());
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&first));
+
+    // Identical VRAM contents give the same name in a new session
+    let other = tempfile::tempdir().unwrap();
+    assert_eq!(dumped_name(other.path()), first);
+}
+
+#[test]
+fn test_dump_triangles() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    gpu.write_gp0(0xE100_0008);
+
+    // Texture coordinates span 16x16 texels
+    draw_triangle(&mut gpu);
+    let names = files(dir.path());
+    assert_eq!(names.len(), 1);
+    assert!(names[0].starts_with("4bit-16x16-"), "{}", names[0]);
+}
+
+#[test]
+fn test_texture_window_is_not_dumped() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        dump_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    gpu.write_gp0(0xE100_0008);
+
+    // 8x8 texture window
+    gpu.write_gp0(0xE200_0021);
+    draw_triangle(&mut gpu);
+    assert!(files(dir.path()).is_empty());
+}
+
+#[test]
+fn test_replacement_drawn_when_upscaling() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    write_png(&dir.path().join(&name), 64, 64, [0, 0xFF, 0, 0xFF]);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    draw_sprite(&mut gpu, 0, 0);
+
+    // The shadow VRAM shows the replacement
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(pixel(&framebuffer, 0, 0), [0, 0xF8, 0]);
+    assert_eq!(pixel(&framebuffer, 31, 31), [0, 0xF8, 0]);
+    assert_eq!(pixel(&framebuffer, 32, 32), [0, 0, 0]);
+
+    // Native VRAM keeps the original texture for readbacks
+    assert_eq!(gpu.read_vram(0, 0), 0x0421);
+}
+
+#[test]
+fn test_replacement_drawn_on_triangles() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    write_png(&dir.path().join(&name), 32, 32, [0, 0, 0xFF, 0xFF]);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    gpu.write_gp0(0xE100_0008);
+
+    // Same texels as the sprite, so the same replacement applies
+    draw_triangle(&mut gpu);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 4, 4), [0, 0, 0xF8]);
+}
+
+#[test]
+fn test_replacement_ignored_at_native_resolution() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    write_png(&dir.path().join(&name), 16, 16, [0, 0xFF, 0, 0xFF]);
+
+    let mut gpu = GPU::new();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    draw_sprite(&mut gpu, 0, 0);
+
+    assert_eq!(gpu.read_vram(0, 0), 0x0421);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0x08, 0x08, 0x08]);
+}
+
+#[test]
+fn test_replacement_transparency() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    write_png(&dir.path().join(&name), 16, 16, [0xFF, 0, 0, 0]);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    draw_sprite(&mut gpu, 0, 0);
+
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0, 0, 0]);
+}
+
+#[test]
+fn test_replacement_directory_listed_when_configured() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    std::fs::remove_file(dir.path().join(&name)).unwrap();
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    let config = TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    };
+    gpu.set_texture_replacement(config.clone()).unwrap();
+    upload_texture(&mut gpu);
+
+    // Files added while drawing are not looked for
+    write_png(&dir.path().join(&name), 16, 16, [0, 0xFF, 0, 0xFF]);
+    draw_sprite(&mut gpu, 0, 0);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0x08, 0x08, 0x08]);
+
+    // Configuring again lists them
+    gpu.set_texture_replacement(config).unwrap();
+    draw_sprite(&mut gpu, 0, 0);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0, 0xF8, 0]);
+}
+
+#[test]
+fn test_replacement_cache_is_bounded() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = dumped_name(dir.path());
+    write_png(&dir.path().join(&name), 16, 16, [0, 0xFF, 0, 0xFF]);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    gpu.set_texture_replacement(TextureReplacementConfig {
+        replacement_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    })
+    .unwrap();
+    upload_texture(&mut gpu);
+    draw_sprite(&mut gpu, 0, 0);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0, 0xF8, 0]);
+
+    // While cached, the replacement is drawn without reading the file
+    std::fs::remove_file(dir.path().join(&name)).unwrap();
+    draw_sprite(&mut gpu, 0, 0);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0, 0xF8, 0]);
+
+    // Unused CLUT entry 0 is part of the hash, so each value is a new
+    // texture; more of them than the cache holds evict the replacement
+    for i in 1..=300 {
+        gpu.write_vram(CLUT.0, CLUT.1, i);
+        draw_sprite(&mut gpu, 0, 32);
+    }
+    gpu.write_vram(CLUT.0, CLUT.1, 0);
+    draw_sprite(&mut gpu, 0, 0);
+    assert_eq!(pixel(&gpu.get_framebuffer(), 0, 0), [0x08, 0x08, 0x08]);
+}
+
+#[test]
+fn test_unsupported_backend() {
+    let mut gpu = GPU::with_renderer(RendererKind::Null.create());
+    let config = TextureReplacementConfig {
+        dump_dir: Some("textures".into()),
+        ..Default::default()
+    };
+
+    assert!(gpu.set_texture_replacement(config).is_err());
+    assert!(gpu
+        .set_texture_replacement(TextureReplacementConfig::default())
+        .is_ok());
+}
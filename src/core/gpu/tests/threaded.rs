@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Threaded rendering tests
+//! Tests that the render thread produces the same VRAM as synchronous drawing
+
+use super::super::*;
+
+/// Upload a 16x16 15-bit texture gradient at (512, 0)
+pub(super) fn upload_texture(gpu: &mut GPU) {
+    gpu.write_gp0(0xA0000000);
+    gpu.write_gp0(0x00000200);
+    gpu.write_gp0(0x00100010);
+    for y in 0..16u32 {
+        for x in (0..16u32).step_by(2) {
+            let p0 = (y << 10) | (x << 5) | 0x1F;
+            let p1 = (y << 10) | ((x + 1) << 5) | 0x1F;
+            gpu.write_gp0((p1 << 16) | p0);
+        }
+    }
+}
+
+/// Issue a mix of every primitive type with state changes in between
+pub(super) fn draw_scene(gpu: &mut GPU) {
+    upload_texture(gpu);
+
+    // Fill background, then set draw area, offset and dithered draw mode
+    gpu.write_gp0(0x02102030);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00F00140);
+    gpu.write_gp0(0xE3000000);
+    gpu.write_gp0(0xE4000000 | (239 << 10) | 319);
+    gpu.write_gp0(0xE5000000 | (4 << 11) | 8);
+    gpu.write_gp0(0xE1000000 | (1 << 9) | 8 | (2 << 7));
+
+    // Monochrome and semi-transparent triangles
+    gpu.write_gp0(0x2000FF00);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000060);
+    gpu.write_gp0(0x00500010);
+    gpu.write_gp0(0x22FF0000);
+    gpu.write_gp0(0x00100010);
+    gpu.write_gp0(0x00100070);
+    gpu.write_gp0(0x00600040);
+
+    // Gouraud triangle and quad
+    gpu.write_gp0(0x300000FF);
+    gpu.write_gp0(0x00200080);
+    gpu.write_gp0(0x0000FF00);
+    gpu.write_gp0(0x002000E0);
+    gpu.write_gp0(0x00FF0000);
+    gpu.write_gp0(0x007000B0);
+    gpu.write_gp0(0x38102030);
+    gpu.write_gp0(0x00800000);
+    gpu.write_gp0(0x00405060);
+    gpu.write_gp0(0x00800040);
+    gpu.write_gp0(0x00708090);
+    gpu.write_gp0(0x00C00000);
+    gpu.write_gp0(0x00A0B0C0);
+    gpu.write_gp0(0x00C00040);
+
+    // Textured triangle and quad from the uploaded page
+    let texpage = 8 | (2 << 7);
+    gpu.write_gp0(0x24808080);
+    gpu.write_gp0(0x00800060);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x008000A0);
+    gpu.write_gp0((texpage << 16) | 0x000F);
+    gpu.write_gp0(0x00C00060);
+    gpu.write_gp0(0x00000F00);
+    gpu.write_gp0(0x2C604020);
+    gpu.write_gp0(0x008000B0);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x008000F0);
+    gpu.write_gp0((texpage << 16) | 0x000F);
+    gpu.write_gp0(0x00C000B0);
+    gpu.write_gp0(0x00000F00);
+    gpu.write_gp0(0x00C000F0);
+    gpu.write_gp0(0x00000F0F);
+
+    // Copy part of the picture while draws may still be queued
+    gpu.write_gp0(0x80000000);
+    gpu.write_gp0(0x00200010);
+    gpu.write_gp0(0x00D00100);
+    gpu.write_gp0(0x00200020);
+
+    // Lines and polylines
+    gpu.write_gp0(0x40FFFFFF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00EF013F);
+    gpu.write_gp0(0x4800FFFF);
+    gpu.write_gp0(0x00100100);
+    gpu.write_gp0(0x00400120);
+    gpu.write_gp0(0x00100130);
+    gpu.write_gp0(0x55555555);
+    gpu.write_gp0(0x500000FF);
+    gpu.write_gp0(0x00E00000);
+    gpu.write_gp0(0x00FF0000);
+    gpu.write_gp0(0x00E00080);
+    gpu.write_gp0(0x5800FF00);
+    gpu.write_gp0(0x00500100);
+    gpu.write_gp0(0x00FF00FF);
+    gpu.write_gp0(0x00900120);
+    gpu.write_gp0(0x0000FFFF);
+    gpu.write_gp0(0x00500138);
+    gpu.write_gp0(0x55555555);
+
+    // Rectangles with mask set/check enabled part way through
+    gpu.write_gp0(0x60FF00FF);
+    gpu.write_gp0(0x00A00100);
+    gpu.write_gp0(0x00100020);
+    gpu.write_gp0(0xE6000003);
+    gpu.write_gp0(0x64808080);
+    gpu.write_gp0(0x00A80108);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    gpu.write_gp0(0x66404040);
+    gpu.write_gp0(0x00B00110);
+    gpu.write_gp0(0x00000404);
+    gpu.write_gp0(0x00080008);
+    gpu.write_gp0(0x7C808080);
+    gpu.write_gp0(0x00C00120);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0xE6000000);
+}
+
+/// Run `scene` on a synchronous and a threaded GPU and compare VRAM
+fn assert_same_output(scene: impl Fn(&mut GPU)) {
+    let mut reference = GPU::new();
+    scene(&mut reference);
+
+    let mut threaded = GPU::new();
+    threaded.set_threaded_rendering(true);
+    scene(&mut threaded);
+    threaded.sync_renderer();
+
+    assert!(
+        reference.vram == threaded.vram,
+        "threaded VRAM differs from synchronous rendering"
+    );
+}
+
+#[test]
+fn test_threaded_rendering_disabled_by_default() {
+    let gpu = GPU::new();
+    assert!(!gpu.threaded_rendering());
+}
+
+#[test]
+fn test_threaded_scene_matches_synchronous() {
+    assert_same_output(draw_scene);
+}
+
+#[test]
+fn test_threaded_texture_cache_matches_synchronous() {
+    assert_same_output(|gpu| {
+        gpu.set_texture_cache_enabled(true);
+        draw_scene(gpu);
+
+        // Overwrite the texture without flushing, redraw, then flush and redraw
+        gpu.write_gp0(0x02FFFFFF);
+        gpu.write_gp0(0x00000200);
+        gpu.write_gp0(0x00100010);
+        gpu.write_gp0(0x64808080);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00100010);
+        gpu.write_gp0(0x01000000);
+        gpu.write_gp0(0x64808080);
+        gpu.write_gp0(0x00000010);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00100010);
+    });
+}
+
+#[test]
+fn test_threaded_gpuread_sees_queued_draws() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+
+    // Red 16x16 rectangle, then read its first two pixels back
+    gpu.write_gp0(0x600000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    gpu.write_gp0(0xC0000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00010002);
+
+    assert_eq!(gpu.read_gpuread(), 0x001F001F);
+}
+
+#[test]
+fn test_threaded_accessors_wait_for_queued_draws() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+
+    // White rectangle over the top-left of the display area
+    gpu.write_gp0(0x60FFFFFF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    assert_eq!(gpu.read_vram(15, 15), 0x7FFF);
+
+    gpu.write_gp0(0x600000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00010001);
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(
+        &framebuffer.pixels[..6],
+        &[0xF8, 0x00, 0x00, 0xF8, 0xF8, 0xF8]
+    );
+}
+
+#[test]
+fn test_threaded_vblank_syncs_vram() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+
+    gpu.write_gp0(0x6000FF00);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    // Run until VBlank starts
+    gpu.tick(GPU::VBLANK_START as u32 * GPU::DOTS_PER_SCANLINE as u32);
+    assert_eq!(gpu.read_vram(0, 0), 0x03E0);
+}
+
+#[test]
+fn test_disable_threaded_rendering_returns_vram() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+    gpu.set_texture_cache_enabled(true);
+
+    gpu.write_gp0(0x60FF0000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    gpu.set_threaded_rendering(false);
+    assert!(!gpu.threaded_rendering());
+    assert!(gpu.texture_cache_enabled());
+    assert_eq!(gpu.read_vram(0, 0), 0x7C00);
+
+    // Drawing continues synchronously
+    gpu.write_gp0(0x600000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00010001);
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+}
+
+#[test]
+fn test_threaded_reset_clears_vram() {
+    let mut gpu = GPU::new();
+    gpu.set_threaded_rendering(true);
+
+    gpu.write_gp0(0x60FFFFFF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+    gpu.reset();
+
+    assert_eq!(gpu.read_vram(0, 0), 0x0000);
+}
@@ -40,15 +40,15 @@ fn test_vblank_timing() {
 }
 
 #[test]
-fn test_vblank_fires_every_564480_cycles() {
+fn test_vblank_fires_every_ntsc_frame() {
     let mut gpu = GPU::new();
     let mut timing = TimingEventManager::new();
 
     // Register GPU timing events
     gpu.register_events(&mut timing);
 
-    // VBlank should fire at 564,480 cycles
-    const CYCLES_PER_FRAME: i32 = 564_480;
+    // VBlank should fire after one NTSC frame (263 × 2153 = 566,239 cycles)
+    const CYCLES_PER_FRAME: i32 = 566_239;
 
     // Verify initial downcount is set to VBlank cycle count
     // Note: Downcount might be set to the HBlank event if it comes first
@@ -63,7 +63,7 @@ fn test_vblank_fires_every_564480_cycles() {
         !triggered
             .iter()
             .any(|&handle| { gpu.vblank_event.is_some() && handle == gpu.vblank_event.unwrap() }),
-        "VBlank should not fire before 564,480 cycles"
+        "VBlank should not fire before 566,239 cycles"
     );
 
     // Advance to VBlank time
@@ -75,7 +75,7 @@ fn test_vblank_fires_every_564480_cycles() {
         triggered
             .iter()
             .any(|&handle| { gpu.vblank_event.is_some() && handle == gpu.vblank_event.unwrap() }),
-        "VBlank should fire at 564,480 cycles"
+        "VBlank should fire at 566,239 cycles"
     );
 }
 
@@ -88,7 +88,7 @@ fn test_hblank_fires_every_scanline() {
     gpu.register_events(&mut timing);
 
     // HBlank should fire every scanline (CYCLES_PER_SCANLINE)
-    const CYCLES_PER_SCANLINE: i32 = 2_146;
+    const CYCLES_PER_SCANLINE: i32 = 2_153;
 
     // Track HBlank events
     let mut hblank_count = 0;
@@ -118,9 +118,9 @@ fn test_frame_timing_at_60hz() {
     // Register GPU timing events
     gpu.register_events(&mut timing);
 
-    // At 60 Hz, each frame is 1/60 second = 16.67ms
-    // At 33.8688 MHz CPU clock, that's 564,480 cycles per frame
-    const CYCLES_PER_FRAME: i32 = 564_480;
+    // NTSC runs at ~59.81 Hz: 3413 video cycles per scanline at
+    // 53.693175 MHz is 2153 CPU cycles, × 263 scanlines = 566,239 cycles
+    const CYCLES_PER_FRAME: i32 = 566_239;
     const FRAMES_TO_TEST: i32 = 5;
 
     let start_time = timing.global_tick_counter;
@@ -151,7 +151,7 @@ fn test_vblank_interrupt_generation() {
     gpu.register_events(&mut timing);
 
     // Run to VBlank
-    timing.pending_ticks = 564_480;
+    timing.pending_ticks = 566_239;
     let triggered = timing.run_events();
 
     // Process GPU events
@@ -177,7 +177,7 @@ fn test_scanline_counter_accuracy_with_timing() {
     assert_eq!(gpu.get_scanline(), 0);
 
     // Run for one scanline worth of cycles via timing system
-    const CYCLES_PER_SCANLINE: i32 = 2_146;
+    const CYCLES_PER_SCANLINE: i32 = 2_153;
 
     timing.pending_ticks = CYCLES_PER_SCANLINE;
     let triggered = timing.run_events();
@@ -216,7 +216,7 @@ fn test_periodic_vblank_events() {
     gpu.register_events(&mut timing);
 
     let mut vblank_count = 0;
-    const CYCLES_PER_FRAME: i32 = 564_480;
+    const CYCLES_PER_FRAME: i32 = 566_239;
 
     // Run for 3 frames
     for _ in 0..3 {
@@ -253,39 +253,38 @@ fn test_scanline_counting() {
 }
 
 #[test]
-fn test_vblank_flag_in_status() {
+fn test_odd_line_flag_in_status() {
     let mut gpu = GPU::new();
 
-    // Initially not in VBlank
-    let status_before = gpu.status();
+    // Bit 31 follows the scanline parity in 240-line mode
+    gpu.scanline = 1;
+    assert_ne!(
+        gpu.status() & (1 << 31),
+        0,
+        "odd scanline should set bit 31"
+    );
+
+    gpu.scanline = 2;
     assert_eq!(
-        status_before & (1 << 31),
+        gpu.status() & (1 << 31),
         0,
-        "VBlank flag should be 0 initially"
+        "even scanline should clear bit 31"
     );
 
-    // Manually set to VBlank region for testing
+    // Bit 31 is always 0 during VBlank
     gpu.scanline = GPU::VBLANK_START;
     gpu.in_vblank = true;
-
-    // VBlank flag should be set in status
-    let status_vblank = gpu.status();
-    assert_ne!(
-        status_vblank & (1 << 31),
+    assert_eq!(
+        gpu.status() & (1 << 31),
         0,
-        "VBlank flag should be 1 when in VBlank"
+        "bit 31 should be 0 during VBlank"
     );
 
-    // Move out of VBlank
-    gpu.scanline = 0;
-    gpu.in_vblank = false;
-
-    // VBlank flag should be clear
-    let status_after = gpu.status();
+    gpu.scanline = GPU::VBLANK_START + 1;
     assert_eq!(
-        status_after & (1 << 31),
+        gpu.status() & (1 << 31),
         0,
-        "VBlank flag should be 0 outside VBlank"
+        "bit 31 should be 0 during VBlank"
     );
 }
 
@@ -395,3 +394,77 @@ fn test_is_in_vblank() {
 
     assert!(!gpu.is_in_vblank());
 }
+
+#[test]
+fn test_video_mode_timing_parameters() {
+    assert_eq!(VideoMode::NTSC.scanlines_per_frame(), 263);
+    assert_eq!(VideoMode::NTSC.dots_per_scanline(), 3413);
+    assert_eq!(VideoMode::NTSC.cpu_cycles_per_scanline(), 2153);
+    assert_eq!(VideoMode::NTSC.cpu_cycles_per_frame(), 566_239);
+
+    assert_eq!(VideoMode::PAL.scanlines_per_frame(), 314);
+    assert_eq!(VideoMode::PAL.dots_per_scanline(), 3406);
+    assert_eq!(VideoMode::PAL.cpu_cycles_per_scanline(), 2168);
+    assert_eq!(VideoMode::PAL.cpu_cycles_per_frame(), 680_752);
+
+    assert!((VideoMode::NTSC.refresh_rate() - 59.81).abs() < 0.01);
+    assert!((VideoMode::PAL.refresh_rate() - 49.75).abs() < 0.01);
+}
+
+#[test]
+fn test_pal_scanline_timing() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(0x08000008); // PAL
+    assert_eq!(gpu.video_mode(), VideoMode::PAL);
+
+    // VBlank starts at scanline 291 instead of 243
+    let (vblank, _) = gpu.tick(243 * 3406);
+    assert!(!vblank);
+    assert_eq!(gpu.get_scanline(), 243);
+
+    let (vblank, _) = gpu.tick(48 * 3406);
+    assert!(vblank);
+    assert_eq!(gpu.get_scanline(), 291);
+
+    // Scanline counter wraps after 314 lines
+    gpu.tick(22 * 3406);
+    assert_eq!(gpu.get_scanline(), 313);
+    assert!(gpu.is_in_vblank());
+    gpu.tick(3406);
+    assert_eq!(gpu.get_scanline(), 0);
+    assert!(!gpu.is_in_vblank());
+}
+
+#[test]
+fn test_pal_timing_events() {
+    let mut gpu = GPU::new();
+    gpu.write_gp1(0x08000008); // PAL before registration
+    let mut timing = TimingEventManager::new();
+    gpu.register_events(&mut timing);
+
+    let vblank = gpu.vblank_event().unwrap();
+    assert_eq!(timing.time_until(vblank), Some(680_752));
+    assert_eq!(timing.downcount, 2168);
+}
+
+#[test]
+fn test_video_mode_switch_updates_event_intervals() {
+    let mut gpu = GPU::new();
+    let mut timing = TimingEventManager::new();
+    gpu.register_events(&mut timing);
+    let vblank = gpu.vblank_event().unwrap();
+
+    // Switch to PAL mid-frame; the pending VBlank keeps its NTSC time
+    gpu.write_gp1(0x08000008);
+    gpu.sync_timing(&mut timing);
+    assert_eq!(timing.time_until(vblank), Some(566_239));
+
+    timing.pending_ticks = 566_239;
+    let triggered = timing.run_events();
+    assert!(triggered.contains(&vblank));
+    gpu.process_events(&mut timing, &triggered);
+    assert!(gpu.is_in_vblank());
+
+    // The following frame uses PAL length
+    assert_eq!(timing.time_until(vblank), Some(680_752));
+}
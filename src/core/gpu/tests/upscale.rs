@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Internal resolution upscaling tests
+//! Tests the shadow VRAM against native rendering and its coherency with
+//! uploads, fills, copies and readbacks
+
+use super::super::render::Upscaler;
+use super::super::*;
+use super::threaded::{draw_scene, upload_texture};
+
+/// RGB bytes of a framebuffer pixel
+fn pixel(framebuffer: &Framebuffer, x: usize, y: usize) -> [u8; 3] {
+    let index = (y * framebuffer.width + x) * 3;
+    framebuffer.pixels[index..index + 3].try_into().unwrap()
+}
+
+/// Check that every pixel of a native region covers a `scale`×`scale` block
+fn assert_blocks_match(
+    native: &Framebuffer,
+    scaled: &Framebuffer,
+    scale: usize,
+    width: usize,
+    height: usize,
+) {
+    for y in 0..height * scale {
+        for x in 0..width * scale {
+            assert_eq!(
+                pixel(scaled, x, y),
+                pixel(native, x / scale, y / scale),
+                "mismatch at scaled ({}, {})",
+                x,
+                y
+            );
+        }
+    }
+}
+
+/// Red right triangle with a diagonal edge from (64, 0) to (0, 64)
+fn draw_triangle(gpu: &mut GPU) {
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000040);
+    gpu.write_gp0(0x00400000);
+}
+
+/// Number of red pixels in a framebuffer row
+fn red_pixels(framebuffer: &Framebuffer, y: usize) -> usize {
+    (0..framebuffer.width)
+        .filter(|&x| pixel(framebuffer, x, y) == [0xF8, 0, 0])
+        .count()
+}
+
+#[test]
+fn test_resolution_scale_defaults_to_native() {
+    let gpu = GPU::new();
+    assert_eq!(gpu.resolution_scale(), 1);
+}
+
+#[test]
+fn test_resolution_scale_is_clamped() {
+    let mut gpu = GPU::new();
+
+    gpu.set_resolution_scale(0).unwrap();
+    assert_eq!(gpu.resolution_scale(), 1);
+
+    gpu.set_resolution_scale(12).unwrap();
+    assert_eq!(gpu.resolution_scale(), GPU::MAX_RESOLUTION_SCALE);
+}
+
+#[test]
+fn test_upscaled_framebuffer_size() {
+    let mut gpu = GPU::new();
+
+    gpu.set_resolution_scale(4).unwrap();
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!((framebuffer.width, framebuffer.height), (1280, 960));
+    assert_eq!(framebuffer.pixels.len(), 1280 * 960 * 3);
+
+    gpu.set_resolution_scale(1).unwrap();
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!((framebuffer.width, framebuffer.height), (320, 240));
+}
+
+#[test]
+fn test_upscaled_native_vram_matches_unscaled() {
+    let mut reference = GPU::new();
+    draw_scene(&mut reference);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(4).unwrap();
+    draw_scene(&mut gpu);
+
+    assert!(
+        reference.vram == gpu.vram,
+        "upscaling changed native VRAM contents"
+    );
+}
+
+#[test]
+fn test_upscaled_triangle_edge_is_smoother() {
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    draw_triangle(&mut gpu);
+
+    // A replicated native image would give identical row pairs
+    let framebuffer = gpu.get_framebuffer();
+    let refined =
+        (0..64).any(|y| red_pixels(&framebuffer, y * 2) != red_pixels(&framebuffer, y * 2 + 1));
+    assert!(refined, "diagonal edge was not rendered at high resolution");
+
+    assert!(red_pixels(&framebuffer, 0) >= 128);
+    assert_eq!(red_pixels(&framebuffer, 200), 0);
+}
+
+#[test]
+fn test_upscaled_vram_keeps_existing_contents() {
+    let mut reference = GPU::new();
+    draw_scene(&mut reference);
+
+    // Enabling upscaling late starts from a copy of native VRAM
+    let mut gpu = GPU::new();
+    draw_scene(&mut gpu);
+    gpu.set_resolution_scale(2).unwrap();
+
+    assert_blocks_match(
+        &reference.get_framebuffer(),
+        &gpu.get_framebuffer(),
+        2,
+        320,
+        240,
+    );
+}
+
+#[test]
+fn test_upscaled_upload_and_fill_cover_whole_blocks() {
+    let scene = |gpu: &mut GPU| {
+        upload_texture(gpu);
+
+        // Upload the texture into the display area, then fill next to it
+        gpu.write_gp0(0x80000000);
+        gpu.write_gp0(0x00000200);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00100010);
+        gpu.write_gp0(0x02FF8040);
+        gpu.write_gp0(0x00000010);
+        gpu.write_gp0(0x00100010);
+    };
+
+    let mut reference = GPU::new();
+    scene(&mut reference);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(3).unwrap();
+    scene(&mut gpu);
+
+    assert_blocks_match(
+        &reference.get_framebuffer(),
+        &gpu.get_framebuffer(),
+        3,
+        40,
+        24,
+    );
+}
+
+#[test]
+fn test_upscaled_textured_rectangle_samples_texels() {
+    let scene = |gpu: &mut GPU| {
+        upload_texture(gpu);
+
+        // Raw 15-bit textured rectangle from the page at (512, 0)
+        gpu.write_gp0(0xE1000000 | 8 | (2 << 7));
+        gpu.write_gp0(0x65808080);
+        gpu.write_gp0(0x00080008);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00100010);
+    };
+
+    let mut reference = GPU::new();
+    scene(&mut reference);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    scene(&mut gpu);
+
+    assert_blocks_match(
+        &reference.get_framebuffer(),
+        &gpu.get_framebuffer(),
+        2,
+        32,
+        32,
+    );
+}
+
+#[test]
+fn test_upscaled_gpuread_returns_native_pixels() {
+    let mut reference = GPU::new();
+    draw_triangle(&mut reference);
+
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    draw_triangle(&mut gpu);
+
+    // Read back a row crossing the diagonal edge
+    for gpu in [&mut reference, &mut gpu] {
+        gpu.write_gp0(0xC0000000);
+        gpu.write_gp0(0x00200000);
+        gpu.write_gp0(0x00010040);
+    }
+    for _ in 0..32 {
+        assert_eq!(gpu.read_gpuread(), reference.read_gpuread());
+    }
+}
+
+#[test]
+fn test_upscaled_copy_keeps_high_resolution_detail() {
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    draw_triangle(&mut gpu);
+
+    // Copy the triangle to (100, 0)
+    gpu.write_gp0(0x80000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000064);
+    gpu.write_gp0(0x00400040);
+
+    let framebuffer = gpu.get_framebuffer();
+    for y in 0..128 {
+        for x in 0..128 {
+            assert_eq!(pixel(&framebuffer, 200 + x, y), pixel(&framebuffer, x, y));
+        }
+    }
+}
+
+#[test]
+fn test_upscaled_threaded_matches_synchronous() {
+    let mut reference = GPU::new();
+    reference.set_resolution_scale(2).unwrap();
+    draw_scene(&mut reference);
+
+    let mut threaded = GPU::new();
+    threaded.set_threaded_rendering(true);
+    threaded.set_resolution_scale(2).unwrap();
+    draw_scene(&mut threaded);
+    threaded.sync_renderer();
+
+    assert!(reference.vram == threaded.vram);
+    assert!(reference.get_framebuffer().pixels == threaded.get_framebuffer().pixels);
+}
+
+#[test]
+fn test_upscaled_reset_clears_shadow_vram() {
+    let mut gpu = GPU::new();
+    gpu.set_resolution_scale(2).unwrap();
+    draw_triangle(&mut gpu);
+    gpu.reset();
+
+    let framebuffer = gpu.get_framebuffer();
+    assert!(framebuffer.pixels.iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn test_upscaled_line_at_coordinate_limits() {
+    let vram = vec![0u16; 1024 * 512];
+    let mut upscaler = Upscaler::new(2, &vram, &Rasterizer::new());
+
+    // Scaled end points and thickness offsets leave the i16 range
+    upscaler.execute(RenderCommand::Line {
+        from: (0x7FFF, 0),
+        to: (-0x8000, 0),
+        color: 0x7FFF,
+    });
+    upscaler.execute(RenderCommand::Line {
+        from: (0x7FFF, 0),
+        to: (0x7FFF, 4),
+        color: 0x7FFF,
+    });
+    upscaler.execute(RenderCommand::GradientLine {
+        from: (100, -0x8000),
+        from_color: (255, 0, 0),
+        to: (100, 0x7FFF),
+        to_color: (0, 0, 255),
+    });
+
+    // The on-screen parts of the lines are still drawn
+    assert_eq!(upscaler.pixel(0, 0), 0x7FFF);
+    assert_eq!(upscaler.pixel(0, 1), 0x7FFF);
+    assert_ne!(upscaler.pixel(200, 100), 0);
+    assert_ne!(upscaler.pixel(201, 100), 0);
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Widescreen rendering tests
+//! Tests the widened drawing areas against native rendering and the frames
+//! read from them
+
+use super::super::*;
+use super::threaded::{draw_scene, upload_texture};
+
+/// RGB bytes of a framebuffer pixel
+fn pixel(framebuffer: &Framebuffer, x: usize, y: usize) -> [u8; 3] {
+    let index = (y * framebuffer.width + x) * 3;
+    framebuffer.pixels[index..index + 3].try_into().unwrap()
+}
+
+/// Columns of a framebuffer row holding a color
+fn columns(framebuffer: &Framebuffer, y: usize, rgb: [u8; 3]) -> Vec<usize> {
+    (0..framebuffer.width)
+        .filter(|&x| pixel(framebuffer, x, y) == rgb)
+        .collect()
+}
+
+/// GPU in widescreen mode drawing into the 320x240 display area
+fn widescreen_gpu() -> GPU {
+    let mut gpu = GPU::new();
+    gpu.set_widescreen(true);
+    gpu.write_gp0(0xE3000000);
+    gpu.write_gp0(0xE4000000 | (239 << 10) | 319);
+    gpu
+}
+
+/// Widescreen column where a native column of the display area starts
+fn column(x: usize) -> usize {
+    x * 4 / 3
+}
+
+#[test]
+fn test_widescreen_frame_width_comes_from_surface() {
+    let mut gpu = widescreen_gpu();
+
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!((framebuffer.width, framebuffer.height), (426, 240));
+    assert_eq!(framebuffer.aspect_ratio, Framebuffer::ASPECT_16_9);
+    assert_eq!(framebuffer.pixels.len(), 426 * 240 * 3);
+}
+
+#[test]
+fn test_widescreen_leaves_native_vram_unchanged() {
+    let mut native = GPU::new();
+    draw_scene(&mut native);
+
+    let mut widescreen = GPU::new();
+    widescreen.set_widescreen(true);
+    draw_scene(&mut widescreen);
+
+    assert!(native.vram == widescreen.vram);
+}
+
+#[test]
+fn test_widescreen_polygon_is_drawn_at_surface_resolution() {
+    let mut gpu = widescreen_gpu();
+
+    // Red triangle from (30, 10) to (90, 10) and (60, 40)
+    gpu.write_gp0(0x200000FF);
+    gpu.write_gp0(0x000A001E);
+    gpu.write_gp0(0x000A005A);
+    gpu.write_gp0(0x0028003C);
+
+    // Every row spans the columns of its native pixels, give or take the
+    // rounding of the edges
+    let framebuffer = gpu.get_framebuffer();
+    for y in 10..40 {
+        let native: Vec<usize> = (0..320)
+            .filter(|&x| gpu.read_vram(x as u16, y as u16) == 0x001F)
+            .collect();
+        let wide = columns(&framebuffer, y, [0xF8, 0, 0]);
+        if native.is_empty() {
+            assert!(wide.len() <= 1, "row {}", y);
+            continue;
+        }
+
+        let (first, last) = (column(native[0]), column(native[native.len() - 1] + 1) - 1);
+        assert!(first.abs_diff(wide[0]) <= 1, "row {}: {:?}", y, wide);
+        assert!(
+            last.abs_diff(wide[wide.len() - 1]) <= 1,
+            "row {}: {:?}",
+            y,
+            wide
+        );
+        assert_eq!(wide.len(), wide[wide.len() - 1] - wide[0] + 1, "row {}", y);
+    }
+}
+
+#[test]
+fn test_widescreen_clip_rect_is_widened() {
+    let mut gpu = widescreen_gpu();
+
+    // White 40x40 rectangle crossing the right edge of the drawing area
+    gpu.write_gp0(0x60FFFFFF);
+    gpu.write_gp0(0x0000012C);
+    gpu.write_gp0(0x00280028);
+
+    // The surface is drawn up to its own right edge...
+    let framebuffer = gpu.get_framebuffer();
+    let white = columns(&framebuffer, 0, [0xF8, 0xF8, 0xF8]);
+    assert_eq!(white, (column(300)..426).collect::<Vec<_>>());
+
+    // ...and native VRAM is still clipped to the drawing area
+    assert_eq!(gpu.read_vram(319, 0), 0x7FFF);
+    assert_eq!(gpu.read_vram(320, 0), 0x0000);
+}
+
+#[test]
+fn test_widescreen_textured_rectangle_steps_per_native_texel() {
+    let mut gpu = widescreen_gpu();
+    upload_texture(&mut gpu);
+
+    // 15-bit texture page at (512, 0), unmodulated 16x16 textured rectangle
+    // at the origin: the green channel of each texel is its U coordinate
+    gpu.write_gp0(0xE1000000 | 8 | (2 << 7));
+    gpu.write_gp0(0x64000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00000000);
+    gpu.write_gp0(0x00100010);
+
+    let framebuffer = gpu.get_framebuffer();
+    for x in 0..column(16) {
+        let u = (0..16).rev().find(|&u| column(u) <= x).unwrap();
+        assert_eq!(pixel(&framebuffer, x, 0)[1], (u as u8) << 3, "column {}", x);
+    }
+}
+
+#[test]
+fn test_widescreen_surface_follows_uploads() {
+    let mut gpu = widescreen_gpu();
+
+    // CPU uploads cover the columns of their native pixels
+    gpu.write_vram(5, 3, 0x7FFF);
+    gpu.write_vram(6, 3, 0x7FFF);
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(columns(&framebuffer, 3, [0xF8, 0xF8, 0xF8]), vec![6, 7, 8]);
+
+    // Fills and copies are stretched in the same way
+    gpu.write_gp0(0x020000FF);
+    gpu.write_gp0(0x00000005);
+    gpu.write_gp0(0x00100001);
+    gpu.write_gp0(0x80000000);
+    gpu.write_gp0(0x00050000);
+    gpu.write_gp0(0x00060000);
+    gpu.write_gp0(0x00010003);
+
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(
+        columns(&framebuffer, 5, [0xF8, 0, 0]),
+        (0..column(16)).collect::<Vec<_>>()
+    );
+    assert_eq!(columns(&framebuffer, 6, [0xF8, 0, 0]), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_widescreen_surface_starts_from_vram() {
+    let mut gpu = GPU::new();
+    gpu.write_gp0(0xE3000000);
+    gpu.write_gp0(0xE4000000 | (239 << 10) | 319);
+    gpu.write_vram(3, 0, 0x001F);
+
+    gpu.set_widescreen(true);
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(framebuffer.width, 426);
+    assert_eq!(columns(&framebuffer, 0, [0xF8, 0, 0]), vec![4]);
+}
+
+#[test]
+fn test_widescreen_is_dropped_when_disabled() {
+    let mut gpu = widescreen_gpu();
+    gpu.set_widescreen(false);
+
+    let framebuffer = gpu.get_framebuffer();
+    assert_eq!(framebuffer.width, 320);
+    assert_eq!(framebuffer.aspect_ratio, Framebuffer::ASPECT_4_3);
+}
@@ -0,0 +1,443 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE fixed-point arithmetic
+//!
+//! This module contains the saturation and overflow helpers shared by all
+//! GTE commands, along with the general arithmetic commands (SQR, OP,
+//! AVSZ3, AVSZ4).
+//!
+//! # Saturation Rules
+//!
+//! - MAC1-3 are 44-bit accumulators; overflow is checked after every addition
+//! - MAC0 is a 32-bit accumulator
+//! - IR1-3 saturate to -8000h..+7FFFh (lm=0) or 0..+7FFFh (lm=1)
+//! - IR0 saturates to 0..+1000h
+//! - SX2/SY2 saturate to -400h..+3FFh
+//! - Color FIFO components saturate to 0..FFh
+//! - SZ3 and OTZ saturate to 0..FFFFh
+//!
+//! # References
+//!
+//! - [PSX-SPX: GTE Saturation](https://psx-spx.consoledev.net/geometrytransformationenginegte/#gte-saturation)
+
+use super::GTE;
+
+/// FLAG bit 31: Error summary (bits 30-23 and 18-13 ORed together)
+pub(super) const FLAG_ERROR: u32 = 1 << 31;
+
+/// FLAG bits 30-28: MAC1-3 result larger than 43 bits and positive
+pub(super) const FLAG_MAC_POSITIVE: [u32; 3] = [1 << 30, 1 << 29, 1 << 28];
+
+/// FLAG bits 27-25: MAC1-3 result larger than 43 bits and negative
+pub(super) const FLAG_MAC_NEGATIVE: [u32; 3] = [1 << 27, 1 << 26, 1 << 25];
+
+/// FLAG bits 24-22: IR1-3 saturated
+pub(super) const FLAG_IR_SATURATED: [u32; 3] = [1 << 24, 1 << 23, 1 << 22];
+
+/// FLAG bits 21-19: Color FIFO R/G/B saturated to 0..FFh
+pub(super) const FLAG_COLOR_SATURATED: [u32; 3] = [1 << 21, 1 << 20, 1 << 19];
+
+/// FLAG bit 18: SZ3 or OTZ saturated to 0..FFFFh
+pub(super) const FLAG_SZ_OTZ_SATURATED: u32 = 1 << 18;
+
+/// FLAG bit 17: Divide overflow
+pub(super) const FLAG_DIVIDE_OVERFLOW: u32 = 1 << 17;
+
+/// FLAG bit 16: MAC0 result larger than 31 bits and positive
+pub(super) const FLAG_MAC0_POSITIVE: u32 = 1 << 16;
+
+/// FLAG bit 15: MAC0 result larger than 31 bits and negative
+pub(super) const FLAG_MAC0_NEGATIVE: u32 = 1 << 15;
+
+/// FLAG bit 14: SX2 saturated to -400h..+3FFh
+pub(super) const FLAG_SX2_SATURATED: u32 = 1 << 14;
+
+/// FLAG bit 13: SY2 saturated to -400h..+3FFh
+pub(super) const FLAG_SY2_SATURATED: u32 = 1 << 13;
+
+/// FLAG bit 12: IR0 saturated to 0..+1000h
+pub(super) const FLAG_IR0_SATURATED: u32 = 1 << 12;
+
+/// FLAG bits that can be written by CTC2 (bits 0-11 always read as 0)
+pub(super) const FLAG_WRITE_MASK: u32 = 0x7FFF_F000;
+
+/// FLAG bits contributing to the error summary bit
+pub(super) const FLAG_ERROR_MASK: u32 = 0x7F87_E000;
+
+impl GTE {
+    /// Check a MAC1-3 intermediate value for 44-bit overflow
+    ///
+    /// Sets the positive/negative overflow flag for the accumulator and
+    /// returns the value truncated (sign-extended) to 44 bits, as the
+    /// hardware does after every addition.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Accumulator index (1-3)
+    /// * `value` - Intermediate value
+    ///
+    /// # Returns
+    ///
+    /// Value truncated to 44 bits
+    #[inline(always)]
+    pub(super) fn truncate_mac(&mut self, index: usize, value: i64) -> i64 {
+        if value > 0x7FF_FFFF_FFFF {
+            self.flags |= FLAG_MAC_POSITIVE[index - 1];
+        } else if value < -0x800_0000_0000 {
+            self.flags |= FLAG_MAC_NEGATIVE[index - 1];
+        }
+
+        (value << 20) >> 20
+    }
+
+    /// Store a MAC1-3 result
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Accumulator index (1-3)
+    /// * `value` - Unshifted result
+    /// * `shift` - Right shift (0 or 12, from the sf bit)
+    ///
+    /// # Returns
+    ///
+    /// The value written to the MAC register
+    #[inline(always)]
+    pub(super) fn set_mac(&mut self, index: usize, value: i64, shift: u32) -> i32 {
+        let value = self.truncate_mac(index, value) >> shift;
+        self.data[Self::MAC0 + index] = value as i32;
+        value as i32
+    }
+
+    /// Store an IR1-3 value with saturation
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Register index (1-3)
+    /// * `value` - Value to saturate
+    /// * `lm` - Limit negative values to 0
+    #[inline(always)]
+    pub(super) fn set_ir(&mut self, index: usize, value: i64, lm: bool) {
+        let min = if lm { 0 } else { -0x8000 };
+        let clamped = value.clamp(min, 0x7FFF);
+        if clamped != value {
+            self.flags |= FLAG_IR_SATURATED[index - 1];
+        }
+        self.data[Self::IR0 + index] = clamped as i32;
+    }
+
+    /// Store a MAC1-3 result and copy it into IR1-3 with saturation
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Register index (1-3)
+    /// * `value` - Unshifted result
+    /// * `shift` - Right shift (0 or 12, from the sf bit)
+    /// * `lm` - Limit negative IR values to 0
+    #[inline(always)]
+    pub(super) fn set_mac_and_ir(&mut self, index: usize, value: i64, shift: u32, lm: bool) {
+        let mac = self.set_mac(index, value, shift);
+        self.set_ir(index, mac as i64, lm);
+    }
+
+    /// Check a MAC0 intermediate value for 32-bit overflow
+    ///
+    /// Used where MAC0 serves as an internal accumulator whose result is
+    /// not stored (RTPS screen X/Y calculation).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Intermediate value
+    ///
+    /// # Returns
+    ///
+    /// The value, unchanged
+    #[inline(always)]
+    pub(super) fn check_mac0(&mut self, value: i64) -> i64 {
+        if value > i32::MAX as i64 {
+            self.flags |= FLAG_MAC0_POSITIVE;
+        } else if value < i32::MIN as i64 {
+            self.flags |= FLAG_MAC0_NEGATIVE;
+        }
+        value
+    }
+
+    /// Store a MAC0 result, flagging 32-bit overflow
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Result
+    #[inline(always)]
+    pub(super) fn set_mac0(&mut self, value: i64) {
+        let value = self.check_mac0(value);
+        self.data[Self::MAC0] = value as i32;
+    }
+
+    /// Store IR0 with saturation to 0..+1000h
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to saturate
+    #[inline(always)]
+    pub(super) fn set_ir0(&mut self, value: i64) {
+        let clamped = value.clamp(0, 0x1000);
+        if clamped != value {
+            self.flags |= FLAG_IR0_SATURATED;
+        }
+        self.data[Self::IR0] = clamped as i32;
+    }
+
+    /// Push a value onto the screen Z FIFO with saturation to 0..FFFFh
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New SZ3 value
+    pub(super) fn push_sz(&mut self, value: i64) {
+        let clamped = value.clamp(0, 0xFFFF);
+        if clamped != value {
+            self.flags |= FLAG_SZ_OTZ_SATURATED;
+        }
+
+        self.data[Self::SZ0] = self.data[Self::SZ1];
+        self.data[Self::SZ1] = self.data[Self::SZ2];
+        self.data[Self::SZ2] = self.data[Self::SZ3];
+        self.data[Self::SZ3] = clamped as i32;
+    }
+
+    /// Push a point onto the screen XY FIFO with saturation to -400h..+3FFh
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - New SX2 value
+    /// * `y` - New SY2 value
+    pub(super) fn push_sxy(&mut self, x: i64, y: i64) {
+        let sx = x.clamp(-0x400, 0x3FF);
+        if sx != x {
+            self.flags |= FLAG_SX2_SATURATED;
+        }
+        let sy = y.clamp(-0x400, 0x3FF);
+        if sy != y {
+            self.flags |= FLAG_SY2_SATURATED;
+        }
+
+        let sxy = ((sy as i32) << 16) | (sx as i32 & 0xFFFF);
+        self.data[Self::SXY0] = self.data[Self::SXY1];
+        self.data[Self::SXY1] = self.data[Self::SXY2];
+        self.data[Self::SXY2] = sxy;
+        self.data[Self::SXYP] = sxy;
+    }
+
+    /// Store OTZ with saturation to 0..FFFFh
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Average Z value
+    #[inline(always)]
+    pub(super) fn set_otz(&mut self, value: i64) {
+        let clamped = value.clamp(0, 0xFFFF);
+        if clamped != value {
+            self.flags |= FLAG_SZ_OTZ_SATURATED;
+        }
+        self.data[Self::OTZ] = clamped as i32;
+    }
+
+    /// Push a color from MAC1-3 onto the color FIFO
+    ///
+    /// The new entry is `[MAC1/16, MAC2/16, MAC3/16, CODE]`, with each
+    /// component saturated to 0..FFh. CODE is taken from the RGBC register.
+    pub(super) fn push_color(&mut self) {
+        let mut rgb = [0u32; 3];
+        for (i, component) in rgb.iter_mut().enumerate() {
+            let value = (self.data[Self::MAC1 + i] >> 4) as i64;
+            let clamped = value.clamp(0, 0xFF);
+            if clamped != value {
+                self.flags |= FLAG_COLOR_SATURATED[i];
+            }
+            *component = clamped as u32;
+        }
+
+        let code = (self.data[Self::RGB] as u32) & 0xFF00_0000;
+
+        self.data[Self::RGB0] = self.data[Self::RGB1];
+        self.data[Self::RGB1] = self.data[Self::RGB2];
+        self.data[Self::RGB2] = (code | (rgb[2] << 16) | (rgb[1] << 8) | rgb[0]) as i32;
+    }
+
+    /// Multiply a matrix by a vector and add a translation vector
+    ///
+    /// Computes `MAC = (T * 1000h + M * V) SAR shift` for all three rows,
+    /// checking for 44-bit overflow after each addition, and copies the
+    /// result into IR1-3.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - 3x3 matrix
+    /// * `vector` - Input vector
+    /// * `translation` - Translation vector (added as `T * 1000h`)
+    /// * `shift` - Right shift (0 or 12, from the sf bit)
+    /// * `lm` - Limit negative IR values to 0
+    pub(super) fn multiply_matrix_vector(
+        &mut self,
+        matrix: &[[i32; 3]; 3],
+        vector: [i32; 3],
+        translation: [i32; 3],
+        shift: u32,
+        lm: bool,
+    ) {
+        for (row, m) in matrix.iter().enumerate() {
+            let index = row + 1;
+            let mut sum = (translation[row] as i64) << 12;
+            for (col, &v) in vector.iter().enumerate() {
+                sum = self.truncate_mac(index, sum + m[col] as i64 * v as i64);
+            }
+            self.set_mac_and_ir(index, sum, shift, lm);
+        }
+    }
+
+    /// Interpolate between a color and the far color (depth cueing)
+    ///
+    /// Computes `MAC = MAC + (FC - MAC) * IR0` in the hardware's two-step form:
+    ///
+    /// ```text
+    /// [IR1,IR2,IR3] = (([RFC,GFC,BFC] SHL 12) - [MAC1,MAC2,MAC3]) SAR (sf*12)
+    /// [MAC1,MAC2,MAC3] = (([IR1,IR2,IR3] * IR0) + [MAC1,MAC2,MAC3]) SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// ```
+    ///
+    /// The first IR saturation always uses lm=0.
+    ///
+    /// # Arguments
+    ///
+    /// * `mac` - Unshifted input color (already scaled by 1000h)
+    /// * `shift` - Right shift (0 or 12, from the sf bit)
+    /// * `lm` - Limit negative IR values to 0 for the final result
+    pub(super) fn interpolate_color(&mut self, mac: [i64; 3], shift: u32, lm: bool) {
+        let far_color = [
+            self.control[Self::RFC],
+            self.control[Self::GFC],
+            self.control[Self::BFC],
+        ];
+
+        for (i, &value) in mac.iter().enumerate() {
+            let index = i + 1;
+            self.set_mac_and_ir(index, ((far_color[i] as i64) << 12) - value, shift, false);
+        }
+
+        let ir0 = self.data[Self::IR0] as i64;
+        for (i, &value) in mac.iter().enumerate() {
+            let index = i + 1;
+            let ir = self.data[Self::IR0 + index] as i64;
+            self.set_mac_and_ir(index, ir * ir0 + value, shift, lm);
+        }
+    }
+
+    /// SQR: Square of vector IR
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [IR1*IR1,IR2*IR2,IR3*IR3] SHR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn sqr(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+
+        for index in 1..=3 {
+            let ir = self.data[Self::IR0 + index] as i64;
+            self.set_mac_and_ir(index, ir * ir, shift, lm);
+        }
+    }
+
+    /// OP: Outer product of two vectors
+    ///
+    /// Computes the cross product of IR and the rotation matrix diagonal
+    /// `D = [RT11, RT22, RT33]`.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// MAC1 = (IR3*D2 - IR2*D3) SAR (sf*12)
+    /// MAC2 = (IR1*D3 - IR3*D1) SAR (sf*12)
+    /// MAC3 = (IR2*D1 - IR1*D2) SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn op(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let rt = self.get_rotation_matrix();
+        let d = [rt[0][0] as i64, rt[1][1] as i64, rt[2][2] as i64];
+        let ir = [
+            self.data[Self::IR1] as i64,
+            self.data[Self::IR2] as i64,
+            self.data[Self::IR3] as i64,
+        ];
+
+        self.set_mac_and_ir(1, ir[2] * d[1] - ir[1] * d[2], shift, lm);
+        self.set_mac_and_ir(2, ir[0] * d[2] - ir[2] * d[0], shift, lm);
+        self.set_mac_and_ir(3, ir[1] * d[0] - ir[0] * d[1], shift, lm);
+    }
+
+    /// AVSZ3: Average of three Z values
+    ///
+    /// Used to compute the ordering table index of a triangle.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// MAC0 = ZSF3 * (SZ1 + SZ2 + SZ3)
+    /// OTZ = MAC0 / 1000h
+    /// ```
+    pub fn avsz3(&mut self) {
+        let zsf3 = self.control[Self::ZSF3] as i16 as i64;
+        let sum = self.data[Self::SZ1] as u16 as i64
+            + self.data[Self::SZ2] as u16 as i64
+            + self.data[Self::SZ3] as u16 as i64;
+        let mac0 = zsf3 * sum;
+
+        self.set_mac0(mac0);
+        self.set_otz(mac0 >> 12);
+    }
+
+    /// AVSZ4: Average of four Z values
+    ///
+    /// Used to compute the ordering table index of a quad.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// MAC0 = ZSF4 * (SZ0 + SZ1 + SZ2 + SZ3)
+    /// OTZ = MAC0 / 1000h
+    /// ```
+    pub fn avsz4(&mut self) {
+        let zsf4 = self.control[Self::ZSF4] as i16 as i64;
+        let sum = self.data[Self::SZ0] as u16 as i64
+            + self.data[Self::SZ1] as u16 as i64
+            + self.data[Self::SZ2] as u16 as i64
+            + self.data[Self::SZ3] as u16 as i64;
+        let mac0 = zsf4 * sum;
+
+        self.set_mac0(mac0);
+        self.set_otz(mac0 >> 12);
+    }
+}
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE color interpolation commands
+//!
+//! These commands interpolate colors towards the far color (depth cueing)
+//! or scale IR by IR0, pushing the result onto the color FIFO.
+//!
+//! | Command | Opcode | Description                                  |
+//! |---------|--------|----------------------------------------------|
+//! | DPCS    | 0x10   | Depth cue color (single)                     |
+//! | INTPL   | 0x11   | Interpolate IR and far color                 |
+//! | DCPL    | 0x29   | Depth cue color light                        |
+//! | DPCT    | 0x2A   | Depth cue color (triple, from color FIFO)    |
+//! | GPF     | 0x3D   | General purpose interpolation                |
+//! | GPL     | 0x3E   | General purpose interpolation with base      |
+//!
+//! # References
+//!
+//! - [PSX-SPX: GTE General Purpose Calculation Commands](https://psx-spx.consoledev.net/geometrytransformationenginegte/#gte-general-purpose-calculation-commands)
+
+use super::GTE;
+
+impl GTE {
+    /// Depth cue a packed RGB color and push the result onto the color FIFO
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [R,G,B] SHL 16
+    /// [MAC1,MAC2,MAC3] = MAC + (FC - MAC) * IR0
+    /// ```
+    fn depth_cue_color(&mut self, rgb: u32, shift: u32, lm: bool) {
+        let mac = [
+            ((rgb & 0xFF) as i64) << 16,
+            (((rgb >> 8) & 0xFF) as i64) << 16,
+            (((rgb >> 16) & 0xFF) as i64) << 16,
+        ];
+        self.interpolate_color(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// DPCS: Depth cue color (single)
+    ///
+    /// Interpolates the RGBC color towards the far color by IR0.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn dpcs(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let rgbc = self.data[Self::RGB] as u32;
+        self.depth_cue_color(rgbc, shift, lm);
+    }
+
+    /// DPCT: Depth cue color (triple)
+    ///
+    /// Performs DPCS three times, each time taking the input color from
+    /// the bottom of the color FIFO (RGB0). After completion the FIFO holds
+    /// the three depth-cued colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn dpct(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        for _ in 0..3 {
+            let rgb0 = self.data[Self::RGB0] as u32;
+            self.depth_cue_color(rgb0, shift, lm);
+        }
+    }
+
+    /// DCPL: Depth cue color light
+    ///
+    /// Multiplies IR by the RGBC color and interpolates towards the far color.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [R*IR1,G*IR2,B*IR3] SHL 4
+    /// [MAC1,MAC2,MAC3] = MAC + (FC - MAC) * IR0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn dcpl(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let mac = self.color_product();
+        self.interpolate_color(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// INTPL: Interpolation of a vector and the far color
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [IR1,IR2,IR3] SHL 12
+    /// [MAC1,MAC2,MAC3] = MAC + (FC - MAC) * IR0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn intpl(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let ir = self.get_ir_vector();
+        let mac = [
+            (ir[0] as i64) << 12,
+            (ir[1] as i64) << 12,
+            (ir[2] as i64) << 12,
+        ];
+        self.interpolate_color(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// GPF: General purpose interpolation
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [IR0*IR1,IR0*IR2,IR0*IR3] SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// Color FIFO = [MAC1/16,MAC2/16,MAC3/16,CODE]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn gpf(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let ir0 = self.data[Self::IR0] as i64;
+        let ir = self.get_ir_vector();
+
+        for (i, &value) in ir.iter().enumerate() {
+            self.set_mac_and_ir(i + 1, value as i64 * ir0, shift, lm);
+        }
+        self.push_color();
+    }
+
+    /// GPL: General purpose interpolation with base
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [MAC1,MAC2,MAC3] = [MAC1,MAC2,MAC3] SHL (sf*12)
+    /// [MAC1,MAC2,MAC3] = (([IR1,IR2,IR3] * IR0) + [MAC1,MAC2,MAC3]) SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// Color FIFO = [MAC1/16,MAC2/16,MAC3/16,CODE]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn gpl(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        let ir0 = self.data[Self::IR0] as i64;
+        let ir = self.get_ir_vector();
+
+        for (i, &value) in ir.iter().enumerate() {
+            let index = i + 1;
+            let base = (self.data[Self::MAC0 + index] as i64) << shift;
+            self.set_mac_and_ir(index, value as i64 * ir0 + base, shift, lm);
+        }
+        self.push_color();
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE perspective division
+//!
+//! RTPS/RTPT divide the projection plane distance H by SZ3 using an
+//! unsigned Newton-Raphson (UNR) reciprocal approximation seeded from a
+//! 257-entry table. The result differs slightly from an exact division, and
+//! games (and their culling) depend on those exact values.
+//!
+//! # Algorithm
+//!
+//! ```text
+//! if H < SZ3*2 then
+//!   z = count_leading_zeroes(SZ3)          ; 0..0Fh (16-bit SZ3)
+//!   n = (H SHL z)                          ; 0..7FFF8000h
+//!   d = (SZ3 SHL z)                        ; 8000h..FFFFh
+//!   u = unr_table[(d-7FC0h) SHR 7] + 101h  ; 200h..101h
+//!   d = ((2000080h - (d * u)) SHR 8)       ; 10000h..0FF01h
+//!   d = ((0000080h + (d * u)) SHR 8)       ; 20000h..10000h
+//!   n = min(1FFFFh, (((n*d) + 8000h) SHR 16))
+//! else
+//!   n = 1FFFFh, divide overflow
+//! ```
+//!
+//! # References
+//!
+//! - [PSX-SPX: GTE Division Inaccuracy](https://psx-spx.consoledev.net/geometrytransformationenginegte/#gte-division-inaccuracy)
+
+/// Result of a division overflow (and the upper limit of any result)
+pub(super) const DIVIDE_MAX: u32 = 0x1FFFF;
+
+/// UNR reciprocal seed table
+///
+/// `unr_table[i] = max(0, (40000h / (i + 100h) + 1) / 2 - 101h)`
+const UNR_TABLE: [u8; 257] = build_unr_table();
+
+/// Build the UNR seed table at compile time
+const fn build_unr_table() -> [u8; 257] {
+    let mut table = [0u8; 257];
+    let mut i = 0;
+    while i < table.len() {
+        let value = (0x40000 / (i as i32 + 0x100) + 1) / 2 - 0x101;
+        table[i] = if value > 0 { value as u8 } else { 0 };
+        i += 1;
+    }
+    table
+}
+
+/// Divide H by SZ3 the way the GTE does
+///
+/// # Arguments
+///
+/// * `h` - Projection plane distance (unsigned 16-bit)
+/// * `sz3` - Screen Z (unsigned 16-bit)
+///
+/// # Returns
+///
+/// `(quotient, overflow)` where quotient is a 1.16 fixed-point value in
+/// 0..1FFFFh and `overflow` is true if `H >= SZ3*2` (the quotient is then
+/// forced to 1FFFFh).
+///
+/// # Example
+///
+/// ```text
+/// unr_divide(0x100, 0x200) -> (0x8000, false)  // 0.5
+/// unr_divide(0x100, 0x80)  -> (0x1FFFF, true)
+/// ```
+pub(super) fn unr_divide(h: u16, sz3: u16) -> (u32, bool) {
+    let h = h as u32;
+    let sz3 = sz3 as u32;
+
+    if h >= sz3 * 2 {
+        return (DIVIDE_MAX, true);
+    }
+
+    let z = (sz3 as u16).leading_zeros();
+    let n = (h << z) as u64;
+    let d = sz3 << z;
+    let u = UNR_TABLE[((d - 0x7FC0) >> 7) as usize] as u32 + 0x101;
+    let d = (0x0200_0080 - d * u) >> 8;
+    let d = ((0x0000_0080 + d * u) >> 8) as u64;
+
+    let quotient = ((n * d + 0x8000) >> 16).min(DIVIDE_MAX as u64);
+    (quotient as u32, false)
+}
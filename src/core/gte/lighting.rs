@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE lighting commands
+//!
+//! Normal color commands compute the light intensity for a surface normal
+//! using the light matrix (LLM) and light color matrix (LCM), then optionally
+//! multiply by the vertex color (NCC*) and apply depth cueing (NCD*).
+//!
+//! | Command | Opcode | Description                                  |
+//! |---------|--------|----------------------------------------------|
+//! | NCDS    | 0x13   | Normal color depth cue (single vector)       |
+//! | CDP     | 0x14   | Color depth cue                              |
+//! | NCDT    | 0x16   | Normal color depth cue (triple vector)       |
+//! | NCCS    | 0x1B   | Normal color color (single vector)           |
+//! | CC      | 0x1C   | Color color                                  |
+//! | NCS     | 0x1E   | Normal color (single vector)                 |
+//! | NCT     | 0x20   | Normal color (triple vector)                 |
+//! | NCCT    | 0x3F   | Normal color color (triple vector)           |
+//!
+//! # References
+//!
+//! - [PSX-SPX: GTE Lighting and Color Commands](https://psx-spx.consoledev.net/geometrytransformationenginegte/#gte-general-purpose-calculation-commands)
+
+use super::GTE;
+
+impl GTE {
+    /// Compute the light color for a normal vector
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3] = (LLM*V) SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3] = (BK*1000h + LCM*IR) SAR (sf*12)
+    /// ```
+    fn light_normal(&mut self, normal: [i32; 3], shift: u32, lm: bool) {
+        let llm = self.get_matrix(Self::L11_L12);
+        self.multiply_matrix_vector(&llm, normal, [0; 3], shift, lm);
+        self.apply_light_color(shift, lm);
+    }
+
+    /// Multiply IR by the light color matrix and add the background color
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3] = (BK*1000h + LCM*IR) SAR (sf*12)
+    /// ```
+    fn apply_light_color(&mut self, shift: u32, lm: bool) {
+        let lcm = self.get_matrix(Self::LR1_LR2);
+        let bk = self.get_control_vector(Self::RBK);
+        let ir = self.get_ir_vector();
+        self.multiply_matrix_vector(&lcm, ir, bk, shift, lm);
+    }
+
+    /// Multiply IR by the RGBC color
+    ///
+    /// # Returns
+    ///
+    /// `[R*IR1, G*IR2, B*IR3] SHL 4` (unshifted MAC values)
+    pub(super) fn color_product(&self) -> [i64; 3] {
+        let rgbc = self.data[Self::RGB] as u32;
+        let ir = self.get_ir_vector();
+
+        [
+            ((rgbc & 0xFF) as i64 * ir[0] as i64) << 4,
+            (((rgbc >> 8) & 0xFF) as i64 * ir[1] as i64) << 4,
+            (((rgbc >> 16) & 0xFF) as i64 * ir[2] as i64) << 4,
+        ]
+    }
+
+    /// Store a color product in MAC1-3/IR1-3
+    fn set_color_product(&mut self, mac: [i64; 3], shift: u32, lm: bool) {
+        for (i, &value) in mac.iter().enumerate() {
+            self.set_mac_and_ir(i + 1, value, shift, lm);
+        }
+    }
+
+    /// Normal color for one vector (NCS/NCT)
+    fn normal_color(&mut self, vector: usize, shift: u32, lm: bool) {
+        let normal = self.get_vector(vector);
+        self.light_normal(normal, shift, lm);
+        self.push_color();
+    }
+
+    /// Normal color color for one vector (NCCS/NCCT)
+    fn normal_color_color(&mut self, vector: usize, shift: u32, lm: bool) {
+        let normal = self.get_vector(vector);
+        self.light_normal(normal, shift, lm);
+        let mac = self.color_product();
+        self.set_color_product(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// Normal color depth cue for one vector (NCDS/NCDT)
+    fn normal_color_depth_cue(&mut self, vector: usize, shift: u32, lm: bool) {
+        let normal = self.get_vector(vector);
+        self.light_normal(normal, shift, lm);
+        let mac = self.color_product();
+        self.interpolate_color(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// NCS: Normal color (single vector)
+    ///
+    /// Computes the light color for V0 and pushes it onto the color FIFO.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn ncs(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.normal_color(0, shift, lm);
+    }
+
+    /// NCT: Normal color (triple vector)
+    ///
+    /// Same as NCS for V0, V1 and V2.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn nct(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        for vector in 0..3 {
+            self.normal_color(vector, shift, lm);
+        }
+    }
+
+    /// NCCS: Normal color color (single vector)
+    ///
+    /// Computes the light color for V0, multiplies it by RGBC and pushes
+    /// the result onto the color FIFO.
+    ///
+    /// # Formula
+    ///
+    /// ```text
+    /// IR = light color of V0
+    /// [MAC1,MAC2,MAC3] = [R*IR1,G*IR2,B*IR3] SHL 4 SAR (sf*12)
+    /// [IR1,IR2,IR3] = [MAC1,MAC2,MAC3]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn nccs(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.normal_color_color(0, shift, lm);
+    }
+
+    /// NCCT: Normal color color (triple vector)
+    ///
+    /// Same as NCCS for V0, V1 and V2.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn ncct(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        for vector in 0..3 {
+            self.normal_color_color(vector, shift, lm);
+        }
+    }
+
+    /// NCDS: Normal color depth cue (single vector)
+    ///
+    /// Like NCCS, but additionally interpolates the result towards the far
+    /// color (FC) by IR0.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn ncds(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.normal_color_depth_cue(0, shift, lm);
+    }
+
+    /// NCDT: Normal color depth cue (triple vector)
+    ///
+    /// Same as NCDS for V0, V1 and V2.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn ncdt(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        for vector in 0..3 {
+            self.normal_color_depth_cue(vector, shift, lm);
+        }
+    }
+
+    /// CC: Color color
+    ///
+    /// Like NCCS, but uses IR as the (already computed) light intensity
+    /// instead of multiplying a normal by the light matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn cc(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.apply_light_color(shift, lm);
+        let mac = self.color_product();
+        self.set_color_product(mac, shift, lm);
+        self.push_color();
+    }
+
+    /// CDP: Color depth cue
+    ///
+    /// Like NCDS, but uses IR as the (already computed) light intensity
+    /// instead of multiplying a normal by the light matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `sf` - Shift flag
+    /// * `lm` - Limit negative IR values to 0
+    pub fn cdp(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.apply_light_color(shift, lm);
+        let mac = self.color_product();
+        self.interpolate_color(mac, shift, lm);
+        self.push_color();
+    }
+}
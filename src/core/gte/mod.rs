@@ -21,11 +21,13 @@
 //!
 //! # Features
 //!
-//! - Matrix and vector operations
+//! - Matrix and vector operations (MVMVA, SQR, OP)
 //! - Perspective transformation (RTPS, RTPT)
 //! - Normal clipping (NCLIP)
-//! - Color depth cueing
-//! - Outer product calculations
+//! - Lighting (NCS, NCT, NCCS, NCCT, NCDS, NCDT, CC, CDP)
+//! - Color depth cueing (DPCS, DPCT, DCPL, INTPL)
+//! - General purpose interpolation (GPF, GPL)
+//! - Ordering table Z averaging (AVSZ3, AVSZ4)
 //!
 //! # Hardware Details
 //!
@@ -38,6 +40,24 @@
 //!
 //! - [PSX-SPX: GTE](http://problemkaputt.de/psx-spx.htm#geometrytransformationenginegte)
 
+mod arithmetic;
+mod color;
+mod division;
+mod lighting;
+
+use arithmetic::{
+    FLAG_DIVIDE_OVERFLOW, FLAG_ERROR, FLAG_ERROR_MASK, FLAG_IR_SATURATED, FLAG_WRITE_MASK,
+};
+
+use crate::core::precision::PrecisionVertex;
+
+/// Horizontal projection scale in widescreen mode
+///
+/// Compresses X by 4:3 / 16:9 = 3/4, so a 16:9 field of view fits into the
+/// game's 4:3 screen. The GPU widens it back by drawing into drawing areas
+/// 4/3 as wide.
+const WIDESCREEN_X_SCALE: (i64, i64) = (3, 4);
+
 /// GTE (Geometry Transformation Engine) - COP2
 ///
 /// The GTE performs 3D geometry transformations and lighting calculations
@@ -73,21 +93,42 @@ pub struct GTE {
     /// - H: Projection plane distance
     control: [i32; 32],
 
-    /// FLAGS register (read from data register 31)
+    /// FLAGS register (read from control register 31)
     ///
     /// Tracks overflow, underflow, and other calculation flags.
     /// Bit layout:
     /// - Bits 0-11: Not used (always 0)
-    /// - Bit 12: MAC0 overflow (positive)
-    /// - Bit 13: MAC0 overflow (negative)
-    /// - Bit 14: Divide overflow
-    /// - Bit 15: SX2 saturated
-    /// - Bit 16: SY2 saturated
-    /// - Bit 17: IR0 saturated
-    /// - Bit 18-29: Various saturation flags
-    /// - Bit 30: Error flag (any error)
-    /// - Bit 31: Flag bit (calculation overflow)
+    /// - Bit 12: IR0 saturated
+    /// - Bit 13: SY2 saturated
+    /// - Bit 14: SX2 saturated
+    /// - Bit 15: MAC0 overflow (negative)
+    /// - Bit 16: MAC0 overflow (positive)
+    /// - Bit 17: Divide overflow
+    /// - Bit 18: SZ3/OTZ saturated
+    /// - Bits 19-21: Color FIFO B/G/R saturated
+    /// - Bits 22-24: IR3/IR2/IR1 saturated
+    /// - Bits 25-27: MAC3/MAC2/MAC1 overflow (negative)
+    /// - Bits 28-30: MAC3/MAC2/MAC1 overflow (positive)
+    /// - Bit 31: Error flag (bits 30-23 and 18-13 ORed together)
     flags: u32,
+
+    /// Precision geometry enabled (off by default)
+    ///
+    /// When set, RTPS/RTPT also keep the unrounded screen coordinates of
+    /// each projected vertex (see [`crate::core::precision`]).
+    precision_enabled: bool,
+
+    /// Precise shadow of the SXY0-2 FIFO
+    ///
+    /// `None` for entries that did not come from a perspective transform
+    /// (or were overwritten through MTC2/LWC2), which have no extra precision.
+    precise_sxy: [Option<PrecisionVertex>; 3],
+
+    /// Widescreen projection enabled (off by default)
+    ///
+    /// When set, RTPS/RTPT scale the projected X coordinate by
+    /// [`WIDESCREEN_X_SCALE`] around OFX.
+    widescreen: bool,
 }
 
 // Allow dead code for GTE register constants that will be used in future commands
@@ -126,7 +167,7 @@ impl GTE {
     const IRGB: usize = 28; // Color conversion input
     const ORGB: usize = 29; // Color conversion output
     const LZCS: usize = 30; // Leading zero count source
-    const LZCR: usize = 31; // Leading zero count result (also FLAGS)
+    const LZCR: usize = 31; // Leading zero count result (read-only)
 
     // Control register indices
     const RT11_RT12: usize = 0; // Rotation matrix R11,R12
@@ -160,7 +201,7 @@ impl GTE {
     const DQB: usize = 28; // Depth queue parameter B
     const ZSF3: usize = 29; // Z scale factor (1/3)
     const ZSF4: usize = 30; // Z scale factor (1/4)
-    const FLAG: usize = 31; // FLAG register (same as data[31])
+    const FLAG: usize = 31; // FLAG register (calculation errors)
 
     /// Create a new GTE instance
     ///
@@ -183,16 +224,107 @@ impl GTE {
             data: [0; 32],
             control: [0; 32],
             flags: 0,
+            precision_enabled: false,
+            precise_sxy: [None; 3],
+            widescreen: false,
         }
     }
 
     /// Reset GTE to initial state
     ///
-    /// Clears all data and control registers.
+    /// Clears all data and control registers. The precision geometry and
+    /// widescreen settings are preserved.
     pub fn reset(&mut self) {
         self.data = [0; 32];
         self.control = [0; 32];
         self.flags = 0;
+        self.precise_sxy = [None; 3];
+    }
+
+    /// Enable or disable precision geometry
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether RTPS/RTPT should record precise screen coordinates
+    pub fn set_precision_enabled(&mut self, enabled: bool) {
+        self.precision_enabled = enabled;
+        self.precise_sxy = [None; 3];
+    }
+
+    /// Check whether precision geometry is enabled
+    pub fn precision_enabled(&self) -> bool {
+        self.precision_enabled
+    }
+
+    /// Enable or disable the widescreen projection hack
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether RTPS/RTPT should compress X for a 16:9 display
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gte::GTE;
+    ///
+    /// let mut gte = GTE::new();
+    /// gte.write_control(26, 100); // H
+    /// gte.write_control(0, 0x1000); // RT11
+    /// gte.write_control(4, 0x1000); // RT33
+    /// gte.write_data(0, 80); // VX0 = 80
+    /// gte.write_data(1, 100); // VZ0 = 100
+    ///
+    /// gte.set_widescreen(true);
+    /// gte.rtps(true, false);
+    /// assert_eq!(gte.read_data(14) as i16, 60); // 80 * 3/4
+    /// ```
+    pub fn set_widescreen(&mut self, enabled: bool) {
+        self.widescreen = enabled;
+    }
+
+    /// Check whether the widescreen projection hack is enabled
+    pub fn widescreen(&self) -> bool {
+        self.widescreen
+    }
+
+    /// Get the precise vertex behind an SXY data register
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Data register index (12-15, SXY0-2 or SXYP)
+    ///
+    /// # Returns
+    ///
+    /// The unrounded screen position and depth of the vertex currently held
+    /// in the register, or `None` if precision geometry is disabled, the
+    /// register is not an SXY register, or its value was not produced by
+    /// RTPS/RTPT
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gte::GTE;
+    ///
+    /// let mut gte = GTE::new();
+    /// gte.set_precision_enabled(true);
+    /// gte.write_control(26, 100); // H
+    /// gte.write_data(1, 200); // VZ0
+    /// gte.write_control(0, 0x1000); // RT11
+    /// gte.write_control(2, 0x1000); // RT22
+    /// gte.write_control(4, 0x1000); // RT33
+    /// gte.write_data(0, 3); // VX0 = 3, VY0 = 0
+    /// gte.rtps(true, false);
+    ///
+    /// let vertex = gte.precise_sxy(14).unwrap();
+    /// assert_eq!(vertex.x, 1.5);
+    /// assert_eq!(vertex.w, 200.0);
+    /// ```
+    pub fn precise_sxy(&self, index: usize) -> Option<PrecisionVertex> {
+        match index {
+            Self::SXY0 | Self::SXY1 | Self::SXY2 => self.precise_sxy[index - Self::SXY0],
+            Self::SXYP => self.precise_sxy[2],
+            _ => None,
+        }
     }
 
     /// Read from data register
@@ -207,10 +339,18 @@ impl GTE {
     ///
     /// # Note
     ///
-    /// Register 31 (LZCR) also serves as the FLAGS register.
+    /// - 16-bit registers read back sign-extended (VZ0-2, IR0-3) or
+    ///   zero-extended (OTZ, SZ0-3); this is ensured on write
+    /// - Register 15 (SXYP) mirrors SXY2
+    /// - Registers 28 (IRGB) and 29 (ORGB) both return IR1-3 packed into a
+    ///   5:5:5 color, each component saturated to 0..1Fh
     #[inline(always)]
     pub fn read_data(&self, index: usize) -> i32 {
-        self.data[index]
+        match index {
+            Self::SXYP => self.data[Self::SXY2],
+            Self::IRGB | Self::ORGB => self.pack_ir_color(),
+            _ => self.data[index],
+        }
     }
 
     /// Write to data register
@@ -222,24 +362,51 @@ impl GTE {
     ///
     /// # Note
     ///
-    /// Writing to register 15 (SXYP) also updates the FIFO.
-    /// Writing to register 28 (IRGB) triggers color conversion.
-    /// Writing to register 30 (LZCS) triggers leading zero count.
+    /// - 16-bit registers only keep the lower 16 bits (see [`read_data`](Self::read_data))
+    /// - Writing to register 15 (SXYP) pushes the SXY FIFO
+    /// - Writing to register 28 (IRGB) expands the 5:5:5 color into IR1-3
+    /// - Writing to register 30 (LZCS) counts its leading bits into LZCR
+    /// - Registers 29 (ORGB) and 31 (LZCR) are read-only
     #[inline(always)]
     pub fn write_data(&mut self, index: usize, value: i32) {
         match index {
+            Self::VZ0 | Self::VZ1 | Self::VZ2 | Self::IR0 | Self::IR1 | Self::IR2 | Self::IR3 => {
+                self.data[index] = value as i16 as i32;
+            }
+            Self::OTZ | Self::SZ0 | Self::SZ1 | Self::SZ2 | Self::SZ3 => {
+                self.data[index] = value & 0xFFFF;
+            }
+            Self::SXY0 | Self::SXY1 | Self::SXY2 => {
+                self.data[index] = value;
+                self.precise_sxy[index - Self::SXY0] = None;
+            }
             Self::SXYP => {
                 // Writing to SXYP pushes to FIFO
                 self.data[Self::SXY0] = self.data[Self::SXY1];
                 self.data[Self::SXY1] = self.data[Self::SXY2];
                 self.data[Self::SXY2] = value;
                 self.data[Self::SXYP] = value;
+                self.precise_sxy = [self.precise_sxy[1], self.precise_sxy[2], None];
+            }
+            Self::IRGB => {
+                // Writing to IRGB expands the 5:5:5 color into IR1-3
+                self.data[Self::IRGB] = value & 0x7FFF;
+                self.data[Self::IR1] = (value & 0x1F) << 7;
+                self.data[Self::IR2] = ((value >> 5) & 0x1F) << 7;
+                self.data[Self::IR3] = ((value >> 10) & 0x1F) << 7;
             }
             Self::LZCS => {
-                // Writing to LZCS triggers leading zero count
+                // Writing to LZCS triggers leading zero count: leading
+                // zeros for positive values, leading ones for negative ones
                 self.data[Self::LZCS] = value;
-                // Count leading zeros of the value (treating as unsigned)
-                self.data[Self::LZCR] = (value as u32).leading_zeros() as i32;
+                self.data[Self::LZCR] = if value < 0 {
+                    value.leading_ones() as i32
+                } else {
+                    value.leading_zeros() as i32
+                };
+            }
+            Self::ORGB | Self::LZCR => {
+                // Read-only
             }
             _ => {
                 self.data[index] = value;
@@ -247,6 +414,15 @@ impl GTE {
         }
     }
 
+    /// Pack IR1-3 into a 5:5:5 color (ORGB)
+    ///
+    /// Each component is `IR / 80h` saturated to 0..1Fh.
+    fn pack_ir_color(&self) -> i32 {
+        let component = |ir: usize| (self.data[ir] >> 7).clamp(0, 0x1F);
+
+        component(Self::IR1) | (component(Self::IR2) << 5) | (component(Self::IR3) << 10)
+    }
+
     /// Read from control register
     ///
     /// # Arguments
@@ -256,6 +432,12 @@ impl GTE {
     /// # Returns
     ///
     /// Register value as i32
+    ///
+    /// # Note
+    ///
+    /// RT33, L33, LB3, H, DQA, ZSF3 and ZSF4 are 16-bit registers and read
+    /// back sign-extended. H is used as an unsigned value by RTPS/RTPT but
+    /// still reads back sign-extended (hardware bug).
     #[inline(always)]
     pub fn read_control(&self, index: usize) -> i32 {
         self.control[index]
@@ -267,9 +449,53 @@ impl GTE {
     ///
     /// * `index` - Register index (0-31)
     /// * `value` - Value to write
+    ///
+    /// # Note
+    ///
+    /// 16-bit registers only keep the lower 16 bits (see
+    /// [`read_control`](Self::read_control)). Writing to register 31 (FLAG)
+    /// only stores bits 12-30; bit 31 is recalculated from the written
+    /// error bits.
     #[inline(always)]
     pub fn write_control(&mut self, index: usize, value: i32) {
-        self.control[index] = value;
+        match index {
+            Self::RT33 | Self::L33 | Self::LB3 | Self::H | Self::DQA | Self::ZSF3 | Self::ZSF4 => {
+                self.control[index] = value as i16 as i32;
+            }
+            Self::FLAG => {
+                self.flags = value as u32 & FLAG_WRITE_MASK;
+                if self.flags & FLAG_ERROR_MASK != 0 {
+                    self.flags |= FLAG_ERROR;
+                }
+                self.control[Self::FLAG] = self.flags as i32;
+            }
+            _ => {
+                self.control[index] = value;
+            }
+        }
+    }
+
+    /// Get a 3x3 matrix from control registers
+    ///
+    /// Matrices are stored across 5 control registers in a packed format
+    /// with 16-bit signed values (the last register holds only M33).
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - First control register of the matrix (RT11_RT12, L11_L12 or LR1_LR2)
+    ///
+    /// # Returns
+    ///
+    /// 3x3 matrix as [[i32; 3]; 3]
+    fn get_matrix(&self, base: usize) -> [[i32; 3]; 3] {
+        let lo = |i: usize| self.control[base + i] as i16 as i32;
+        let hi = |i: usize| (self.control[base + i] >> 16) as i16 as i32;
+
+        [
+            [lo(0), hi(0), lo(1)],
+            [hi(1), lo(2), hi(2)],
+            [lo(3), hi(3), lo(4)],
+        ]
     }
 
     /// Get rotation matrix from control registers
@@ -281,25 +507,189 @@ impl GTE {
     ///
     /// 3x3 rotation matrix as [[i32; 3]; 3]
     fn get_rotation_matrix(&self) -> [[i32; 3]; 3] {
+        self.get_matrix(Self::RT11_RT12)
+    }
+
+    /// Get input vector V0, V1 or V2
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Vector index (0-2)
+    ///
+    /// # Returns
+    ///
+    /// Vector as [x, y, z] with 16-bit signed components
+    fn get_vector(&self, index: usize) -> [i32; 3] {
+        let xy = self.data[Self::VXY0 + index * 2];
+        let z = self.data[Self::VZ0 + index * 2];
+
+        [xy as i16 as i32, (xy >> 16) as i16 as i32, z as i16 as i32]
+    }
+
+    /// Get the IR vector [IR1, IR2, IR3]
+    fn get_ir_vector(&self) -> [i32; 3] {
         [
-            [
-                (self.control[Self::RT11_RT12] & 0xFFFF) as i16 as i32,
-                (self.control[Self::RT11_RT12] >> 16) as i16 as i32,
-                (self.control[Self::RT13_RT21] & 0xFFFF) as i16 as i32,
-            ],
-            [
-                (self.control[Self::RT13_RT21] >> 16) as i16 as i32,
-                (self.control[Self::RT22_RT23] & 0xFFFF) as i16 as i32,
-                (self.control[Self::RT22_RT23] >> 16) as i16 as i32,
-            ],
-            [
-                (self.control[Self::RT31_RT32] & 0xFFFF) as i16 as i32,
-                (self.control[Self::RT31_RT32] >> 16) as i16 as i32,
-                (self.control[Self::RT33] & 0xFFFF) as i16 as i32,
-            ],
+            self.data[Self::IR1] as i16 as i32,
+            self.data[Self::IR2] as i16 as i32,
+            self.data[Self::IR3] as i16 as i32,
         ]
     }
 
+    /// Get a 32-bit vector from three consecutive control registers
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - First control register (TRX, RBK or RFC)
+    fn get_control_vector(&self, base: usize) -> [i32; 3] {
+        [
+            self.control[base],
+            self.control[base + 1],
+            self.control[base + 2],
+        ]
+    }
+
+    /// Transform and project a single vertex (shared by RTPS and RTPT)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Input vector index (0-2)
+    /// * `shift` - Right shift (0 or 12, from the sf bit)
+    /// * `lm` - Limit negative IR values to 0
+    /// * `last` - True for the last vertex of the command; depth cueing
+    ///   (MAC0/IR0) is only written for it
+    ///
+    /// # Hardware Quirks
+    ///
+    /// - IR3 is saturated from MAC3, but its saturation flag is computed
+    ///   from `MAC3 SAR 12` (relevant when sf=0)
+    /// - SZ3 is always `MAC3 SAR 12`, regardless of sf
+    /// - The perspective division uses the UNR reciprocal approximation
+    fn rtp(&mut self, index: usize, shift: u32, lm: bool, last: bool) {
+        let vector = self.get_vector(index);
+        let rt = self.get_rotation_matrix();
+        let tr = self.get_control_vector(Self::TRX);
+
+        // IR1/IR2 = MAC1/MAC2 = (TR * 1000h + RT * V) SAR (sf*12)
+        let mut z = 0;
+        for (row, m) in rt.iter().enumerate() {
+            let mac = row + 1;
+            let mut sum = (tr[row] as i64) << 12;
+            for (col, &v) in vector.iter().enumerate() {
+                sum = self.truncate_mac(mac, sum + m[col] as i64 * v as i64);
+            }
+
+            if mac < 3 {
+                self.set_mac_and_ir(mac, sum, shift, lm);
+            } else {
+                z = sum;
+            }
+        }
+
+        // IR3 = MAC3, with the saturation flag taken from MAC3 SAR 12
+        let mac3 = self.set_mac(3, z, shift) as i64;
+        let min = if lm { 0 } else { -0x8000 };
+        self.data[Self::IR3] = mac3.clamp(min, 0x7FFF) as i32;
+        let z_shifted = z >> 12;
+        if !(-0x8000..=0x7FFF).contains(&z_shifted) {
+            self.flags |= FLAG_IR_SATURATED[2];
+        }
+
+        // SZ3 = MAC3 SAR ((1-sf)*12)
+        self.push_sz(z_shifted);
+
+        // Perspective division: H / SZ3
+        let (quotient, overflow) =
+            division::unr_divide(self.control[Self::H] as u16, self.data[Self::SZ3] as u16);
+        if overflow {
+            self.flags |= FLAG_DIVIDE_OVERFLOW;
+        }
+        let quotient = quotient as i64;
+
+        // SX2/SY2 = (quotient * IR + OF) SAR 16
+        let ir1 = self.data[Self::IR1] as i64;
+        let ir2 = self.data[Self::IR2] as i64;
+        let mut x = quotient * ir1;
+        if self.widescreen {
+            x = x * WIDESCREEN_X_SCALE.0 / WIDESCREEN_X_SCALE.1;
+        }
+        let sx = self.check_mac0(x + self.control[Self::OFX] as i64);
+        let sy = self.check_mac0(quotient * ir2 + self.control[Self::OFY] as i64);
+        self.push_sxy(sx >> 16, sy >> 16);
+
+        if self.precision_enabled {
+            let vertex = self.precise_projection(index, shift, lm, z, overflow.then_some(quotient));
+            self.precise_sxy = [self.precise_sxy[1], self.precise_sxy[2], Some(vertex)];
+        }
+
+        if last {
+            // IR0 = MAC0 SAR 12, MAC0 = quotient * DQA + DQB
+            let dqa = self.control[Self::DQA] as i16 as i64;
+            let dqb = self.control[Self::DQB] as i64;
+            let mac0 = quotient * dqa + dqb;
+            self.set_mac0(mac0);
+            self.set_ir0(mac0 >> 12);
+        }
+    }
+
+    /// Perspective transform of one vertex without intermediate rounding
+    ///
+    /// Repeats the RTPS calculation in floating point: the view-space X/Y
+    /// keep their fraction bits, and H/SZ3 is an exact division instead of
+    /// the UNR approximation. The widescreen scale is applied like in the
+    /// integer path, and results saturate like SX2/SY2.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Input vector (0-2)
+    /// * `shift` - MAC shift (sf*12)
+    /// * `lm` - Limit negative IR values to 0
+    /// * `z` - Unshifted MAC3 sum of the transform
+    /// * `overflow_quotient` - UNR result if the division overflowed
+    fn precise_projection(
+        &self,
+        index: usize,
+        shift: u32,
+        lm: bool,
+        z: i64,
+        overflow_quotient: Option<i64>,
+    ) -> PrecisionVertex {
+        let vector = self.get_vector(index);
+        let rt = self.get_rotation_matrix();
+        let tr = self.get_control_vector(Self::TRX);
+
+        let min = if lm { 0.0 } else { -32768.0 };
+        let scale = (1u64 << shift) as f64;
+        let view = |row: usize| {
+            let sum = ((tr[row] as i64) << 12)
+                + rt[row]
+                    .iter()
+                    .zip(vector.iter())
+                    .map(|(&m, &v)| m as i64 * v as i64)
+                    .sum::<i64>();
+            (sum as f64 / scale).clamp(min, 32767.0)
+        };
+
+        let depth = (z as f64 / 4096.0).clamp(0.0, 65535.0);
+        let quotient = match overflow_quotient {
+            Some(quotient) => quotient as f64 / 65536.0,
+            None => (self.control[Self::H] as u16) as f64 / depth,
+        };
+
+        let x_scale = if self.widescreen {
+            WIDESCREEN_X_SCALE.0 as f64 / WIDESCREEN_X_SCALE.1 as f64
+        } else {
+            1.0
+        };
+        let x = quotient * view(0) * x_scale + self.control[Self::OFX] as f64 / 65536.0;
+        let y = quotient * view(1) + self.control[Self::OFY] as f64 / 65536.0;
+
+        PrecisionVertex::new(
+            x.clamp(-1024.0, 1023.0) as f32,
+            y.clamp(-1024.0, 1023.0) as f32,
+            depth as f32,
+        )
+    }
+
     /// RTPS: Rotate, Translate, Perspective Transform, Single
     ///
     /// This is the most commonly used GTE command. It transforms a single
@@ -309,146 +699,49 @@ impl GTE {
     /// 1. Load input vector from V0 (VXY0, VZ0)
     /// 2. Multiply by rotation matrix (RT)
     /// 3. Add translation vector (TR)
-    /// 4. Perform perspective division
+    /// 4. Perform perspective division (UNR reciprocal)
     /// 5. Add screen offset and store in SXY FIFO
+    /// 6. Calculate depth cueing factor (IR0)
     ///
     /// # Arguments
     ///
     /// * `sf` - Shift flag: if true, shift right by 12 bits (fixed-point adjustment)
+    /// * `lm` - Limit negative IR values to 0
     ///
     /// # Formula
     ///
     /// ```text
-    /// MAC = RT * V + TR
-    /// SXY = (H * MAC.xy / MAC.z) + Offset
+    /// IR1 = MAC1 = (TRX*1000h + RT11*VX0 + RT12*VY0 + RT13*VZ0) SAR (sf*12)
+    /// IR2 = MAC2 = (TRY*1000h + RT21*VX0 + RT22*VY0 + RT23*VZ0) SAR (sf*12)
+    /// IR3 = MAC3 = (TRZ*1000h + RT31*VX0 + RT32*VY0 + RT33*VZ0) SAR (sf*12)
+    /// SZ3 = MAC3 SAR ((1-sf)*12)
+    /// MAC0 = (H/SZ3)*IR1 + OFX, SX2 = MAC0/10000h
+    /// MAC0 = (H/SZ3)*IR2 + OFY, SY2 = MAC0/10000h
+    /// MAC0 = (H/SZ3)*DQA + DQB, IR0 = MAC0/1000h
     /// ```
-    pub fn rtps(&mut self, sf: bool) {
+    ///
+    /// With the widescreen hack enabled, `(H/SZ3)*IR1` is scaled by 3/4
+    /// before OFX is added.
+    pub fn rtps(&mut self, sf: bool, lm: bool) {
         let shift = if sf { 12 } else { 0 };
-
-        // Load input vector V0
-        let vx = (self.data[Self::VXY0] & 0xFFFF) as i16 as i32;
-        let vy = (self.data[Self::VXY0] >> 16) as i16 as i32;
-        let vz = self.data[Self::VZ0] as i16 as i32;
-
-        // Get rotation matrix
-        let rt = self.get_rotation_matrix();
-
-        // Get translation vector
-        let trx = self.control[Self::TRX] as i64;
-        let try_val = self.control[Self::TRY] as i64;
-        let trz = self.control[Self::TRZ] as i64;
-
-        // Matrix multiplication with translation: MAC = (RT * V + TR * 0x1000) SAR (sf*12)
-        // Hardware formula: MACn = (TRn*0x1000 + matrix_terms) SAR (sf*12)
-        // Cast to i64 before multiplication to prevent intermediate i32 overflow
-        let mac1 = (rt[0][0] as i64 * vx as i64
-            + rt[0][1] as i64 * vy as i64
-            + rt[0][2] as i64 * vz as i64
-            + (trx << 12))
-            >> shift;
-        let mac2 = (rt[1][0] as i64 * vx as i64
-            + rt[1][1] as i64 * vy as i64
-            + rt[1][2] as i64 * vz as i64
-            + (try_val << 12))
-            >> shift;
-        let mac3 = (rt[2][0] as i64 * vx as i64
-            + rt[2][1] as i64 * vy as i64
-            + rt[2][2] as i64 * vz as i64
-            + (trz << 12))
-            >> shift;
-
-        // Store MAC values (saturated to 32-bit)
-        self.data[Self::MAC1] = mac1.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-        self.data[Self::MAC2] = mac2.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-        self.data[Self::MAC3] = mac3.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-
-        // Reset FLAGS for this operation (we currently only model divide overflow).
-        self.flags = 0;
-
-        // Perspective transformation.
-        // MAC values are in 12.4 fixed point; apply a 12-bit scale so that
-        // typical PSX-style ranges don't collapse to zero.
-        let h = self.control[Self::H] as i64;
-        let z = mac3;
-
-        let (sx, sy) = if z <= 0 {
-            // Divide overflow case: negative/zero Z.
-            self.flags |= 1 << 14; // Bit 14: divide overflow
-
-            // Saturated scale value used by the real GTE on overflow.
-            let scale = 0x1FFFF_i64;
-            let sx = ((scale * mac1) >> 12).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-            let sy = ((scale * mac2) >> 12).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-            (sx, sy)
-        } else {
-            // Normal division with fixed-point aware scale,
-            // clamped to the hardware 17-bit range.
-            let scale = ((h << 12) / z).min(0x1FFFF);
-            let sx = ((scale * mac1) >> 12).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-            let sy = ((scale * mac2) >> 12).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-            (sx, sy)
-        };
-
-        // Apply screen offset
-        let ofx = self.control[Self::OFX];
-        let ofy = self.control[Self::OFY];
-
-        let sx_screen = sx + ofx;
-        let sy_screen = sy + ofy;
-
-        // Update screen coordinate FIFO
-        self.data[Self::SXY0] = self.data[Self::SXY1];
-        self.data[Self::SXY1] = self.data[Self::SXY2];
-        self.data[Self::SXY2] =
-            (sy_screen.clamp(-1024, 1023) << 16) | (sx_screen.clamp(-1024, 1023) & 0xFFFF);
-        self.data[Self::SXYP] = self.data[Self::SXY2];
-
-        // Update screen Z FIFO
-        self.data[Self::SZ0] = self.data[Self::SZ1];
-        self.data[Self::SZ1] = self.data[Self::SZ2];
-        self.data[Self::SZ2] = self.data[Self::SZ3];
-        self.data[Self::SZ3] = (z.clamp(0, 0xFFFF) as u16) as i32;
-
-        // Calculate OTZ (average Z for ordering table)
-        let sz_avg = (self.data[Self::SZ1] + self.data[Self::SZ2] + self.data[Self::SZ3]) / 3;
-        self.data[Self::OTZ] = sz_avg.clamp(0, 0xFFFF);
-
-        // Set IR registers (intermediate results)
-        self.data[Self::IR1] = mac1.clamp(-32768, 32767) as i32;
-        self.data[Self::IR2] = mac2.clamp(-32768, 32767) as i32;
-        self.data[Self::IR3] = mac3.clamp(0, 65535) as i32;
-
-        // Mirror FLAGS into the shared LZCR/FLAG register slot.
-        self.data[Self::LZCR] = self.flags as i32;
+        self.rtp(0, shift, lm, true);
     }
 
     /// RTPT: Rotate, Translate, Perspective Transform, Triple
     ///
-    /// Similar to RTPS but processes three vertices (V0, V1, V2) in sequence.
-    /// This is more efficient than calling RTPS three times.
+    /// Same as RTPS, but processes three vertices (V0, V1, V2) in sequence.
+    /// This is more efficient than calling RTPS three times. Depth cueing
+    /// (MAC0/IR0) is only calculated for the last vertex.
     ///
     /// # Arguments
     ///
     /// * `sf` - Shift flag: if true, shift right by 12 bits
-    pub fn rtpt(&mut self, sf: bool) {
-        // Process V0
-        self.rtps(sf);
-
-        // Process V1 - swap V0 with V1 temporarily
-        let v0_xy = self.data[Self::VXY0];
-        let v0_z = self.data[Self::VZ0];
-        self.data[Self::VXY0] = self.data[Self::VXY1];
-        self.data[Self::VZ0] = self.data[Self::VZ1];
-        self.rtps(sf);
-
-        // Process V2 - swap with V2
-        self.data[Self::VXY0] = self.data[Self::VXY2];
-        self.data[Self::VZ0] = self.data[Self::VZ2];
-        self.rtps(sf);
-
-        // Restore V0
-        self.data[Self::VXY0] = v0_xy;
-        self.data[Self::VZ0] = v0_z;
+    /// * `lm` - Limit negative IR values to 0
+    pub fn rtpt(&mut self, sf: bool, lm: bool) {
+        let shift = if sf { 12 } else { 0 };
+        self.rtp(0, shift, lm, false);
+        self.rtp(1, shift, lm, false);
+        self.rtp(2, shift, lm, true);
     }
 
     /// NCLIP: Normal Clipping
@@ -480,11 +773,7 @@ impl GTE {
         let result =
             (sx0 * sy1) + (sx1 * sy2) + (sx2 * sy0) - (sx0 * sy2) - (sx1 * sy0) - (sx2 * sy1);
 
-        self.data[Self::MAC0] = result;
-
-        // Clear flags
-        self.flags = 0;
-        self.data[Self::LZCR] = 0;
+        self.set_mac0(result as i64);
     }
 
     /// MVMVA: Multiply Vector by Matrix and Vector Addition
@@ -499,11 +788,17 @@ impl GTE {
     /// # Format
     ///
     /// The command word specifies:
-    /// - Bits [20:19]: Translation vector selection
-    /// - Bits [18:17]: Multiply vector selection
-    /// - Bits [16:15]: Multiply matrix selection
-    /// - Bit 10: lm flag (limit negative to 0)
     /// - Bit 19: sf flag (shift fraction)
+    /// - Bits [18:17]: Multiply matrix (0=RT, 1=LLM, 2=LCM, 3=garbage)
+    /// - Bits [16:15]: Multiply vector (0=V0, 1=V1, 2=V2, 3=IR)
+    /// - Bits [14:13]: Translation vector (0=TR, 1=BK, 2=FC, 3=none)
+    /// - Bit 10: lm flag (limit negative to 0)
+    ///
+    /// # Hardware Quirks
+    ///
+    /// - Matrix 3 returns a garbage matrix built from RGBC.R, IR0, RT13 and RT22
+    /// - With the FC translation vector, only the last two matrix columns end
+    ///   up in the result; the first column and FC only affect the flags
     pub fn mvmva(&mut self, command: u32) {
         let sf = ((command >> 19) & 1) != 0;
         let mx = (command >> 17) & 0x3; // Matrix selection
@@ -514,92 +809,58 @@ impl GTE {
         let shift = if sf { 12 } else { 0 };
 
         // Select input vector
-        let (vx, vy, vz) = match v {
-            0 => {
-                // V0
-                let vx = (self.data[Self::VXY0] & 0xFFFF) as i16 as i32;
-                let vy = (self.data[Self::VXY0] >> 16) as i16 as i32;
-                let vz = self.data[Self::VZ0] as i16 as i32;
-                (vx, vy, vz)
-            }
-            1 => {
-                // V1
-                let vx = (self.data[Self::VXY1] & 0xFFFF) as i16 as i32;
-                let vy = (self.data[Self::VXY1] >> 16) as i16 as i32;
-                let vz = self.data[Self::VZ1] as i16 as i32;
-                (vx, vy, vz)
-            }
-            2 => {
-                // V2
-                let vx = (self.data[Self::VXY2] & 0xFFFF) as i16 as i32;
-                let vy = (self.data[Self::VXY2] >> 16) as i16 as i32;
-                let vz = self.data[Self::VZ2] as i16 as i32;
-                (vx, vy, vz)
-            }
-            3 => {
-                // IR (short vector)
-                (
-                    self.data[Self::IR1] as i16 as i32,
-                    self.data[Self::IR2] as i16 as i32,
-                    self.data[Self::IR3] as i16 as i32,
-                )
-            }
-            _ => unreachable!(),
+        let vector = match v {
+            0..=2 => self.get_vector(v as usize),
+            _ => self.get_ir_vector(),
         };
 
-        // Select matrix (simplified - only rotation matrix for now)
+        // Select matrix
         let matrix = match mx {
-            0..=3 => self.get_rotation_matrix(),
-            _ => [[0; 3]; 3],
+            0 => self.get_rotation_matrix(),
+            1 => self.get_matrix(Self::L11_L12),
+            2 => self.get_matrix(Self::LR1_LR2),
+            _ => {
+                let r = ((self.data[Self::RGB] & 0xFF) << 4) as i16 as i32;
+                let ir0 = self.data[Self::IR0] as i16 as i32;
+                let rt = self.get_rotation_matrix();
+                [
+                    [-r, r, ir0],
+                    [rt[0][2], rt[0][2], rt[0][2]],
+                    [rt[1][1], rt[1][1], rt[1][1]],
+                ]
+            }
         };
 
-        // Select translation vector (simplified - using TR for now)
-        let (tx, ty, tz) = match cv {
-            0 | 1 => (
-                self.control[Self::TRX] as i64,
-                self.control[Self::TRY] as i64,
-                self.control[Self::TRZ] as i64,
-            ),
-            _ => (0, 0, 0),
+        // Select translation vector
+        let translation = match cv {
+            0 => self.get_control_vector(Self::TRX),
+            1 => self.get_control_vector(Self::RBK),
+            2 => self.get_control_vector(Self::RFC),
+            _ => [0; 3],
         };
 
-        // Matrix multiplication with translation: MAC = (Matrix * V + T * 0x1000) SAR (sf*12)
-        // Hardware formula: MACn = (Tn*0x1000 + matrix_terms) SAR (sf*12)
-        // Cast to i64 before multiplication to prevent intermediate i32 overflow
-        let mac1 = (matrix[0][0] as i64 * vx as i64
-            + matrix[0][1] as i64 * vy as i64
-            + matrix[0][2] as i64 * vz as i64
-            + (tx << 12))
-            >> shift;
-        let mac2 = (matrix[1][0] as i64 * vx as i64
-            + matrix[1][1] as i64 * vy as i64
-            + matrix[1][2] as i64 * vz as i64
-            + (ty << 12))
-            >> shift;
-        let mac3 = (matrix[2][0] as i64 * vx as i64
-            + matrix[2][1] as i64 * vy as i64
-            + matrix[2][2] as i64 * vz as i64
-            + (tz << 12))
-            >> shift;
-
-        // Store results
-        self.data[Self::MAC1] = mac1.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-        self.data[Self::MAC2] = mac2.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-        self.data[Self::MAC3] = mac3.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
-
-        // Update IR registers with limiting
-        let min_val = if lm { 0 } else { -32768 };
-        self.data[Self::IR1] = mac1.clamp(min_val, 32767) as i32;
-        self.data[Self::IR2] = mac2.clamp(min_val, 32767) as i32;
-        self.data[Self::IR3] = mac3.clamp(min_val, 32767) as i32;
-
-        self.flags = 0;
-        self.data[Self::LZCR] = 0;
+        if cv == 2 {
+            // Far color bug: the first column is computed (affecting flags)
+            // but discarded from the result
+            for (row, m) in matrix.iter().enumerate() {
+                let index = row + 1;
+                let first = self.truncate_mac(index, (translation[row] as i64) << 12);
+                let first = self.truncate_mac(index, first + m[0] as i64 * vector[0] as i64);
+                self.set_ir(index, first >> shift, false);
+
+                let sum = self.truncate_mac(index, m[1] as i64 * vector[1] as i64);
+                self.set_mac_and_ir(index, sum + m[2] as i64 * vector[2] as i64, shift, lm);
+            }
+        } else {
+            self.multiply_matrix_vector(&matrix, vector, translation, shift, lm);
+        }
     }
 
     /// Execute GTE command
     ///
     /// Dispatches a GTE command to the appropriate handler based on the opcode.
+    /// FLAG is cleared before the command runs, and its error summary bit
+    /// (bit 31) is updated afterwards.
     ///
     /// # Arguments
     ///
@@ -612,29 +873,59 @@ impl GTE {
     /// - Bit 10: lm (limit negative values)
     /// - Other bits: Command-specific parameters
     ///
-    /// # Common Commands
-    ///
-    /// - 0x01: RTPS (Perspective transform single)
-    /// - 0x06: NCLIP (Normal clipping)
-    /// - 0x12: MVMVA (Matrix-vector multiply)
-    /// - 0x30: RTPT (Perspective transform triple)
+    /// # Commands
+    ///
+    /// | Opcode | Command | Opcode | Command | Opcode | Command |
+    /// |--------|---------|--------|---------|--------|---------|
+    /// | 0x01   | RTPS    | 0x13   | NCDS    | 0x28   | SQR     |
+    /// | 0x06   | NCLIP   | 0x14   | CDP     | 0x29   | DCPL    |
+    /// | 0x0C   | OP      | 0x16   | NCDT    | 0x2A   | DPCT    |
+    /// | 0x10   | DPCS    | 0x1B   | NCCS    | 0x2D   | AVSZ3   |
+    /// | 0x11   | INTPL   | 0x1C   | CC      | 0x2E   | AVSZ4   |
+    /// | 0x12   | MVMVA   | 0x1E   | NCS     | 0x30   | RTPT    |
+    /// | 0x3D   | GPF     | 0x20   | NCT     | 0x3E   | GPL     |
+    /// | 0x3F   | NCCT    |        |         |        |         |
     pub fn execute(&mut self, command: u32) {
         let opcode = command & 0x3F;
         let sf = (command & 0x80000) != 0; // Shift flag (bit 19)
+        let lm = (command & 0x400) != 0; // Limit flag (bit 10)
+
+        self.flags = 0;
 
         match opcode {
-            0x01 => self.rtps(sf),
+            0x01 => self.rtps(sf, lm),
             0x06 => self.nclip(),
+            0x0C => self.op(sf, lm),
+            0x10 => self.dpcs(sf, lm),
+            0x11 => self.intpl(sf, lm),
             0x12 => self.mvmva(command),
-            0x30 => self.rtpt(sf),
-            // TODO: Implement remaining GTE commands as needed
+            0x13 => self.ncds(sf, lm),
+            0x14 => self.cdp(sf, lm),
+            0x16 => self.ncdt(sf, lm),
+            0x1B => self.nccs(sf, lm),
+            0x1C => self.cc(sf, lm),
+            0x1E => self.ncs(sf, lm),
+            0x20 => self.nct(sf, lm),
+            0x28 => self.sqr(sf, lm),
+            0x29 => self.dcpl(sf, lm),
+            0x2A => self.dpct(sf, lm),
+            0x2D => self.avsz3(),
+            0x2E => self.avsz4(),
+            0x30 => self.rtpt(sf, lm),
+            0x3D => self.gpf(sf, lm),
+            0x3E => self.gpl(sf, lm),
+            0x3F => self.ncct(sf, lm),
             _ => {
                 log::warn!("Unknown GTE command: 0x{:02X}", opcode);
                 // Set error flag for unknown commands
-                self.flags = 0x80000000;
-                self.data[Self::LZCR] = 0x80000000u32 as i32;
+                self.flags = FLAG_ERROR;
             }
         }
+
+        if self.flags & FLAG_ERROR_MASK != 0 {
+            self.flags |= FLAG_ERROR;
+        }
+        self.control[Self::FLAG] = self.flags as i32;
     }
 }
 
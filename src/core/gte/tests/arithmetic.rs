@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE arithmetic command tests
+//!
+//! Tests for SQR, OP, AVSZ3, AVSZ4, MVMVA and the shared saturation rules.
+
+use super::super::*;
+
+/// Build a command word with sf and lm bits
+fn cmd(opcode: u32, sf: bool, lm: bool) -> u32 {
+    opcode | ((sf as u32) << 19) | ((lm as u32) << 10)
+}
+
+/// Build an MVMVA command word
+fn mvmva_cmd(sf: bool, mx: u32, v: u32, cv: u32, lm: bool) -> u32 {
+    cmd(0x12, sf, lm) | (mx << 17) | (v << 15) | (cv << 13)
+}
+
+fn set_ir(gte: &mut GTE, ir: [i32; 3]) {
+    gte.write_data(GTE::IR1, ir[0]);
+    gte.write_data(GTE::IR2, ir[1]);
+    gte.write_data(GTE::IR3, ir[2]);
+}
+
+fn flag(gte: &GTE) -> u32 {
+    gte.read_control(GTE::FLAG) as u32
+}
+
+#[test]
+fn test_sqr_with_shift() {
+    let mut gte = GTE::new();
+    set_ir(&mut gte, [0x100, -0x200, 0x10]);
+
+    gte.execute(cmd(0x28, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x10);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x40);
+    assert_eq!(gte.read_data(GTE::MAC3), 0);
+    assert_eq!(gte.read_data(GTE::IR2), 0x40);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_sqr_without_shift_saturates_ir() {
+    let mut gte = GTE::new();
+    set_ir(&mut gte, [0x100, -0x200, 0x10]);
+
+    gte.execute(cmd(0x28, false, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x10000);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x40000);
+    assert_eq!(gte.read_data(GTE::MAC3), 0x100);
+    assert_eq!(gte.read_data(GTE::IR1), 0x7FFF);
+    assert_eq!(gte.read_data(GTE::IR2), 0x7FFF);
+    assert_eq!(gte.read_data(GTE::IR3), 0x100);
+
+    // IR1/IR2 saturated, plus error summary
+    assert_eq!(flag(&gte), (1 << 31) | (1 << 24) | (1 << 23));
+}
+
+#[test]
+fn test_op_outer_product() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x2000);
+    gte.write_control(GTE::RT33, 0x3000);
+    set_ir(&mut gte, [0x10, 0x20, 0x40]);
+
+    gte.execute(cmd(0x0C, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x20);
+    assert_eq!(gte.read_data(GTE::MAC2), -0x10);
+    assert_eq!(gte.read_data(GTE::MAC3), 0);
+    assert_eq!(gte.read_data(GTE::IR2), -0x10);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_op_lm_limits_negative_ir() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x2000);
+    gte.write_control(GTE::RT33, 0x3000);
+    set_ir(&mut gte, [0x10, 0x20, 0x40]);
+
+    gte.execute(cmd(0x0C, true, true));
+
+    // MAC keeps the negative value, IR is clamped to 0
+    assert_eq!(gte.read_data(GTE::MAC2), -0x10);
+    assert_eq!(gte.read_data(GTE::IR2), 0);
+    assert_eq!(flag(&gte), (1 << 31) | (1 << 23));
+}
+
+#[test]
+fn test_avsz3() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::ZSF3, 0x555);
+    gte.write_data(GTE::SZ1, 300);
+    gte.write_data(GTE::SZ2, 600);
+    gte.write_data(GTE::SZ3, 900);
+
+    gte.execute(0x2D);
+
+    assert_eq!(gte.read_data(GTE::MAC0), 1800 * 0x555);
+    assert_eq!(gte.read_data(GTE::OTZ), (1800 * 0x555) >> 12);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_avsz4() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::ZSF4, 0x400);
+    gte.write_data(GTE::SZ0, 100);
+    gte.write_data(GTE::SZ1, 200);
+    gte.write_data(GTE::SZ2, 300);
+    gte.write_data(GTE::SZ3, 400);
+
+    gte.execute(0x2E);
+
+    assert_eq!(gte.read_data(GTE::MAC0), 1000 * 0x400);
+    assert_eq!(gte.read_data(GTE::OTZ), 250);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_avsz3_saturation() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::ZSF3, 0x7FFF);
+    gte.write_data(GTE::SZ1, 0xFFFF);
+    gte.write_data(GTE::SZ2, 0xFFFF);
+    gte.write_data(GTE::SZ3, 0xFFFF);
+
+    gte.execute(0x2D);
+
+    // MAC0 overflows 32 bits and OTZ saturates
+    assert_eq!(gte.read_data(GTE::OTZ), 0xFFFF);
+    assert_eq!(flag(&gte), (1 << 31) | (1 << 18) | (1 << 16));
+
+    // Negative scale factor saturates OTZ to 0
+    gte.write_control(GTE::ZSF3, -0x100);
+    gte.execute(0x2D);
+    assert_eq!(gte.read_data(GTE::OTZ), 0);
+    assert_eq!(flag(&gte), (1 << 31) | (1 << 18));
+}
+
+#[test]
+fn test_flags_cleared_per_command() {
+    let mut gte = GTE::new();
+    set_ir(&mut gte, [0x100, 0, 0]);
+    gte.execute(cmd(0x28, false, false));
+    assert_ne!(flag(&gte), 0);
+
+    set_ir(&mut gte, [0x100, 0, 0]);
+    gte.execute(cmd(0x28, true, false));
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_mvmva_light_matrix_with_background_color() {
+    let mut gte = GTE::new();
+    // LLM = identity
+    gte.write_control(GTE::L11_L12, 0x1000);
+    gte.write_control(GTE::L22_L23, 0x1000);
+    gte.write_control(GTE::L33, 0x1000);
+    // BK = (1, 2, 3)
+    gte.write_control(GTE::RBK, 1);
+    gte.write_control(GTE::GBK, 2);
+    gte.write_control(GTE::BBK, 3);
+    gte.write_data(GTE::VXY1, (0x200 << 16) | 0x100);
+    gte.write_data(GTE::VZ1, 0x300);
+
+    gte.execute(mvmva_cmd(true, 1, 1, 1, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x101);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x202);
+    assert_eq!(gte.read_data(GTE::MAC3), 0x303);
+    assert_eq!(gte.read_data(GTE::IR3), 0x303);
+}
+
+#[test]
+fn test_mvmva_color_matrix_ir_vector_no_translation() {
+    let mut gte = GTE::new();
+    // LCM: row 0 = (0x2000, 0, 0), row 1 = (0, 0, 0x1000), row 2 = 0
+    gte.write_control(GTE::LR1_LR2, 0x2000);
+    gte.write_control(GTE::LG2_LG3, 0x1000 << 16);
+    set_ir(&mut gte, [0x10, 0x20, 0x30]);
+
+    gte.execute(mvmva_cmd(true, 2, 3, 3, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x20);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x30);
+    assert_eq!(gte.read_data(GTE::MAC3), 0);
+}
+
+#[test]
+fn test_mvmva_far_color_bug() {
+    let mut gte = GTE::new();
+    // RT = identity
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x1000);
+    gte.write_control(GTE::RT33, 0x1000);
+    gte.write_control(GTE::RFC, 0x1000);
+    gte.write_control(GTE::GFC, 0x1000);
+    gte.write_control(GTE::BFC, 0x1000);
+    gte.write_data(GTE::VXY0, (0x200 << 16) | 0x100);
+    gte.write_data(GTE::VZ0, 0x300);
+
+    gte.execute(mvmva_cmd(true, 0, 0, 2, false));
+
+    // Only the last two columns contribute; FC and column 1 are dropped
+    assert_eq!(gte.read_data(GTE::MAC1), 0);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x200);
+    assert_eq!(gte.read_data(GTE::MAC3), 0x300);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_mvmva_mac_overflow_flag() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::TRX, 0x7FFF_FFFF);
+    gte.write_control(GTE::RT11_RT12, 0x7FFF);
+    gte.write_data(GTE::VXY0, 0x7FFF);
+
+    gte.execute(mvmva_cmd(true, 0, 0, 0, false));
+
+    // (TRX << 12) + RT11*VX exceeds 43 bits
+    assert_ne!(flag(&gte) & (1 << 30), 0);
+    assert_ne!(flag(&gte) & (1 << 31), 0);
+}
@@ -35,9 +35,89 @@ fn test_data_register_read_write() {
     gte.write_data(GTE::MAC1, 0x12345678);
     assert_eq!(gte.read_data(GTE::MAC1), 0x12345678);
 
-    // Test register 31 (LZCR/FLAGS) can be written and read
+    // Register 31 (LZCR) is read-only
+    gte.write_data(GTE::LZCS, 0x00FF_0000);
     gte.write_data(GTE::LZCR, 0xABCDEF00u32 as i32);
-    assert_eq!(gte.read_data(GTE::LZCR) as u32, 0xABCDEF00);
+    assert_eq!(gte.read_data(GTE::LZCR), 8);
+}
+
+#[test]
+fn test_data_register_sign_extension() {
+    let mut gte = GTE::new();
+
+    // VZ0-2 and IR0-3 are signed 16-bit
+    for reg in [
+        GTE::VZ0,
+        GTE::VZ1,
+        GTE::VZ2,
+        GTE::IR0,
+        GTE::IR1,
+        GTE::IR2,
+        GTE::IR3,
+    ] {
+        gte.write_data(reg, 0x1234_8000);
+        assert_eq!(gte.read_data(reg), -0x8000);
+        gte.write_data(reg, 0x1234_7FFF);
+        assert_eq!(gte.read_data(reg), 0x7FFF);
+    }
+
+    // OTZ and SZ0-3 are unsigned 16-bit
+    for reg in [GTE::OTZ, GTE::SZ0, GTE::SZ1, GTE::SZ2, GTE::SZ3] {
+        gte.write_data(reg, 0xFFFF_8000u32 as i32);
+        assert_eq!(gte.read_data(reg), 0x8000);
+    }
+
+    // 32-bit registers are unaffected
+    gte.write_data(GTE::RGB0, 0xFFFF_8000u32 as i32);
+    assert_eq!(gte.read_data(GTE::RGB0) as u32, 0xFFFF_8000);
+}
+
+#[test]
+fn test_control_register_sign_extension() {
+    let mut gte = GTE::new();
+
+    for reg in [
+        GTE::RT33,
+        GTE::L33,
+        GTE::LB3,
+        GTE::H,
+        GTE::DQA,
+        GTE::ZSF3,
+        GTE::ZSF4,
+    ] {
+        gte.write_control(reg, 0x0000_FFFF);
+        assert_eq!(gte.read_control(reg), -1);
+        gte.write_control(reg, 0x7FFF_1000);
+        assert_eq!(gte.read_control(reg), 0x1000);
+    }
+
+    // Packed and 32-bit registers are unaffected
+    gte.write_control(GTE::RT11_RT12, 0x8000_FFFFu32 as i32);
+    assert_eq!(gte.read_control(GTE::RT11_RT12) as u32, 0x8000_FFFF);
+    gte.write_control(GTE::OFX, 0x0001_8000);
+    assert_eq!(gte.read_control(GTE::OFX), 0x0001_8000);
+}
+
+#[test]
+fn test_h_reads_sign_extended_but_divides_unsigned() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x1000);
+    gte.write_control(GTE::RT33, 0x1000);
+
+    // H = 8000h (sign-extended on read)
+    gte.write_control(GTE::H, 0x8000);
+    assert_eq!(gte.read_control(GTE::H), -0x8000);
+
+    // 8000h / C000h = 0.6667 (H used as unsigned); an overflow would give 1FFFFh
+    gte.write_data(GTE::VXY0, 0x100);
+    gte.write_data(GTE::VZ0, 0x6000);
+    gte.write_control(GTE::TRZ, 0x6000);
+    gte.rtps(true, false);
+
+    assert_eq!(gte.read_data(GTE::SZ3), 0xC000);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32 & (1 << 17), 0);
+    assert_eq!(gte.read_data(GTE::SXY2) & 0xFFFF, 0xAA);
 }
 
 #[test]
@@ -52,6 +132,29 @@ fn test_control_register_read_write() {
     assert_eq!(gte.read_control(GTE::H), 2000);
 }
 
+#[test]
+fn test_flag_register_write() {
+    let mut gte = GTE::new();
+
+    // Bits 0-11 are read-only zero
+    gte.write_control(GTE::FLAG, 0xFFFF_FFFFu32 as i32);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, 0xFFFF_F000);
+
+    // Bit 31 can't be written directly
+    gte.write_control(GTE::FLAG, 0x8000_0000u32 as i32);
+    assert_eq!(gte.read_control(GTE::FLAG), 0);
+
+    // Bit 31 summarizes bits 30-23 and 18-13 only
+    gte.write_control(GTE::FLAG, 1 << 12);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, 1 << 12);
+    gte.write_control(GTE::FLAG, 1 << 22);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, 1 << 22);
+    gte.write_control(GTE::FLAG, 1 << 13);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, (1 << 31) | (1 << 13));
+    gte.write_control(GTE::FLAG, 1 << 23);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, (1 << 31) | (1 << 23));
+}
+
 #[test]
 fn test_leading_zero_count() {
     let mut gte = GTE::new();
@@ -60,13 +163,22 @@ fn test_leading_zero_count() {
     gte.write_data(GTE::LZCS, 0x00000001);
     assert_eq!(gte.read_data(GTE::LZCR), 31);
 
-    // Test negative value
+    // Negative values count leading ones
     gte.write_data(GTE::LZCS, -1);
-    assert_eq!(gte.read_data(GTE::LZCR), 0);
+    assert_eq!(gte.read_data(GTE::LZCR), 32);
+
+    gte.write_data(GTE::LZCS, 0xF000_0000u32 as i32);
+    assert_eq!(gte.read_data(GTE::LZCR), 4);
+
+    gte.write_data(GTE::LZCS, i32::MIN);
+    assert_eq!(gte.read_data(GTE::LZCR), 1);
 
     // Test zero
     gte.write_data(GTE::LZCS, 0);
     assert_eq!(gte.read_data(GTE::LZCR), 32);
+
+    // LZCS itself reads back unchanged
+    assert_eq!(gte.read_data(GTE::LZCS), 0);
 }
 
 #[test]
@@ -89,3 +201,71 @@ fn test_sxy_fifo() {
     assert_eq!(gte.read_data(GTE::SXY1), 200);
     assert_eq!(gte.read_data(GTE::SXY0), 100);
 }
+
+#[test]
+fn test_sxyp_mirrors_sxy2() {
+    let mut gte = GTE::new();
+
+    gte.write_data(GTE::SXY2, 0x0010_0020);
+    assert_eq!(gte.read_data(GTE::SXYP), 0x0010_0020);
+}
+
+#[test]
+fn test_irgb_expands_to_ir() {
+    let mut gte = GTE::new();
+
+    // R=1Fh, G=10h, B=01h (bit 15 and above are ignored)
+    gte.write_data(
+        GTE::IRGB,
+        0xFFFF_0000u32 as i32 | (0x01 << 10) | (0x10 << 5) | 0x1F,
+    );
+
+    assert_eq!(gte.read_data(GTE::IR1), 0xF80);
+    assert_eq!(gte.read_data(GTE::IR2), 0x800);
+    assert_eq!(gte.read_data(GTE::IR3), 0x080);
+}
+
+#[test]
+fn test_orgb_packs_saturated_ir() {
+    let mut gte = GTE::new();
+
+    gte.write_data(GTE::IR1, 0x7FFF); // Saturates to 1Fh
+    gte.write_data(GTE::IR2, 0x0480); // 09h
+    gte.write_data(GTE::IR3, -0x100); // Saturates to 0
+
+    let expected = (0x09 << 5) | 0x1F;
+    assert_eq!(gte.read_data(GTE::ORGB), expected);
+    assert_eq!(gte.read_data(GTE::IRGB), expected);
+
+    // ORGB is read-only
+    gte.write_data(GTE::ORGB, 0x7FFF);
+    assert_eq!(gte.read_data(GTE::IR1), 0x7FFF);
+    assert_eq!(gte.read_data(GTE::ORGB), expected);
+}
+
+#[test]
+fn test_irgb_orgb_round_trip() {
+    let mut gte = GTE::new();
+
+    for color in [0x0000, 0x7FFF, 0x1234, 0x5A5A] {
+        gte.write_data(GTE::IRGB, color);
+        assert_eq!(gte.read_data(GTE::ORGB), color);
+    }
+}
+
+#[test]
+fn test_sz_fifo_registers() {
+    let mut gte = GTE::new();
+
+    // SZ0-3 are plain registers; only RTPS/RTPT push the FIFO
+    gte.write_data(GTE::SZ3, 0x1234);
+    assert_eq!(gte.read_data(GTE::SZ3), 0x1234);
+    assert_eq!(gte.read_data(GTE::SZ2), 0);
+
+    gte.write_control(GTE::RT33, 0x1000);
+    gte.write_data(GTE::VZ0, 0x100);
+    gte.rtps(true, false);
+
+    assert_eq!(gte.read_data(GTE::SZ2), 0x1234);
+    assert_eq!(gte.read_data(GTE::SZ3), 0x100);
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE color interpolation command tests
+//!
+//! Tests for DPCS, DPCT, DCPL, INTPL, GPF and GPL.
+
+use super::super::*;
+
+fn cmd(opcode: u32, sf: bool, lm: bool) -> u32 {
+    opcode | ((sf as u32) << 19) | ((lm as u32) << 10)
+}
+
+fn set_ir(gte: &mut GTE, ir: [i32; 3]) {
+    gte.write_data(GTE::IR1, ir[0]);
+    gte.write_data(GTE::IR2, ir[1]);
+    gte.write_data(GTE::IR3, ir[2]);
+}
+
+fn set_far_color(gte: &mut GTE, fc: [i32; 3]) {
+    gte.write_control(GTE::RFC, fc[0]);
+    gte.write_control(GTE::GFC, fc[1]);
+    gte.write_control(GTE::BFC, fc[2]);
+}
+
+fn flag(gte: &GTE) -> u32 {
+    gte.read_control(GTE::FLAG) as u32
+}
+
+#[test]
+fn test_dpcs_no_depth_cue() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::RGB, 0xAB30_2010u32 as i32);
+    set_far_color(&mut gte, [0xFF0, 0xFF0, 0xFF0]);
+
+    gte.execute(cmd(0x10, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x100);
+    assert_eq!(gte.read_data(GTE::MAC3), 0x300);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0xAB30_2010);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_dpcs_full_depth_cue() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::RGB, 0x0030_2010);
+    gte.write_data(GTE::IR0, 0x1000);
+    set_far_color(&mut gte, [0x800, 0, 0]);
+
+    gte.execute(cmd(0x10, true, false));
+
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0000_0080);
+}
+
+#[test]
+fn test_dpct_uses_color_fifo() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::RGB, 0xCC00_0000u32 as i32);
+    gte.write_data(GTE::RGB0, 0x0001_0203);
+    gte.write_data(GTE::RGB1, 0x0004_0506);
+    gte.write_data(GTE::RGB2, 0x0007_0809);
+
+    gte.execute(cmd(0x2A, true, false));
+
+    // IR0 = 0: colors pass through in order, CODE comes from RGBC
+    assert_eq!(gte.read_data(GTE::RGB0) as u32, 0xCC01_0203);
+    assert_eq!(gte.read_data(GTE::RGB1) as u32, 0xCC04_0506);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0xCC07_0809);
+}
+
+#[test]
+fn test_dcpl() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::RGB, 0x0020_4080);
+    set_ir(&mut gte, [0x1000, 0x1000, 0x1000]);
+
+    gte.execute(cmd(0x29, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x800);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0020_4080);
+}
+
+#[test]
+fn test_intpl() {
+    let mut gte = GTE::new();
+    set_ir(&mut gte, [0x100, 0x200, 0x300]);
+    set_far_color(&mut gte, [0x800, 0x400, 0]);
+
+    gte.execute(cmd(0x11, true, false));
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0030_2010);
+
+    set_ir(&mut gte, [0x100, 0x200, 0x300]);
+    gte.write_data(GTE::IR0, 0x1000);
+    gte.execute(cmd(0x11, true, false));
+    assert_eq!(gte.read_data(GTE::MAC1), 0x800);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0000_4080);
+}
+
+#[test]
+fn test_intpl_first_step_ignores_lm() {
+    let mut gte = GTE::new();
+    set_ir(&mut gte, [0x100, 0x100, 0x100]);
+    gte.write_data(GTE::IR0, 0x800);
+
+    gte.execute(cmd(0x11, true, true));
+
+    // (FC - IR) is negative but must not be clamped to 0 by lm
+    assert_eq!(gte.read_data(GTE::MAC1), 0x80);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_gpf() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::RGB, 0x2000_0000);
+    gte.write_data(GTE::IR0, 0x800);
+    set_ir(&mut gte, [0x1000, 0x2000, -0x1000]);
+
+    gte.execute(cmd(0x3D, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x800);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x1000);
+    assert_eq!(gte.read_data(GTE::MAC3), -0x800);
+    assert_eq!(gte.read_data(GTE::IR3), -0x800);
+    // G saturates high, B saturates low; color flags don't set bit 31
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x2000_FF80);
+    assert_eq!(flag(&gte), (1 << 20) | (1 << 19));
+}
+
+#[test]
+fn test_gpl() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::MAC1, 0x100);
+    gte.write_data(GTE::MAC2, 0x200);
+    gte.write_data(GTE::MAC3, 0x300);
+    gte.write_data(GTE::IR0, 0x1000);
+    set_ir(&mut gte, [0x10, 0x20, 0x30]);
+
+    gte.execute(cmd(0x3E, true, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x110);
+    assert_eq!(gte.read_data(GTE::MAC2), 0x220);
+    assert_eq!(gte.read_data(GTE::MAC3), 0x330);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0033_2211);
+}
+
+#[test]
+fn test_gpl_without_shift() {
+    let mut gte = GTE::new();
+    gte.write_data(GTE::MAC1, 0x100);
+    gte.write_data(GTE::IR0, 2);
+    set_ir(&mut gte, [0x10, 0, 0]);
+
+    gte.execute(cmd(0x3E, false, false));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x120);
+}
@@ -60,8 +60,8 @@ fn test_execute_nclip_command() {
 fn test_execute_unknown_command() {
     let mut gte = GTE::new();
 
-    // Execute unknown command
-    gte.execute(0xFF);
+    // Execute unknown command (opcode 0x00 is unused)
+    gte.execute(0x00);
 
     // Should set error flag
     assert_ne!(gte.flags & 0x80000000, 0);
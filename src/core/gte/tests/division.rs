@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE UNR division tests
+
+use super::super::division::{unr_divide, DIVIDE_MAX};
+
+#[test]
+fn test_unr_divide_exact_powers_of_two() {
+    assert_eq!(unr_divide(0x100, 0x200), (0x8000, false));
+    assert_eq!(unr_divide(200, 200), (0x10000, false));
+    assert_eq!(unr_divide(300, 600), (0x8000, false));
+}
+
+#[test]
+fn test_unr_divide_rounding() {
+    // Values from the hardware algorithm (not an exact division)
+    assert_eq!(unr_divide(400, 300), (0x15555, false));
+    assert_eq!(unr_divide(300, 400), (0xC000, false));
+    assert_eq!(unr_divide(300, 500), (0x999A, false));
+    assert_eq!(unr_divide(1, 0xFFFF), (0x1, false));
+}
+
+#[test]
+fn test_unr_divide_overflow() {
+    // H >= SZ3*2 overflows
+    assert_eq!(unr_divide(200, 100), (DIVIDE_MAX, true));
+    assert_eq!(unr_divide(0x100, 0x80), (DIVIDE_MAX, true));
+    assert_eq!(unr_divide(0, 0), (DIVIDE_MAX, true));
+
+    // Just below the limit
+    assert!(!unr_divide(199, 100).1);
+}
+
+#[test]
+fn test_unr_divide_zero_h() {
+    assert_eq!(unr_divide(0, 1), (0, false));
+}
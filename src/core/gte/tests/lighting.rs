@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE lighting command tests
+//!
+//! Tests for NCS, NCT, NCCS, NCCT, NCDS, NCDT, CC and CDP.
+
+use super::super::*;
+
+fn cmd(opcode: u32, sf: bool, lm: bool) -> u32 {
+    opcode | ((sf as u32) << 19) | ((lm as u32) << 10)
+}
+
+/// Create a GTE with identity light and light color matrices
+fn create_lit_gte() -> GTE {
+    let mut gte = GTE::new();
+    // LLM = identity
+    gte.write_control(GTE::L11_L12, 0x1000);
+    gte.write_control(GTE::L22_L23, 0x1000);
+    gte.write_control(GTE::L33, 0x1000);
+    // LCM = identity
+    gte.write_control(GTE::LR1_LR2, 0x1000);
+    gte.write_control(GTE::LG2_LG3, 0x1000);
+    gte.write_control(GTE::LB3, 0x1000);
+    gte
+}
+
+fn set_vector(gte: &mut GTE, index: usize, v: [i16; 3]) {
+    gte.write_data(
+        GTE::VXY0 + index * 2,
+        ((v[1] as i32) << 16) | (v[0] as u16 as i32),
+    );
+    gte.write_data(GTE::VZ0 + index * 2, v[2] as i32);
+}
+
+fn set_ir(gte: &mut GTE, ir: [i32; 3]) {
+    gte.write_data(GTE::IR1, ir[0]);
+    gte.write_data(GTE::IR2, ir[1]);
+    gte.write_data(GTE::IR3, ir[2]);
+}
+
+fn flag(gte: &GTE) -> u32 {
+    gte.read_control(GTE::FLAG) as u32
+}
+
+#[test]
+fn test_ncs() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x3400_0000u32 as i32);
+    set_vector(&mut gte, 0, [0x800, 0x400, 0x100]);
+
+    gte.execute(cmd(0x1E, true, true));
+
+    assert_eq!(gte.read_data(GTE::MAC1), 0x800);
+    assert_eq!(gte.read_data(GTE::IR2), 0x400);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x3410_4080);
+    assert_eq!(flag(&gte), 0);
+}
+
+#[test]
+fn test_ncs_lm_and_background_color() {
+    let mut gte = create_lit_gte();
+    gte.write_control(GTE::RBK, 0x100);
+    gte.write_control(GTE::GBK, 0x100);
+    gte.write_control(GTE::BBK, 0x100);
+    set_vector(&mut gte, 0, [0x800, 0x400, -0x400]);
+
+    gte.execute(cmd(0x1E, true, true));
+
+    // Negative light intensity clamps to 0 (IR3 saturated), then BK is added
+    assert_eq!(gte.read_data(GTE::IR1), 0x900);
+    assert_eq!(gte.read_data(GTE::IR2), 0x500);
+    assert_eq!(gte.read_data(GTE::IR3), 0x100);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0010_5090);
+    // IR3 saturation is not part of the error summary
+    assert_eq!(flag(&gte), 1 << 22);
+}
+
+#[test]
+fn test_ncs_color_saturation() {
+    let mut gte = create_lit_gte();
+    set_vector(&mut gte, 0, [0x800, 0x2000, 0]);
+
+    gte.execute(cmd(0x1E, true, true));
+
+    // G = 0x2000/16 = 0x200 saturates to 0xFF; color flags don't set bit 31
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0000_FF80);
+    assert_eq!(flag(&gte), 1 << 20);
+}
+
+#[test]
+fn test_nct() {
+    let mut gte = create_lit_gte();
+    set_vector(&mut gte, 0, [0x100, 0, 0]);
+    set_vector(&mut gte, 1, [0, 0x200, 0]);
+    set_vector(&mut gte, 2, [0, 0, 0x300]);
+
+    gte.execute(cmd(0x20, true, true));
+
+    assert_eq!(gte.read_data(GTE::RGB0) as u32, 0x0000_0010);
+    assert_eq!(gte.read_data(GTE::RGB1) as u32, 0x0000_2000);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0030_0000);
+}
+
+#[test]
+fn test_nccs() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    set_vector(&mut gte, 0, [0x800, 0x400, 0x1000]);
+
+    gte.execute(cmd(0x1B, true, true));
+
+    // Light color halved by the 0x80 vertex color
+    assert_eq!(gte.read_data(GTE::MAC1), 0x400);
+    assert_eq!(gte.read_data(GTE::IR3), 0x800);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0080_2040);
+}
+
+#[test]
+fn test_ncct() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    set_vector(&mut gte, 0, [0x800, 0, 0]);
+    set_vector(&mut gte, 1, [0, 0x800, 0]);
+    set_vector(&mut gte, 2, [0, 0, 0x800]);
+
+    gte.execute(cmd(0x3F, true, true));
+
+    assert_eq!(gte.read_data(GTE::RGB0) as u32, 0x0000_0040);
+    assert_eq!(gte.read_data(GTE::RGB1) as u32, 0x0000_4000);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0040_0000);
+}
+
+#[test]
+fn test_ncds_no_depth_cue() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    set_vector(&mut gte, 0, [0x800, 0x400, 0x1000]);
+
+    gte.execute(cmd(0x13, true, true));
+
+    // IR0 = 0: same result as NCCS
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0080_2040);
+}
+
+#[test]
+fn test_ncds_half_depth_cue() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    gte.write_data(GTE::IR0, 0x800);
+    set_vector(&mut gte, 0, [0x800, 0x400, 0x1000]);
+
+    gte.execute(cmd(0x13, true, true));
+
+    // Halfway towards a black far color
+    assert_eq!(gte.read_data(GTE::MAC1), 0x200);
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0040_1020);
+}
+
+#[test]
+fn test_ncdt_full_depth_cue() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    gte.write_data(GTE::IR0, 0x1000);
+    gte.write_control(GTE::RFC, 0x100);
+    gte.write_control(GTE::GFC, 0x200);
+    gte.write_control(GTE::BFC, 0x300);
+    set_vector(&mut gte, 0, [0x800, 0, 0]);
+    set_vector(&mut gte, 1, [0, 0x800, 0]);
+    set_vector(&mut gte, 2, [0, 0, 0x800]);
+
+    gte.execute(cmd(0x16, true, true));
+
+    // IR0 = 1.0: every vertex becomes the far color
+    for reg in [GTE::RGB0, GTE::RGB1, GTE::RGB2] {
+        assert_eq!(gte.read_data(reg) as u32, 0x0030_2010);
+    }
+}
+
+#[test]
+fn test_cc() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    set_ir(&mut gte, [0x800, 0x400, 0x1000]);
+
+    gte.execute(cmd(0x1C, true, true));
+
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0080_2040);
+}
+
+#[test]
+fn test_cdp() {
+    let mut gte = create_lit_gte();
+    gte.write_data(GTE::RGB, 0x0080_8080);
+    gte.write_data(GTE::IR0, 0x800);
+    set_ir(&mut gte, [0x800, 0x400, 0x1000]);
+
+    gte.execute(cmd(0x14, true, true));
+
+    assert_eq!(gte.read_data(GTE::RGB2) as u32, 0x0040_1020);
+}
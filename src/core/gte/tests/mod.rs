@@ -17,6 +17,12 @@
 //!
 //! Organized tests for the GTE (Geometry Transformation Engine).
 
+mod arithmetic;
 mod basic;
+mod color;
 mod commands;
+mod division;
+mod lighting;
+mod precision;
+mod properties;
 mod transforms;
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precision geometry tests
+//!
+//! Tests for the precise SXY shadow FIFO kept alongside RTPS/RTPT.
+
+use super::super::*;
+
+fn create_projection_gte() -> GTE {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x1000);
+    gte.write_control(GTE::RT33, 0x1000);
+    gte.write_control(GTE::H, 200);
+    gte.write_control(GTE::OFX, 160 << 16);
+    gte.write_control(GTE::OFY, 120 << 16);
+    gte.set_precision_enabled(true);
+    gte
+}
+
+fn set_vector(gte: &mut GTE, index: usize, v: [i16; 3]) {
+    gte.write_data(
+        GTE::VXY0 + index * 2,
+        ((v[1] as i32) << 16) | (v[0] as u16 as i32),
+    );
+    gte.write_data(GTE::VZ0 + index * 2, v[2] as i32);
+}
+
+#[test]
+fn test_precision_disabled_by_default() {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::H, 200);
+    set_vector(&mut gte, 0, [10, 10, 300]);
+    gte.rtps(true, false);
+
+    assert!(!gte.precision_enabled());
+    assert_eq!(gte.precise_sxy(GTE::SXY2), None);
+}
+
+#[test]
+fn test_rtps_keeps_sub_pixel_position() {
+    let mut gte = create_projection_gte();
+
+    // 7 * 200 / 300 = 4.666..., integer SX2 drops the fraction
+    set_vector(&mut gte, 0, [7, -7, 300]);
+    gte.rtps(true, false);
+
+    let sxy2 = gte.read_data(GTE::SXY2);
+    assert_eq!(sxy2 as i16, 164);
+    assert_eq!((sxy2 >> 16) as i16, 115);
+
+    let vertex = gte.precise_sxy(GTE::SXY2).unwrap();
+    assert!((vertex.x - (160.0 + 14.0 / 3.0)).abs() < 1e-3);
+    assert!((vertex.y - (120.0 - 14.0 / 3.0)).abs() < 1e-3);
+    assert_eq!(vertex.w, 300.0);
+    assert_eq!(gte.precise_sxy(GTE::SXYP), Some(vertex));
+}
+
+#[test]
+fn test_precise_position_keeps_view_fraction() {
+    let mut gte = create_projection_gte();
+
+    // Rotation of 0.5 gives view X = 1.5, lost by the IR1 integer
+    gte.write_control(GTE::RT11_RT12, 0x800);
+    set_vector(&mut gte, 0, [3, 0, 200]);
+    gte.rtps(true, false);
+
+    assert_eq!(gte.read_data(GTE::IR1), 1);
+    let vertex = gte.precise_sxy(GTE::SXY2).unwrap();
+    assert!((vertex.x - 161.5).abs() < 1e-3);
+}
+
+#[test]
+fn test_precise_position_saturates() {
+    let mut gte = create_projection_gte();
+    gte.write_control(GTE::OFX, 0);
+
+    set_vector(&mut gte, 0, [0x7000, 0, 100]);
+    gte.rtps(true, false);
+
+    let vertex = gte.precise_sxy(GTE::SXY2).unwrap();
+    assert_eq!(vertex.x, 1023.0);
+    assert_eq!(gte.read_data(GTE::SXY2) as i16, 0x3FF);
+}
+
+#[test]
+fn test_rtpt_fills_precise_fifo() {
+    let mut gte = create_projection_gte();
+    set_vector(&mut gte, 0, [1, 0, 300]);
+    set_vector(&mut gte, 1, [2, 0, 400]);
+    set_vector(&mut gte, 2, [3, 0, 500]);
+
+    gte.rtpt(true, false);
+
+    let depths: Vec<f32> = (0..3)
+        .map(|i| gte.precise_sxy(GTE::SXY0 + i).unwrap().w)
+        .collect();
+    assert_eq!(depths, vec![300.0, 400.0, 500.0]);
+}
+
+#[test]
+fn test_sxy_write_drops_precise_vertex() {
+    let mut gte = create_projection_gte();
+    set_vector(&mut gte, 0, [1, 0, 300]);
+    set_vector(&mut gte, 1, [2, 0, 400]);
+    set_vector(&mut gte, 2, [3, 0, 500]);
+    gte.rtpt(true, false);
+
+    gte.write_data(GTE::SXY1, 0x0010_0010);
+
+    assert!(gte.precise_sxy(GTE::SXY0).is_some());
+    assert_eq!(gte.precise_sxy(GTE::SXY1), None);
+    assert!(gte.precise_sxy(GTE::SXY2).is_some());
+}
+
+#[test]
+fn test_sxyp_write_shifts_precise_fifo() {
+    let mut gte = create_projection_gte();
+    set_vector(&mut gte, 0, [1, 0, 300]);
+    gte.rtps(true, false);
+
+    gte.write_data(GTE::SXYP, 0x0010_0010);
+
+    assert_eq!(gte.precise_sxy(GTE::SXY1).unwrap().w, 300.0);
+    assert_eq!(gte.precise_sxy(GTE::SXY2), None);
+    assert_eq!(gte.precise_sxy(GTE::SXYP), None);
+}
+
+#[test]
+fn test_precise_sxy_other_registers() {
+    let mut gte = create_projection_gte();
+    set_vector(&mut gte, 0, [1, 0, 300]);
+    gte.rtps(true, false);
+
+    assert_eq!(gte.precise_sxy(GTE::SZ3), None);
+    assert_eq!(gte.precise_sxy(GTE::VXY0), None);
+}
+
+#[test]
+fn test_disabling_precision_clears_fifo() {
+    let mut gte = create_projection_gte();
+    set_vector(&mut gte, 0, [1, 0, 300]);
+    gte.rtps(true, false);
+
+    gte.set_precision_enabled(false);
+    assert_eq!(gte.precise_sxy(GTE::SXY2), None);
+
+    // Reset keeps the setting itself
+    gte.set_precision_enabled(true);
+    gte.reset();
+    assert!(gte.precision_enabled());
+}
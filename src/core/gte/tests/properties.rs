@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GTE property-based tests
+//!
+//! Compares RTPS/RTPT and the FLAG register against a straightforward
+//! reference model written directly from the PSX-SPX formulas.
+
+use super::super::*;
+use proptest::prelude::*;
+
+/// RTPS inputs
+#[derive(Debug, Clone)]
+struct Inputs {
+    rt: [[i16; 3]; 3],
+    tr: [i32; 3],
+    v: [i16; 3],
+    h: u16,
+    ofx: i32,
+    ofy: i32,
+    dqa: i16,
+    dqb: i32,
+    sf: bool,
+    lm: bool,
+}
+
+/// RTPS outputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Outputs {
+    mac: [i32; 4],
+    ir: [i32; 4],
+    sx2: i32,
+    sy2: i32,
+    sz3: i32,
+    flag: u32,
+}
+
+/// Reference UNR division, with the seed computed on the fly
+fn reference_divide(h: u32, sz3: u32) -> Option<u64> {
+    if h >= sz3 * 2 {
+        return None;
+    }
+
+    let z = 16 - (32 - sz3.leading_zeros());
+    let n = (h as u64) << z;
+    let d = (sz3 as u64) << z;
+    let i = (d - 0x7FC0) >> 7;
+    let u = (0x40000 / (i + 0x100)).div_ceil(2).saturating_sub(0x101) + 0x101;
+    let d = (0x200_0080 - d * u) >> 8;
+    let d = (0x80 + d * u) >> 8;
+    Some(((n * d + 0x8000) >> 16).min(0x1FFFF))
+}
+
+/// Reference RTPS
+fn reference_rtps(input: &Inputs) -> Outputs {
+    let mut flag = 0u32;
+    let shift = if input.sf { 12 } else { 0 };
+    let ir_min = if input.lm { 0 } else { -0x8000 };
+
+    let mut mac = [0i32; 4];
+    let mut ir = [0i32; 4];
+    let mut z = 0i64;
+
+    for row in 0..3 {
+        let mut sum = (input.tr[row] as i64) << 12;
+        for col in 0..3 {
+            sum += input.rt[row][col] as i64 * input.v[col] as i64;
+            if sum >= 1 << 43 {
+                flag |= 1 << (30 - row);
+            } else if sum < -(1 << 43) {
+                flag |= 1 << (27 - row);
+            }
+            // Sign-extend from 44 bits
+            sum = (sum << 20) >> 20;
+        }
+
+        mac[row + 1] = (sum >> shift) as i32;
+        ir[row + 1] = mac[row + 1].clamp(ir_min, 0x7FFF);
+        if row < 2 && ir[row + 1] != mac[row + 1] {
+            flag |= 1 << (24 - row);
+        }
+        if row == 2 {
+            z = sum;
+        }
+    }
+
+    if !(-0x8000..=0x7FFF).contains(&(z >> 12)) {
+        flag |= 1 << 22;
+    }
+
+    let sz3 = (z >> 12).clamp(0, 0xFFFF);
+    if sz3 != z >> 12 {
+        flag |= 1 << 18;
+    }
+
+    let quotient = match reference_divide(input.h as u32, sz3 as u32) {
+        Some(q) => q as i64,
+        None => {
+            flag |= 1 << 17;
+            0x1FFFF
+        }
+    };
+
+    let mac0 = |value: i64, flag: &mut u32| {
+        if value > i32::MAX as i64 {
+            *flag |= 1 << 16;
+        } else if value < i32::MIN as i64 {
+            *flag |= 1 << 15;
+        }
+        value
+    };
+
+    let x = mac0(quotient * ir[1] as i64 + input.ofx as i64, &mut flag) >> 16;
+    let y = mac0(quotient * ir[2] as i64 + input.ofy as i64, &mut flag) >> 16;
+    let sx2 = x.clamp(-0x400, 0x3FF);
+    if sx2 != x {
+        flag |= 1 << 14;
+    }
+    let sy2 = y.clamp(-0x400, 0x3FF);
+    if sy2 != y {
+        flag |= 1 << 13;
+    }
+
+    let dq = mac0(quotient * input.dqa as i64 + input.dqb as i64, &mut flag);
+    mac[0] = dq as i32;
+    ir[0] = (dq >> 12).clamp(0, 0x1000) as i32;
+    if ir[0] as i64 != dq >> 12 {
+        flag |= 1 << 12;
+    }
+
+    if flag & 0x7F87_E000 != 0 {
+        flag |= 1 << 31;
+    }
+
+    Outputs {
+        mac,
+        ir,
+        sx2: sx2 as i32,
+        sy2: sy2 as i32,
+        sz3: sz3 as i32,
+        flag,
+    }
+}
+
+/// Load inputs into a GTE through the register interface
+fn load(gte: &mut GTE, input: &Inputs) {
+    let pack = |lo: i16, hi: i16| ((hi as i32) << 16) | (lo as u16 as i32);
+    let rt = &input.rt;
+    gte.write_control(GTE::RT11_RT12, pack(rt[0][0], rt[0][1]));
+    gte.write_control(GTE::RT13_RT21, pack(rt[0][2], rt[1][0]));
+    gte.write_control(GTE::RT22_RT23, pack(rt[1][1], rt[1][2]));
+    gte.write_control(GTE::RT31_RT32, pack(rt[2][0], rt[2][1]));
+    gte.write_control(GTE::RT33, rt[2][2] as i32);
+    gte.write_control(GTE::TRX, input.tr[0]);
+    gte.write_control(GTE::TRY, input.tr[1]);
+    gte.write_control(GTE::TRZ, input.tr[2]);
+    gte.write_control(GTE::H, input.h as i16 as i32);
+    gte.write_control(GTE::OFX, input.ofx);
+    gte.write_control(GTE::OFY, input.ofy);
+    gte.write_control(GTE::DQA, input.dqa as i32);
+    gte.write_control(GTE::DQB, input.dqb);
+    gte.write_data(GTE::VXY0, pack(input.v[0], input.v[1]));
+    gte.write_data(GTE::VZ0, input.v[2] as i32);
+}
+
+/// Read RTPS outputs from a GTE
+fn outputs(gte: &GTE) -> Outputs {
+    let sxy2 = gte.read_data(GTE::SXY2);
+    Outputs {
+        mac: [
+            gte.read_data(GTE::MAC0),
+            gte.read_data(GTE::MAC1),
+            gte.read_data(GTE::MAC2),
+            gte.read_data(GTE::MAC3),
+        ],
+        ir: [
+            gte.read_data(GTE::IR0),
+            gte.read_data(GTE::IR1),
+            gte.read_data(GTE::IR2),
+            gte.read_data(GTE::IR3),
+        ],
+        sx2: sxy2 as i16 as i32,
+        sy2: sxy2 >> 16,
+        sz3: gte.read_data(GTE::SZ3),
+        flag: gte.read_control(GTE::FLAG) as u32,
+    }
+}
+
+fn command(opcode: u32, sf: bool, lm: bool) -> u32 {
+    opcode | ((sf as u32) << 19) | ((lm as u32) << 10)
+}
+
+prop_compose! {
+    fn arb_inputs()(
+        rt in prop::array::uniform3(prop::array::uniform3(any::<i16>())),
+        tr in prop::array::uniform3(any::<i32>()),
+        v in prop::array::uniform3(any::<i16>()),
+        h in any::<u16>(),
+        ofx in any::<i32>(),
+        ofy in any::<i32>(),
+        dqa in any::<i16>(),
+        dqb in any::<i32>(),
+        sf in any::<bool>(),
+        lm in any::<bool>(),
+    ) -> Inputs {
+        Inputs { rt, tr, v, h, ofx, ofy, dqa, dqb, sf, lm }
+    }
+}
+
+prop_compose! {
+    /// Inputs in the range typically used by games (small translation,
+    /// positive depth), which exercise the division rather than overflow
+    fn arb_typical_inputs()(
+        rt in prop::array::uniform3(prop::array::uniform3(-0x1000i16..=0x1000)),
+        tr in prop::array::uniform3(-0x400i32..0x400),
+        trz in 0x100i32..0x4000,
+        v in prop::array::uniform3(-0x400i16..0x400),
+        h in 0x80u16..0x400,
+        ofx in -0x100_0000i32..0x100_0000,
+        ofy in -0x100_0000i32..0x100_0000,
+        dqa in any::<i16>(),
+        dqb in any::<i32>(),
+    ) -> Inputs {
+        Inputs { rt, tr: [tr[0], tr[1], trz], v, h, ofx, ofy, dqa, dqb, sf: true, lm: false }
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_rtps_matches_reference(input in arb_inputs()) {
+        let mut gte = GTE::new();
+        load(&mut gte, &input);
+
+        gte.execute(command(0x01, input.sf, input.lm));
+
+        prop_assert_eq!(outputs(&gte), reference_rtps(&input));
+    }
+
+    #[test]
+    fn prop_rtps_typical_matches_reference(input in arb_typical_inputs()) {
+        let mut gte = GTE::new();
+        load(&mut gte, &input);
+
+        gte.execute(command(0x01, input.sf, input.lm));
+
+        prop_assert_eq!(outputs(&gte), reference_rtps(&input));
+    }
+
+    #[test]
+    fn prop_rtpt_matches_three_rtps(
+        input in arb_inputs(),
+        v1 in prop::array::uniform3(any::<i16>()),
+        v2 in prop::array::uniform3(any::<i16>()),
+    ) {
+        let pack = |lo: i16, hi: i16| ((hi as i32) << 16) | (lo as u16 as i32);
+
+        // RTPT only calculates depth cueing for the last vertex; disable it
+        // so its MAC0/IR0 flags don't differ from three RTPS commands
+        let input = Inputs { dqa: 0, dqb: 0, ..input };
+
+        let mut rtpt = GTE::new();
+        load(&mut rtpt, &input);
+        rtpt.write_data(GTE::VXY1, pack(v1[0], v1[1]));
+        rtpt.write_data(GTE::VZ1, v1[2] as i32);
+        rtpt.write_data(GTE::VXY2, pack(v2[0], v2[1]));
+        rtpt.write_data(GTE::VZ2, v2[2] as i32);
+        rtpt.execute(command(0x30, input.sf, input.lm));
+
+        let mut rtps = GTE::new();
+        let mut flag = 0;
+        for v in [input.v, v1, v2] {
+            load(&mut rtps, &Inputs { v, ..input.clone() });
+            rtps.execute(command(0x01, input.sf, input.lm));
+            flag |= rtps.read_control(GTE::FLAG) as u32;
+        }
+
+        for reg in [GTE::SXY0, GTE::SXY1, GTE::SXY2, GTE::SZ1, GTE::SZ2, GTE::SZ3,
+                    GTE::MAC0, GTE::MAC1, GTE::MAC2, GTE::MAC3,
+                    GTE::IR0, GTE::IR1, GTE::IR2, GTE::IR3] {
+            prop_assert_eq!(rtpt.read_data(reg), rtps.read_data(reg));
+        }
+        prop_assert_eq!(rtpt.read_control(GTE::FLAG) as u32, flag);
+    }
+
+    #[test]
+    fn prop_flag_write_read(value in any::<u32>()) {
+        let mut gte = GTE::new();
+        gte.write_control(GTE::FLAG, value as i32);
+
+        let flag = gte.read_control(GTE::FLAG) as u32;
+        prop_assert_eq!(flag & 0x7FFF_FFFF, value & 0x7FFF_F000);
+        prop_assert_eq!(flag >> 31 != 0, value & 0x7F87_E000 != 0);
+    }
+
+    #[test]
+    fn prop_error_bit_summarizes_flags(input in arb_inputs()) {
+        let mut gte = GTE::new();
+        load(&mut gte, &input);
+        gte.execute(command(0x01, input.sf, input.lm));
+
+        let flag = gte.read_control(GTE::FLAG) as u32;
+        prop_assert_eq!(flag & 0xFFF, 0);
+        prop_assert_eq!(flag >> 31 != 0, flag & 0x7F87_E000 != 0);
+    }
+}
@@ -45,7 +45,7 @@ fn test_rtps_identity_matrix() {
     gte.write_data(GTE::VZ0, 30);
 
     // Execute RTPS
-    gte.rtps(false);
+    gte.rtps(false, false);
 
     // Verify MAC values are set (should be transformed coordinates)
     // With identity matrix and zero translation:
@@ -94,3 +94,207 @@ fn test_nclip_counter_clockwise() {
         "MAC0 should be negative for counter-clockwise triangle"
     );
 }
+
+/// Create a GTE with an identity rotation matrix and zero translation
+fn create_identity_gte() -> GTE {
+    let mut gte = GTE::new();
+    gte.write_control(GTE::RT11_RT12, 0x1000);
+    gte.write_control(GTE::RT22_RT23, 0x1000);
+    gte.write_control(GTE::RT33, 0x1000);
+    gte
+}
+
+fn set_vector(gte: &mut GTE, index: usize, v: [i16; 3]) {
+    gte.write_data(
+        GTE::VXY0 + index * 2,
+        ((v[1] as i32) << 16) | (v[0] as u16 as i32),
+    );
+    gte.write_data(GTE::VZ0 + index * 2, v[2] as i32);
+}
+
+fn sxy(gte: &GTE, index: usize) -> (i16, i16) {
+    let value = gte.read_data(GTE::SXY0 + index);
+    (value as i16, (value >> 16) as i16)
+}
+
+#[test]
+fn test_rtps_projection() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 200);
+    gte.write_control(GTE::OFX, 160 << 16);
+    gte.write_control(GTE::OFY, 120 << 16);
+    gte.write_control(GTE::DQA, 0x80);
+    gte.write_control(GTE::DQB, 0);
+    set_vector(&mut gte, 0, [100, 50, 200]);
+
+    gte.rtps(true, false);
+
+    assert_eq!(gte.read_data(GTE::MAC1), 100);
+    assert_eq!(gte.read_data(GTE::IR2), 50);
+    assert_eq!(gte.read_data(GTE::SZ3), 200);
+    assert_eq!(sxy(&gte, 2), (260, 170));
+    assert_eq!(gte.read_data(GTE::MAC0), 0x80_0000);
+    assert_eq!(gte.read_data(GTE::IR0), 0x800);
+    assert_eq!(gte.flags, 0);
+}
+
+#[test]
+fn test_rtps_unr_division() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 300);
+    set_vector(&mut gte, 0, [100, -50, 500]);
+
+    gte.execute(0x0008_0001); // RTPS sf=1
+
+    // H/SZ3 = 0x999A (UNR result, exact would be 0x9999.9)
+    assert_eq!(sxy(&gte, 2), (60, -31));
+}
+
+#[test]
+fn test_rtps_divide_overflow() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 200);
+    set_vector(&mut gte, 0, [100, 50, 100]);
+
+    gte.execute(0x0008_0001);
+
+    // Quotient saturates to 1FFFFh
+    assert_eq!(sxy(&gte, 2), (199, 99));
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, (1 << 31) | (1 << 17));
+}
+
+#[test]
+fn test_rtps_screen_saturation() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 400);
+    set_vector(&mut gte, 0, [1000, -1000, 300]);
+
+    gte.execute(0x0008_0001);
+
+    assert_eq!(sxy(&gte, 2), (0x3FF, -0x400));
+    assert_eq!(
+        gte.read_control(GTE::FLAG) as u32,
+        (1 << 31) | (1 << 14) | (1 << 13)
+    );
+}
+
+#[test]
+fn test_rtps_ir3_flag_quirk() {
+    let mut gte = create_identity_gte();
+    set_vector(&mut gte, 0, [0, 0, 0x7000]);
+
+    // sf=0: IR3 saturates, but MAC3 SAR 12 is in range so no flag is set
+    gte.execute(0x0000_0001);
+
+    assert_eq!(gte.read_data(GTE::MAC3), 0x700_0000);
+    assert_eq!(gte.read_data(GTE::IR3), 0x7FFF);
+    assert_eq!(gte.read_data(GTE::SZ3), 0x7000);
+    assert_eq!(gte.read_control(GTE::FLAG), 0);
+}
+
+#[test]
+fn test_rtps_negative_z() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 100);
+    set_vector(&mut gte, 0, [0, 0, -0x100]);
+
+    gte.execute(0x0008_0001);
+
+    // SZ3 saturates to 0, which in turn overflows the division
+    assert_eq!(gte.read_data(GTE::IR3), -0x100);
+    assert_eq!(gte.read_data(GTE::SZ3), 0);
+    assert_eq!(
+        gte.read_control(GTE::FLAG) as u32,
+        (1 << 31) | (1 << 18) | (1 << 17)
+    );
+}
+
+#[test]
+fn test_rtps_depth_cue_saturation() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 200);
+    gte.write_control(GTE::DQA, -0x100);
+    set_vector(&mut gte, 0, [0, 0, 200]);
+
+    gte.execute(0x0008_0001);
+
+    // MAC0 = 10000h * -100h is negative: IR0 saturates to 0
+    assert_eq!(gte.read_data(GTE::MAC0), -0x100_0000);
+    assert_eq!(gte.read_data(GTE::IR0), 0);
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, 1 << 12);
+}
+
+#[test]
+fn test_rtpt_fifo_and_depth_cue() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 300);
+    gte.write_control(GTE::DQA, 0x100);
+    set_vector(&mut gte, 0, [100, 100, 400]);
+    set_vector(&mut gte, 1, [100, 100, 500]);
+    set_vector(&mut gte, 2, [100, 100, 600]);
+
+    gte.execute(0x0008_0030); // RTPT sf=1
+
+    assert_eq!(sxy(&gte, 0), (75, 75));
+    assert_eq!(sxy(&gte, 1), (60, 60));
+    assert_eq!(sxy(&gte, 2), (50, 50));
+    assert_eq!(gte.read_data(GTE::SZ1), 400);
+    assert_eq!(gte.read_data(GTE::SZ2), 500);
+    assert_eq!(gte.read_data(GTE::SZ3), 600);
+
+    // Depth cueing uses the last vertex (H/SZ3 = 0.5)
+    assert_eq!(gte.read_data(GTE::IR0), 0x800);
+
+    // Input vectors are left untouched
+    assert_eq!(gte.read_data(GTE::VZ0), 400);
+}
+
+#[test]
+fn test_rtpt_flags_accumulate() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 300);
+    set_vector(&mut gte, 0, [0, 0, 100]); // Divide overflow
+    set_vector(&mut gte, 1, [0, 0, 500]);
+    set_vector(&mut gte, 2, [0, 0, 600]);
+
+    gte.execute(0x0008_0030);
+
+    assert_eq!(gte.read_control(GTE::FLAG) as u32, (1 << 31) | (1 << 17));
+}
+
+#[test]
+fn test_rtps_widescreen_scales_x() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 200);
+    gte.write_control(GTE::OFX, 160 << 16);
+    gte.write_control(GTE::OFY, 120 << 16);
+    gte.set_widescreen(true);
+    set_vector(&mut gte, 0, [100, 100, 400]);
+
+    gte.rtps(true, false);
+
+    // X = 50 * 3/4 = 37.5 around OFX, Y unchanged
+    assert_eq!(sxy(&gte, 2), (160 + 37, 120 + 50));
+    assert_eq!(gte.read_data(GTE::IR1), 100);
+}
+
+#[test]
+fn test_rtpt_widescreen_scales_all_vertices() {
+    let mut gte = create_identity_gte();
+    gte.write_control(GTE::H, 300);
+    set_vector(&mut gte, 0, [-80, 0, 300]);
+    set_vector(&mut gte, 1, [40, 0, 300]);
+    set_vector(&mut gte, 2, [400, 0, 300]);
+
+    gte.set_widescreen(true);
+    gte.rtpt(true, false);
+
+    assert_eq!(sxy(&gte, 0).0, -60);
+    assert_eq!(sxy(&gte, 1).0, 30);
+    assert_eq!(sxy(&gte, 2).0, 300);
+
+    // Switching back restores the normal projection
+    gte.set_widescreen(false);
+    gte.rtps(true, false);
+    assert_eq!(sxy(&gte, 2).0, -80);
+}
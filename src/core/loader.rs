@@ -18,7 +18,7 @@
 //! This module handles the loading of game executables including:
 //! - SYSTEM.CNF configuration file parsing
 //! - PSX-EXE executable format loading
-//! - ISO9660 filesystem basic support
+//! - ISO9660 filesystem support (see `cdrom::CDROM::read_file`)
 //!
 //! # Game Boot Sequence
 //!
@@ -50,6 +50,8 @@
 //! 0x14-0x17: Initial GP (global pointer)
 //! 0x18-0x1B: Load address
 //! 0x1C-0x1F: Load size
+//! 0x28-0x2B: BSS address
+//! 0x2C-0x2F: BSS size
 //! 0x30-0x33: Stack base
 //! 0x34-0x37: Stack offset
 //! 0x800+:    Executable data
@@ -71,6 +73,7 @@
 //! ```
 
 use super::error::{EmulatorError, Result};
+use super::memory::Bus;
 
 /// SYSTEM.CNF configuration
 ///
@@ -206,6 +209,12 @@ pub struct PSXExecutable {
     /// Size of data to load
     pub load_size: u32,
 
+    /// BSS start address (zero-filled on load)
+    pub bss_address: u32,
+
+    /// BSS size in bytes
+    pub bss_size: u32,
+
     /// Stack base address
     pub stack_base: u32,
 
@@ -264,6 +273,8 @@ impl PSXExecutable {
         let gp = u32::from_le_bytes([data[0x14], data[0x15], data[0x16], data[0x17]]);
         let load_address = u32::from_le_bytes([data[0x18], data[0x19], data[0x1A], data[0x1B]]);
         let load_size = u32::from_le_bytes([data[0x1C], data[0x1D], data[0x1E], data[0x1F]]);
+        let bss_address = u32::from_le_bytes([data[0x28], data[0x29], data[0x2A], data[0x2B]]);
+        let bss_size = u32::from_le_bytes([data[0x2C], data[0x2D], data[0x2E], data[0x2F]]);
         let stack_base = u32::from_le_bytes([data[0x30], data[0x31], data[0x32], data[0x33]]);
         let stack_offset = u32::from_le_bytes([data[0x34], data[0x35], data[0x36], data[0x37]]);
 
@@ -278,6 +289,15 @@ impl PSXExecutable {
             )));
         }
 
+        // BSS is cleared on load, so it has to fit in RAM
+        let bss_start = (bss_address & 0x1FFFFF) as u64;
+        if bss_start + bss_size as u64 > Bus::RAM_SIZE as u64 {
+            return Err(EmulatorError::LoaderError(format!(
+                "Invalid PSX-EXE: BSS at 0x{:08X} (size 0x{:X}) exceeds RAM",
+                bss_address, bss_size
+            )));
+        }
+
         let data_start = Self::HEADER_SIZE;
         let data_end = data_start + load_size_usize;
         let exe_data = data[data_start..data_end].to_vec();
@@ -295,6 +315,8 @@ impl PSXExecutable {
             gp,
             load_address,
             load_size,
+            bss_address,
+            bss_size,
             stack_base,
             stack_offset,
             data: exe_data,
@@ -381,6 +403,10 @@ mod tests {
         // Stack offset = 0
         data[0x34..0x38].copy_from_slice(&0u32.to_le_bytes());
 
+        // BSS = 0x80018000, 0x400 bytes
+        data[0x28..0x2C].copy_from_slice(&0x80018000u32.to_le_bytes());
+        data[0x2C..0x30].copy_from_slice(&0x400u32.to_le_bytes());
+
         let exe = PSXExecutable::load(&data).unwrap();
 
         assert_eq!(exe.pc, 0x80010000);
@@ -389,6 +415,8 @@ mod tests {
         assert_eq!(exe.load_size, 0x100);
         assert_eq!(exe.stack_base, 0x801FFF00);
         assert_eq!(exe.stack_offset, 0);
+        assert_eq!(exe.bss_address, 0x80018000);
+        assert_eq!(exe.bss_size, 0x400);
         assert_eq!(exe.data.len(), 0x100);
     }
 
@@ -422,4 +450,22 @@ mod tests {
         let result = PSXExecutable::load(&data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_psx_exe_bss_exceeds_ram() {
+        let mut data = vec![0u8; 0x800];
+        data[0..8].copy_from_slice(b"PS-X EXE");
+
+        // BSS ending exactly at the end of RAM is fine
+        data[0x28..0x2C].copy_from_slice(&0x801F_0000u32.to_le_bytes());
+        data[0x2C..0x30].copy_from_slice(&0x1_0000u32.to_le_bytes());
+        assert!(PSXExecutable::load(&data).is_ok());
+
+        // A corrupt size is rejected before anything is allocated
+        data[0x2C..0x30].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+        assert!(PSXExecutable::load(&data).is_err());
+
+        data[0x2C..0x30].copy_from_slice(&0x1_0001u32.to_le_bytes());
+        assert!(PSXExecutable::load(&data).is_err());
+    }
 }
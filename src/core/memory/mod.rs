@@ -55,6 +55,7 @@ use crate::core::dma::DMA;
 use crate::core::error::{EmulatorError, Result};
 use crate::core::gpu::GPU;
 use crate::core::interrupt::InterruptController;
+use crate::core::precision::PrecisionVertex;
 use crate::core::spu::SPU;
 use crate::core::system::ControllerPorts;
 use crate::core::timer::Timers;
@@ -178,7 +179,7 @@ pub struct Bus {
 
 impl Bus {
     /// RAM size (2MB)
-    const RAM_SIZE: usize = 2 * 1024 * 1024;
+    pub(crate) const RAM_SIZE: usize = 2 * 1024 * 1024;
 
     /// BIOS size (512KB)
     const BIOS_SIZE: usize = 512 * 1024;
@@ -1094,6 +1095,28 @@ impl Bus {
         }
     }
 
+    /// Record a precision vertex stored to memory
+    ///
+    /// Called after SWC2 stores an SXY register that has a precise shadow
+    /// in the GTE. Only stores to main RAM are tracked, since those are the
+    /// only ones the GPU can fetch through DMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaddr` - Virtual address the SXY value was stored to
+    /// * `value` - Integer SXY value that was stored
+    /// * `vertex` - Precise vertex for that value
+    pub fn record_precision_vertex(&mut self, vaddr: u32, value: u32, vertex: PrecisionVertex) {
+        if self.identify_region(vaddr) != MemoryRegion::RAM {
+            return;
+        }
+
+        if let Some(gpu) = &self.gpu {
+            gpu.borrow_mut()
+                .record_precision_vertex(self.translate_address(vaddr), value, vertex);
+        }
+    }
+
     /// Write directly to BIOS memory (test helper)
     ///
     /// This method bypasses the read-only protection of BIOS and allows
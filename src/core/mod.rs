@@ -25,6 +25,7 @@
 //! - Controller (Input devices)
 //! - Timer (3 timer/counter channels)
 //! - Interrupt Controller (IRQ management)
+//! - Precision geometry (sub-pixel vertex cache between GTE and GPU)
 //! - Timing Event System (Global timing and event scheduling)
 //! - System integration
 
@@ -40,6 +41,7 @@ pub mod gte;
 pub mod interrupt;
 pub mod loader;
 pub mod memory;
+pub mod precision;
 pub mod save_state;
 pub mod spu;
 pub mod system;
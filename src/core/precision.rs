@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Precision geometry (PGXP-style) vertex cache
+//!
+//! The GTE computes screen coordinates with far more precision than the
+//! 11-bit integers it writes to SXY0-2. Games store those integers to RAM,
+//! build GP0 packets around them and DMA the packets to the GPU, so the
+//! fractional part is lost and polygons "wobble" as they move.
+//!
+//! When precision geometry is enabled, the GTE keeps a shadow copy of each
+//! projected vertex (sub-pixel X/Y plus the view-space depth W). Storing an
+//! SXY register with SWC2 records that copy here, keyed by the RAM address
+//! it was written to. When the GPU later receives a vertex word from the
+//! same address, it looks the copy up again and can rasterize with sub-pixel
+//! positions and perspective-correct texture coordinates.
+//!
+//! Every entry also remembers the integer value that was stored, so a
+//! lookup only succeeds while RAM still holds exactly that vertex. Stale
+//! entries (the game reused the memory for something else) are ignored.
+//!
+//! # References
+//!
+//! - [PSX-SPX: GTE Perspective Transformation](http://problemkaputt.de/psx-spx.htm#gteperspectivetransformation)
+
+use std::collections::HashMap;
+
+/// High-precision projected vertex
+///
+/// Screen position as computed by the GTE before it is rounded down and
+/// saturated to the integer SXY registers.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PrecisionVertex {
+    /// Screen X coordinate (sub-pixel, including OFX)
+    pub x: f32,
+
+    /// Screen Y coordinate (sub-pixel, including OFY)
+    pub y: f32,
+
+    /// View-space depth used for perspective correction
+    pub w: f32,
+}
+
+impl PrecisionVertex {
+    /// Create a new precision vertex
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Screen X coordinate
+    /// * `y` - Screen Y coordinate
+    /// * `w` - View-space depth
+    pub fn new(x: f32, y: f32, w: f32) -> Self {
+        Self { x, y, w }
+    }
+}
+
+/// Cached precision vertex together with the word it was stored as
+#[derive(Debug, Clone, Copy)]
+struct PrecisionEntry {
+    /// Integer SXY value written to memory
+    value: u32,
+
+    /// Precise vertex for that value
+    vertex: PrecisionVertex,
+}
+
+/// Address-keyed cache of precision vertices
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::precision::{PrecisionCache, PrecisionVertex};
+///
+/// let mut cache = PrecisionCache::new();
+/// let vertex = PrecisionVertex::new(10.25, 20.75, 300.0);
+///
+/// cache.record(0x8001_0000, 0x0014_000A, vertex);
+/// assert_eq!(cache.lookup(0x0001_0000, 0x0014_000A), Some(vertex));
+/// assert_eq!(cache.lookup(0x0001_0000, 0x0014_000B), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct PrecisionCache {
+    /// Entries keyed by physical RAM word address
+    entries: HashMap<u32, PrecisionEntry>,
+}
+
+impl PrecisionCache {
+    /// Mask converting any RAM address (KUSEG/KSEG0/KSEG1, mirrors) to a
+    /// physical word address
+    const ADDRESS_MASK: u32 = 0x001F_FFFC;
+
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the precise vertex stored at a RAM address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Virtual or physical address the SXY value was stored to
+    /// * `value` - Integer SXY value that was stored
+    /// * `vertex` - Precise vertex for that value
+    pub fn record(&mut self, address: u32, value: u32, vertex: PrecisionVertex) {
+        self.entries.insert(
+            address & Self::ADDRESS_MASK,
+            PrecisionEntry { value, vertex },
+        );
+    }
+
+    /// Look up the precise vertex for a word read from RAM
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Virtual or physical address the word was read from
+    /// * `value` - Word read from that address
+    ///
+    /// # Returns
+    ///
+    /// The precise vertex if one was recorded for this address and memory
+    /// still holds the same value, `None` otherwise
+    pub fn lookup(&self, address: u32, value: u32) -> Option<PrecisionVertex> {
+        self.entries
+            .get(&(address & Self::ADDRESS_MASK))
+            .filter(|entry| entry.value == value)
+            .map(|entry| entry.vertex)
+    }
+
+    /// Number of cached vertices
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all cached vertices
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -27,8 +27,11 @@ use super::audio::AudioBackend;
 use super::cdrom::CDROM;
 use super::cpu::{CpuTracer, CPU};
 use super::dma::DMA;
-use super::error::{EmulatorError, Result};
-use super::gpu::GPU;
+use super::error::{EmulatorError, GpuError, Result};
+use super::gpu::{
+    CropMode, Deinterlace, Renderer, SoftwareRenderer, TextureDepth, TextureInfo,
+    TextureReplacementConfig, VramImage, GPU,
+};
 use super::interrupt::{interrupts, InterruptController};
 use super::memory::Bus;
 use super::spu::SPU;
@@ -98,17 +101,60 @@ pub struct System {
 }
 
 impl System {
+    /// BIOS shell entry point
+    ///
+    /// The BIOS jumps here once the kernel is initialized, just before
+    /// booting the disc. Sideloaded executables take over at this point.
+    const SHELL_ENTRY_POINT: u32 = 0x80030000;
+
+    /// Maximum number of instructions to run while waiting for the shell
+    const FAST_BOOT_MAX_INSTRUCTIONS: u64 = 100_000_000;
+
+    /// RAM area holding the boot command line (argc/argv for sideloaded executables)
+    const EXE_ARGS_ADDRESS: u32 = 0x80000180;
+
+    /// Size of the boot command line area in bytes
+    const EXE_ARGS_SIZE: usize = 128;
+
+    /// Default stack pointer when the executable header doesn't specify one
+    const DEFAULT_EXE_STACK: u32 = 0x801FFFF0;
+
     /// Create a new System instance
     ///
     /// Initializes all hardware components to their reset state.
     /// Sets up memory-mapped I/O connections between components.
     /// Registers timing events for all components.
+    /// The GPU draws with the software renderer.
     ///
     /// # Returns
     /// Initialized System instance
     pub fn new() -> Self {
+        Self::with_renderer(Box::new(SoftwareRenderer::new()))
+    }
+
+    /// Create a new System instance with a specific GPU rendering backend
+    ///
+    /// # Arguments
+    ///
+    /// * `renderer` - Backend the GPU draws with
+    ///
+    /// # Returns
+    /// Initialized System instance
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gpu::RendererKind;
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::with_renderer(RendererKind::Null.create());
+    /// assert_eq!(system.renderer_name(), "null");
+    /// ```
+    pub fn with_renderer(renderer: Box<dyn Renderer>) -> Self {
+        log::info!("GPU renderer: {}", renderer.name());
+
         // Create GPU wrapped in Rc<RefCell> for shared access
-        let gpu = Rc::new(RefCell::new(GPU::new()));
+        let gpu = Rc::new(RefCell::new(GPU::with_renderer(renderer)));
 
         // Create DMA controller wrapped in Rc<RefCell> for shared access
         let dma = Rc::new(RefCell::new(DMA::new()));
@@ -463,8 +509,9 @@ impl System {
 
     /// Execute one frame worth of instructions
     ///
-    /// The PlayStation CPU runs at approximately 33.8688 MHz.
-    /// At 60 fps, one frame requires approximately 564,480 cycles.
+    /// A frame ends on the GPU's next VBlank event, so its length follows
+    /// the current video mode: about 566,239 cycles for NTSC (~59.81 Hz)
+    /// and 680,752 cycles for PAL (~49.75 Hz) at 33.8688 MHz.
     ///
     /// This method uses event-driven execution through the timing system.
     /// The CPU executes until the timing system signals the frame is complete.
@@ -484,15 +531,28 @@ impl System {
     /// system.run_frame().unwrap(); // Execute one frame
     /// ```
     pub fn run_frame(&mut self) -> Result<()> {
-        // PSX CPU runs at ~33.8688 MHz
-        // At 60 fps, one frame = 33868800 / 60 ≈ 564,480 cycles
-        const CYCLES_PER_FRAME: u64 = 564_480;
+        let frame_cycles = self.cycles_until_vblank();
 
         // Set frame target in timing system
-        self.timing.set_frame_target(CYCLES_PER_FRAME);
-
-        // Execute CPU until timing system signals frame complete
-        self.cpu.execute(&mut self.bus, &mut self.timing)?;
+        self.timing.set_frame_target(frame_cycles);
+
+        // Execute CPU until timing system signals frame complete. GPU
+        // events are delivered as they fire, so drawing finishes, the
+        // interlace field advances and VBlank is signalled on this path too.
+        let gpu = Rc::clone(&self.gpu);
+        let interrupt_controller = Rc::clone(&self.interrupt_controller);
+        self.cpu
+            .execute_with_events(&mut self.bus, &mut self.timing, |timing, triggered| {
+                let mut gpu = gpu.borrow_mut();
+                gpu.process_events(timing, triggered);
+
+                let (vblank_irq, _) = gpu.poll_interrupts();
+                if vblank_irq {
+                    interrupt_controller
+                        .borrow_mut()
+                        .request(interrupts::VBLANK);
+                }
+            })?;
 
         // Tick SPU for one frame worth of cycles and queue audio if available
         #[cfg(feature = "audio")]
@@ -501,7 +561,7 @@ impl System {
             let audio_samples = {
                 let mut cdrom = self.cdrom.borrow_mut();
                 let mut spu = self.spu.borrow_mut();
-                spu.tick_with_cd(CYCLES_PER_FRAME as u32, &mut cdrom.cd_audio)
+                spu.tick_with_cd(frame_cycles as u32, &mut cdrom.cd_audio)
             };
 
             if let Some(ref mut audio) = self.audio {
@@ -520,9 +580,48 @@ impl System {
         // Update total cycles from timing system
         self.cycles = self.timing.global_tick_counter;
 
+        // Finish the frame's rendering before the frontend reads VRAM
+        self.gpu.borrow_mut().sync_renderer();
+
         Ok(())
     }
 
+    /// Compute the number of cycles until the next GPU VBlank
+    ///
+    /// Applies any pending video mode change to the GPU timing events first.
+    /// Falls back to one full frame of the current video mode if the VBlank
+    /// event is not active or is already due.
+    fn cycles_until_vblank(&mut self) -> u64 {
+        let mut gpu = self.gpu.borrow_mut();
+        gpu.sync_timing(&mut self.timing);
+
+        let frame_cycles = gpu.video_mode().cpu_cycles_per_frame() as u64;
+        gpu.vblank_event()
+            .and_then(|handle| self.timing.time_until(handle))
+            .filter(|&cycles| cycles > 0)
+            .unwrap_or(frame_cycles)
+    }
+
+    /// Get the frame rate of the current video mode
+    ///
+    /// Follows the NTSC/PAL bit set by GP1(08h), so frontends can pace
+    /// emulation at 60 or 50 Hz.
+    ///
+    /// # Returns
+    /// Frames per second (about 59.81 for NTSC, 49.75 for PAL)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let system = System::new();
+    /// assert!((system.frame_rate() - 59.81).abs() < 0.01);
+    /// ```
+    pub fn frame_rate(&self) -> f64 {
+        self.gpu.borrow().video_mode().refresh_rate()
+    }
+
     /// Get current PC value
     ///
     /// # Returns
@@ -615,21 +714,18 @@ impl System {
 
     /// Load a game from CD-ROM and prepare for execution
     ///
-    /// **Current Implementation Status (Partial):**
-    ///
-    /// Currently implemented:
+    /// Performs the same steps the BIOS shell would to boot a disc:
     /// 1. Load disc image from .cue file
-    /// 2. Read SYSTEM.CNF from disc (hard-coded filename: "SYSTEM.CNF;1")
-    /// 3. Parse SYSTEM.CNF to find boot executable path
-    ///
-    /// **Not yet implemented (TODO):**
-    /// 4. Full ISO9660 filesystem parsing to locate executable by path
-    /// 5. Load PSX-EXE file from disc
-    /// 6. Copy executable data to RAM
-    /// 7. Set CPU registers (PC, GP, SP, FP)
+    /// 2. Read SYSTEM.CNF from the disc's ISO9660 filesystem
+    /// 3. Parse SYSTEM.CNF to find the boot executable path
+    /// 4. Read the PSX-EXE named by `BOOT=` from the disc
+    /// 5. Run the BIOS until its kernel is set up, then copy the executable
+    ///    to RAM, zero-fill its BSS and set the CPU registers, like
+    ///    [`load_exe`](Self::load_exe)
     ///
-    /// This method will return an error until ISO9660 support is completed.
-    /// The full game boot sequence is planned for a future phase.
+    /// The stack pointer is taken from the `STACK=` entry of SYSTEM.CNF if it
+    /// differs from the default, otherwise from the executable header
+    /// (stack base + offset) if present.
     ///
     /// # Arguments
     ///
@@ -637,8 +733,8 @@ impl System {
     ///
     /// # Returns
     ///
-    /// - `Ok(())` if disc loads and SYSTEM.CNF is parsed successfully
-    /// - `Err(EmulatorError)` currently returns error for unimplemented executable loading
+    /// - `Ok(())` if the executable was loaded and the CPU is ready to run it
+    /// - `Err(EmulatorError)` if the disc, SYSTEM.CNF or executable is invalid
     ///
     /// # Example
     ///
@@ -647,19 +743,13 @@ impl System {
     ///
     /// let mut system = System::new();
     /// system.load_bios("SCPH1001.BIN").unwrap();
+    /// system.load_game("game.cue").unwrap();
     ///
-    /// // Currently only loads disc and parses SYSTEM.CNF
-    /// // Full executable loading not yet implemented
-    /// match system.load_game("game.cue") {
-    ///     Ok(_) => println!("Disc loaded, SYSTEM.CNF parsed"),
-    ///     Err(_) => println!("Executable loading not yet implemented"),
-    /// }
+    /// // CPU now starts at the executable entry point
+    /// system.run_frame().unwrap();
     /// ```
     pub fn load_game(&mut self, cue_path: &str) -> Result<()> {
-        use super::loader::SystemConfig;
-        // PSXExecutable will be used when full ISO9660 parsing is implemented
-        #[allow(unused_imports)]
-        use super::loader::PSXExecutable;
+        use super::loader::{PSXExecutable, SystemConfig};
 
         log::info!("Loading game from: {}", cue_path);
 
@@ -687,42 +777,538 @@ impl System {
         log::debug!("Stack: 0x{:08X}", config.stack);
 
         // Step 4: Read executable from disc
-        // A full implementation would need ISO9660 parsing to locate the executable
-        // TODO: Implement full ISO9660 file system parsing
-        //
-        // When implemented, this would be:
-        // let exe_data = self.cdrom.borrow_mut().read_file(&config.boot_file)?;
-        // let exe = PSXExecutable::load(&exe_data)?;
-        //
-        // // Step 5: Load executable data into RAM
-        // self.bus.write_ram_slice(exe.load_address, &exe.data)?;
-        //
-        // // Step 6: Set CPU registers
-        // self.cpu.set_pc(exe.pc);
-        // self.cpu.set_reg(28, exe.gp);  // $gp (global pointer)
-        //
-        // // Setup stack
-        // let sp = if config.stack != 0x801FFF00 {
-        //     config.stack
-        // } else if exe.stack_base != 0 {
-        //     exe.stack_base + exe.stack_offset
-        // } else {
-        //     config.stack
-        // };
-        // self.cpu.set_reg(29, sp);  // $sp (stack pointer)
-        // self.cpu.set_reg(30, sp);  // $fp (frame pointer)
-        //
-        // log::info!("Game loaded successfully!");
-        // log::info!("Entry point: 0x{:08X}", exe.pc);
-        // log::info!("Global pointer: 0x{:08X}", exe.gp);
-        // log::info!("Stack pointer: 0x{:08X}", sp);
-
-        // For now, return error since executable loading is not implemented
-        Err(EmulatorError::LoaderError(format!(
-            "ISO9660 filesystem parsing not yet implemented. Cannot load executable: {}. \
-             Disc loaded successfully and SYSTEM.CNF parsed, but full boot sequence requires ISO9660 support.",
-            config.boot_file
-        )))
+        let exe_data = self
+            .cdrom
+            .borrow_mut()
+            .read_file(&config.boot_file)
+            .map_err(EmulatorError::CdRom)?;
+        let exe = PSXExecutable::load(&exe_data)?;
+
+        // Step 5: Boot the executable
+        let sp = if config.stack != 0x801FFF00 {
+            config.stack
+        } else if exe.stack_base != 0 {
+            exe.stack_base.wrapping_add(exe.stack_offset)
+        } else {
+            config.stack
+        };
+        self.boot_executable(&exe, &[], sp)?;
+
+        log::info!("Game loaded successfully!");
+        Ok(())
+    }
+
+    /// Sideload a PSX-EXE file
+    ///
+    /// Equivalent to [`load_exe_with_args`](Self::load_exe_with_args) with no arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the PSX-EXE file
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the executable was loaded and the CPU is at its entry point
+    /// - `Err(EmulatorError)` if the file is invalid or the BIOS never reached the shell
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.load_bios("SCPH1001.BIN").unwrap();
+    /// system.load_exe("homebrew.exe").unwrap();
+    /// ```
+    pub fn load_exe(&mut self, path: &str) -> Result<()> {
+        self.load_exe_with_args(path, &[])
+    }
+
+    /// Sideload a PSX-EXE file with command line arguments
+    ///
+    /// Runs the BIOS until its kernel is set up and it jumps to the shell
+    /// entry point (0x80030000), then replaces the shell with the executable:
+    /// 1. Copy the text/data section to its load address
+    /// 2. Zero-fill the BSS section
+    /// 3. Write argc/argv to the boot command line area (0x80000180)
+    /// 4. Set PC, GP, SP, FP and A0/A1 (argc/argv)
+    ///
+    /// The stack pointer comes from the executable header (stack base + offset),
+    /// falling back to 0x801FFFF0 if the header leaves it zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the PSX-EXE file
+    /// * `args` - Arguments passed to the executable as argv
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the executable was loaded and the CPU is at its entry point
+    /// - `Err(EmulatorError)` if the file is invalid, the arguments don't fit,
+    ///   or the BIOS never reached the shell
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.load_bios("SCPH1001.BIN").unwrap();
+    /// system
+    ///     .load_exe_with_args("test.exe", &["--verbose".to_string()])
+    ///     .unwrap();
+    /// ```
+    pub fn load_exe_with_args(&mut self, path: &str, args: &[String]) -> Result<()> {
+        use super::loader::PSXExecutable;
+
+        log::info!("Sideloading executable: {}", path);
+
+        let data = std::fs::read(path)?;
+        let exe = PSXExecutable::load(&data)?;
+
+        let sp = if exe.stack_base != 0 {
+            exe.stack_base.wrapping_add(exe.stack_offset)
+        } else {
+            Self::DEFAULT_EXE_STACK
+        };
+        self.boot_executable(&exe, args, sp)
+    }
+
+    /// Start an executable in place of the BIOS shell
+    ///
+    /// Runs the BIOS until its kernel is set up and it jumps to the shell
+    /// entry point, so kernel calls made by the executable find initialized
+    /// tables. Then copies the text section, zero-fills the BSS, writes
+    /// argc/argv and sets PC, GP, SP, FP and A0/A1.
+    ///
+    /// # Arguments
+    ///
+    /// * `exe` - Executable to start
+    /// * `args` - Arguments passed to the executable as argv
+    /// * `sp` - Initial stack and frame pointer
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the CPU is at the executable entry point
+    /// - `Err(EmulatorError)` if the arguments don't fit or the BIOS never
+    ///   reached the shell
+    fn boot_executable(
+        &mut self,
+        exe: &super::loader::PSXExecutable,
+        args: &[String],
+        sp: u32,
+    ) -> Result<()> {
+        // Let the BIOS initialize the kernel before taking over
+        self.run_until_shell()?;
+
+        // Copy text section and clear BSS (the loader has checked that it
+        // fits in RAM)
+        self.bus.write_ram_slice(exe.load_address, &exe.data)?;
+        if exe.bss_size > 0 {
+            self.bus
+                .write_ram_slice(exe.bss_address, &vec![0u8; exe.bss_size as usize])?;
+        }
+
+        let argv = self.write_exe_args(args)?;
+
+        self.cpu.set_pc(exe.pc);
+        self.cpu.set_reg(4, args.len() as u32); // $a0 (argc)
+        self.cpu.set_reg(5, argv); // $a1 (argv)
+        self.cpu.set_reg(28, exe.gp); // $gp (global pointer)
+        self.cpu.set_reg(29, sp); // $sp (stack pointer)
+        self.cpu.set_reg(30, sp); // $fp (frame pointer)
+
+        log::info!("Executable loaded successfully!");
+        log::info!("Entry point: 0x{:08X}", exe.pc);
+        log::info!("Global pointer: 0x{:08X}", exe.gp);
+        log::info!("Stack pointer: 0x{:08X}", sp);
+
+        Ok(())
+    }
+
+    /// Run the BIOS until it reaches the shell entry point
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` once PC equals 0x80030000
+    /// - `Err(EmulatorError)` if execution fails or the shell is never reached
+    fn run_until_shell(&mut self) -> Result<()> {
+        let mut executed = 0u64;
+
+        while self.cpu.pc() != Self::SHELL_ENTRY_POINT {
+            if executed >= Self::FAST_BOOT_MAX_INSTRUCTIONS {
+                return Err(EmulatorError::LoaderError(format!(
+                    "BIOS did not reach shell entry point 0x{:08X} after {} instructions",
+                    Self::SHELL_ENTRY_POINT,
+                    executed
+                )));
+            }
+
+            self.step()?;
+            executed += 1;
+        }
+
+        log::debug!(
+            "Reached shell entry point after {} instructions ({} cycles)",
+            executed,
+            self.cycles
+        );
+
+        Ok(())
+    }
+
+    /// Write argv for a sideloaded executable to RAM
+    ///
+    /// Layout in the boot command line area: an array of `argc` string
+    /// pointers followed by the NUL-terminated argument strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Arguments to write
+    ///
+    /// # Returns
+    ///
+    /// Address of the argv pointer array (0 if there are no arguments)
+    fn write_exe_args(&mut self, args: &[String]) -> Result<u32> {
+        if args.is_empty() {
+            return Ok(0);
+        }
+
+        let table_size = args.len() * 4;
+        let strings_size: usize = args.iter().map(|arg| arg.len() + 1).sum();
+        if table_size + strings_size > Self::EXE_ARGS_SIZE {
+            return Err(EmulatorError::LoaderError(format!(
+                "Executable arguments too long: {} bytes (max {})",
+                table_size + strings_size,
+                Self::EXE_ARGS_SIZE
+            )));
+        }
+
+        let mut area = vec![0u8; table_size];
+        for (i, arg) in args.iter().enumerate() {
+            let address = Self::EXE_ARGS_ADDRESS + area.len() as u32;
+            area[i * 4..i * 4 + 4].copy_from_slice(&address.to_le_bytes());
+            area.extend_from_slice(arg.as_bytes());
+            area.push(0);
+        }
+
+        self.bus.write_ram_slice(Self::EXE_ARGS_ADDRESS, &area)?;
+
+        Ok(Self::EXE_ARGS_ADDRESS)
+    }
+
+    /// Enable or disable precision geometry (PGXP-style)
+    ///
+    /// When enabled, the GTE keeps sub-pixel screen coordinates and depth for
+    /// every projected vertex, and the GPU uses them for polygons whose
+    /// vertices were stored with SWC2 and sent through DMA. This removes
+    /// polygon jitter and affine texture warping. Off by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether precision geometry should be used
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// assert!(!system.precision_geometry_enabled());
+    ///
+    /// system.set_precision_geometry(true);
+    /// assert!(system.precision_geometry_enabled());
+    /// ```
+    pub fn set_precision_geometry(&mut self, enabled: bool) {
+        self.cpu.gte_mut().set_precision_enabled(enabled);
+        self.gpu.borrow_mut().set_precision_enabled(enabled);
+        log::info!(
+            "Precision geometry {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Check whether precision geometry is enabled
+    ///
+    /// # Returns
+    /// true if precision geometry is active
+    pub fn precision_geometry_enabled(&self) -> bool {
+        self.cpu.gte().precision_enabled()
+    }
+
+    /// Enable or disable the widescreen hack
+    ///
+    /// Compresses the X coordinate of GTE perspective transforms by 3/4 so
+    /// 3D scenes cover a 16:9 field of view, and has the GPU draw every
+    /// primitive into a copy of its drawing area widened by 4/3, with the
+    /// clip rectangle widened to match. Frames are read from the widened
+    /// area, so they are 4/3 as wide as the display area and shown at 16:9.
+    /// Can be toggled at any time; 2D elements that do not go through the
+    /// GTE are widened as well. Native VRAM is left as the game drew it.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether widescreen output should be used
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.set_widescreen(true);
+    /// assert!(system.widescreen_enabled());
+    /// assert_eq!(system.gpu().borrow_mut().get_framebuffer().aspect_ratio, 16.0 / 9.0);
+    /// ```
+    pub fn set_widescreen(&mut self, enabled: bool) {
+        self.cpu.gte_mut().set_widescreen(enabled);
+        self.gpu.borrow_mut().set_widescreen(enabled);
+        log::info!(
+            "Widescreen hack {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Check whether the widescreen hack is enabled
+    ///
+    /// # Returns
+    /// true if widescreen output is active
+    pub fn widescreen_enabled(&self) -> bool {
+        self.cpu.gte().widescreen()
+    }
+
+    /// Allow or suppress GPU dithering
+    ///
+    /// Games enable dithering through the GP0(E1h) draw mode; turning this
+    /// off renders shaded and texture-blended primitives without the dither
+    /// pattern regardless of the game setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether dithering may be applied
+    pub fn set_dithering(&mut self, enabled: bool) {
+        self.gpu.borrow_mut().set_dithering_enabled(enabled);
+        log::info!("Dithering {}", if enabled { "enabled" } else { "disabled" });
+    }
+
+    /// Check whether GPU dithering is allowed
+    ///
+    /// # Returns
+    /// true if dithering may be applied
+    pub fn dithering_enabled(&self) -> bool {
+        self.gpu.borrow().dithering_enabled()
+    }
+
+    /// Select how 480-line interlaced frames are deinterlaced
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Deinterlacing method (weave, bob or blend)
+    pub fn set_deinterlace_mode(&mut self, mode: Deinterlace) {
+        self.gpu.borrow_mut().set_deinterlace_mode(mode);
+        log::info!("Deinterlacing set to {:?}", mode);
+    }
+
+    /// Select whether frames include the overscan borders
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Cropped display range or full overscan picture
+    pub fn set_crop_mode(&mut self, mode: CropMode) {
+        self.gpu.borrow_mut().set_crop_mode(mode);
+        log::info!("Framebuffer crop mode set to {:?}", mode);
+    }
+
+    /// Get the current framebuffer cropping mode
+    ///
+    /// # Returns
+    /// Whether frames are cropped to the display range or show overscan
+    pub fn crop_mode(&self) -> CropMode {
+        self.gpu.borrow().crop_mode()
+    }
+
+    /// Get the current deinterlacing method
+    ///
+    /// # Returns
+    /// The method used for 480-line interlaced frames
+    pub fn deinterlace_mode(&self) -> Deinterlace {
+        self.gpu.borrow().deinterlace_mode()
+    }
+
+    /// Enable or disable texture cache emulation
+    ///
+    /// Needed by games that rely on stale texture cache contents after
+    /// uploading to a texture page they are drawing from.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the GPU texture cache should be emulated
+    pub fn set_texture_cache(&mut self, enabled: bool) {
+        self.gpu.borrow_mut().set_texture_cache_enabled(enabled);
+        log::info!(
+            "Texture cache emulation {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Check whether texture cache emulation is enabled
+    ///
+    /// # Returns
+    /// true if texel fetches go through the emulated texture cache
+    pub fn texture_cache_enabled(&self) -> bool {
+        self.gpu.borrow().texture_cache_enabled()
+    }
+
+    /// Enable or disable threaded GPU rendering
+    ///
+    /// Moves rasterization to a dedicated worker thread. Output is
+    /// identical to synchronous rendering; VRAM is synchronized at the end
+    /// of every frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether primitives are rasterized on a worker thread
+    pub fn set_threaded_rendering(&mut self, enabled: bool) {
+        self.gpu.borrow_mut().set_threaded_rendering(enabled);
+        log::info!(
+            "Threaded GPU rendering {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Check whether threaded GPU rendering is enabled
+    ///
+    /// # Returns
+    /// true if primitives are rasterized on a worker thread
+    pub fn threaded_rendering(&self) -> bool {
+        self.gpu.borrow().threaded_rendering()
+    }
+
+    /// Set the GPU internal resolution scale factor
+    ///
+    /// Renders polygons, lines and rectangles at `scale` times the native
+    /// resolution, so frames become `scale` times larger in each direction.
+    /// Clamped to 1-8; 1 renders at native resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Scale factor
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the scale was applied
+    /// - `Err(EmulatorError::Gpu)` if the rendering backend cannot upscale
+    pub fn set_resolution_scale(&mut self, scale: u16) -> Result<()> {
+        let mut gpu = self.gpu.borrow_mut();
+        gpu.set_resolution_scale(scale)?;
+        log::info!("GPU resolution scale set to {}x", gpu.resolution_scale());
+        Ok(())
+    }
+
+    /// Set the texture dumping and replacement directories
+    ///
+    /// Textures are dumped as PNG files named after a hash of their texels
+    /// and CLUT. Replacement PNGs with the same names are drawn instead of
+    /// the originals while the resolution scale is above 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Dump and replacement directories
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the directories were applied
+    /// - `Err(EmulatorError::Gpu)` if the rendering backend does not support it
+    pub fn set_texture_replacement(&mut self, config: TextureReplacementConfig) -> Result<()> {
+        if let Some(dir) = &config.dump_dir {
+            log::info!("Dumping textures to {}", dir.display());
+        }
+        if let Some(dir) = &config.replacement_dir {
+            log::info!("Loading texture replacements from {}", dir.display());
+        }
+        self.gpu.borrow_mut().set_texture_replacement(config)?;
+        Ok(())
+    }
+
+    /// Get the name of the GPU rendering backend
+    ///
+    /// # Returns
+    /// Backend name ("software", "null" or "recording" for the built-in ones)
+    pub fn renderer_name(&self) -> &'static str {
+        self.gpu.borrow_mut().renderer_name()
+    }
+
+    /// Get the GPU internal resolution scale factor
+    ///
+    /// # Returns
+    /// Scale factor (1 = native resolution)
+    pub fn resolution_scale(&self) -> u16 {
+        self.gpu.borrow().resolution_scale()
+    }
+
+    /// Export the full 1024×512 VRAM to an image file
+    ///
+    /// Can be called at any point, e.g. between frames. The format is
+    /// chosen from the extension (`.png` or `.ppm`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output image path
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the image was written
+    /// - `Err(EmulatorError)` if the format is unknown or writing fails
+    pub fn export_vram(&self, path: &str) -> Result<()> {
+        let image = self.gpu.borrow_mut().export_vram();
+        image.save(path)?;
+        log::info!("VRAM exported to {}", path);
+        Ok(())
+    }
+
+    /// Export a decoded texture page to an image file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output image path (`.png` or `.ppm`)
+    /// * `texture` - Texture page position, CLUT position and color depth
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the image was written
+    /// - `Err(EmulatorError)` if the format is unknown or writing fails
+    pub fn export_texture_page(&self, path: &str, texture: &TextureInfo) -> Result<()> {
+        let image = self.gpu.borrow_mut().export_texture_page(texture);
+        image.save(path)?;
+        log::info!(
+            "Texture page ({}, {}) exported to {}",
+            texture.page_x,
+            texture.page_y,
+            path
+        );
+        Ok(())
+    }
+
+    /// Export all palettes in VRAM to an image file, one palette per line
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output image path (`.png` or `.ppm`)
+    /// * `depth` - Texture depth the CLUTs are used with (4-bit or 8-bit)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(count)` with the number of palettes written
+    /// - `Err(EmulatorError)` if VRAM holds no palettes, the format is
+    ///   unknown or writing fails
+    pub fn export_palettes(&self, path: &str, depth: TextureDepth) -> Result<usize> {
+        let palettes = self.gpu.borrow_mut().clut_palettes(depth);
+        if palettes.is_empty() {
+            return Err(GpuError::ImageExport("no palettes found in VRAM".to_string()).into());
+        }
+        VramImage::from_palettes(&palettes, 8).save(path)?;
+        log::info!("{} palettes exported to {}", palettes.len(), path);
+        Ok(palettes.len())
     }
 
     /// Enable CPU execution tracing to a file
@@ -30,9 +30,9 @@ fn test_system_timing_manager_created() {
     // Verify timing manager is initialized properly
     assert_eq!(system.timing.global_tick_counter, 0);
     assert_eq!(system.timing.pending_ticks, 0);
-    // With GPU events activated, downcount should be set to HBlank interval (2146 cycles)
-    // which is the smallest periodic event
-    assert_eq!(system.timing.downcount, 2146);
+    // With GPU events activated, downcount should be set to the NTSC HBlank
+    // interval (2153 cycles) which is the smallest periodic event
+    assert_eq!(system.timing.downcount, 2153);
 }
 
 #[test]
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PSX-EXE sideloading tests
+
+use super::super::*;
+use std::io::Write;
+
+/// Create a system whose BIOS immediately jumps to the shell entry point
+fn create_fast_boot_system() -> System {
+    let mut system = System::new();
+
+    // lui $t0, 0x8003 ; jr $t0 ; nop
+    let program: [u32; 3] = [0x3C088003, 0x01000008, 0x00000000];
+    let bytes: Vec<u8> = program.iter().flat_map(|w| w.to_le_bytes()).collect();
+    system.bus_mut().write_bios_for_test(0, &bytes);
+
+    system
+}
+
+/// Build a PSX-EXE with the given header fields
+fn build_exe(stack_base: u32, bss_address: u32, bss_size: u32) -> Vec<u8> {
+    let mut data = vec![0u8; 0x800 + 0x800];
+    data[0..8].copy_from_slice(b"PS-X EXE");
+    data[0x10..0x14].copy_from_slice(&0x80010000u32.to_le_bytes()); // PC
+    data[0x14..0x18].copy_from_slice(&0x80020000u32.to_le_bytes()); // GP
+    data[0x18..0x1C].copy_from_slice(&0x80010000u32.to_le_bytes()); // Load address
+    data[0x1C..0x20].copy_from_slice(&0x800u32.to_le_bytes()); // Load size
+    data[0x28..0x2C].copy_from_slice(&bss_address.to_le_bytes());
+    data[0x2C..0x30].copy_from_slice(&bss_size.to_le_bytes());
+    data[0x30..0x34].copy_from_slice(&stack_base.to_le_bytes());
+    data[0x800..0x804].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+    data
+}
+
+/// Write a PSX-EXE with the given header fields to a temporary file
+fn write_exe(stack_base: u32, bss_address: u32, bss_size: u32) -> tempfile::NamedTempFile {
+    let data = build_exe(stack_base, bss_address, bss_size);
+    let mut file = tempfile::Builder::new()
+        .prefix("test_exe_")
+        .suffix(".exe")
+        .tempfile()
+        .unwrap();
+    file.write_all(&data).unwrap();
+    file
+}
+
+#[test]
+fn test_load_exe_sets_registers_and_copies_text() {
+    let mut system = create_fast_boot_system();
+    let exe = write_exe(0x801FFF00, 0, 0);
+
+    system.load_exe(exe.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(system.pc(), 0x80010000);
+    assert_eq!(system.cpu().reg(4), 0); // argc
+    assert_eq!(system.cpu().reg(5), 0); // argv
+    assert_eq!(system.cpu().reg(28), 0x80020000);
+    assert_eq!(system.cpu().reg(29), 0x801FFF00);
+    assert_eq!(system.cpu().reg(30), 0x801FFF00);
+    assert_eq!(system.bus().read32(0x80010000).unwrap(), 0xDEADBEEF);
+}
+
+#[test]
+fn test_load_exe_default_stack() {
+    let mut system = create_fast_boot_system();
+    let exe = write_exe(0, 0, 0);
+
+    system.load_exe(exe.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(system.cpu().reg(29), 0x801FFFF0);
+}
+
+#[test]
+fn test_load_exe_zeroes_bss() {
+    let mut system = create_fast_boot_system();
+    system
+        .bus_mut()
+        .write_ram_slice(0x80018000, &[0xFF; 0x100])
+        .unwrap();
+    let exe = write_exe(0, 0x80018000, 0x80);
+
+    system.load_exe(exe.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(system.bus().read32(0x80018000).unwrap(), 0);
+    assert_eq!(system.bus().read32(0x8001807C).unwrap(), 0);
+    // Memory past the BSS is untouched
+    assert_eq!(system.bus().read32(0x80018080).unwrap(), 0xFFFFFFFF);
+}
+
+#[test]
+fn test_load_exe_with_args() {
+    let mut system = create_fast_boot_system();
+    let exe = write_exe(0, 0, 0);
+    let args = vec!["test.exe".to_string(), "-v".to_string()];
+
+    system
+        .load_exe_with_args(exe.path().to_str().unwrap(), &args)
+        .unwrap();
+
+    assert_eq!(system.cpu().reg(4), 2);
+    let argv = system.cpu().reg(5);
+    assert_eq!(argv, 0x80000180);
+
+    for (i, arg) in args.iter().enumerate() {
+        let ptr = system.bus().read32(argv + i as u32 * 4).unwrap();
+        let bytes: Vec<u8> = (0..=arg.len() as u32)
+            .map(|offset| system.bus().read8(ptr + offset).unwrap())
+            .collect();
+        assert_eq!(&bytes[..arg.len()], arg.as_bytes());
+        assert_eq!(bytes[arg.len()], 0);
+    }
+}
+
+#[test]
+fn test_load_exe_args_too_long() {
+    let mut system = create_fast_boot_system();
+    let exe = write_exe(0, 0, 0);
+    let args = vec!["x".repeat(200)];
+
+    let result = system.load_exe_with_args(exe.path().to_str().unwrap(), &args);
+    assert!(matches!(result, Err(EmulatorError::LoaderError(_))));
+}
+
+#[test]
+fn test_load_exe_invalid_file() {
+    let mut system = create_fast_boot_system();
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(&[0u8; 0x900]).unwrap();
+
+    assert!(system.load_exe(file.path().to_str().unwrap()).is_err());
+    // The BIOS is not run when the executable is rejected
+    assert_eq!(system.pc(), 0xBFC00000);
+}
+
+#[test]
+fn test_load_game_boots_like_sideloaded_exe() {
+    use crate::core::cdrom::tests::iso9660::{build_image, write_disc};
+
+    let mut system = create_fast_boot_system();
+    system
+        .bus_mut()
+        .write_ram_slice(0x80018000, &[0xFF; 0x100])
+        .unwrap();
+
+    let image = build_image(
+        b"BOOT = cdrom:\\DATA\\MAIN.EXE;1\r\nSTACK = 0x801FF000\r\n",
+        &build_exe(0, 0x80018000, 0x80),
+    );
+    let (_bin, cue) = write_disc(&image);
+
+    system.load_game(cue.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(system.pc(), 0x80010000);
+    assert_eq!(system.cpu().reg(28), 0x80020000);
+    assert_eq!(system.cpu().reg(29), 0x801FF000);
+    assert_eq!(system.bus().read32(0x80010000).unwrap(), 0xDEADBEEF);
+
+    // BSS is cleared, memory past it is untouched
+    assert_eq!(system.bus().read32(0x80018000).unwrap(), 0);
+    assert_eq!(system.bus().read32(0x8001807C).unwrap(), 0);
+    assert_eq!(system.bus().read32(0x80018080).unwrap(), 0xFFFFFFFF);
+}
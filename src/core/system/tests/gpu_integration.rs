@@ -122,7 +122,7 @@ fn test_gp0_command_via_bus() {
     system.bus.write32(0x1F801810, 0x7FFF7FFF).unwrap();
 
     // Verify pixel was written to VRAM
-    assert_eq!(system.gpu.borrow().read_vram(0, 0), 0x7FFF);
+    assert_eq!(system.gpu.borrow_mut().read_vram(0, 0), 0x7FFF);
 }
 
 #[test]
@@ -172,10 +172,10 @@ fn test_vram_transfer_via_bus() {
     system.bus.write32(0x1F801810, 0xCCCCDDDD).unwrap();
 
     // Verify pixels written correctly
-    assert_eq!(system.gpu.borrow().read_vram(10, 10), 0xBBBB);
-    assert_eq!(system.gpu.borrow().read_vram(11, 10), 0xAAAA);
-    assert_eq!(system.gpu.borrow().read_vram(10, 11), 0xDDDD);
-    assert_eq!(system.gpu.borrow().read_vram(11, 11), 0xCCCC);
+    assert_eq!(system.gpu.borrow_mut().read_vram(10, 10), 0xBBBB);
+    assert_eq!(system.gpu.borrow_mut().read_vram(11, 10), 0xAAAA);
+    assert_eq!(system.gpu.borrow_mut().read_vram(10, 11), 0xDDDD);
+    assert_eq!(system.gpu.borrow_mut().read_vram(11, 11), 0xCCCC);
 }
 
 #[test]
@@ -198,3 +198,211 @@ fn test_gpu_memory_mirroring() {
     assert_eq!(status1, status2);
     assert_eq!(status2, status3);
 }
+
+#[test]
+fn test_widescreen_toggle() {
+    let mut system = System::new();
+    assert!(!system.widescreen_enabled());
+
+    system.set_widescreen(true);
+    assert!(system.cpu.gte().widescreen());
+    assert!(system.gpu.borrow().widescreen());
+
+    // Survives a reset, since it is a setting rather than hardware state
+    system.reset();
+    assert!(system.widescreen_enabled());
+    assert_eq!(system.gpu.borrow_mut().get_framebuffer().width, 426);
+
+    system.set_widescreen(false);
+    assert!(!system.cpu.gte().widescreen());
+    assert!(!system.gpu.borrow().widescreen());
+}
+
+#[test]
+fn test_precision_geometry_toggle() {
+    let mut system = System::new();
+    assert!(!system.precision_geometry_enabled());
+
+    system.set_precision_geometry(true);
+    assert!(system.cpu.gte().precision_enabled());
+    assert!(system.gpu.borrow().precision_enabled());
+
+    system.set_precision_geometry(false);
+    assert!(!system.gpu.borrow().precision_enabled());
+}
+
+#[test]
+fn test_run_frame_ends_on_vblank_in_pal_mode() {
+    let mut system = System::new();
+
+    // Infinite loop in BIOS: j 0xBFC00000; nop
+    let jump_bytes = 0x0BF00000u32.to_le_bytes();
+    system.bus_mut().write_bios_for_test(0, &jump_bytes);
+    system
+        .bus_mut()
+        .write_bios_for_test(4, &[0x00, 0x00, 0x00, 0x00]);
+    system.reset();
+
+    // First frame ends on the NTSC VBlank
+    system.run_frame().unwrap();
+    assert_eq!(system.cycles(), 566_239);
+    assert!((system.frame_rate() - 59.81).abs() < 0.01);
+
+    // GP1(08h) bit 3 selects PAL
+    system.bus.write32(0x1F801814, 0x08000008).unwrap();
+    assert!((system.frame_rate() - 49.75).abs() < 0.01);
+
+    // The VBlank already scheduled for this frame keeps its NTSC time;
+    // every following frame is a PAL frame
+    system.run_frame().unwrap();
+    assert_eq!(system.cycles(), 2 * 566_239);
+
+    system.run_frame().unwrap();
+    assert_eq!(system.cycles(), 2 * 566_239 + 680_752);
+
+    system.run_frame().unwrap();
+    assert_eq!(system.cycles(), 2 * 566_239 + 2 * 680_752);
+}
+
+#[test]
+fn test_vram_export() {
+    let system = System::new();
+    system.gpu.borrow_mut().write_vram(16, 480, 0x001F);
+
+    let dir = tempfile::tempdir().unwrap();
+    let vram = dir.path().join("vram.ppm");
+    system.export_vram(vram.to_str().unwrap()).unwrap();
+    let data = std::fs::read(&vram).unwrap();
+    assert!(data.starts_with(b"P6\n1024 512\n255\n"));
+    assert_eq!(data.len(), 16 + 1024 * 512 * 3);
+
+    let palettes = dir.path().join("palettes.png");
+    let count = system
+        .export_palettes(palettes.to_str().unwrap(), TextureDepth::T4Bit)
+        .unwrap();
+    assert_eq!(count, 1);
+
+    // A blank palette export is an error rather than an empty image
+    system.gpu.borrow_mut().write_vram(16, 480, 0);
+    assert!(system
+        .export_palettes(palettes.to_str().unwrap(), TextureDepth::T4Bit)
+        .is_err());
+}
+
+#[test]
+fn test_run_frame_rolls_over_gpu_stats() {
+    let mut system = System::new();
+
+    // Infinite loop in BIOS
+    let jump_bytes = 0x0BF00000u32.to_le_bytes();
+    system.bus_mut().write_bios_for_test(0, &jump_bytes);
+    system
+        .bus_mut()
+        .write_bios_for_test(4, &[0x00, 0x00, 0x00, 0x00]);
+    system.reset();
+
+    // Draw a 16x16 rectangle through the GP0 port
+    system.bus.write32(0x1F801810, 0x6000_00FF).unwrap();
+    system.bus.write32(0x1F801810, 0x0000_0000).unwrap();
+    system.bus.write32(0x1F801810, 0x0010_0010).unwrap();
+
+    system.run_frame().unwrap();
+    let gpu = system.gpu.borrow();
+    assert_eq!(gpu.last_frame_stats().rectangles, 1);
+    assert_eq!(gpu.last_frame_stats().estimated_pixels, 16 * 16);
+    assert_eq!(gpu.frame_stats().primitives(), 0);
+}
+
+#[test]
+fn test_run_frame_finishes_drawing() {
+    let mut system = System::new();
+
+    // Infinite loop in BIOS
+    let jump_bytes = 0x0BF00000u32.to_le_bytes();
+    system.bus_mut().write_bios_for_test(0, &jump_bytes);
+    system
+        .bus_mut()
+        .write_bios_for_test(4, &[0x00, 0x00, 0x00, 0x00]);
+    system.reset();
+
+    // Draw a 16x16 rectangle through the GP0 port
+    system.bus.write32(0x1F801810, 0x6000_00FF).unwrap();
+    system.bus.write32(0x1F801810, 0x0000_0000).unwrap();
+    system.bus.write32(0x1F801810, 0x0010_0010).unwrap();
+    assert!(system.gpu.borrow().is_busy());
+
+    system.run_frame().unwrap();
+
+    // Ready for commands and DMA again
+    let gpu = system.gpu.borrow();
+    assert!(!gpu.is_busy());
+    assert_ne!(gpu.status() & (1 << 26), 0);
+    assert_ne!(gpu.status() & (1 << 28), 0);
+}
+
+#[test]
+fn test_run_frame_alternates_interlace_field() {
+    let mut system = System::new();
+
+    // Infinite loop in BIOS
+    let jump_bytes = 0x0BF00000u32.to_le_bytes();
+    system.bus_mut().write_bios_for_test(0, &jump_bytes);
+    system
+        .bus_mut()
+        .write_bios_for_test(4, &[0x00, 0x00, 0x00, 0x00]);
+    system.reset();
+
+    // 640x480 interlaced, drawing to the displayed field prohibited
+    system.bus.write32(0x1F801814, 0x0800_0027).unwrap();
+
+    let mut fields = Vec::new();
+    for _ in 0..4 {
+        // Redraw a 4x4 rectangle every frame
+        system.bus.write32(0x1F801810, 0x6000_00FF).unwrap();
+        system.bus.write32(0x1F801810, 0x0000_0000).unwrap();
+        system.bus.write32(0x1F801810, 0x0004_0004).unwrap();
+
+        system.run_frame().unwrap();
+        fields.push(system.gpu.borrow().interlace_field());
+    }
+
+    assert_eq!(fields, [true, false, true, false]);
+
+    // Both even and odd lines have been drawn
+    let mut gpu = system.gpu.borrow_mut();
+    assert_eq!(gpu.read_vram(0, 0), 0x001F);
+    assert_eq!(gpu.read_vram(0, 1), 0x001F);
+}
+
+#[test]
+fn test_run_frame_records_capture_frames() {
+    let mut system = System::new();
+
+    // Infinite loop in BIOS
+    let jump_bytes = 0x0BF00000u32.to_le_bytes();
+    system.bus_mut().write_bios_for_test(0, &jump_bytes);
+    system
+        .bus_mut()
+        .write_bios_for_test(4, &[0x00, 0x00, 0x00, 0x00]);
+    system.reset();
+
+    system.gpu.borrow_mut().start_capture(2);
+
+    for x in [0u32, 32, 64] {
+        system.bus.write32(0x1F801810, 0x6000_00FF).unwrap();
+        system.bus.write32(0x1F801810, x).unwrap();
+        system.bus.write32(0x1F801810, 0x0010_0010).unwrap();
+        system.run_frame().unwrap();
+    }
+
+    let mut gpu = system.gpu.borrow_mut();
+    assert!(!gpu.capture_in_progress());
+    let capture = gpu.finish_capture().unwrap();
+    assert_eq!(capture.frames(), 2);
+
+    // Only the first two rectangles are part of the capture
+    let mut replayed = capture.replay();
+    assert_eq!(replayed.read_vram(0, 0), 0x001F);
+    assert_eq!(replayed.read_vram(32, 0), 0x001F);
+    assert_eq!(replayed.read_vram(64, 0), 0x0000);
+}
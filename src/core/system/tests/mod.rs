@@ -23,6 +23,7 @@ mod basic;
 mod bios;
 mod controller_integration;
 mod dma_integration;
+mod exe_loading;
 mod execution;
 mod gpu_integration;
 mod interrupt_integration;
@@ -292,6 +292,64 @@ impl TimingEventManager {
         self.update_downcount();
     }
 
+    /// Change the interval of a periodic event
+    ///
+    /// The event keeps its next scheduled run; the new interval applies
+    /// when it is rescheduled after that run.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Event handle
+    /// * `interval` - New interval between executions (in CPU cycles)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::timing::TimingEventManager;
+    ///
+    /// let mut timing = TimingEventManager::new();
+    /// let vblank = timing.register_periodic_event("VBlank", 566_239);
+    /// timing.set_interval(vblank, 680_752); // Switch to PAL timing
+    /// ```
+    pub fn set_interval(&mut self, handle: EventHandle, interval: TickCount) {
+        let event = self
+            .events
+            .iter_mut()
+            .find(|event| event.id == handle)
+            .unwrap_or_else(|| panic!("invalid event handle {}", handle));
+        event.interval = interval;
+    }
+
+    /// Get the number of cycles until an event runs
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Event handle
+    ///
+    /// # Returns
+    ///
+    /// `Some(cycles)` if the event is active (0 if it is due), `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::timing::TimingEventManager;
+    ///
+    /// let mut timing = TimingEventManager::new();
+    /// let event = timing.register_event("Test");
+    /// assert_eq!(timing.time_until(event), None);
+    ///
+    /// timing.schedule(event, 1000);
+    /// assert_eq!(timing.time_until(event), Some(1000));
+    /// ```
+    pub fn time_until(&self, handle: EventHandle) -> Option<GlobalTicks> {
+        let current_time = self.get_current_time();
+        self.events
+            .iter()
+            .find(|event| event.id == handle && event.active)
+            .map(|event| event.next_run_time.saturating_sub(current_time))
+    }
+
     /// Get current time (global_tick_counter + pending_ticks)
     ///
     /// # Returns
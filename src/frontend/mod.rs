@@ -21,6 +21,8 @@
 //! - Framebuffer rendering (GPU → Screen)
 //! - FPS counter and status display
 //! - Main emulation loop timing
+//! - Runtime settings (F9: toggle widescreen)
+//! - Debug dumps (F10: save VRAM as PNG)
 //!
 //! # Architecture
 //!
@@ -84,7 +86,7 @@ impl FrontendState {
 /// Frontend for the PlayStation emulator
 ///
 /// Provides a Slint-based UI that displays the GPU framebuffer and status information.
-/// Runs the emulation loop at approximately 60 FPS.
+/// Runs the emulation loop at the emulated refresh rate (50 or 60 FPS).
 pub struct Frontend {
     /// Slint window instance
     window: MainWindow,
@@ -128,9 +130,32 @@ impl Frontend {
         // Enable debug mode by default to see GPU/CPU info
         window.set_debug_mode(true);
         window.set_running(true);
+        window.set_widescreen(system.widescreen_enabled());
 
         let state = Rc::new(RefCell::new(FrontendState::new(system)));
 
+        // F9 toggles the widescreen hack
+        let window_weak = window.as_weak();
+        let state_rc = state.clone();
+        window.on_toggle_widescreen(move || {
+            let mut state = state_rc.borrow_mut();
+            let enabled = !state.system.widescreen_enabled();
+            state.system.set_widescreen(enabled);
+
+            if let Some(window) = window_weak.upgrade() {
+                window.set_widescreen(enabled);
+            }
+        });
+
+        // F10 saves VRAM, named by the current time
+        let state_rc = state.clone();
+        window.on_dump_vram(move || {
+            let path = format!("vram-{}.png", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+            if let Err(e) = state_rc.borrow().system.export_vram(&path) {
+                log::error!("Failed to export VRAM: {}", e);
+            }
+        });
+
         Self { window, state }
     }
 
@@ -247,12 +272,15 @@ impl Frontend {
             }
         }
 
-        // Create timer for emulation loop (60 FPS = ~16.67ms per frame)
-        let timer = Timer::default();
+        // Create timer for emulation loop, paced at the emulated refresh rate
+        // (~59.81 Hz NTSC, ~49.75 Hz PAL)
+        let timer = Rc::new(Timer::default());
+        let timer_weak = Rc::downgrade(&timer);
         let window_weak = self.window.as_weak();
         let state_rc = self.state.clone();
+        let frame_interval = Self::frame_interval(&self.state.borrow().system);
 
-        timer.start(TimerMode::Repeated, Duration::from_millis(16), move || {
+        timer.start(TimerMode::Repeated, frame_interval, move || {
             let frame_start = Instant::now();
 
             // Run one frame of emulation
@@ -265,22 +293,34 @@ impl Frontend {
                 return;
             }
 
+            // Follow NTSC/PAL switches made by the game
+            let frame_interval = Self::frame_interval(&state.system);
+            if let Some(timer) = timer_weak.upgrade() {
+                if timer.interval() != frame_interval {
+                    log::info!("Frame pacing set to {:.2} Hz", state.system.frame_rate());
+                    timer.set_interval(frame_interval);
+                }
+            }
+
             // Get framebuffer from GPU
             let gpu = state.system.gpu();
-            let framebuffer = gpu.borrow().get_framebuffer();
-            let display_area = gpu.borrow().display_area();
+            let framebuffer = gpu.borrow_mut().get_framebuffer();
             let gpu_status = gpu.borrow().status();
+            let gpu_stats = *gpu.borrow().last_frame_stats();
             drop(gpu);
 
-            let width = display_area.width as usize;
-            let height = display_area.height as usize;
-
-            // Convert to Slint image
-            let image = Self::framebuffer_to_image(&framebuffer, width, height);
+            // Convert to Slint image (sized from the framebuffer, which is
+            // wider than the display area in widescreen mode)
+            let image = Self::framebuffer_to_image(
+                &framebuffer.pixels,
+                framebuffer.width,
+                framebuffer.height,
+            );
 
             // Update display
             if let Some(window) = window_weak.upgrade() {
                 window.set_framebuffer(image);
+                window.set_display_aspect(framebuffer.display_aspect_ratio());
 
                 // Update FPS counter
                 state.frame_count += 1;
@@ -298,6 +338,7 @@ impl Frontend {
                 let pc = state.system.pc();
                 window.set_cpu_pc(format!("PC: 0x{:08X}", pc).into());
                 window.set_gpu_status(format!("GPU: 0x{:08X}", gpu_status).into());
+                window.set_gpu_stats(gpu_stats.to_string().into());
 
                 // Track frame time
                 let frame_time = frame_start.elapsed();
@@ -338,6 +379,14 @@ impl Frontend {
         Ok(())
     }
 
+    /// Get the host timer interval for one emulated frame
+    ///
+    /// # Arguments
+    /// * `system` - Emulated system (its video mode selects 50 or 60 Hz)
+    fn frame_interval(system: &System) -> Duration {
+        Duration::from_secs_f64(1.0 / system.frame_rate())
+    }
+
     /// Log performance metrics
     ///
     /// Logs average frame time and FPS every 5 seconds
@@ -52,7 +52,7 @@ pub fn assert_memory_word(bus: &psrx::core::memory::Bus, addr: u32, expected: u3
 
 /// Assert VRAM pixel has expected color
 #[allow(dead_code)]
-pub fn assert_vram_pixel(gpu: &psrx::core::gpu::GPU, x: u16, y: u16, expected: u16) {
+pub fn assert_vram_pixel(gpu: &mut psrx::core::gpu::GPU, x: u16, y: u16, expected: u16) {
     let actual = gpu.read_vram(x, y);
     assert_eq!(
         actual, expected,
@@ -58,3 +58,118 @@ pub fn execute_n_instructions(cpu: &mut CPU, bus: &mut Bus, n: usize) {
         let _ = cpu.step(bus);
     }
 }
+
+/// Build a PSX-EXE file with the given header fields and code
+///
+/// The code is padded to a multiple of 2048 bytes as required by the format.
+#[allow(dead_code)]
+pub fn build_psx_exe(pc: u32, gp: u32, load_address: u32, stack_base: u32, code: &[u8]) -> Vec<u8> {
+    let load_size = code.len().div_ceil(2048) * 2048;
+    let mut exe = vec![0u8; 0x800 + load_size];
+
+    exe[0..8].copy_from_slice(b"PS-X EXE");
+    exe[0x10..0x14].copy_from_slice(&pc.to_le_bytes());
+    exe[0x14..0x18].copy_from_slice(&gp.to_le_bytes());
+    exe[0x18..0x1C].copy_from_slice(&load_address.to_le_bytes());
+    exe[0x1C..0x20].copy_from_slice(&(load_size as u32).to_le_bytes());
+    exe[0x30..0x34].copy_from_slice(&stack_base.to_le_bytes());
+    exe[0x800..0x800 + code.len()].copy_from_slice(code);
+    exe
+}
+
+/// Load a BIOS that jumps straight to the shell entry point
+///
+/// Lets executables be booted without a real BIOS image. The BIOS file is
+/// written to a temporary directory and removed once it is loaded.
+#[allow(dead_code)]
+pub fn load_fast_boot_bios(system: &mut System) {
+    // lui $t0, 0x8003 ; jr $t0 ; nop
+    let program: [u32; 3] = [0x3C088003, 0x01000008, 0x00000000];
+    let mut bios = vec![0u8; 512 * 1024];
+    for (word, bytes) in program.iter().zip(bios.chunks_exact_mut(4)) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bios.bin");
+    std::fs::write(&path, &bios).unwrap();
+    system.load_bios(path.to_str().unwrap()).unwrap();
+}
+
+/// Build a Mode 2 disc image with an ISO9660 filesystem
+///
+/// All files are placed in the root directory. The image is written to
+/// `game.bin`/`game.cue` inside a temporary directory, which must be kept
+/// alive while the disc is in use.
+///
+/// # Returns
+///
+/// The temporary directory and the path of the .cue file
+#[allow(dead_code)]
+pub fn build_test_disc(files: &[(&str, &[u8])]) -> (tempfile::TempDir, String) {
+    const SECTOR_SIZE: usize = 2352;
+    const ROOT_LBA: usize = 18;
+
+    fn dir_record(name: &[u8], lba: u32, size: u32, is_dir: bool) -> Vec<u8> {
+        let length = (33 + name.len() + 1) & !1;
+        let mut record = vec![0u8; length];
+        record[0] = length as u8;
+        record[2..6].copy_from_slice(&lba.to_le_bytes());
+        record[10..14].copy_from_slice(&size.to_le_bytes());
+        record[25] = if is_dir { 0x02 } else { 0x00 };
+        record[32] = name.len() as u8;
+        record[33..33 + name.len()].copy_from_slice(name);
+        record
+    }
+
+    fn write_user_data(image: &mut Vec<u8>, lba: usize, data: &[u8]) {
+        for (i, chunk) in data.chunks(2048).enumerate() {
+            let offset = (lba + i) * SECTOR_SIZE + 24;
+            if image.len() < (lba + i + 1) * SECTOR_SIZE {
+                image.resize((lba + i + 1) * SECTOR_SIZE, 0);
+            }
+            image[offset..offset + chunk.len()].copy_from_slice(chunk);
+        }
+    }
+
+    let mut image = Vec::new();
+
+    // Primary Volume Descriptor
+    let mut pvd = vec![0u8; 2048];
+    pvd[0] = 0x01;
+    pvd[1..6].copy_from_slice(b"CD001");
+    let root = dir_record(&[0x00], ROOT_LBA as u32, 2048, true);
+    pvd[156..156 + root.len()].copy_from_slice(&root);
+    write_user_data(&mut image, 16, &pvd);
+
+    // Root directory and file data
+    let mut root_dir = Vec::new();
+    root_dir.extend(dir_record(&[0x00], ROOT_LBA as u32, 2048, true));
+    root_dir.extend(dir_record(&[0x01], ROOT_LBA as u32, 2048, true));
+
+    let mut next_lba = ROOT_LBA + 1;
+    for (name, data) in files {
+        let record_name = format!("{};1", name);
+        root_dir.extend(dir_record(
+            record_name.as_bytes(),
+            next_lba as u32,
+            data.len() as u32,
+            false,
+        ));
+        write_user_data(&mut image, next_lba, data);
+        next_lba += data.len().div_ceil(2048).max(1);
+    }
+    write_user_data(&mut image, ROOT_LBA, &root_dir);
+    image.resize(next_lba * SECTOR_SIZE, 0);
+
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    std::fs::write(dir.path().join("game.bin"), &image).expect("Failed to write .bin");
+    let cue_path = dir.path().join("game.cue");
+    std::fs::write(
+        &cue_path,
+        "FILE \"game.bin\" BINARY\n  TRACK 01 MODE2/2352\n    INDEX 01 00:02:00\n",
+    )
+    .expect("Failed to write .cue");
+
+    (dir, cue_path.to_str().unwrap().to_string())
+}
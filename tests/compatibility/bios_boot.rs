@@ -161,7 +161,7 @@ fn test_logo_display() {
 
     // Get framebuffer
     let gpu = system.gpu();
-    let framebuffer = gpu.borrow().get_framebuffer();
+    let framebuffer = gpu.borrow_mut().get_framebuffer().pixels;
 
     // Verify framebuffer is not all black
     // Count non-black pixels (RGB values not all zero)
@@ -21,3 +21,6 @@ mod common;
 
 #[path = "system/initialization.rs"]
 mod initialization;
+
+#[path = "system/game_loading.rs"]
+mod game_loading;
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Game loading tests
+//!
+//! Tests that verify booting an executable from a disc image's ISO9660 filesystem.
+
+use crate::common::fixtures::{build_psx_exe, build_test_disc, load_fast_boot_bios};
+use psrx::core::system::System;
+
+#[test]
+fn test_load_game_sets_up_executable() {
+    let code = [0x78u8, 0x56, 0x34, 0x12];
+    let exe = build_psx_exe(0x80010000, 0x80020000, 0x80010000, 0x801FFFF0, &code);
+    let (_dir, cue_path) = build_test_disc(&[
+        ("SYSTEM.CNF", b"BOOT = cdrom:\\GAME.EXE;1\r\nTCB = 4\r\n"),
+        ("GAME.EXE", &exe),
+    ]);
+
+    let mut system = System::new();
+    load_fast_boot_bios(&mut system);
+    system.load_game(&cue_path).unwrap();
+
+    assert_eq!(system.pc(), 0x80010000);
+    assert_eq!(system.cpu().reg(28), 0x80020000);
+    assert_eq!(system.cpu().reg(29), 0x801FFFF0);
+    assert_eq!(system.cpu().reg(30), 0x801FFFF0);
+    assert_eq!(system.bus().read32(0x80010000).unwrap(), 0x12345678);
+}
+
+#[test]
+fn test_load_game_uses_system_cnf_stack() {
+    let exe = build_psx_exe(0x80010000, 0, 0x80010000, 0x801FFFF0, &[0; 4]);
+    let (_dir, cue_path) = build_test_disc(&[
+        ("SYSTEM.CNF", b"BOOT = cdrom:GAME.EXE;1\nSTACK = 801FFF00\n"),
+        ("GAME.EXE", &exe),
+    ]);
+
+    let mut system = System::new();
+    load_fast_boot_bios(&mut system);
+    system.load_game(&cue_path).unwrap();
+
+    // Default STACK value defers to the executable header
+    assert_eq!(system.cpu().reg(29), 0x801FFFF0);
+
+    let (_dir, cue_path) = build_test_disc(&[
+        ("SYSTEM.CNF", b"BOOT = cdrom:GAME.EXE;1\nSTACK = 801FF000\n"),
+        ("GAME.EXE", &exe),
+    ]);
+    let mut system = System::new();
+    load_fast_boot_bios(&mut system);
+    system.load_game(&cue_path).unwrap();
+    assert_eq!(system.cpu().reg(29), 0x801FF000);
+}
+
+#[test]
+fn test_load_game_missing_executable() {
+    let (_dir, cue_path) = build_test_disc(&[("SYSTEM.CNF", b"BOOT = cdrom:\\MISSING.EXE;1\n")]);
+
+    let mut system = System::new();
+    assert!(system.load_game(&cue_path).is_err());
+}